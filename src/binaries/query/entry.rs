@@ -282,6 +282,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
     if conf.log.structlog.on {
         println!("    structlog: {}", conf.log.structlog);
     }
+    if conf.log.history.on {
+        println!("    history: {}", conf.log.history);
+    }
 
     println!();
     println!(
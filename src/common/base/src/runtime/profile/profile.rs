@@ -15,6 +15,8 @@
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Instant;
 
 use parking_lot::Mutex;
 
@@ -48,6 +50,8 @@ pub struct Profile {
     pub title: Arc<String>,
 
     pub statistics: [AtomicUsize; std::mem::variant_count::<ProfileStatisticsName>()],
+    /// The time of the first processing, in the nanoseconds of `Profile::now_nanos`.
+    pub first_process_nanos: AtomicUsize,
     pub metrics_registry: Option<Arc<ScopedRegistry>>,
     pub errors: Arc<Mutex<Vec<NodeErrorType>>>,
 }
@@ -66,6 +70,7 @@ impl Clone for Profile {
             statistics: std::array::from_fn(|idx| {
                 AtomicUsize::new(self.statistics[idx].load(Ordering::SeqCst))
             }),
+            first_process_nanos: AtomicUsize::new(self.first_process_nanos.load(Ordering::SeqCst)),
             errors: self.errors.clone(),
         }
     }
@@ -94,6 +99,7 @@ impl Profile {
             title,
             labels,
             statistics: Self::create_items(),
+            first_process_nanos: AtomicUsize::new(usize::MAX),
             metrics_registry,
             errors: Arc::new(Mutex::new(vec![])),
         }
@@ -108,6 +114,40 @@ impl Profile {
         });
     }
 
+    /// Whether the processor of the current thread belongs to a plan, whose profile
+    /// is reported, the costly statistics are only collected if so.
+    pub fn is_plan_profiled() -> bool {
+        ThreadTracker::with(|x| {
+            x.borrow()
+                .payload
+                .profile
+                .as_ref()
+                .is_some_and(|profile| profile.plan_id.is_some())
+        })
+    }
+
+    /// The nanoseconds elapsed since the first call, the clock of the processing times.
+    pub fn now_nanos() -> usize {
+        static START: LazyLock<Instant> = LazyLock::new(Instant::now);
+        START.elapsed().as_nanos() as usize
+    }
+
+    /// Records that the processor of the current thread processed from `start` to `end`,
+    /// the wall time of the processor is from its first start to its last end.
+    pub fn record_process_time(start: usize, end: usize) {
+        ThreadTracker::with(|x| match x.borrow().payload.profile.as_ref() {
+            None => {}
+            Some(profile) => {
+                let first_start = profile
+                    .first_process_nanos
+                    .fetch_min(start, Ordering::SeqCst)
+                    .min(start);
+                profile.statistics[ProfileStatisticsName::WallTime as usize]
+                    .fetch_max(end.saturating_sub(first_start), Ordering::SeqCst);
+            }
+        });
+    }
+
     pub fn load_profile(&self, name: ProfileStatisticsName) -> usize {
         self.statistics[name as usize].load(Ordering::SeqCst)
     }
@@ -58,6 +58,17 @@ pub enum ProfileStatisticsName {
     MemoryUsage,
     ExternalServerRetryCount,
     ExternalServerRequestCount,
+    // The statistics are positional arrays shared across nodes and persisted profiles,
+    // the new statistics must be appended.
+    /// The time from the first to the last processing in nanoseconds,
+    /// the max of the processors of the plan as they run in parallel
+    WallTime,
+
+    AggregateFlushRows,
+    AggregateFlushBytes,
+    AggregateFlushTime,
+
+    BitmapSemiJoinBuildKeys,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, Debug)]
@@ -69,6 +80,18 @@ pub enum StatisticsUnit {
     Count,
 }
 
+impl ProfileStatisticsName {
+    /// Merges two values of the statistics at `index` of the same plan, the values of
+    /// `WallTime` are merged by max, while the others, like `CpuTime`, are summed.
+    pub fn merge_value(index: usize, lhs: usize, rhs: usize) -> usize {
+        if index == ProfileStatisticsName::WallTime as usize {
+            lhs.max(rhs)
+        } else {
+            lhs + rhs
+        }
+    }
+}
+
 impl Display for ProfileStatisticsName {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -312,6 +335,41 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 unit: StatisticsUnit::Count,
                 plain_statistics: true,
             }),
+            (ProfileStatisticsName::WallTime, ProfileDesc {
+                display_name: "wall time",
+                desc: "The time from the first to the last processing in nanoseconds, the max of the parallel processors",
+                index: ProfileStatisticsName::WallTime as usize,
+                unit: StatisticsUnit::NanoSeconds,
+                plain_statistics: false,
+            }),
+            (ProfileStatisticsName::AggregateFlushRows, ProfileDesc {
+                display_name: "aggregate flush rows",
+                desc: "The number of the group rows flushed from the aggregate hash tables",
+                index: ProfileStatisticsName::AggregateFlushRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::AggregateFlushBytes, ProfileDesc {
+                display_name: "aggregate flush bytes",
+                desc: "The bytes of the group columns flushed from the aggregate hash tables",
+                index: ProfileStatisticsName::AggregateFlushBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::AggregateFlushTime, ProfileDesc {
+                display_name: "aggregate flush time",
+                desc: "The time spent to flush the group columns from the aggregate hash tables in nanoseconds",
+                index: ProfileStatisticsName::AggregateFlushTime as usize,
+                unit: StatisticsUnit::NanoSeconds,
+                plain_statistics: false,
+            }),
+            (ProfileStatisticsName::BitmapSemiJoinBuildKeys, ProfileDesc {
+                display_name: "bitmap semi join build keys",
+                desc: "The number of the build keys of the semi joins probed with a bitmap instead of a hash table",
+                index: ProfileStatisticsName::BitmapSemiJoinBuildKeys as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
         ]))
     }).clone()
 }
@@ -180,6 +180,9 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when the written rows don't satisfy
+    /// a check constraint of the table.
+    CheckConstraintViolated(1304),
 
     // License related errors starts here
 
@@ -31,6 +31,7 @@ pub struct Config {
     pub profile: ProfileLogConfig,
     pub structlog: StructLogConfig,
     pub tracing: TracingConfig,
+    pub history: HistoryLogConfig,
 }
 
 impl Config {
@@ -224,6 +225,35 @@ impl Default for StructLogConfig {
     }
 }
 
+/// Persist the query log into `system_history.query_history`, a fuse table
+/// which is flushed every `interval` seconds and keeps `retention` hours of logs.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct HistoryLogConfig {
+    pub on: bool,
+    pub interval: u64,
+    pub retention: u64,
+}
+
+impl Display for HistoryLogConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, interval={}s, retention={}h",
+            self.on, self.interval, self.retention
+        )
+    }
+}
+
+impl Default for HistoryLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            interval: 15,
+            retention: 168,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
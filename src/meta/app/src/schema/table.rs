@@ -279,6 +279,7 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    pub constraints: BTreeMap<String, TableConstraint>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -296,6 +297,48 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+/// A constraint declared on the table, only the check constraints are enforced
+/// when the data is written, the others are informational.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableConstraint {
+    pub name: String,
+    pub constraint_type: TableConstraintType,
+    pub column_ids: Vec<u32>,
+    // the referenced table and columns of a foreign key constraint.
+    pub reference_table: String,
+    pub reference_columns: Vec<String>,
+    // the boolean expression of a check constraint.
+    pub check_expr: Option<String>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableConstraintType {
+    PrimaryKey = 0,
+    Unique = 1,
+    ForeignKey = 2,
+    Check = 3,
+}
+
+impl Display for TableConstraintType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableConstraintType::PrimaryKey => write!(f, "PRIMARY KEY"),
+            TableConstraintType::Unique => write!(f, "UNIQUE"),
+            TableConstraintType::ForeignKey => write!(f, "FOREIGN KEY"),
+            TableConstraintType::Check => write!(f, "CHECK"),
+        }
+    }
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -437,6 +480,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            constraints: BTreeMap::new(),
         }
     }
 }
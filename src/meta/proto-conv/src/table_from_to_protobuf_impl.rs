@@ -27,6 +27,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -192,6 +193,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in p.constraints {
+            constraints.insert(name, mt::TableConstraint::from_pb(constraint)?);
+        }
+
         let cluster_key_seq = if let Some(seq) = p.cluster_key_seq {
             seq
         } else if p.cluster_keys.is_empty() {
@@ -232,6 +238,7 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            constraints,
         };
         Ok(v)
     }
@@ -241,6 +248,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in &self.constraints {
+            constraints.insert(name.clone(), constraint.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -273,6 +284,46 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            constraints,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            constraint_type: FromPrimitive::from_i32(p.constraint_type).ok_or_else(|| {
+                Incompatible::new(format!(
+                    "invalid TableConstraintType: {}",
+                    p.constraint_type
+                ))
+            })?,
+            column_ids: p.column_ids,
+            reference_table: p.reference_table,
+            reference_columns: p.reference_columns,
+            check_expr: p.check_expr,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let p = pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            constraint_type: self.constraint_type as i32,
+            column_ids: self.column_ids.clone(),
+            reference_table: self.reference_table.clone(),
+            reference_columns: self.reference_columns.clone(),
+            check_expr: self.check_expr.clone(),
         };
         Ok(p)
     }
@@ -151,6 +151,8 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (119, "2025-01-25: Add: virtual_column add alias_names and auto_generated field"),
     (120, "2025-02-11: Add: Add new UserPrivilege CreateWarehouse and new OwnershipObject::Warehouse"),
     (121, "2025-03-03: Add: Add new FileFormat AvroFileFormatParams"),
+    (122, "2025-03-10: Add: table.proto: add TableConstraint and TableMeta.constraints"),
+    (123, "2025-03-12: Add: table.proto: add TableConstraint.check_expr and the CHECK constraint type"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -144,6 +144,7 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        constraints: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
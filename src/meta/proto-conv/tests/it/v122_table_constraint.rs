@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableConstraintType;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v122_table_constraint() -> anyhow::Result<()> {
+    let table_constraint_v122 = vec![
+        10, 4, 102, 107, 95, 97, 16, 2, 26, 2, 0, 1, 34, 6, 100, 98, 49, 46, 116, 50, 42, 1, 120,
+        42, 1, 121, 160, 6, 122, 168, 6, 24,
+    ];
+
+    let want = || TableConstraint {
+        name: "fk_a".to_string(),
+        constraint_type: TableConstraintType::ForeignKey,
+        column_ids: vec![0, 1],
+        reference_table: "db1.t2".to_string(),
+        reference_columns: vec!["x".to_string(), "y".to_string()],
+        check_expr: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_constraint_v122.as_slice(), 122, want())?;
+
+    Ok(())
+}
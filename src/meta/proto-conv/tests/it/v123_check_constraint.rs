@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableConstraintType;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v123_check_constraint() -> anyhow::Result<()> {
+    let check_constraint_v123 = vec![
+        10, 7, 99, 104, 107, 95, 97, 103, 101, 16, 3, 26, 1, 1, 50, 7, 97, 103, 101, 32, 62, 32,
+        48, 160, 6, 123, 168, 6, 24,
+    ];
+
+    let want = || TableConstraint {
+        name: "chk_age".to_string(),
+        constraint_type: TableConstraintType::Check,
+        column_ids: vec![1],
+        reference_table: "".to_string(),
+        reference_columns: vec![],
+        check_expr: Some("age > 0".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), check_constraint_v123.as_slice(), 123, want())?;
+
+    Ok(())
+}
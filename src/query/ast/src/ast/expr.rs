@@ -85,13 +85,15 @@ pub enum Expr {
         subquery: Box<Query>,
         not: bool,
     },
-    /// `BETWEEN ... AND ...`
+    /// `BETWEEN [ SYMMETRIC ] ... AND ...`
     Between {
         span: Span,
         expr: Box<Expr>,
         low: Box<Expr>,
         high: Box<Expr>,
         not: bool,
+        /// The bounds may be given in any order.
+        symmetric: bool,
     },
     /// Binary operation
     BinaryOp {
@@ -580,13 +582,18 @@ impl Display for Expr {
                     low,
                     high,
                     not,
+                    symmetric,
                     ..
                 } => {
                     write_expr(expr, Some(affix), true, f)?;
                     if *not {
                         write!(f, " NOT")?;
                     }
-                    write!(f, " BETWEEN {low} AND {high}")?;
+                    write!(f, " BETWEEN")?;
+                    if *symmetric {
+                        write!(f, " SYMMETRIC")?;
+                    }
+                    write!(f, " {low} AND {high}")?;
                 }
                 Expr::UnaryOp { op, expr, .. } => {
                     match op {
@@ -1319,7 +1326,10 @@ impl Display for WindowSpec {
                 " BETWEEN {} AND {}",
                 format_frame(&frame.start_bound),
                 format_frame(&frame.end_bound)
-            )?
+            )?;
+            if let Some(exclusion) = &frame.exclusion {
+                write!(f, " {exclusion}")?;
+            }
         }
         write!(f, ")")?;
         Ok(())
@@ -1332,6 +1342,7 @@ pub struct WindowFrame {
     pub units: WindowFrameUnits,
     pub start_bound: WindowFrameBound,
     pub end_bound: WindowFrameBound,
+    pub exclusion: Option<FrameExclusion>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EnumAsInner, Drive, DriveMut)]
@@ -1351,6 +1362,30 @@ pub enum WindowFrameBound {
     Following(Option<Box<Expr>>),
 }
 
+/// Specifies which rows are excluded from [WindowFrame], e.g. `EXCLUDE CURRENT ROW`.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum FrameExclusion {
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`, the current row and its peers.
+    Group,
+    /// `EXCLUDE TIES`, the peers of the current row but not the current row itself.
+    Ties,
+    /// `EXCLUDE NO OTHERS`
+    NoOthers,
+}
+
+impl Display for FrameExclusion {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            FrameExclusion::CurrentRow => write!(f, "EXCLUDE CURRENT ROW"),
+            FrameExclusion::Group => write!(f, "EXCLUDE GROUP"),
+            FrameExclusion::Ties => write!(f, "EXCLUDE TIES"),
+            FrameExclusion::NoOthers => write!(f, "EXCLUDE NO OTHERS"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Lambda {
     pub params: Vec<Identifier>,
@@ -1766,7 +1801,9 @@ impl ExprReplacer {
     }
 
     fn replace_pivot(&self, pivot: &mut Pivot) {
-        self.replace_expr(&mut pivot.aggregate);
+        for aggregate in pivot.aggregates.iter_mut() {
+            self.replace_expr(aggregate);
+        }
         match &mut pivot.values {
             PivotValues::ColumnValues(exprs) => {
                 for expr in exprs.iter_mut() {
@@ -1837,6 +1874,25 @@ impl ExprReplacer {
                 self.replace_table_table_reference(&mut join.right);
             }
             TableReference::Location { .. } => (),
+            TableReference::MatchRecognize {
+                input,
+                match_recognize,
+                ..
+            } => {
+                self.replace_table_table_reference(input);
+                for expr in match_recognize.partition_by.iter_mut() {
+                    self.replace_expr(expr);
+                }
+                for order_by in match_recognize.order_by.iter_mut() {
+                    self.replace_expr(&mut order_by.expr);
+                }
+                for measure in match_recognize.measures.iter_mut() {
+                    self.replace_expr(&mut measure.expr);
+                }
+                for define in match_recognize.defines.iter_mut() {
+                    self.replace_expr(&mut define.condition);
+                }
+            }
         }
     }
 
@@ -1902,6 +1958,12 @@ impl ExprReplacer {
                     }
                 }
 
+                if let Some(distinct_on) = &mut select.distinct_on {
+                    for expr in distinct_on.iter_mut() {
+                        self.replace_expr(expr);
+                    }
+                }
+
                 for select_list_item in select.select_list.iter_mut() {
                     match select_list_item {
                         SelectTarget::AliasedExpr { expr, .. } => {
@@ -115,6 +115,7 @@ pub struct CTE {
     pub alias: TableAlias,
     pub materialized: bool,
     pub query: Box<Query>,
+    pub cycle: Option<CteCycle>,
 }
 
 impl Display for CTE {
@@ -125,10 +126,30 @@ impl Display for CTE {
             write!(f, "MATERIALIZED ")?;
         }
         write!(f, "({})", self.query)?;
+        if let Some(cycle) = &self.cycle {
+            write!(f, " {cycle}")?;
+        }
         Ok(())
     }
 }
 
+/// `CYCLE <columns> SET <mark_column> USING <path_column>` clause of a recursive CTE.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CteCycle {
+    pub span: Span,
+    pub columns: Vec<Identifier>,
+    pub mark_column: Identifier,
+    pub path_column: Identifier,
+}
+
+impl Display for CteCycle {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CYCLE ")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, " SET {} USING {}", self.mark_column, self.path_column)
+    }
+}
+
 #[derive(Educe, Drive, DriveMut)]
 #[educe(
     PartialEq(bound = false, attrs = "#[recursive::recursive]"),
@@ -149,6 +170,8 @@ pub struct SelectStmt {
     pub span: Span,
     pub hints: Option<Hint>,
     pub distinct: bool,
+    // `DISTINCT ON (expr, ...)`, keeps the first row of each group of the expressions
+    pub distinct_on: Option<Vec<Expr>>,
     pub top_n: Option<u64>,
     // Result set of current subquery
     pub select_list: Vec<SelectTarget>,
@@ -178,6 +201,11 @@ impl Display for SelectStmt {
         if self.distinct {
             write!(f, "DISTINCT ")?;
         }
+        if let Some(distinct_on) = &self.distinct_on {
+            write!(f, "DISTINCT ON (")?;
+            write_comma_separated_list(f, distinct_on)?;
+            write!(f, ") ")?;
+        }
         if let Some(topn) = &self.top_n {
             write!(f, "TOP {} ", topn)?;
         }
@@ -609,14 +637,16 @@ pub enum PivotValues {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Pivot {
-    pub aggregate: Expr,
+    pub aggregates: Vec<Expr>,
     pub value_column: Identifier,
     pub values: PivotValues,
 }
 
 impl Display for Pivot {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "PIVOT({} FOR {} IN (", self.aggregate, self.value_column)?;
+        write!(f, "PIVOT(")?;
+        write_comma_separated_list(f, &self.aggregates)?;
+        write!(f, " FOR {} IN (", self.value_column)?;
         match &self.values {
             PivotValues::ColumnValues(column_values) => {
                 write_comma_separated_list(f, column_values)?;
@@ -650,6 +680,164 @@ impl Display for Unpivot {
     }
 }
 
+/// `MATCH_RECOGNIZE(...)` row pattern recognition clause
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub measures: Vec<MatchMeasure>,
+    pub pattern: RowPattern,
+    pub defines: Vec<MatchDefine>,
+}
+
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MATCH_RECOGNIZE(")?;
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, " ")?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+            write!(f, " ")?;
+        }
+        if !self.measures.is_empty() {
+            write!(f, "MEASURES ")?;
+            write_comma_separated_list(f, &self.measures)?;
+            write!(f, " ")?;
+        }
+        write!(f, "PATTERN ({})", self.pattern)?;
+        if !self.defines.is_empty() {
+            write!(f, " DEFINE ")?;
+            write_comma_separated_list(f, &self.defines)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// `<expr> AS <alias>` in the `MEASURES` clause
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchMeasure {
+    pub expr: Expr,
+    pub alias: Identifier,
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.alias)
+    }
+}
+
+/// `<variable> AS <condition>` in the `DEFINE` clause
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchDefine {
+    pub variable: Identifier,
+    pub condition: Expr,
+}
+
+impl Display for MatchDefine {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.variable, self.condition)
+    }
+}
+
+/// The row pattern of `MATCH_RECOGNIZE`, a regular expression over pattern variables.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum RowPattern {
+    Variable(Identifier),
+    /// `^`, the start of a partition
+    Start,
+    /// `$`, the end of a partition
+    End,
+    Concat(Vec<RowPattern>),
+    Alternation(Vec<RowPattern>),
+    Group(Box<RowPattern>),
+    Quantified {
+        pattern: Box<RowPattern>,
+        quantifier: RowPatternQuantifier,
+        reluctant: bool,
+    },
+}
+
+impl Display for RowPattern {
+    #[recursive::recursive]
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RowPattern::Variable(variable) => write!(f, "{variable}"),
+            RowPattern::Start => write!(f, "^"),
+            RowPattern::End => write!(f, "$"),
+            RowPattern::Concat(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            RowPattern::Alternation(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            RowPattern::Group(pattern) => write!(f, "({pattern})"),
+            RowPattern::Quantified {
+                pattern,
+                quantifier,
+                reluctant,
+            } => {
+                write!(f, "{pattern}{quantifier}")?;
+                if *reluctant {
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum RowPatternQuantifier {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+    /// `{n}`, `{n,}`, `{,m}` or `{n,m}`
+    Range { min: Option<u64>, max: Option<u64> },
+}
+
+impl Display for RowPatternQuantifier {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RowPatternQuantifier::ZeroOrMore => write!(f, "*"),
+            RowPatternQuantifier::OneOrMore => write!(f, "+"),
+            RowPatternQuantifier::ZeroOrOne => write!(f, "?"),
+            RowPatternQuantifier::Range { min, max } if min == max => {
+                write!(f, "{{{}}}", min.unwrap_or_default())
+            }
+            RowPatternQuantifier::Range { min, max } => {
+                write!(f, "{{")?;
+                if let Some(min) = min {
+                    write!(f, "{min}")?;
+                }
+                write!(f, ",")?;
+                if let Some(max) = max {
+                    write!(f, "{max}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
 pub struct WithOptions {
     pub options: BTreeMap<String, String>,
@@ -856,6 +1044,13 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `<table_ref> MATCH_RECOGNIZE(...) [ AS alias ]`
+    MatchRecognize {
+        span: Span,
+        input: Box<TableReference>,
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -1050,6 +1245,17 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::MatchRecognize {
+                span: _,
+                input,
+                match_recognize,
+                alias,
+            } => {
+                write!(f, "{input} {match_recognize}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
@@ -79,6 +79,7 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::DisableVariantCheck(v) => self.options.disable_variant_check = v,
             CopyIntoTableOption::ReturnFailedOnly(v) => self.options.return_failed_only = v,
             CopyIntoTableOption::OnError(v) => self.options.on_error = OnErrorMode::from_str(&v)?,
+            CopyIntoTableOption::ValidationMode(v) => self.options.validation_mode = v,
             CopyIntoTableOption::ColumnMatchMode(v) => {
                 self.options.column_match_mode = Some(ColumnMatchMode::from_str(&v)?)
             }
@@ -574,6 +575,7 @@ pub enum CopyIntoTableOption {
     DisableVariantCheck(bool),
     ReturnFailedOnly(bool),
     OnError(String),
+    ValidationMode(String),
     ColumnMatchMode(String),
 }
 
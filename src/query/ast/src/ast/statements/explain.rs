@@ -50,4 +50,5 @@ pub enum ExplainOption {
     Logical,
     Optimized,
     Decorrelated,
+    Decisions,
 }
@@ -176,6 +176,7 @@ pub enum Statement {
     VacuumDropTable(VacuumDropTableStmt),
     VacuumTemporaryFiles(VacuumTemporaryFiles),
     AnalyzeTable(AnalyzeTableStmt),
+    ChecksumTable(ChecksumTableStmt),
     ExistsTable(ExistsTableStmt),
 
     // Dictionaries
@@ -452,6 +453,7 @@ impl Statement {
             | Statement::VacuumDropTable(..)
             | Statement::VacuumTemporaryFiles(..)
             | Statement::AnalyzeTable(..)
+            | Statement::ChecksumTable(..)
             | Statement::ExistsTable(..)
             | Statement::ShowCreateDictionary(..)
             | Statement::ShowDictionaries(..)
@@ -610,6 +612,7 @@ impl Display for Statement {
                                     ExplainOption::Logical => "LOGICAL",
                                     ExplainOption::Optimized => "OPTIMIZED",
                                     ExplainOption::Decorrelated => "DECORRELATED",
+                                    ExplainOption::Decisions => "DECISIONS",
                                 }
                             })
                             .join(", ")
@@ -787,6 +790,7 @@ impl Display for Statement {
             Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTemporaryFiles(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::ChecksumTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDictionary(stmt) => write!(f, "{stmt}")?,
             Statement::DropDictionary(stmt) => write!(f, "{stmt}")?,
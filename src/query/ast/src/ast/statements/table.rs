@@ -284,7 +284,11 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(
+        Vec<ColumnDefinition>,
+        Option<Vec<InvertedIndexDefinition>>,
+        Vec<ConstraintDefinition>,
+    ),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -295,13 +299,17 @@ pub enum CreateTableSource {
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, inverted_indexes, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
                 if let Some(inverted_indexes) = inverted_indexes {
                     write!(f, ", ")?;
                     write_comma_separated_list(f, inverted_indexes)?;
                 }
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -425,6 +433,9 @@ pub enum AlterTableAction {
     DropColumn {
         column: Identifier,
     },
+    AddConstraint {
+        constraint: ConstraintDefinition,
+    },
     AlterTableClusterKey {
         cluster_by: ClusterOption,
     },
@@ -475,6 +486,9 @@ impl Display for AlterTableAction {
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")?;
             }
+            AlterTableAction::AddConstraint { constraint } => {
+                write!(f, "ADD {constraint}")?;
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 write!(f, "{cluster_by}")?;
             }
@@ -697,6 +711,8 @@ pub struct AnalyzeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// Only collect the statistics of these columns if not empty.
+    pub columns: Vec<Identifier>,
 }
 
 impl Display for AnalyzeTableStmt {
@@ -709,11 +725,36 @@ impl Display for AnalyzeTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.columns.is_empty() {
+            write!(f, " FOR COLUMNS (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
 
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct ChecksumTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for ChecksumTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CHECKSUM TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
@@ -932,10 +973,82 @@ impl Display for InvertedIndexDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub kind: ConstraintKind,
+}
+
+impl Display for ConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum ConstraintKind {
+    PrimaryKey {
+        columns: Vec<Identifier>,
+    },
+    Unique {
+        columns: Vec<Identifier>,
+    },
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+    },
+    Check {
+        expr: Box<Expr>,
+    },
+}
+
+impl Display for ConstraintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintKind::PrimaryKey { columns } => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            ConstraintKind::Unique { columns } => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            ConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                write_dot_separated_list(f, ref_database.iter().chain(Some(ref_table)))?;
+                if !ref_columns.is_empty() {
+                    write!(f, " (")?;
+                    write_comma_separated_list(f, ref_columns)?;
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            ConstraintKind::Check { expr } => {
+                write!(f, "CHECK ({expr})")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
     InvertedIndex(InvertedIndexDefinition),
+    Constraint(ConstraintDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -947,6 +1060,9 @@ impl Display for CreateDefinition {
             CreateDefinition::InvertedIndex(inverted_index_def) => {
                 write!(f, "{}", inverted_index_def)?;
             }
+            CreateDefinition::Constraint(constraint_def) => {
+                write!(f, "{}", constraint_def)?;
+            }
         }
         Ok(())
     }
@@ -128,7 +128,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
                 [ FILE_FORMAT = ( { TYPE = { CSV | NDJSON | PARQUET | TSV | AVRO } [ formatTypeOptions ] } ) ]
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
-                [ VALIDATION_MODE = RETURN_ROWS ]
+                [ VALIDATION_MODE = { RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS } ]
                 [ copyOptions ]`"
     )(i)
 }
@@ -174,6 +174,9 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
         map(rule! { ON_ERROR ~ "=" ~ #ident }, |(_, _, on_error)| {
             CopyIntoTableOption::OnError(on_error.to_string())
         }),
+        map(rule! { VALIDATION_MODE ~ "=" ~ #ident }, |(_, _, mode)| {
+            CopyIntoTableOption::ValidationMode(mode.to_string())
+        }),
         map(
             rule! { COLUMN_MATCH_MODE ~ "=" ~ #ident },
             |(_, _, mode)| CopyIntoTableOption::ColumnMatchMode(mode.to_string()),
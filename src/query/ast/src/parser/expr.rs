@@ -167,11 +167,12 @@ pub enum ExprElement {
         subquery: Box<Query>,
         not: bool,
     },
-    /// `BETWEEN ... AND ...`
+    /// `BETWEEN [ SYMMETRIC ] ... AND ...`
     Between {
         low: Box<Expr>,
         high: Box<Expr>,
         not: bool,
+        symmetric: bool,
     },
     /// Binary operation
     BinaryOp {
@@ -818,12 +819,18 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 subquery,
                 not,
             },
-            ExprElement::Between { low, high, not } => Expr::Between {
+            ExprElement::Between {
+                low,
+                high,
+                not,
+                symmetric,
+            } => Expr::Between {
                 span: transform_span(elem.span.tokens),
                 expr: Box::new(lhs),
                 low,
                 high,
                 not,
+                symmetric,
             },
             ExprElement::PgCast { target_type } => Expr::Cast {
                 span: transform_span(elem.span.tokens),
@@ -878,12 +885,13 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     );
     let between = map(
         rule! {
-            NOT? ~ BETWEEN ~ ^#subexpr(BETWEEN_PREC) ~ ^AND ~ ^#subexpr(BETWEEN_PREC)
+            NOT? ~ BETWEEN ~ SYMMETRIC? ~ ^#subexpr(BETWEEN_PREC) ~ ^AND ~ ^#subexpr(BETWEEN_PREC)
         },
-        |(opt_not, _, low, _, high)| ExprElement::Between {
+        |(opt_not, _, opt_symmetric, low, _, high)| ExprElement::Between {
             low: Box::new(low),
             high: Box::new(high),
             not: opt_not.is_some(),
+            symmetric: opt_symmetric.is_some(),
         },
     );
     let cast = map(
@@ -1094,6 +1102,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             },
         },
     );
+    let function_call_with_order_by = map(
+        rule! {
+            #function_name
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list1(subexpr(0))
+            ~ ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) ~ ")"
+            ~ #window_function?
+        },
+        |(name, _, opt_distinct, args, _, _, order_by, _, window)| ExprElement::FunctionCall {
+            func: FunctionCall {
+                distinct: opt_distinct.is_some(),
+                name,
+                args,
+                params: vec![],
+                order_by,
+                window,
+                lambda: None,
+            },
+        },
+    );
     let function_call_with_params_window = map(
         rule! {
             #function_name
@@ -1401,6 +1428,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 | #function_call_with_lambda : "`function(..., x -> ...)`"
                 | #function_call_with_window : "`function(...) OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
                 | #function_call_with_within_group_window: "`function(...) [ WITHIN GROUP ( ORDER BY <expr>, ... ) ] OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
+                | #function_call_with_order_by: "`function(... ORDER BY <expr>, ...)`"
                 | #function_call_with_params_window : "`function(...)(...) OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
                 | #function_call : "`function(...)`"
             ),
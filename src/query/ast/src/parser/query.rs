@@ -58,6 +58,7 @@ pub enum SetOperationElement {
     SelectStmt {
         hints: Option<Hint>,
         distinct: bool,
+        distinct_on: Option<Vec<Expr>>,
         top_n: Option<u64>,
         select_list: Vec<SelectTarget>,
         from: Vec<TableReference>,
@@ -117,6 +118,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             Ok(SetOperationElement::SelectStmt {
                 hints: None,
                 distinct: false,
+                distinct_on: None,
                 top_n: None,
                 select_list: vec![SelectTarget::StarColumns {
                     qualified: vec![Indirection::Star(Some(Range { start: 0, end: 0 }))],
@@ -135,7 +137,9 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     let select_stmt = map_res(
         rule! {
             ( FROM ~ ^#comma_separated_list1(table_reference) )?
-            ~ SELECT ~ #hint? ~ DISTINCT? ~ #top_n? ~ ^#comma_separated_list1(select_target)
+            ~ SELECT ~ #hint?
+            ~ ( DISTINCT ~ ( ON ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )? )?
+            ~ #top_n? ~ ^#comma_separated_list1(select_target)
             ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
             ~ ( WHERE ~ ^#expr )?
             ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
@@ -165,7 +169,8 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
 
             Ok(SetOperationElement::SelectStmt {
                 hints: opt_hints,
-                distinct: opt_distinct.is_some(),
+                distinct: matches!(opt_distinct, Some((_, None))),
+                distinct_on: opt_distinct.and_then(|(_, on)| on.map(|(_, _, keys, _)| keys)),
                 top_n: opt_top_n,
                 select_list,
                 from: opt_from_block_first
@@ -273,6 +278,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
             SetOperationElement::SelectStmt {
                 hints,
                 distinct,
+                distinct_on,
                 top_n,
                 select_list,
                 from,
@@ -286,6 +292,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 hints,
                 top_n,
                 distinct,
+                distinct_on,
                 select_list,
                 from,
                 selection,
@@ -391,15 +398,27 @@ pub fn row_values(i: Input) -> IResult<Vec<Expr>> {
 }
 
 pub fn with(i: Input) -> IResult<With> {
+    let cycle = map(
+        consumed(rule! {
+            CYCLE ~ ^#comma_separated_list1(ident) ~ ^SET ~ ^#ident ~ ^USING ~ ^#ident
+        }),
+        |(span, (_, columns, _, mark_column, _, path_column))| CteCycle {
+            span: transform_span(span.tokens),
+            columns,
+            mark_column,
+            path_column,
+        },
+    );
     let cte = map(
         consumed(rule! {
-            #table_alias_without_as ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")"
+            #table_alias_without_as ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")" ~ #cycle?
         }),
-        |(span, (table_alias, _, materialized, _, query, _))| CTE {
+        |(span, (table_alias, _, materialized, _, query, _, cycle))| CTE {
             span: transform_span(span.tokens),
             alias: table_alias,
             materialized: materialized.is_some(),
             query: Box::new(query),
+            cycle,
         },
     );
 
@@ -770,6 +789,11 @@ pub enum TableReferenceElement {
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
     },
+    // MATCH_RECOGNIZE(...) [ AS alias ]
+    MatchRecognize {
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
@@ -873,6 +897,30 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
     );
 
+    let match_recognize = map(
+        rule! {
+            MATCH_RECOGNIZE ~ "("
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ ( MEASURES ~ ^#comma_separated_list1(match_measure) )?
+            ~ PATTERN ~ ^"(" ~ ^#row_pattern ~ ^")"
+            ~ ( DEFINE ~ ^#comma_separated_list1(match_define) )?
+            ~ ^")" ~ #table_alias?
+        },
+        |(_, _, partition_by, order_by, measures, _, _, pattern, _, defines, _, alias)| {
+            TableReferenceElement::MatchRecognize {
+                match_recognize: Box::new(MatchRecognize {
+                    partition_by: partition_by.map(|(_, _, exprs)| exprs).unwrap_or_default(),
+                    order_by: order_by.map(|(_, _, exprs)| exprs).unwrap_or_default(),
+                    measures: measures.map(|(_, measures)| measures).unwrap_or_default(),
+                    pattern,
+                    defines: defines.map(|(_, defines)| defines).unwrap_or_default(),
+                }),
+                alias,
+            }
+        },
+    );
+
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
         | #table_function
@@ -882,20 +930,21 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #match_recognize
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
 
-// PIVOT(expr FOR col IN (ident, ... | subquery))
+// PIVOT(expr, ... FOR col IN ([expr, ...] | subquery))
 fn pivot(i: Input) -> IResult<Pivot> {
     map(
         rule! {
-            PIVOT ~ "(" ~ #expr ~ FOR ~ #ident ~ IN ~ "(" ~ #pivot_values ~ ")" ~ ")"
+            PIVOT ~ "(" ~ #comma_separated_list1(expr) ~ FOR ~ #ident ~ IN ~ "(" ~ #pivot_values? ~ ")" ~ ")"
         },
-        |(_pivot, _, aggregate, _for, value_column, _in, _, values, _, _)| Pivot {
-            aggregate,
+        |(_pivot, _, aggregates, _for, value_column, _in, _, values, _, _)| Pivot {
+            aggregates,
             value_column,
-            values,
+            values: values.unwrap_or(PivotValues::ColumnValues(vec![])),
         },
     )(i)
 }
@@ -914,6 +963,125 @@ fn unpivot(i: Input) -> IResult<Unpivot> {
     )(i)
 }
 
+// <expr> AS <alias>
+fn match_measure(i: Input) -> IResult<MatchMeasure> {
+    map(
+        rule! {
+            #expr ~ AS ~ ^#ident
+        },
+        |(expr, _, alias)| MatchMeasure { expr, alias },
+    )(i)
+}
+
+// <variable> AS <condition>
+fn match_define(i: Input) -> IResult<MatchDefine> {
+    map(
+        rule! {
+            #ident ~ AS ~ ^#expr
+        },
+        |(variable, _, condition)| MatchDefine {
+            variable,
+            condition,
+        },
+    )(i)
+}
+
+// <pattern> [ | <pattern> ]...
+pub fn row_pattern(i: Input) -> IResult<RowPattern> {
+    map(
+        rule! {
+            #row_pattern_concat ~ ( "|" ~ ^#row_pattern_concat )*
+        },
+        |(first, rest)| {
+            if rest.is_empty() {
+                first
+            } else {
+                let mut patterns = vec![first];
+                patterns.extend(rest.into_iter().map(|(_, pattern)| pattern));
+                RowPattern::Alternation(patterns)
+            }
+        },
+    )(i)
+}
+
+fn row_pattern_concat(i: Input) -> IResult<RowPattern> {
+    map(
+        rule! {
+            #row_pattern_quantified+
+        },
+        |mut patterns| {
+            if patterns.len() == 1 {
+                patterns.remove(0)
+            } else {
+                RowPattern::Concat(patterns)
+            }
+        },
+    )(i)
+}
+
+fn row_pattern_quantified(i: Input) -> IResult<RowPattern> {
+    map(
+        rule! {
+            #row_pattern_primary ~ #row_pattern_quantifier?
+        },
+        |(pattern, quantifier)| match quantifier {
+            Some((quantifier, reluctant)) => RowPattern::Quantified {
+                pattern: Box::new(pattern),
+                quantifier,
+                reluctant,
+            },
+            None => pattern,
+        },
+    )(i)
+}
+
+fn row_pattern_primary(i: Input) -> IResult<RowPattern> {
+    let variable = map(rule! { #ident }, RowPattern::Variable);
+    let start = value(RowPattern::Start, rule! { "^" });
+    let end = value(RowPattern::End, rule! { "$" });
+    let group = map(
+        rule! {
+            "(" ~ #row_pattern ~ ^")"
+        },
+        |(_, pattern, _)| RowPattern::Group(Box::new(pattern)),
+    );
+
+    rule!(
+        #variable
+        | #start
+        | #end
+        | #group
+    )(i)
+}
+
+fn row_pattern_quantifier(i: Input) -> IResult<(RowPatternQuantifier, bool)> {
+    let zero_or_more = value(RowPatternQuantifier::ZeroOrMore, rule! { "*" });
+    let one_or_more = value(RowPatternQuantifier::OneOrMore, rule! { "+" });
+    let zero_or_one = value(RowPatternQuantifier::ZeroOrOne, rule! { "?" });
+    let exact = map(
+        rule! {
+            "{" ~ #literal_u64 ~ "}"
+        },
+        |(_, n, _)| RowPatternQuantifier::Range {
+            min: Some(n),
+            max: Some(n),
+        },
+    );
+    let range = map(
+        rule! {
+            "{" ~ #literal_u64? ~ "," ~ #literal_u64? ~ ^"}"
+        },
+        |(_, min, _, max, _)| RowPatternQuantifier::Range { min, max },
+    );
+
+    map(
+        rule! {
+            ( #zero_or_more | #one_or_more | #zero_or_one | #exact | #range ) ~ "?"?
+        },
+        |(quantifier, reluctant)| (quantifier, reluctant.is_some()),
+    )(i)
+}
+
 fn pivot_values(i: Input) -> IResult<PivotValues> {
     alt((
         map(comma_separated_list1(expr), PivotValues::ColumnValues),
@@ -953,6 +1121,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchRecognize { .. } => Affix::Postfix(Precedence(20)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -1102,6 +1271,15 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchRecognize {
+                match_recognize,
+                alias,
+            } => Ok(TableReference::MatchRecognize {
+                span: transform_span(op.span.tokens),
+                input: Box::new(lhs),
+                match_recognize,
+                alias,
+            }),
             _ => unreachable!(),
         }
     }
@@ -1186,13 +1364,23 @@ pub fn window_frame_between(i: Input) -> IResult<(WindowFrameBound, WindowFrameB
     ))(i)
 }
 
+pub fn window_frame_exclusion(i: Input) -> IResult<FrameExclusion> {
+    let exclusion = alt((
+        value(FrameExclusion::CurrentRow, rule! { CURRENT ~ ^ROW }),
+        value(FrameExclusion::Group, rule! { GROUP }),
+        value(FrameExclusion::Ties, rule! { TIES }),
+        value(FrameExclusion::NoOthers, rule! { NO ~ ^OTHERS }),
+    ));
+    map(rule! { EXCLUDE ~ ^#exclusion }, |(_, exclusion)| exclusion)(i)
+}
+
 pub fn window_spec(i: Input) -> IResult<WindowSpec> {
     map(
         rule! {
             #ident?
             ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(subexpr(0)) )?
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
-            ~ ( (ROWS | RANGE) ~ ^#window_frame_between )?
+            ~ ( (ROWS | RANGE) ~ ^#window_frame_between ~ #window_frame_exclusion? )?
         },
         |(existing_window_name, opt_partition, opt_order, between)| WindowSpec {
             existing_window_name,
@@ -1209,6 +1397,7 @@ pub fn window_spec(i: Input) -> IResult<WindowSpec> {
                     units: unit,
                     start_bound: bw.0,
                     end_bound: bw.1,
+                    exclusion: x.2,
                 }
             }),
         },
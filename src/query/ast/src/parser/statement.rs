@@ -1088,12 +1088,28 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let analyze_table = map(
         rule! {
             ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
+            ~ ( FOR ~ ^COLUMNS ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), columns)| {
             Statement::AnalyzeTable(AnalyzeTableStmt {
                 catalog,
                 database,
                 table,
+                columns: columns
+                    .map(|(_, _, _, columns, _)| columns)
+                    .unwrap_or_default(),
+            })
+        },
+    );
+    let checksum_table = map(
+        rule! {
+            CHECKSUM ~ TABLE ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, (catalog, database, table))| {
+            Statement::ChecksumTable(ChecksumTableStmt {
+                catalog,
+                database,
+                table,
             })
         },
     );
@@ -2557,7 +2573,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [FOR COLUMNS (<column>, ...)]`"
+        ),
+        rule!(
+            #checksum_table : "`CHECKSUM TABLE [<database>.]<table>`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -3198,6 +3217,57 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     )(i)
 }
 
+pub fn constraint_def(i: Input) -> IResult<ConstraintDefinition> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ ^KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| ConstraintKind::PrimaryKey { columns },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| ConstraintKind::Unique { columns },
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ ^KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), opt_ref_columns)| {
+            ConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns: opt_ref_columns
+                    .map(|(_, ref_columns, _)| ref_columns)
+                    .unwrap_or_default(),
+            }
+        },
+    );
+    let check = map(
+        rule! {
+            CHECK ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| ConstraintKind::Check {
+            expr: Box::new(expr),
+        },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ ^#ident )?
+            ~ ( #primary_key | #unique | #foreign_key | #check )
+        },
+        |(opt_name, kind)| ConstraintDefinition {
+            name: opt_name.map(|(_, name)| name),
+            kind,
+        },
+    )(i)
+}
+
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
         map(rule! { #column_def }, CreateDefinition::Column),
@@ -3205,6 +3275,7 @@ pub fn create_def(i: Input) -> IResult<CreateDefinition> {
             rule! { #inverted_index_def },
             CreateDefinition::InvertedIndex,
         ),
+        map(rule! { #constraint_def }, CreateDefinition::Constraint),
     ))(i)
 }
 
@@ -3589,6 +3660,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
             let mut inverted_indexes = Vec::new();
+            let mut constraints = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
@@ -3597,6 +3669,9 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
                     CreateDefinition::InvertedIndex(inverted_index) => {
                         inverted_indexes.push(inverted_index);
                     }
+                    CreateDefinition::Constraint(constraint) => {
+                        constraints.push(constraint);
+                    }
                 }
             }
             let opt_inverted_indexes = if !inverted_indexes.is_empty() {
@@ -3604,7 +3679,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            CreateTableSource::Columns(columns, opt_inverted_indexes, constraints)
         },
     );
     let like = map(
@@ -3771,6 +3846,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, new_comment)| AlterTableAction::ModifyTableComment { new_comment },
     );
+    let add_constraint = map(
+        rule! {
+            ADD ~ #constraint_def
+        },
+        |(_, constraint)| AlterTableAction::AddConstraint { constraint },
+    );
     let add_column = map(
         rule! {
             ADD ~ COLUMN? ~ #column_def ~ ( #add_column_option )?
@@ -3851,6 +3932,7 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #rename_table
         | #rename_column
         | #modify_table_comment
+        | #add_constraint
         | #add_column
         | #drop_column
         | #modify_column
@@ -4837,13 +4919,14 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
     map(
         rule! {
-            VERBOSE | LOGICAL | OPTIMIZED | DECORRELATED
+            VERBOSE | LOGICAL | OPTIMIZED | DECORRELATED | DECISIONS
         },
         |opt| match &opt.kind {
             VERBOSE => ExplainOption::Verbose,
             LOGICAL => ExplainOption::Logical,
             OPTIMIZED => ExplainOption::Optimized,
             DECORRELATED => ExplainOption::Decorrelated,
+            DECISIONS => ExplainOption::Decisions,
             _ => unreachable!(),
         },
     )(i)
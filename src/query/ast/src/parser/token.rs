@@ -449,6 +449,8 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONSUME", ignore(ascii_case))]
     CONSUME,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -465,6 +467,10 @@ pub enum TokenKind {
     COLUMNS,
     #[token("CHARACTER", ignore(ascii_case))]
     CHARACTER,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
+    #[token("CHECKSUM", ignore(ascii_case))]
+    CHECKSUM,
     #[token("CONFLICT", ignore(ascii_case))]
     CONFLICT,
     #[token("COMPRESSION", ignore(ascii_case))]
@@ -487,6 +493,8 @@ pub enum TokenKind {
     CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
+    #[token("CYCLE", ignore(ascii_case))]
+    CYCLE,
     #[token("DATABASE", ignore(ascii_case))]
     DATABASE,
     #[token("DATABASES", ignore(ascii_case))]
@@ -517,6 +525,8 @@ pub enum TokenKind {
     DECLARE,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
+    #[token("DEFINE", ignore(ascii_case))]
+    DEFINE,
     #[token("DEFLATE", ignore(ascii_case))]
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
@@ -635,6 +645,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -804,6 +816,8 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MEASURES", ignore(ascii_case))]
+    MEASURES,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
@@ -828,6 +842,8 @@ pub enum TokenKind {
     MODIFY,
     #[token("MATERIALIZED", ignore(ascii_case))]
     MATERIALIZED,
+    #[token("MATCH_RECOGNIZE", ignore(ascii_case))]
+    MATCH_RECOGNIZE,
     #[token("MUST_CHANGE_PASSWORD", ignore(ascii_case))]
     MUST_CHANGE_PASSWORD,
     #[token("NEXT_DAY", ignore(ascii_case))]
@@ -842,6 +858,8 @@ pub enum TokenKind {
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO", ignore(ascii_case))]
+    NO,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -878,6 +896,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHERS", ignore(ascii_case))]
+    OTHERS,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -958,6 +978,8 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
@@ -1034,6 +1056,8 @@ pub enum TokenKind {
     OPTIMIZED,
     #[token("DECORRELATED", ignore(ascii_case))]
     DECORRELATED,
+    #[token("DECISIONS", ignore(ascii_case))]
+    DECISIONS,
     #[token("SATURDAY", ignore(ascii_case))]
     SATURDAY,
     #[token("SCHEMA", ignore(ascii_case))]
@@ -1132,6 +1156,8 @@ pub enum TokenKind {
     SEMI,
     #[token("SOUNDS", ignore(ascii_case))]
     SOUNDS,
+    #[token("SYMMETRIC", ignore(ascii_case))]
+    SYMMETRIC,
     #[token("SYNC", ignore(ascii_case))]
     SYNC,
     #[token("SYSTEM", ignore(ascii_case))]
@@ -1162,6 +1188,8 @@ pub enum TokenKind {
     THEN,
     #[token("THURSDAY", ignore(ascii_case))]
     THURSDAY,
+    #[token("TIES", ignore(ascii_case))]
+    TIES,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -1210,6 +1238,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1228,6 +1258,8 @@ pub enum TokenKind {
     USING,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
+    #[token("VALIDATION_MODE", ignore(ascii_case))]
+    VALIDATION_MODE,
     #[token("VALUES", ignore(ascii_case))]
     VALUES,
     #[token("VARBINARY", ignore(ascii_case))]
@@ -1713,6 +1745,7 @@ impl TokenKind {
             | TokenKind::SELECT
             | TokenKind::PIVOT
             | TokenKind::UNPIVOT
+            | TokenKind::MATCH_RECOGNIZE
             // | TokenKind::SESSION_USER
             // | TokenKind::SIMILAR
             | TokenKind::SOME
@@ -1095,6 +1095,7 @@ fn test_query() {
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
         r#"with t2(tt) as materialized (select a from t), t3 as materialized (select * from t), t4 as (select a from t where a > 1) select t2.tt, t3.a, t4.a from t2, t3, t4 where t2.tt > 1"#,
         r#"with recursive t2(tt) as (select a from t1 union select tt from t2) select t2.tt from t2"#,
+        r#"with recursive t2(tt) as (select a from t1 union select tt from t2) cycle tt set is_cycle using path select t2.tt from t2"#,
         r#"with t(a,b) as (values(1,1),(2,null),(null,5)) select t.a, t.b from t"#,
         r#"select c_count cc, count(*) as custdist, sum(c_acctbal) as totacctbal
             from customer, orders ODS,
@@ -1216,6 +1217,7 @@ fn test_expr() {
         r#"t.0"#,
         r#"(NULL,).0"#,
         r#"col1 not between 1 and 2"#,
+        r#"col1 between symmetric 1 and 2"#,
         r#"sum(col1)"#,
         r#""random"()"#,
         r#"random(distinct)"#,
@@ -1257,6 +1259,7 @@ fn test_expr() {
         r#"{'k1':1,'k2':2}"#,
         // within group
         r#"LISTAGG(salary, '|') WITHIN GROUP (ORDER BY salary DESC NULLS LAST)"#,
+        r#"ARRAY_AGG(DISTINCT x ORDER BY y DESC)"#,
         // window expr
         r#"ROW_NUMBER() OVER (ORDER BY salary DESC)"#,
         r#"SUM(salary) OVER ()"#,
@@ -1267,6 +1270,8 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING EXCLUDE CURRENT ROW)"#,
+        r#"COUNT() OVER (ORDER BY hire_date RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING EXCLUDE TIES)"#,
         r#"QUANTILE_CONT(0.5)(salary) OVER (PARTITION BY department ORDER BY hire_date)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
@@ -43,6 +43,9 @@ pub struct DataSourcePlan {
     pub base_block_ids: Option<Scalar>,
     // used for recluster to update stream columns
     pub update_stream_columns: bool,
+    // read the parts in parallel but output the blocks in the order of the parts,
+    // at the cost of some parallelism.
+    pub ordered_output: bool,
 
     // data mask policy for `output_schema` columns
     pub data_mask_policy: Option<BTreeMap<FieldIndex, RemoteExpr>>,
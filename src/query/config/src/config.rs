@@ -45,6 +45,7 @@ use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
+use databend_common_tracing::HistoryLogConfig as InnerHistoryLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
 use databend_common_tracing::OTLPEndpointConfig as InnerOTLPEndpointConfig;
 use databend_common_tracing::OTLPProtocol;
@@ -1538,6 +1539,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// The max number of the rows kept in `system.access_history`.
+    #[clap(long, value_name = "VALUE", default_value = "10000")]
+    pub max_access_history_size: usize,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1778,6 +1783,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            max_access_history_size: self.max_access_history_size,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1872,6 +1878,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            max_access_history_size: inner.max_access_history_size,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -1976,6 +1983,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub tracing: TracingConfig,
+
+    #[clap(flatten)]
+    pub history: HistoryLogConfig,
 }
 
 impl Default for LogConfig {
@@ -2059,6 +2069,13 @@ impl TryInto<InnerLogConfig> for LogConfig {
 
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
+        let history: InnerHistoryLogConfig = self.history.try_into()?;
+        if history.on && history.interval == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "`history.interval` must be greater than 0 when `history.on` is true".to_string(),
+            ));
+        }
+
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
@@ -2067,6 +2084,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             profile,
             structlog,
             tracing,
+            history,
         })
     }
 }
@@ -2083,6 +2101,7 @@ impl From<InnerLogConfig> for LogConfig {
             profile: inner.profile.into(),
             structlog: inner.structlog.into(),
             tracing: inner.tracing.into(),
+            history: inner.history.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -2424,6 +2443,62 @@ impl From<InnerStructLogConfig> for StructLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct HistoryLogConfig {
+    #[clap(
+        long = "log-history-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
+    )]
+    #[serde(rename = "on")]
+    pub log_history_on: bool,
+
+    /// The interval in seconds to flush the query log into `system_history.query_history`
+    #[clap(
+        long = "log-history-interval",
+        value_name = "VALUE",
+        default_value = "15"
+    )]
+    #[serde(rename = "interval")]
+    pub log_history_interval: u64,
+
+    /// The retention period in hours of `system_history.query_history`, 0 means forever
+    #[clap(
+        long = "log-history-retention",
+        value_name = "VALUE",
+        default_value = "168"
+    )]
+    #[serde(rename = "retention")]
+    pub log_history_retention: u64,
+}
+
+impl Default for HistoryLogConfig {
+    fn default() -> Self {
+        InnerHistoryLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerHistoryLogConfig> for HistoryLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerHistoryLogConfig> {
+        Ok(InnerHistoryLogConfig {
+            on: self.log_history_on,
+            interval: self.log_history_interval,
+            retention: self.log_history_retention,
+        })
+    }
+}
+
+impl From<InnerHistoryLogConfig> for HistoryLogConfig {
+    fn from(inner: InnerHistoryLogConfig) -> Self {
+        Self {
+            log_history_on: inner.on,
+            log_history_interval: inner.interval,
+            log_history_retention: inner.retention,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct TracingConfig {
@@ -208,6 +208,8 @@ pub struct QueryConfig {
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    /// The max number of the rows kept in `system.access_history`.
+    pub max_access_history_size: usize,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -300,6 +302,7 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            max_access_history_size: 10_000,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -122,6 +122,7 @@ async fn test_block_pruner() -> Result<()> {
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        constraints: Default::default(),
         attached_columns: None,
     };
 
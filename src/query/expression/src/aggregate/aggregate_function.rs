@@ -126,6 +126,20 @@ pub trait AggregateFunction: fmt::Display + Sync + Send {
 
     fn merge_result(&self, place: AggrState, builder: &mut ColumnBuilder) -> Result<()>;
 
+    /// Finalize the states of a batch of groups flushed from the aggregate payload.
+    ///
+    /// `places` are the state addresses of the flushed rows, as returned by
+    /// `PayloadFlushState::state_places`. Functions with custom state finalization
+    /// can override it, by default the states are finalized with `batch_merge_result`.
+    fn flush_result(
+        &self,
+        places: &[StateAddr],
+        loc: Box<[AggrStateLoc]>,
+        builder: &mut ColumnBuilder,
+    ) -> Result<()> {
+        self.batch_merge_result(places, loc, builder)
+    }
+
     // std::mem::needs_drop::<State>
     // if true will call drop_state
     fn need_manual_drop_state(&self) -> bool {
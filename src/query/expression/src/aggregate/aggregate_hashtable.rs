@@ -24,13 +24,12 @@ use super::partitioned_payload::PartitionedPayload;
 use super::payload_flush::PayloadFlushState;
 use super::probe_state::ProbeState;
 use crate::aggregate::payload_row::row_match_columns;
-use crate::group_hash_columns;
+use crate::group_hash_columns_with_seed;
 use crate::new_sel;
 use crate::read;
 use crate::types::DataType;
 use crate::AggregateFunctionRef;
 use crate::Column;
-use crate::ColumnBuilder;
 use crate::HashTableConfig;
 use crate::InputColumns;
 use crate::Payload;
@@ -51,6 +50,11 @@ pub struct AggregateHashTable {
     pub config: HashTableConfig,
 
     current_radix_bits: u64,
+    // The partitions which will not receive more rows, see `complete_partition`.
+    completed_partitions: Vec<bool>,
+    // The partitions already flushed by `flush_completed_partitions`, they are skipped
+    // by `merge_result`.
+    flushed_partitions: Vec<bool>,
     entries: Vec<Entry>,
     count: usize,
     capacity: usize,
@@ -82,12 +86,15 @@ impl AggregateHashTable {
             count: 0,
             direct_append: false,
             current_radix_bits: config.initial_radix_bits,
+            completed_partitions: vec![],
+            flushed_partitions: vec![],
             payload: PartitionedPayload::new(
                 group_types,
                 aggrs,
                 1 << config.initial_radix_bits,
                 vec![arena],
-            ),
+            )
+            .with_hash_seed(config.hash_seed),
             capacity,
             config,
         }
@@ -111,12 +118,15 @@ impl AggregateHashTable {
             count: 0,
             direct_append: !need_init_entry,
             current_radix_bits: config.initial_radix_bits,
+            completed_partitions: vec![],
+            flushed_partitions: vec![],
             payload: PartitionedPayload::new(
                 group_types,
                 aggrs,
                 1 << config.initial_radix_bits,
                 vec![arena],
-            ),
+            )
+            .with_hash_seed(config.hash_seed),
             capacity,
             config,
         }
@@ -177,7 +187,11 @@ impl AggregateHashTable {
         row_count: usize,
     ) -> Result<usize> {
         state.row_count = row_count;
-        group_hash_columns(group_columns, &mut state.group_hashes);
+        group_hash_columns_with_seed(
+            group_columns,
+            &mut state.group_hashes,
+            self.config.hash_seed,
+        );
 
         let new_group_count = if self.direct_append {
             for idx in 0..row_count {
@@ -386,6 +400,7 @@ impl AggregateHashTable {
         payload: &Payload,
         flush_state: &mut PayloadFlushState,
     ) -> Result<()> {
+        payload.check_hash_seed(self.config.hash_seed)?;
         flush_state.clear();
 
         while payload.flush(flush_state) {
@@ -423,36 +438,83 @@ impl AggregateHashTable {
     }
 
     pub fn merge_result(&mut self, flush_state: &mut PayloadFlushState) -> Result<bool> {
-        if !self.payload.flush(flush_state) {
-            return Ok(false);
+        if self.flushed_partitions.is_empty() {
+            if !self.payload.flush(flush_state) {
+                return Ok(false);
+            }
+
+            self.payload.flush_aggregate_results(flush_state)?;
+            return Ok(true);
         }
 
-        let row_count = flush_state.row_count;
-        flush_state.aggregate_results.clear();
-        if let Some(states_layout) = self.payload.states_layout.as_ref() {
-            for (aggr, loc) in self
-                .payload
-                .aggrs
-                .iter()
-                .zip(states_layout.states_loc.iter().cloned())
+        // Skip the partitions flushed by `flush_completed_partitions`.
+        while flush_state.flush_partition < self.payload.partition_count() {
+            let partition = flush_state.flush_partition;
+            if !self.flushed_partitions[partition]
+                && self.payload.payloads[partition].flush(flush_state)
             {
-                let return_type = aggr.return_type()?;
-                let mut builder = ColumnBuilder::with_capacity(&return_type, row_count * 4);
+                self.payload.flush_aggregate_results(flush_state)?;
+                return Ok(true);
+            }
 
-                aggr.batch_merge_result(
-                    &flush_state.state_places.as_slice()[0..row_count],
-                    loc,
-                    &mut builder,
-                )?;
-                flush_state.aggregate_results.push(builder.build());
+            flush_state.clear();
+            flush_state.flush_partition = partition + 1;
+        }
+        Ok(false)
+    }
+
+    /// Marks the partition as complete, no more rows will be added to it.
+    ///
+    /// The caller must guarantee the input of the partition is exhausted, e.g. the
+    /// upstream of a streaming shuffle has sent all the rows hashed to it. It's only
+    /// valid in the final aggregation: the partial aggregation may repartition the
+    /// rows, while here the partition of a group is fully determined by the radix
+    /// bits of its hash, see `PartitionedPayload::partition_of`.
+    pub fn complete_partition(&mut self, partition: usize) {
+        debug_assert!(!self.config.partial_agg);
+        let partition_count = self.payload.partition_count();
+        debug_assert!(partition < partition_count);
+        self.completed_partitions.resize(partition_count, false);
+        self.completed_partitions[partition] = true;
+    }
+
+    /// Like `merge_result`, but only flushes the final results of the completed
+    /// partitions which were not flushed yet, while the other partitions are still
+    /// accumulating.
+    ///
+    /// Returns false once all of them are flushed, it can be called again after more
+    /// partitions are completed. The flushed partitions are skipped by `merge_result`,
+    /// which must only be called after this returns false.
+    pub fn flush_completed_partitions(&mut self, state: &mut PayloadFlushState) -> Result<bool> {
+        let partition_count = self.payload.partition_count();
+        self.flushed_partitions.resize(partition_count, false);
+
+        while state.flush_partition < partition_count {
+            let partition = state.flush_partition;
+            let completed = self
+                .completed_partitions
+                .get(partition)
+                .is_some_and(|completed| *completed);
+
+            if completed && !self.flushed_partitions[partition] {
+                if self.payload.payloads[partition].flush(state) {
+                    self.payload.flush_aggregate_results(state)?;
+                    return Ok(true);
+                }
+                self.flushed_partitions[partition] = true;
             }
+
+            state.clear();
+            state.flush_partition = partition + 1;
         }
-        Ok(true)
+
+        state.clear();
+        Ok(false)
     }
 
     fn maybe_repartition(&mut self) -> bool {
         // already final stage or the max radix bits
-        if !self.config.partial_agg || (self.current_radix_bits == self.config.max_radix_bits) {
+        if !self.config.partial_agg || (self.current_radix_bits >= self.config.max_radix_bits) {
             return false;
         }
 
@@ -461,7 +523,8 @@ impl AggregateHashTable {
         let mut new_radix_bits = self.current_radix_bits;
 
         if bytes_per_partition > MAX_PAGE_SIZE * self.config.block_fill_factor as usize {
-            new_radix_bits += self.config.repartition_radix_bits_incr;
+            new_radix_bits = (new_radix_bits + self.config.repartition_radix_bits_incr)
+                .min(self.config.max_radix_bits);
         }
 
         loop {
@@ -579,14 +642,18 @@ impl AggregateHashTable {
 
     pub fn allocated_bytes(&self) -> usize {
         self.payload.memory_size()
-            + self
-                .payload
-                .arenas
-                .iter()
-                .map(|arena| arena.allocated_bytes())
-                .sum::<usize>()
+            + self.payload.arena_bytes()
             + self.entries.len() * std::mem::size_of::<Entry>()
     }
+
+    /// Whether the arenas have grown beyond `max_arena_bytes` of the config, and the hash
+    /// table should be spilled and replaced by one with new arenas.
+    ///
+    /// The rows are appended before the check, so a key larger than the cap is still
+    /// stored, and the hash table is spilled right after it.
+    pub fn exceeds_arena_limit(&self) -> bool {
+        self.config.max_arena_bytes != 0 && self.payload.arena_bytes() > self.config.max_arena_bytes
+    }
 }
 
 /// Upper 16 bits are salt
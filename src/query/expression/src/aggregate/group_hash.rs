@@ -61,6 +61,29 @@ pub fn group_hash_columns(cols: InputColumns, values: &mut [u64]) {
     }
 }
 
+/// Like `group_hash_columns`, but mixes a non-zero `seed` into the hashes, so the
+/// distribution of the groups to the partitions and the buckets can't be predicted
+/// without knowing the seed.
+pub fn group_hash_columns_with_seed(cols: InputColumns, values: &mut [u64], seed: u64) {
+    group_hash_columns(cols, values);
+    if seed != 0 {
+        for value in values.iter_mut().take(cols[0].len()) {
+            *value = seed_hash(*value, seed);
+        }
+    }
+}
+
+// The finalizer of MurmurHash3, which spreads the seed over all the bits.
+#[inline]
+fn seed_hash(hash: u64, seed: u64) -> u64 {
+    let mut h = hash ^ seed;
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^ (h >> 33)
+}
+
 pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut [u64]) {
     match c.data_type() {
         DataType::Null => {}
@@ -66,6 +66,12 @@ pub struct HashTableConfig {
     pub block_fill_factor: f64,
     pub partial_agg: bool,
     pub max_partial_capacity: usize,
+    // The seed mixed into the hashes of the groups, it must be the same for all the
+    // hash tables of a query, whose payloads are partitioned and merged by the hashes.
+    pub hash_seed: u64,
+    // The bytes the arenas of the group keys and states may grow to before the hash table
+    // should be spilled instead of growing them further, 0 means unbounded.
+    pub max_arena_bytes: usize,
 }
 
 impl Default for HashTableConfig {
@@ -78,6 +84,8 @@ impl Default for HashTableConfig {
             block_fill_factor: 1.8,
             partial_agg: false,
             max_partial_capacity: 131072,
+            hash_seed: 0,
+            max_arena_bytes: 0,
         }
     }
 }
@@ -89,6 +97,30 @@ impl HashTableConfig {
         self
     }
 
+    /// Caps the partitions of the payloads to `max_partition_count`, rounded down to a power
+    /// of two. Fewer partitions mean fewer buffers flushed at the same time, at the cost of
+    /// larger partitions, and `1` disables the partitioning.
+    pub fn with_max_partition_count(mut self, max_partition_count: usize) -> Self {
+        let max_radix_bits = max_partition_count.max(1).ilog2() as u64;
+        self.max_radix_bits = self.max_radix_bits.min(max_radix_bits);
+        if self.initial_radix_bits > self.max_radix_bits {
+            self = self.with_initial_radix_bits(self.max_radix_bits);
+        }
+        self
+    }
+
+    pub fn with_hash_seed(mut self, hash_seed: u64) -> Self {
+        self.hash_seed = hash_seed;
+        self
+    }
+
+    /// Caps the arenas of the hash table to `max_arena_bytes`, see
+    /// [`AggregateHashTable::exceeds_arena_limit`], and `0` leaves them unbounded.
+    pub fn with_max_arena_bytes(mut self, max_arena_bytes: usize) -> Self {
+        self.max_arena_bytes = max_arena_bytes;
+        self
+    }
+
     pub fn with_partial(mut self, partial_agg: bool, active_threads: usize) -> Self {
         self.partial_agg = partial_agg;
 
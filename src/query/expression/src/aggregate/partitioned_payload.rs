@@ -41,6 +41,7 @@ pub struct PartitionedPayload {
     pub states_layout: Option<StatesLayout>,
 
     pub arenas: Vec<Arc<Bump>>,
+    pub hash_seed: u64,
 
     partition_count: u64,
     mask_v: u64,
@@ -51,14 +52,30 @@ unsafe impl Send for PartitionedPayload {}
 unsafe impl Sync for PartitionedPayload {}
 
 impl PartitionedPayload {
+    /// Creates a payload split into `partition_count` partitions by the hashes of the groups,
+    /// the count must be a power of two, and `1` keeps all the rows in a single partition.
     pub fn new(
         group_types: Vec<DataType>,
         aggrs: Vec<AggregateFunctionRef>,
         partition_count: u64,
         arenas: Vec<Arc<Bump>>,
+    ) -> Self {
+        Self::new_with_radix_offset(group_types, aggrs, partition_count, 0, arenas)
+    }
+
+    /// Partitions the rows by the hash bits following the leading `radix_offset` bits,
+    /// which splits one partition of a payload with `1 << radix_offset` partitions
+    /// into `partition_count` partitions.
+    pub fn new_with_radix_offset(
+        group_types: Vec<DataType>,
+        aggrs: Vec<AggregateFunctionRef>,
+        partition_count: u64,
+        radix_offset: u64,
+        arenas: Vec<Arc<Bump>>,
     ) -> Self {
         let radix_bits = partition_count.trailing_zeros() as u64;
         debug_assert_eq!(1 << radix_bits, partition_count);
+        debug_assert!(radix_offset + radix_bits <= 48);
 
         let states_layout = if !aggrs.is_empty() {
             Some(get_states_layout(&aggrs).unwrap())
@@ -96,9 +113,19 @@ impl PartitionedPayload {
             partition_count,
 
             arenas,
-            mask_v: mask(radix_bits),
-            shift_v: shift(radix_bits),
+            hash_seed: 0,
+            mask_v: mask(radix_offset, radix_bits),
+            shift_v: shift(radix_offset, radix_bits),
+        }
+    }
+
+    /// Sets the seed the hashes of the groups appended to the payloads are computed with.
+    pub fn with_hash_seed(mut self, hash_seed: u64) -> Self {
+        self.hash_seed = hash_seed;
+        for payload in self.payloads.iter_mut() {
+            payload.hash_seed = hash_seed;
         }
+        self
     }
 
     pub fn mark_min_cardinality(&mut self) {
@@ -128,8 +155,7 @@ impl PartitionedPayload {
             let select_vector = &state.empty_vector;
 
             for idx in select_vector.iter().take(new_group_rows).copied() {
-                let hash = state.group_hashes[idx];
-                let partition_idx = ((hash & self.mask_v) >> self.shift_v) as usize;
+                let partition_idx = self.partition_of(state.group_hashes[idx]);
                 let sel = &mut state.partition_entries[partition_idx];
 
                 sel[state.partition_count[partition_idx]] = idx;
@@ -154,6 +180,15 @@ impl PartitionedPayload {
         }
     }
 
+    /// The bytes allocated by the arenas, which hold the data of the variable-length group
+    /// keys and the aggregate states.
+    pub fn arena_bytes(&self) -> usize {
+        self.arenas
+            .iter()
+            .map(|arena| arena.allocated_bytes())
+            .sum()
+    }
+
     pub fn repartition(self, new_partition_count: usize, state: &mut PayloadFlushState) -> Self {
         if self.partition_count() == new_partition_count {
             return self;
@@ -164,13 +199,15 @@ impl PartitionedPayload {
             self.aggrs.clone(),
             new_partition_count as u64,
             self.arenas.clone(),
-        );
+        )
+        .with_hash_seed(self.hash_seed);
 
         new_partition_payload.combine(self, state);
         new_partition_payload
     }
 
     pub fn combine(&mut self, other: PartitionedPayload, state: &mut PayloadFlushState) {
+        debug_assert_eq!(self.hash_seed, other.hash_seed);
         if other.partition_count == self.partition_count {
             for (l, r) in self.payloads.iter_mut().zip(other.payloads.into_iter()) {
                 l.combine(r);
@@ -193,6 +230,7 @@ impl PartitionedPayload {
         if other.len() == 0 {
             return;
         }
+        debug_assert_eq!(self.hash_seed, other.hash_seed);
 
         if self.partition_count == 1 {
             self.payloads[0].combine(other);
@@ -245,7 +283,7 @@ impl PartitionedPayload {
 
             let hash = unsafe { read::<u64>(state.addresses[idx].add(self.hash_offset) as _) };
 
-            let partition_idx = ((hash & self.mask_v) >> self.shift_v) as usize;
+            let partition_idx = self.partition_of(hash);
 
             let sel = &mut state.probe_state.partition_entries[partition_idx];
             sel[state.probe_state.partition_count[partition_idx]] = idx;
@@ -255,6 +293,12 @@ impl PartitionedPayload {
         true
     }
 
+    /// The partition of the group with the hash `hash`.
+    #[inline]
+    pub fn partition_of(&self, hash: u64) -> usize {
+        ((hash & self.mask_v) >> self.shift_v) as usize
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.payloads.iter().map(|x| x.len()).sum()
@@ -277,11 +321,11 @@ impl PartitionedPayload {
 }
 
 #[inline]
-fn shift(radix_bits: u64) -> u64 {
-    48 - radix_bits
+fn shift(radix_offset: u64, radix_bits: u64) -> u64 {
+    48 - radix_offset - radix_bits
 }
 
 #[inline]
-fn mask(radix_bits: u64) -> u64 {
-    ((1 << radix_bits) - 1) << shift(radix_bits)
+fn mask(radix_offset: u64, radix_bits: u64) -> u64 {
+    ((1 << radix_bits) - 1) << shift(radix_offset, radix_bits)
 }
@@ -17,10 +17,13 @@ use std::sync::Arc;
 
 use bumpalo::Bump;
 use databend_common_base::runtime::drop_guard;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use itertools::Itertools;
 use log::info;
 use strength_reduce::StrengthReducedU64;
 
+use super::payload_row::rowformat_is_address;
 use super::payload_row::rowformat_size;
 use super::payload_row::serialize_column_to_rowformat;
 use crate::read;
@@ -70,6 +73,8 @@ pub struct Payload {
 
     // if set, the payload contains at least duplicate rows
     pub min_cardinality: Option<usize>,
+    // The seed mixed into the hashes of the groups, see `HashTableConfig::hash_seed`.
+    pub hash_seed: u64,
 }
 
 unsafe impl Send for Payload {}
@@ -142,6 +147,7 @@ impl Payload {
             tuple_size,
             row_per_page,
             min_cardinality: None,
+            hash_seed: 0,
             total_rows: 0,
             group_offsets,
             group_sizes,
@@ -339,11 +345,24 @@ impl Payload {
             other.total_rows,
             other.pages.iter().map(|x| x.rows).sum::<usize>()
         );
+        debug_assert_eq!(self.hash_seed, other.hash_seed);
 
         self.total_rows += other.total_rows;
         self.pages.append(other.pages.as_mut());
     }
 
+    /// The stored hashes of the groups are only comparable with the ones computed with
+    /// the same seed, e.g. the payloads hashed on different nodes of a cluster.
+    pub fn check_hash_seed(&self, hash_seed: u64) -> Result<()> {
+        if self.hash_seed != hash_seed {
+            return Err(ErrorCode::Internal(format!(
+                "Cannot merge the aggregate payload hashed with seed {} into the one hashed with seed {}, the setting `aggregate_hash_seed` must be the same on all the nodes",
+                self.hash_seed, hash_seed
+            )));
+        }
+        Ok(())
+    }
+
     pub fn mark_min_cardinality(&mut self) {
         if self.min_cardinality.is_none() {
             self.min_cardinality = Some(self.total_rows);
@@ -422,6 +441,59 @@ impl Payload {
         true
     }
 
+    /// Iterates the group keys of the rows as they are stored in the pages: the validity
+    /// bytes of the nullable keys followed by the keys at `group_offsets`, which are the
+    /// first `hash_offset` bytes of each row.
+    ///
+    /// The strings, binaries and nested values are stored as a `u32` length and the `u64`
+    /// address of the data in the arena, and the keys of the NULLs are not meaningful, so
+    /// the bytes of equal keys are only equal for fixed-size and non-NULL keys, see
+    /// [`Payload::iter_canonical_group_key_bytes`] for the bytes comparable in any case.
+    pub fn iter_group_key_bytes(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.pages.iter().flat_map(move |page| {
+            (0..page.rows).map(move |row| unsafe {
+                std::slice::from_raw_parts(self.data_ptr(page, row), self.hash_offset)
+            })
+        })
+    }
+
+    /// Iterates the group keys of the rows in a form which is equal for the equal keys.
+    ///
+    /// Each key is encoded in order as the validity byte if the key is nullable, nothing
+    /// more for a NULL, the `u32` length (little endian) followed by the data for the
+    /// strings, binaries and nested values, or the fixed-size bytes of the other values.
+    pub fn iter_canonical_group_key_bytes(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        self.iter_group_key_bytes().map(|row| {
+            let mut bytes = Vec::with_capacity(row.len());
+            for (idx, data_type) in self.group_types.iter().enumerate() {
+                if data_type.is_nullable() {
+                    let valid = row[self.validity_offsets[idx]];
+                    bytes.push(valid);
+                    if valid == 0 {
+                        continue;
+                    }
+                }
+
+                let offset = self.group_offsets[idx];
+                if rowformat_is_address(data_type) {
+                    let (len, data) = unsafe {
+                        let len = read::<u32>(row.as_ptr().add(offset) as _);
+                        let addr = read::<u64>(row.as_ptr().add(offset + 4) as _);
+                        (
+                            len,
+                            std::slice::from_raw_parts(addr as *const u8, len as usize),
+                        )
+                    };
+                    bytes.extend_from_slice(&len.to_le_bytes());
+                    bytes.extend_from_slice(data);
+                } else {
+                    bytes.extend_from_slice(&row[offset..offset + self.group_sizes[idx]]);
+                }
+            }
+            bytes
+        })
+    }
+
     pub fn empty_block(&self, fake_rows: Option<usize>) -> DataBlock {
         let fake_rows = fake_rows.unwrap_or(0);
         let columns = (0..self.aggrs.len())
@@ -485,3 +557,85 @@ impl Drop for Payload {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_column::bitmap::Bitmap;
+
+    use super::*;
+    use crate::types::AnyType;
+    use crate::types::Int32Type;
+    use crate::types::NullableColumn;
+    use crate::types::NullableType;
+    use crate::types::StringType;
+    use crate::FromData;
+
+    fn new_payload(group_columns: &[Column]) -> Payload {
+        let group_types = group_columns.iter().map(Column::data_type).collect();
+        let mut payload = Payload::new(Arc::new(Bump::new()), group_types, vec![], None);
+
+        let rows = group_columns[0].len();
+        let mut select_vector = [0; BATCH_SIZE];
+        for (idx, sel) in select_vector.iter_mut().take(rows).enumerate() {
+            *sel = idx;
+        }
+        let hashes = vec![0; rows];
+        let mut address = vec![std::ptr::null(); rows];
+        let mut page_index = vec![0; rows];
+        payload.reserve_append_rows(
+            &select_vector,
+            &hashes,
+            &mut address,
+            &mut page_index,
+            rows,
+            group_columns.into(),
+        );
+        payload
+    }
+
+    #[test]
+    fn test_group_key_bytes() {
+        // The rows 0 and 2 have the same keys, the inner values of the NULLs are different.
+        let nullable = |values: Vec<i32>| {
+            let column = NullableColumn::<AnyType>::new(
+                Int32Type::from_data(values),
+                Bitmap::from([true, true, true, false, false]),
+            );
+            NullableType::<AnyType>::upcast_column(column)
+        };
+        let fixed_size = new_payload(&[
+            nullable(vec![1, 2, 1, 3, 4]),
+            Int32Type::from_data(vec![5, 5, 5, 6, 6]),
+        ]);
+
+        let rows = fixed_size.iter_group_key_bytes().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 5);
+        assert!(rows.iter().all(|row| row.len() == fixed_size.hash_offset));
+        assert_eq!(rows[0], rows[2]);
+        assert_ne!(rows[0], rows[1]);
+        assert_ne!(rows[0], rows[3]);
+
+        let rows = fixed_size
+            .iter_canonical_group_key_bytes()
+            .collect::<Vec<_>>();
+        assert_eq!(rows[0], rows[2]);
+        assert_ne!(rows[0], rows[1]);
+        assert_eq!(rows[3], rows[4]);
+        assert_eq!(rows[3], [0, 6, 0, 0, 0]);
+
+        // The strings are in the arena, only the canonical bytes of equal strings are equal.
+        let variable_length = new_payload(&[
+            nullable(vec![1, 2, 1, 3, 4]),
+            StringType::from_data(vec!["a", "a", "a", "bc", "bc"]),
+        ]);
+        let rows = variable_length
+            .iter_canonical_group_key_bytes()
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0], rows[2]);
+        assert_ne!(rows[0], rows[1]);
+        assert_eq!(rows[3], rows[4]);
+        assert_eq!(rows[3], [0, 2, 0, 0, 0, b'b', b'c']);
+        assert_eq!(rows[0], [1, 1, 0, 0, 0, 1, 0, 0, 0, b'a']);
+    }
+}
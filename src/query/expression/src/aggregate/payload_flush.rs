@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_io::prelude::bincode_deserialize_from_slice;
 use ethnum::i256;
@@ -24,20 +28,25 @@ use crate::read;
 use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::Decimal;
+use crate::types::decimal::DecimalColumn;
+use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalType;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::ArgType;
+use crate::types::Bitmap;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::DecimalDataType;
 use crate::types::DecimalSize;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
 use crate::types::ValueType;
 use crate::with_number_mapped_type;
+use crate::BlockCompactor;
 use crate::Column;
 use crate::ColumnBuilder;
 use crate::DataBlock;
@@ -54,6 +63,8 @@ pub struct PayloadFlushState {
     pub flush_partition: usize,
     pub flush_page: usize,
     pub flush_page_row: usize,
+    // Only collected if enabled by `enable_metrics`, kept across `clear`.
+    pub metrics: Option<PayloadFlushMetrics>,
 
     pub addresses: [*const u8; BATCH_SIZE],
     pub state_places: [StateAddr; BATCH_SIZE],
@@ -69,6 +80,7 @@ impl Default for PayloadFlushState {
             flush_partition: 0,
             flush_page: 0,
             flush_page_row: 0,
+            metrics: None,
             addresses: [std::ptr::null::<u8>(); BATCH_SIZE],
             state_places: [StateAddr::new(0); BATCH_SIZE],
         }
@@ -92,6 +104,158 @@ impl PayloadFlushState {
     pub fn take_aggregate_results(&mut self) -> Vec<Column> {
         std::mem::take(&mut self.aggregate_results)
     }
+
+    /// Starts to collect the rows, bytes and time of the flushed group columns.
+    pub fn enable_metrics(&mut self) {
+        self.metrics
+            .get_or_insert_with(PayloadFlushMetrics::default);
+    }
+
+    /// The state addresses of the rows produced by the last successful flush.
+    pub fn state_places(&self) -> &[StateAddr] {
+        &self.state_places[0..self.row_count]
+    }
+}
+
+/// The kinds of the group columns the flush metrics are broken down by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlushColumnKind {
+    Boolean,
+    Number,
+    Decimal,
+    Temporal,
+    String,
+    Binary,
+    Other,
+}
+
+impl FlushColumnKind {
+    pub const COUNT: usize = 7;
+
+    pub fn from_data_type(data_type: &DataType) -> Self {
+        match data_type.remove_nullable() {
+            DataType::Boolean => FlushColumnKind::Boolean,
+            DataType::Number(_) => FlushColumnKind::Number,
+            DataType::Decimal(_) => FlushColumnKind::Decimal,
+            DataType::Timestamp | DataType::Date => FlushColumnKind::Temporal,
+            DataType::String => FlushColumnKind::String,
+            DataType::Binary | DataType::Bitmap | DataType::Variant | DataType::Geometry => {
+                FlushColumnKind::Binary
+            }
+            _ => FlushColumnKind::Other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FlushColumnMetrics {
+    pub rows: usize,
+    pub bytes: usize,
+    pub time: Duration,
+}
+
+impl FlushColumnMetrics {
+    fn merge(&mut self, other: &FlushColumnMetrics) {
+        self.rows += other.rows;
+        self.bytes += other.bytes;
+        self.time += other.time;
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PayloadFlushMetrics {
+    // The flushed rows, counted once per flush whatever the number of the group columns.
+    rows: usize,
+    columns: [FlushColumnMetrics; FlushColumnKind::COUNT],
+}
+
+impl PayloadFlushMetrics {
+    fn record(&mut self, kind: FlushColumnKind, column: &Column, time: Duration) {
+        self.columns[kind as usize].merge(&FlushColumnMetrics {
+            rows: column.len(),
+            bytes: column.memory_size(),
+            time,
+        });
+    }
+
+    pub fn get(&self, kind: FlushColumnKind) -> &FlushColumnMetrics {
+        &self.columns[kind as usize]
+    }
+
+    /// The flushed rows, and the bytes and time of all the group columns.
+    pub fn total(&self) -> FlushColumnMetrics {
+        let mut total = FlushColumnMetrics::default();
+        for metrics in self.columns.iter() {
+            total.merge(metrics);
+        }
+        total.rows = self.rows;
+        total
+    }
+}
+
+// The size of `target` if a `source` column can be narrowed from `Decimal256` into it.
+fn decimal_narrowed_size(source: &DataType, target: &DataType) -> Option<DecimalSize> {
+    if source.is_nullable() != target.is_nullable() {
+        return None;
+    }
+    match (source.remove_nullable(), target.remove_nullable()) {
+        (
+            DataType::Decimal(DecimalDataType::Decimal256(from)),
+            DataType::Decimal(DecimalDataType::Decimal128(to)),
+        ) if from.scale == to.scale => Some(to),
+        _ => None,
+    }
+}
+
+/// Narrows a `Decimal256` column (or its nullable column) into a `Decimal128` column of `size`,
+/// the scale is unchanged, so it fails if a valid value doesn't fit in the precision of `size`.
+pub fn narrow_decimal_column(column: &Column, size: DecimalSize) -> Result<Column> {
+    match column {
+        Column::Nullable(column) => {
+            let narrowed = narrow_decimal_values(&column.column, size, Some(&column.validity))?;
+            Ok(NullableColumn::new_column(
+                narrowed,
+                column.validity.clone(),
+            ))
+        }
+        _ => narrow_decimal_values(column, size, None),
+    }
+}
+
+fn narrow_decimal_values(
+    column: &Column,
+    size: DecimalSize,
+    validity: Option<&Bitmap>,
+) -> Result<Column> {
+    let Column::Decimal(DecimalColumn::Decimal256(values, from)) = column else {
+        return Err(ErrorCode::Internal(format!(
+            "Expected a Decimal256 column to narrow, but got {}",
+            column.data_type()
+        )));
+    };
+
+    let min = i256::from(i128::min_for_precision(size.precision));
+    let max = i256::from(i128::max_for_precision(size.precision));
+    let mut narrowed = Vec::with_capacity(values.len());
+    for (idx, value) in values.iter().enumerate() {
+        // The values under the null rows are meaningless.
+        if validity.is_some_and(|validity| !validity.get_bit(idx)) {
+            narrowed.push(0);
+            continue;
+        }
+        if *value < min || *value > max {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: {} doesn't fit in {}",
+                value.display(from.scale),
+                DataType::Decimal(DecimalDataType::Decimal128(size))
+            )));
+        }
+        narrowed.push(*value.low());
+    }
+    Ok(Column::Decimal(DecimalColumn::Decimal128(
+        narrowed.into(),
+        size,
+    )))
 }
 
 impl PartitionedPayload {
@@ -110,6 +274,27 @@ impl PartitionedPayload {
             self.flush(state)
         }
     }
+
+    /// Finalize the aggregate states of the rows produced by the last successful flush
+    /// into `state.aggregate_results`, one column per aggregate function.
+    pub fn flush_aggregate_results(&self, state: &mut PayloadFlushState) -> Result<()> {
+        state.aggregate_results.clear();
+        let Some(states_layout) = self.states_layout.as_ref() else {
+            return Ok(());
+        };
+
+        for (aggr, loc) in self
+            .aggrs
+            .iter()
+            .zip(states_layout.states_loc.iter().cloned())
+        {
+            let return_type = aggr.return_type()?;
+            let mut builder = ColumnBuilder::with_capacity(&return_type, state.row_count * 4);
+            aggr.flush_result(state.state_places(), loc, &mut builder)?;
+            state.aggregate_results.push(builder.build());
+        }
+        Ok(())
+    }
 }
 
 impl Payload {
@@ -164,6 +349,78 @@ impl Payload {
         Ok(Some(DataBlock::new_from_columns(cols)))
     }
 
+    /// Flushes all the rows into `compactor`, the finalized aggregate results followed
+    /// by the group columns are appended to the builders of the compactor directly,
+    /// without assembling an intermediate block.
+    ///
+    /// A `Decimal256` column, either a group column or the result of an aggregate function
+    /// like `SUM`, can be flushed into a `Decimal128` column of the same scale, it fails if
+    /// a value doesn't fit in the precision of the target type. The columns of each batch
+    /// of rows are narrowed before any of them is appended. If it fails, none of the rows
+    /// of the payload are appended.
+    pub fn flush_into_compactor(
+        &self,
+        state: &mut PayloadFlushState,
+        compactor: &mut BlockCompactor,
+    ) -> Result<()> {
+        let mut source_types = Vec::with_capacity(self.aggrs.len() + self.group_types.len());
+        for aggr in self.aggrs.iter() {
+            source_types.push(aggr.return_type()?);
+        }
+        source_types.extend(self.group_types.iter().cloned());
+
+        // The target sizes of the columns narrowed from `Decimal256` to `Decimal128`.
+        let mut data_types = Vec::with_capacity(source_types.len());
+        let mut narrowed_sizes = Vec::with_capacity(source_types.len());
+        for (idx, source_type) in source_types.iter().enumerate() {
+            let narrowed = compactor.data_types().get(idx).and_then(|target_type| {
+                decimal_narrowed_size(source_type, target_type).map(|size| (target_type, size))
+            });
+            match narrowed {
+                Some((target_type, size)) => {
+                    data_types.push(target_type.clone());
+                    narrowed_sizes.push(Some(size));
+                }
+                None => {
+                    data_types.push(source_type.clone());
+                    narrowed_sizes.push(None);
+                }
+            }
+        }
+        compactor.check_data_types(&data_types)?;
+
+        compactor.try_append(|compactor| {
+            while self.flush(state) {
+                let mut columns = Vec::with_capacity(source_types.len());
+                if let Some(states_layout) = self.states_layout.as_ref() {
+                    for ((aggr, loc), return_type) in self
+                        .aggrs
+                        .iter()
+                        .zip(states_layout.states_loc.iter().cloned())
+                        .zip(source_types.iter())
+                    {
+                        let mut builder =
+                            ColumnBuilder::with_capacity(return_type, state.row_count);
+                        aggr.flush_result(state.state_places(), loc, &mut builder)?;
+                        columns.push(builder.build());
+                    }
+                }
+                columns.extend(state.take_group_columns());
+
+                for (column, size) in columns.iter_mut().zip(narrowed_sizes.iter()) {
+                    if let Some(size) = size {
+                        *column = narrow_decimal_column(column, *size)?;
+                    }
+                }
+                for (idx, column) in columns.iter().enumerate() {
+                    compactor.builder_mut(idx).append_column(column);
+                }
+                compactor.commit_rows(state.row_count);
+            }
+            Ok(())
+        })
+    }
+
     pub fn group_by_flush_all(&self) -> Result<DataBlock> {
         let mut state = PayloadFlushState::default();
         let mut blocks = vec![];
@@ -180,7 +437,110 @@ impl Payload {
         DataBlock::concat(&blocks)
     }
 
+    /// Flushes the rows row by row from the position of `state`, the finalized aggregate
+    /// results followed by the group values, for the consumers of rows like the protocol
+    /// encoders. The group values are read from the row layout of the payload directly,
+    /// without building the group columns and transposing them. The aggregate results are
+    /// still finalized batch by batch, the aggregate functions only finalize into columns.
+    ///
+    /// The iterator ends after the first error.
+    pub fn flush_rows<'a>(
+        &'a self,
+        state: &'a mut PayloadFlushState,
+    ) -> impl Iterator<Item = Result<Vec<Scalar>>> + 'a {
+        let mut batch_row = 0;
+        let mut batch_rows = 0;
+        let mut failed = false;
+        std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            if batch_row == batch_rows {
+                if !self.flush_addresses(state) {
+                    return None;
+                }
+                if let Err(cause) = self.flush_aggregate_results(state) {
+                    failed = true;
+                    return Some(Err(cause));
+                }
+                batch_row = 0;
+                batch_rows = state.row_count;
+            }
+
+            let address = state.addresses[batch_row];
+            let mut row = Vec::with_capacity(self.aggrs.len() + self.group_types.len());
+            for column in state.aggregate_results.iter() {
+                row.push(unsafe { column.index_unchecked(batch_row) }.to_owned());
+            }
+            for col_index in 0..self.group_types.len() {
+                match unsafe { self.flush_group_scalar(col_index, address) } {
+                    Ok(scalar) => row.push(scalar),
+                    Err(cause) => {
+                        failed = true;
+                        return Some(Err(cause));
+                    }
+                }
+            }
+            batch_row += 1;
+            Some(Ok(row))
+        })
+    }
+
+    unsafe fn flush_group_scalar(&self, col_index: usize, address: *const u8) -> Result<Scalar> {
+        let data_type = &self.group_types[col_index];
+        if data_type.is_nullable()
+            && !read::<bool>(address.add(self.validity_offsets[col_index]) as _)
+        {
+            return Ok(Scalar::Null);
+        }
+
+        let address = address.add(self.group_offsets[col_index]);
+        let scalar = match data_type.remove_nullable() {
+            DataType::Null => Scalar::Null,
+            DataType::EmptyArray => Scalar::EmptyArray,
+            DataType::EmptyMap => Scalar::EmptyMap,
+            DataType::Boolean => flush_type_scalar::<BooleanType>(address),
+            DataType::Number(v) => with_number_mapped_type!(|NUM_TYPE| match v {
+                NumberDataType::NUM_TYPE => flush_type_scalar::<NumberType<NUM_TYPE>>(address),
+            }),
+            DataType::Decimal(DecimalDataType::Decimal128(size)) => {
+                Scalar::Decimal(DecimalScalar::Decimal128(read::<i128>(address), size))
+            }
+            DataType::Decimal(DecimalDataType::Decimal256(size)) => {
+                Scalar::Decimal(DecimalScalar::Decimal256(read::<i256>(address), size))
+            }
+            DataType::Timestamp => flush_type_scalar::<TimestampType>(address),
+            DataType::Date => flush_type_scalar::<DateType>(address),
+            DataType::Binary => Scalar::Binary(flush_var_len_slice(address).to_vec()),
+            DataType::String => {
+                Scalar::String(std::str::from_utf8(flush_var_len_slice(address))?.to_string())
+            }
+            DataType::Bitmap => Scalar::Bitmap(flush_var_len_slice(address).to_vec()),
+            DataType::Variant => Scalar::Variant(flush_var_len_slice(address).to_vec()),
+            DataType::Geometry => Scalar::Geometry(flush_var_len_slice(address).to_vec()),
+            DataType::Nullable(_) => unreachable!(),
+            _ => bincode_deserialize_from_slice(flush_var_len_slice(address))?,
+        };
+        Ok(scalar)
+    }
+
     pub fn flush(&self, state: &mut PayloadFlushState) -> bool {
+        if !self.flush_addresses(state) {
+            return false;
+        }
+
+        if let Some(metrics) = state.metrics.as_mut() {
+            metrics.rows += state.row_count;
+        }
+        for col_index in 0..self.group_types.len() {
+            let col = self.flush_column(col_index, state);
+            state.group_columns.push(col);
+        }
+        true
+    }
+
+    // Loads the addresses and the states of the next batch of rows into `state`.
+    fn flush_addresses(&self, state: &mut PayloadFlushState) -> bool {
         if state.flush_page >= self.pages.len() {
             return false;
         }
@@ -192,7 +552,7 @@ impl Payload {
             state.flush_page_row = 0;
             state.row_count = 0;
 
-            return self.flush(state);
+            return self.flush_addresses(state);
         }
 
         let end = (state.flush_page_row + BATCH_SIZE).min(page.rows);
@@ -215,16 +575,25 @@ impl Payload {
             }
         }
 
-        for col_index in 0..self.group_types.len() {
-            let col = self.flush_column(col_index, state);
-            state.group_columns.push(col);
-        }
-
         state.flush_page_row = end;
         true
     }
 
     fn flush_column(&self, col_index: usize, state: &mut PayloadFlushState) -> Column {
+        if state.metrics.is_none() {
+            return self.flush_group_column(col_index, state);
+        }
+
+        let instant = Instant::now();
+        let col = self.flush_group_column(col_index, state);
+        let kind = FlushColumnKind::from_data_type(&self.group_types[col_index]);
+        if let Some(metrics) = state.metrics.as_mut() {
+            metrics.record(kind, &col, instant.elapsed());
+        }
+        col
+    }
+
+    fn flush_group_column(&self, col_index: usize, state: &mut PayloadFlushState) -> Column {
         let len = state.probe_state.row_count;
 
         let col_offset = self.group_offsets[col_index];
@@ -364,3 +733,14 @@ impl Payload {
         builder.build()
     }
 }
+
+unsafe fn flush_type_scalar<T: ArgType>(address: *const u8) -> Scalar {
+    T::upcast_scalar(read::<T::Scalar>(address))
+}
+
+// The variable-length values are stored as their length and the address of the data.
+unsafe fn flush_var_len_slice<'a>(address: *const u8) -> &'a [u8] {
+    let len = read::<u32>(address) as usize;
+    let data_address = read::<u64>(address.add(4)) as usize as *const u8;
+    std::slice::from_raw_parts(data_address, len)
+}
@@ -67,6 +67,23 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
     }
 }
 
+/// Whether the values of the type are stored in the row format as a `u32` length and
+/// the `u64` address of the data in the arena, instead of the values themselves.
+pub fn rowformat_is_address(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Binary
+            | DataType::String
+            | DataType::Bitmap
+            | DataType::Variant
+            | DataType::Geometry
+            | DataType::Geography
+            | DataType::Array(_)
+            | DataType::Map(_)
+            | DataType::Tuple(_)
+    )
+}
+
 /// This serialize column into row format by fixed size
 pub unsafe fn serialize_column_to_rowformat(
     arena: &Bump,
@@ -111,6 +111,7 @@ pub struct FunctionContext {
     pub parse_datetime_ignore_remainder: bool,
     pub enable_strict_datetime_parser: bool,
     pub random_function_seed: bool,
+    pub convert_tz_null_on_dst_transition: bool,
 }
 
 impl Default for FunctionContext {
@@ -131,6 +132,7 @@ impl Default for FunctionContext {
             parse_datetime_ignore_remainder: false,
             enable_strict_datetime_parser: true,
             random_function_seed: false,
+            convert_tz_null_on_dst_transition: false,
         }
     }
 }
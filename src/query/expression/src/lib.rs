@@ -84,6 +84,7 @@ pub use crate::register_comparison::*;
 pub use crate::register_vectorize::*;
 pub use crate::row::*;
 pub use crate::schema::*;
+pub use crate::utils::block_compactor::BlockCompactor;
 pub use crate::utils::block_thresholds::BlockThresholds;
 pub use crate::utils::*;
 pub use crate::values::*;
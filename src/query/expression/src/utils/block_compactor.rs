@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::types::DataType;
+use crate::BlockThresholds;
+use crate::Column;
+use crate::ColumnBuilder;
+use crate::DataBlock;
+
+/// Accumulates rows into one column builder per column of the target schema,
+/// and emits a block each time the accumulated rows are large enough according
+/// to the `BlockThresholds`.
+///
+/// The rows can be appended block by block with `append_block`, or written to
+/// the builders directly (see `builder_mut`) and then committed by `commit_rows`,
+/// wrapped in `try_append` to discard the partially appended rows on failure.
+pub struct BlockCompactor {
+    thresholds: BlockThresholds,
+    data_types: Vec<DataType>,
+    builders: Vec<ColumnBuilder>,
+    num_rows: usize,
+    blocks: Vec<DataBlock>,
+}
+
+impl BlockCompactor {
+    pub fn new(data_types: Vec<DataType>, thresholds: BlockThresholds) -> Self {
+        let builders = Self::create_builders(&data_types, &thresholds);
+        BlockCompactor {
+            thresholds,
+            data_types,
+            builders,
+            num_rows: 0,
+            blocks: vec![],
+        }
+    }
+
+    fn create_builders(
+        data_types: &[DataType],
+        thresholds: &BlockThresholds,
+    ) -> Vec<ColumnBuilder> {
+        data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, thresholds.min_rows_per_block))
+            .collect()
+    }
+
+    pub fn data_types(&self) -> &[DataType] {
+        &self.data_types
+    }
+
+    /// Checks that the rows to append have the types of the target schema.
+    pub fn check_data_types(&self, data_types: &[DataType]) -> Result<()> {
+        if data_types.len() != self.data_types.len() {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Expected {} columns to compact, but got {}",
+                self.data_types.len(),
+                data_types.len()
+            )));
+        }
+        for (i, (expected, actual)) in self.data_types.iter().zip(data_types).enumerate() {
+            if expected != actual {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "Expected type {expected} of column {i} to compact, but got {actual}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The builder of the `index`-th column, the rows written to the builders
+    /// must be committed by `commit_rows` afterwards.
+    pub fn builder_mut(&mut self, index: usize) -> &mut ColumnBuilder {
+        &mut self.builders[index]
+    }
+
+    pub fn append_block(&mut self, block: &DataBlock) -> Result<()> {
+        let data_types = block
+            .columns()
+            .iter()
+            .map(|entry| entry.data_type.clone())
+            .collect::<Vec<_>>();
+        self.check_data_types(&data_types)?;
+
+        for (builder, entry) in self.builders.iter_mut().zip(block.columns()) {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            builder.append_column(&column);
+        }
+        self.commit_rows(block.num_rows());
+        Ok(())
+    }
+
+    /// Runs `append` to append rows as a whole, if it fails, all the rows appended by it
+    /// are discarded, including the ones already emitted in blocks, so the compactor is
+    /// left as it was before.
+    pub fn try_append<F>(&mut self, append: F) -> Result<()>
+    where F: FnOnce(&mut Self) -> Result<()> {
+        let num_blocks = self.blocks.len();
+        let num_rows = self.num_rows;
+        let result = append(self);
+        if result.is_err() {
+            self.rollback(num_blocks, num_rows)?;
+        }
+        result
+    }
+
+    // Restores the first `num_rows` rows following the first `num_blocks` blocks into
+    // the builders, the rows after them, committed or not, are discarded.
+    fn rollback(&mut self, num_blocks: usize, num_rows: usize) -> Result<()> {
+        let blocks = self.blocks.split_off(num_blocks);
+        let pending = std::mem::replace(
+            &mut self.builders,
+            Self::create_builders(&self.data_types, &self.thresholds),
+        );
+        for (idx, (builder, pending)) in self.builders.iter_mut().zip(pending).enumerate() {
+            let mut columns = blocks
+                .iter()
+                .map(|block| {
+                    let entry = block.get_by_offset(idx);
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, block.num_rows())
+                })
+                .collect::<Vec<_>>();
+            columns.push(pending.build());
+            let column = Column::concat_columns(columns.into_iter())?;
+            builder.append_column(&column.slice(0..num_rows));
+        }
+        self.num_rows = num_rows;
+        Ok(())
+    }
+
+    pub fn commit_rows(&mut self, num_rows: usize) {
+        self.num_rows += num_rows;
+        let num_bytes = self.builders.iter().map(|b| b.memory_size()).sum();
+        if self.thresholds.check_large_enough(self.num_rows, num_bytes) {
+            self.emit();
+        }
+    }
+
+    fn emit(&mut self) {
+        let builders = std::mem::replace(
+            &mut self.builders,
+            Self::create_builders(&self.data_types, &self.thresholds),
+        );
+        let columns = builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect();
+        self.blocks.push(DataBlock::new_from_columns(columns));
+        self.num_rows = 0;
+    }
+
+    /// Takes the blocks emitted so far.
+    pub fn take_blocks(&mut self) -> Vec<DataBlock> {
+        std::mem::take(&mut self.blocks)
+    }
+
+    /// Emits the remaining rows even if they are not large enough, and takes
+    /// all the blocks.
+    pub fn finish(&mut self) -> Vec<DataBlock> {
+        if self.num_rows > 0 {
+            self.emit();
+        }
+        self.take_blocks()
+    }
+}
@@ -23,6 +23,7 @@ use borsh::BorshSerialize;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::AggrState;
 use databend_common_expression::AggrStateRegistry;
 use databend_common_expression::AggrStateType;
@@ -50,6 +51,8 @@ pub struct SortAggState {
 pub struct AggregateFunctionSortAdaptor {
     inner: AggregateFunctionRef,
     sort_descs: Vec<AggregateFunctionSortDesc>,
+    // Deduplicate the arguments after sorting, used for `agg(DISTINCT x ORDER BY y)`.
+    distinct: bool,
 }
 
 impl AggregateFunction for AggregateFunctionSortAdaptor {
@@ -185,13 +188,16 @@ impl AggregateFunction for AggregateFunctionSortAdaptor {
         let args = (0..block.num_columns())
             .filter(|i| !not_arg_indexes.contains(i))
             .collect_vec();
+        if self.distinct {
+            block = Self::distinct_by_args(block, &args)?;
+        }
 
         self.inner.init_state(inner_place);
         self.inner.accumulate(
             inner_place,
             InputColumns::new_block_proxy(&args, &block),
             None,
-            num_rows,
+            block.num_rows(),
         )?;
         self.inner.merge_result(inner_place, builder)
     }
@@ -222,7 +228,25 @@ impl AggregateFunctionSortAdaptor {
         if sort_descs.is_empty() {
             return Ok(inner);
         }
-        Ok(Arc::new(AggregateFunctionSortAdaptor { inner, sort_descs }))
+        Ok(Arc::new(AggregateFunctionSortAdaptor {
+            inner,
+            sort_descs,
+            distinct: false,
+        }))
+    }
+
+    /// Like `create`, but only the first row of each distinct argument tuple in sort order
+    /// is fed into `inner`. The `_distinct` combinator can not be used together with sorting,
+    /// because its hash set does not preserve the order of the rows.
+    pub fn create_distinct(
+        inner: AggregateFunctionRef,
+        sort_descs: Vec<AggregateFunctionSortDesc>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(AggregateFunctionSortAdaptor {
+            inner,
+            sort_descs,
+            distinct: true,
+        }))
     }
 
     fn get_state(place: AggrState) -> &mut SortAggState {
@@ -255,6 +279,22 @@ impl AggregateFunctionSortAdaptor {
         }
     }
 
+    fn distinct_by_args(block: DataBlock, args: &[usize]) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        {
+            let mut seen = HashSet::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let key = args
+                    .iter()
+                    .map(|i| block.get_by_offset(*i).value.index(row))
+                    .collect_vec();
+                bitmap.push(seen.insert(key));
+            }
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+
     fn merge_states_inner(state: &mut SortAggState, other: &SortAggState) {
         if state.columns.is_empty() && state.data_types.is_empty() {
             state.columns = other.columns.clone();
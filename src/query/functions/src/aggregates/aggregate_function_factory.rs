@@ -41,6 +41,7 @@ const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 7] = [
 ];
 
 const STATE_SUFFIX: &str = "_state";
+const DISTINCT_SUFFIX: &str = "_distinct";
 
 pub type AggregateFunctionCreator = Box<
     dyn Fn(
@@ -217,6 +218,18 @@ impl AggregateFunctionFactory {
         let name = name.as_ref();
         let mut features = AggregateFunctionFeatures::default();
 
+        // `agg(DISTINCT x ORDER BY y)`: deduplicate inside the sort adaptor after sorting,
+        // the distinct combinator would lose the order of the sorted rows.
+        if !sort_descs.is_empty() {
+            let lowercase_name = name.to_lowercase();
+            if let Some(nested_name) = lowercase_name.strip_suffix(DISTINCT_SUFFIX) {
+                if self.case_insensitive_desc.contains_key(nested_name) {
+                    let agg = self.get_or_null(nested_name, params, arguments, vec![], or_null)?;
+                    return AggregateFunctionSortAdaptor::create_distinct(agg, sort_descs);
+                }
+            }
+        }
+
         if NEED_NULL_AGGREGATE_FUNCTIONS.contains(&name) {
             let mut agg =
                 self.get_impl(name, params, arguments, sort_descs.clone(), &mut features)?;
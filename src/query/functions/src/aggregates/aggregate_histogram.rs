@@ -40,24 +40,54 @@ use crate::aggregates::aggregate_unary::UnaryState;
 use crate::aggregates::assert_variadic_arguments;
 use crate::aggregates::AggregateUnaryFunction;
 
+/// The state keeps at most `max_num_buckets * HISTOGRAM_SKETCH_FACTOR` distinct
+/// values, adjacent values are compacted together once the limit is exceeded.
+const HISTOGRAM_SKETCH_FACTOR: u64 = 256;
+
 struct HistogramData {
     pub max_num_buckets: u64,
     pub data_type: DataType,
 }
 
+impl HistogramData {
+    fn max_sketch_size(&self) -> usize {
+        self.max_num_buckets.saturating_mul(HISTOGRAM_SKETCH_FACTOR) as usize
+    }
+}
+
 impl FunctionData for HistogramData {
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
 
+/// Aggregated information of the values that were compacted into one key of
+/// the value map. The key is the upper bound of the compacted values.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HistogramEntry {
+    pub count: u64,
+    pub ndv: u64,
+}
+
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct HistogramState<T>
 where
     T: ValueType,
     T::Scalar: Ord + BorshSerialize + BorshDeserialize,
 {
-    pub value_map: BTreeMap<T::Scalar, u64>,
+    pub value_map: BTreeMap<T::Scalar, HistogramEntry>,
+}
+
+impl<T> HistogramState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    fn compact_if_needed(&mut self, max_size: usize) {
+        while max_size > 0 && self.value_map.len() > max_size {
+            self.value_map = compact_value_map(std::mem::take(&mut self.value_map));
+        }
+    }
 }
 
 impl<T> Default for HistogramState<T>
@@ -80,13 +110,21 @@ where
     fn add(
         &mut self,
         other: T::ScalarRef<'_>,
-        _function_data: Option<&dyn FunctionData>,
+        function_data: Option<&dyn FunctionData>,
     ) -> Result<()> {
         let other = T::to_owned_scalar(other);
         match self.value_map.entry(other) {
-            Entry::Occupied(o) => *o.into_mut() += 1,
+            Entry::Occupied(o) => o.into_mut().count += 1,
             Entry::Vacant(v) => {
-                v.insert(1);
+                v.insert(HistogramEntry { count: 1, ndv: 1 });
+                if let Some(histogram_data) = function_data {
+                    let histogram_data = unsafe {
+                        histogram_data
+                            .as_any()
+                            .downcast_ref_unchecked::<HistogramData>()
+                    };
+                    self.compact_if_needed(histogram_data.max_sketch_size());
+                }
             }
         };
 
@@ -96,7 +134,11 @@ where
     fn merge(&mut self, rhs: &Self) -> Result<()> {
         for (key, value) in rhs.value_map.iter() {
             match self.value_map.get_mut(key) {
-                Some(entry) => entry.add_assign(value),
+                Some(entry) => {
+                    entry.count.add_assign(value.count);
+                    // The key itself is the distinct value shared by both sides.
+                    entry.ndv += value.ndv - 1;
+                }
                 None => {
                     self.value_map.insert(key.clone(), *value);
                 }
@@ -117,6 +159,8 @@ where
                 .downcast_ref_unchecked::<HistogramData>()
         };
 
+        // States merged from multiple partitions may exceed the sketch size again.
+        self.compact_if_needed(histogram_data.max_sketch_size());
         let mut buckets = build_histogram(&self.value_map, histogram_data.max_num_buckets);
 
         let decimal_i128_size = histogram_data
@@ -321,7 +365,7 @@ struct Bucket<T> {
 ///
 /// `return` true if the values can be assigned to the buckets, false otherwise.
 fn can_assign_into_buckets<T: Ord>(
-    value_map: &BTreeMap<T, u64>,
+    value_map: &BTreeMap<T, HistogramEntry>,
     max_bucket_size: u64,
     num_buckets: u64,
 ) -> bool {
@@ -332,14 +376,14 @@ fn can_assign_into_buckets<T: Ord>(
     let mut used_buckets = 1;
     let mut current_bucket_size = 0;
 
-    for (_, count) in value_map.iter() {
-        current_bucket_size += count;
+    for (_, entry) in value_map.iter() {
+        current_bucket_size += entry.count;
 
         // If adding the current value to the current bucket would exceed max_bucket_size,
         // then we start a new bucket.
         if current_bucket_size > max_bucket_size {
             used_buckets += 1;
-            current_bucket_size = *count;
+            current_bucket_size = entry.count;
         }
 
         // If we have used more buckets than num_buckets, we cannot assign the values to buckets.
@@ -360,12 +404,15 @@ fn can_assign_into_buckets<T: Ord>(
 /// `value_map` the map of values and their counts
 /// `num_buckets` the desired number of buckets
 /// `return` the maximum number of values that can fit into each bucket
-fn calculate_bucket_max_values<T: Ord>(value_map: &BTreeMap<T, u64>, num_buckets: u64) -> u64 {
+fn calculate_bucket_max_values<T: Ord>(
+    value_map: &BTreeMap<T, HistogramEntry>,
+    num_buckets: u64,
+) -> u64 {
     // Assume that the value map is not empty
     debug_assert!(!value_map.is_empty());
 
     // Calculate the total number of values in the map
-    let total_values = value_map.values().sum();
+    let total_values = value_map.values().map(|entry| entry.count).sum();
 
     // If there is only one bucket, then all values will be assigned to that bucket
     if num_buckets == 1 {
@@ -434,8 +481,13 @@ fn calculate_bucket_max_values<T: Ord>(value_map: &BTreeMap<T, u64>, num_buckets
 /// `max_num_buckets` The maximum number of buckets that can be used.
 ///
 /// `return` the histogram buckets.
-fn build_histogram<T>(value_map: &BTreeMap<T, u64>, max_num_buckets: u64) -> Vec<Bucket<T>>
-where T: Ord + Clone {
+fn build_histogram<T>(
+    value_map: &BTreeMap<T, HistogramEntry>,
+    max_num_buckets: u64,
+) -> Vec<Bucket<T>>
+where
+    T: Ord + Clone,
+{
     let mut buckets = Vec::new();
 
     // If the input map is empty, there is nothing to build.
@@ -466,11 +518,11 @@ where T: Ord + Clone {
 
     // Iterate over the ordered map of distinct values and their counts.
     while let Some(curr) = iter.next() {
-        let count = *curr.1;
+        let count = curr.1.count;
         let current_value = curr.0;
 
         // Update the bucket counts and track the number of distinct values assigned.
-        distinct_values_count += 1;
+        distinct_values_count += curr.1.ndv;
         remaining_distinct_values -= 1;
         values_count += count;
         cumulative_values += count;
@@ -481,7 +533,7 @@ where T: Ord + Clone {
 
         if let Some(next) = next {
             if remaining_distinct_values as u64 > remaining_empty_buckets
-                && values_count + *next.1 <= bucket_max_values
+                && values_count + next.1.count <= bucket_max_values
             {
                 // If the current value is the last in the input map and there are more remaining
                 // distinct values than empty buckets and adding the value does not cause the bucket
@@ -513,14 +565,50 @@ where T: Ord + Clone {
     buckets
 }
 
+/// Halves the number of keys by folding every two adjacent values into the
+/// larger one. The total count and the number of distinct values are kept, only
+/// the lower bounds of the folded values are lost.
+fn compact_value_map<T: Ord>(
+    value_map: BTreeMap<T, HistogramEntry>,
+) -> BTreeMap<T, HistogramEntry> {
+    let mut compacted = BTreeMap::new();
+    let mut pending: Option<(T, HistogramEntry)> = None;
+
+    for (key, entry) in value_map.into_iter() {
+        match pending.take() {
+            None => pending = Some((key, entry)),
+            Some((_, prev)) => {
+                compacted.insert(key, HistogramEntry {
+                    count: prev.count + entry.count,
+                    ndv: prev.ndv + entry.ndv,
+                });
+            }
+        }
+    }
+
+    // An odd number of keys leaves the largest one unpaired.
+    if let Some((key, entry)) = pending {
+        compacted.insert(key, entry);
+    }
+
+    compacted
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
     use super::build_histogram;
-
-    fn create_test_map<T: Ord>(mut values: Vec<T>, mut counts: Vec<u64>) -> BTreeMap<T, u64> {
-        std::iter::zip(values.drain(..), counts.drain(..)).collect()
+    use super::compact_value_map;
+    use super::HistogramEntry;
+
+    fn create_test_map<T: Ord>(
+        mut values: Vec<T>,
+        mut counts: Vec<u64>,
+    ) -> BTreeMap<T, HistogramEntry> {
+        std::iter::zip(values.drain(..), counts.drain(..))
+            .map(|(value, count)| (value, HistogramEntry { count, ndv: 1 }))
+            .collect()
     }
 
     // Test case 1: Test when input map is empty.
@@ -683,4 +771,26 @@ mod tests {
             assert_eq!(b.pre_sum, pre_sum[i]);
         }
     }
+
+    // Test case 8: Test compaction keeps the total count and the number of distinct values.
+    #[test]
+    fn test_compact_value_map() {
+        let values = (0..101).collect::<Vec<u32>>();
+        let counts = (0..101).map(|v| v % 7 + 1).collect::<Vec<u64>>();
+        let total: u64 = counts.iter().sum();
+        let value_map = create_test_map(values, counts);
+
+        let compacted = compact_value_map(value_map);
+        assert_eq!(compacted.len(), 51);
+        assert_eq!(compacted.keys().last(), Some(&100));
+        assert_eq!(compacted.values().map(|e| e.count).sum::<u64>(), total);
+        assert_eq!(compacted.values().map(|e| e.ndv).sum::<u64>(), 101);
+
+        let max_num_buckets = 8;
+        let buckets = build_histogram::<u32>(&compacted, max_num_buckets);
+        assert!(buckets.len() as u64 <= max_num_buckets);
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), total);
+        assert_eq!(buckets.iter().map(|b| b.ndv).sum::<u64>(), 101);
+        assert_eq!(buckets.last().unwrap().upper, 100);
+    }
 }
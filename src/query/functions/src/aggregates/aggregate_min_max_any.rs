@@ -31,6 +31,7 @@ use ethnum::i256;
 
 use super::aggregate_function_factory::AggregateFunctionDescription;
 use super::aggregate_function_factory::AggregateFunctionSortDesc;
+use super::aggregate_scalar_state::first_valid_index;
 use super::aggregate_scalar_state::need_manual_drop_state;
 use super::aggregate_scalar_state::ChangeIf;
 use super::aggregate_scalar_state::CmpAny;
@@ -100,6 +101,15 @@ where C: ChangeIf<StringType> + Default
             return Ok(());
         }
 
+        if C::KEEP_FIRST {
+            if self.value.is_none() {
+                if let Some(idx) = first_valid_index(column_len, validity) {
+                    self.add(other.index(idx).unwrap(), function_data)?;
+                }
+            }
+            return Ok(());
+        }
+
         let column_iter = 0..other.len();
         if let Some(validity) = validity {
             if validity.null_count() == column_len {
@@ -215,6 +225,17 @@ where
             return Ok(());
         }
 
+        // `any` only stores the first valid value, the rest of the batch is skipped.
+        if C::KEEP_FIRST {
+            if self.value.is_none() {
+                if let Some(idx) = first_valid_index(column_len, validity) {
+                    let v = unsafe { T::index_column_unchecked(&other, idx) };
+                    self.add(v, function_data)?;
+                }
+            }
+            return Ok(());
+        }
+
         let column_iter = T::iter_column(&other);
         if let Some(v) = validity {
             if v.true_count() as f64 / v.len() as f64 >= SELECTIVITY_THRESHOLD {
@@ -21,6 +21,7 @@ use databend_common_expression::types::decimal::*;
 use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::*;
 
+use super::aggregate_scalar_state::first_valid_index;
 use super::aggregate_scalar_state::ChangeIf;
 use super::FunctionData;
 use super::UnaryState;
@@ -90,6 +91,16 @@ where
             return Ok(());
         }
 
+        if C::KEEP_FIRST {
+            if self.value.is_none() {
+                if let Some(idx) = first_valid_index(column_len, validity) {
+                    let v = unsafe { T::index_column_unchecked(&other, idx) };
+                    self.add(v, function_data)?;
+                }
+            }
+            return Ok(());
+        }
+
         let column_iter = T::iter_column(&other);
         match validity {
             Some(validity)
@@ -80,10 +80,22 @@ macro_rules! with_compare_mapped_type {
 }
 
 pub trait ChangeIf<T: ValueType>: Send + Sync + 'static {
+    /// The first value is never changed, so only the first valid row of a batch is needed.
+    const KEEP_FIRST: bool = false;
+
     fn change_if(l: &T::ScalarRef<'_>, r: &T::ScalarRef<'_>) -> bool;
     fn change_if_ordering(ordering: Ordering) -> bool;
 }
 
+/// The index of the first valid row of a column with `len` rows.
+#[inline]
+pub fn first_valid_index(len: usize, validity: Option<&Bitmap>) -> Option<usize> {
+    match validity {
+        Some(validity) => validity.iter().take(len).position(|valid| valid),
+        None => (len > 0).then_some(0),
+    }
+}
+
 #[derive(Default)]
 pub struct CmpMin;
 
@@ -126,6 +138,8 @@ where
 pub struct CmpAny;
 
 impl<T: ValueType> ChangeIf<T> for CmpAny {
+    const KEEP_FIRST: bool = true;
+
     #[inline]
     fn change_if(_: &T::ScalarRef<'_>, _: &T::ScalarRef<'_>) -> bool {
         false
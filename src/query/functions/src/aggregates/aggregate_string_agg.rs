@@ -46,6 +46,9 @@ use crate::aggregates::AggrStateLoc;
 use crate::aggregates::AggregateFunction;
 use crate::BUILTIN_FUNCTIONS;
 
+// Appended to the result when it is truncated to the max length.
+const OVERFLOW_INDICATOR: &str = "...";
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct StringAggState {
     values: String,
@@ -56,6 +59,8 @@ pub struct AggregateStringAggFunction {
     display_name: String,
     delimiter: String,
     value_type: DataType,
+    // Max number of characters of the result, the rest is replaced by `OVERFLOW_INDICATOR`.
+    max_length: Option<usize>,
 }
 
 impl AggregateFunction for AggregateStringAggFunction {
@@ -173,7 +178,19 @@ impl AggregateFunction for AggregateStringAggFunction {
         let builder = StringType::try_downcast_builder(builder).unwrap();
         if !state.values.is_empty() {
             let len = state.values.len() - self.delimiter.len();
-            builder.put_and_commit(&state.values[..len]);
+            let values = &state.values[..len];
+            let truncate_pos = self
+                .max_length
+                .and_then(|max_length| values.char_indices().nth(max_length))
+                .map(|(pos, _)| pos);
+            match truncate_pos {
+                Some(pos) => {
+                    builder.put_str(&values[..pos]);
+                    builder.put_str(OVERFLOW_INDICATOR);
+                    builder.commit_row();
+                }
+                None => builder.put_and_commit(values),
+            }
         } else {
             builder.put_and_commit("");
         }
@@ -201,11 +218,13 @@ impl AggregateStringAggFunction {
         display_name: &str,
         delimiter: String,
         value_type: DataType,
+        max_length: Option<usize>,
     ) -> Result<Arc<dyn AggregateFunction>> {
         let func = AggregateStringAggFunction {
             display_name: display_name.to_string(),
             delimiter,
             value_type,
+            max_length,
         };
         Ok(Arc::new(func))
     }
@@ -235,12 +254,24 @@ pub fn try_create_aggregate_string_agg_function(
             display_name, value_type
         )));
     }
-    let delimiter = if params.len() == 1 {
+    let delimiter = if !params.is_empty() {
         params[0].as_string().unwrap().clone()
     } else {
         String::new()
     };
-    AggregateStringAggFunction::try_create(display_name, delimiter, value_type)
+    let max_length = match params.get(1) {
+        Some(param) => match param.as_number().and_then(|n| n.integer_to_i128()) {
+            Some(max_length) if max_length >= 0 => Some(max_length as usize),
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "The max length of aggregate function {} must be non-negative int",
+                    display_name
+                )));
+            }
+        },
+        None => None,
+    };
+    AggregateStringAggFunction::try_create(display_name, delimiter, value_type, max_length)
 }
 
 pub fn aggregate_string_agg_function_desc() -> AggregateFunctionDescription {
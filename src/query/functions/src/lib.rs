@@ -57,7 +57,12 @@ pub fn is_cacheable_function(name: &str) -> bool {
 #[ctor]
 pub static BUILTIN_FUNCTIONS: FunctionRegistry = builtin_functions();
 
-pub const ASYNC_FUNCTIONS: [Ascii<&str>; 2] = [Ascii::new("nextval"), Ascii::new("dict_get")];
+pub const ASYNC_FUNCTIONS: [Ascii<&str>; 4] = [
+    Ascii::new("nextval"),
+    Ascii::new("dict_get"),
+    Ascii::new("table_checksum"),
+    Ascii::new("system$estimate_query_cost"),
+];
 
 pub const GENERAL_WITHIN_GROUP_FUNCTIONS: [Ascii<&str>; 5] = [
     Ascii::new("array_agg"),
@@ -399,20 +399,60 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry
-        .register_passthrough_nullable_1_arg::<ArrayType<ArrayType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
-            "array_flatten",
-            |_, domain| FunctionDomain::Domain(domain.clone().flatten()),
-            vectorize_1_arg::<ArrayType<ArrayType<GenericType<0>>>, ArrayType<GenericType<0>>>(
-                |arr, ctx| {
-                    let mut builder = ColumnBuilder::with_capacity(&ctx.generics[0], arr.len());
-                    for v in arr.iter() {
-                        builder.append_column(&v);
+    // Flattens one level of the nested arrays as before, or the first `depth` levels
+    // by `array_flatten(arr, depth)`, the binder injects the constant `depth` as the param.
+    registry.register_function_factory("array_flatten", |params, args_type| {
+        let depth = match (params, args_type) {
+            ([], [_]) => 1,
+            ([depth], [_, DataType::Number(ty)]) if ty.is_integer() => {
+                usize::try_from(depth.get_i64()?)
+                    .ok()
+                    .filter(|depth| *depth > 0)?
+            }
+            _ => return None,
+        };
+        let (return_type, levels) = flatten_array_type(&args_type[0].remove_nullable(), depth)?;
+
+        let mut signature_args_type = args_type.to_vec();
+        signature_args_type[0] = args_type[0].remove_nullable();
+        let function = Function {
+            signature: FunctionSignature {
+                name: "array_flatten".to_string(),
+                args_type: signature_args_type,
+                return_type: return_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, _| {
+                    let len = args[0].as_column().map(|col| col.len());
+                    let mut builder = ColumnBuilder::with_capacity(&return_type, len.unwrap_or(1));
+                    for idx in 0..len.unwrap_or(1) {
+                        let array = unsafe { args[0].index_unchecked(idx) };
+                        match (&return_type, array) {
+                            (DataType::Array(element_type), ScalarRef::Array(col)) => {
+                                let mut element_builder =
+                                    ColumnBuilder::with_capacity(element_type, col.len());
+                                for item in col.iter() {
+                                    flatten_array_item(item, levels, &mut element_builder);
+                                }
+                                builder.push(ScalarRef::Array(element_builder.build()));
+                            }
+                            _ => builder.push_default(),
+                        }
                     }
-                    builder.build()
-                }
-            ),
-        );
+                    match len {
+                        Some(_) => Value::Column(builder.build()),
+                        None => Value::Scalar(builder.build_scalar()),
+                    }
+                }),
+            },
+        };
+        if args_type[0].is_nullable() {
+            Some(Arc::new(function.passthrough_nullable()))
+        } else {
+            Some(Arc::new(function))
+        }
+    });
 
     registry
         .register_passthrough_nullable_2_arg::<ArrayType<StringType>, StringType, StringType, _, _>(
@@ -858,10 +898,8 @@ pub fn register(registry: &mut FunctionRegistry) {
                 let data_type = arr.data_type();
                 let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len());
                 let mut set: StackHashSet<u128, 16> = StackHashSet::with_capacity(arr.len());
+                // NULL is hashed like the other values, so at most one NULL is kept.
                 for val in arr.iter() {
-                    if val == ScalarRef::Null {
-                        continue;
-                    }
                     let mut hasher = SipHasher24::new();
                     val.hash(&mut hasher);
                     let hash128 = hasher.finish128();
@@ -877,6 +915,34 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         }),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullType>, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<ArrayType<NullType>, EmptyArrayType>(|_, _| ()),
+    );
+
+    // The arrays without NULL elements are also compacted by this function,
+    // they are casted to the arrays of nullable elements first.
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
+        "array_compact",
+        |_, domain| {
+            FunctionDomain::Domain(domain.as_ref().and_then(|domain| domain.value.clone()).map(|value| *value))
+        },
+        vectorize_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>>(|arr, ctx| {
+            let mut builder = ColumnBuilder::with_capacity(&ctx.generics[0], arr.len());
+            for val in arr.iter().flatten() {
+                builder.push(val);
+            }
+            builder.build()
+        }),
+    );
 }
 
 fn register_array_aggr(registry: &mut FunctionRegistry) {
@@ -1010,3 +1076,35 @@ fn register_array_aggr(registry: &mut FunctionRegistry) {
         );
     }
 }
+
+/// Returns the return type of `array_flatten` and the number of the nested levels
+/// to flatten, at most `depth` levels are flattened.
+fn flatten_array_type(data_type: &DataType, depth: usize) -> Option<(DataType, usize)> {
+    let mut element_type = match data_type {
+        DataType::EmptyArray => return Some((DataType::EmptyArray, 0)),
+        DataType::Array(element_type) => element_type.as_ref().clone(),
+        _ => return None,
+    };
+    let mut levels = 0;
+    while levels < depth {
+        match element_type.remove_nullable() {
+            DataType::Array(box inner_type) => element_type = inner_type,
+            DataType::EmptyArray => return Some((DataType::EmptyArray, levels + 1)),
+            _ => break,
+        }
+        levels += 1;
+    }
+    Some((DataType::Array(Box::new(element_type)), levels))
+}
+
+/// Pushes the elements of `item` flattened by `levels` levels, the NULL arrays
+/// have no elements.
+fn flatten_array_item(item: ScalarRef, levels: usize, builder: &mut ColumnBuilder) {
+    if levels == 0 {
+        builder.push(item);
+    } else if let ScalarRef::Array(col) = item {
+        for item in col.iter() {
+            flatten_array_item(item, levels - 1, builder);
+        }
+    }
+}
@@ -17,7 +17,9 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Arc;
 
+use databend_common_expression::types::decimal::DecimalScalar;
 use databend_common_expression::types::decimal::DecimalType;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::number::F32;
@@ -25,8 +27,10 @@ use databend_common_expression::types::number::F64;
 use databend_common_expression::types::ArgType;
 use databend_common_expression::types::BitmapType;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::GenericType;
+use databend_common_expression::types::Int64Type;
 use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
@@ -39,9 +43,16 @@ use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::with_integer_mapped_type;
 use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::FromData;
+use databend_common_expression::Function;
 use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
 use ethnum::i256;
 use md5::Digest;
 use md5::Md5 as Md5Hasher;
@@ -75,6 +86,50 @@ pub fn register(registry: &mut FunctionRegistry) {
         });
     }
 
+    // The stable hash of any number of values, the same values always have the same hash
+    // whatever the versions and platforms are, so it can be used to shard and sample the rows.
+    registry.register_function_factory("hash", |_, args_type| {
+        if args_type.is_empty() {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "hash".to_string(),
+                args_type: (0..args_type.len()).map(DataType::Generic).collect(),
+                return_type: DataType::Number(NumberDataType::Int64),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(|args, _| {
+                    let len = args.iter().find_map(|arg| match arg {
+                        Value::Column(col) => Some(col.len()),
+                        _ => None,
+                    });
+
+                    let hash_row = |idx: usize| {
+                        let mut hasher = StableHasher::default();
+                        for arg in args {
+                            match arg {
+                                Value::Scalar(scalar) => hasher.write_scalar(&scalar.as_ref()),
+                                Value::Column(col) => {
+                                    hasher.write_scalar(&unsafe { col.index_unchecked(idx) })
+                                }
+                            }
+                        }
+                        hasher.finish() as i64
+                    };
+
+                    match len {
+                        Some(len) => Value::Column(Int64Type::from_data(
+                            (0..len).map(hash_row).collect::<Vec<_>>(),
+                        )),
+                        None => Value::Scalar(Scalar::Number(NumberScalar::Int64(hash_row(0)))),
+                    }
+                }),
+            },
+        }))
+    });
+
     // Could be used in exchange
     registry.register_passthrough_nullable_1_arg::<GenericType<0>, NumberType<u64>, _, _>(
         "siphash64",
@@ -271,6 +326,141 @@ impl Hasher for CityHasher64 {
     }
 }
 
+/// The 64-bit FNV-1a hash of the values of the `hash` function.
+///
+/// The encoding of the values is part of the hash, so it must never be changed. Each value
+/// is prefixed by the tag of its kind and the variable-length values by their lengths, so
+/// that the boundaries of the values are hashed as well. The integers are hashed as `i128`
+/// and the floats as `f64`, so the same value has the same hash whatever its width is.
+struct StableHasher {
+    state: u64,
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self {
+            state: 0xcbf2_9ce4_8422_2325,
+        }
+    }
+}
+
+impl StableHasher {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_tag(&mut self, tag: u8) {
+        self.write(&[tag]);
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_bytes(&mut self, tag: u8, bytes: &[u8]) {
+        self.write_tag(tag);
+        self.write_len(bytes.len());
+        self.write(bytes);
+    }
+
+    fn write_column(&mut self, tag: u8, column: &Column) {
+        self.write_tag(tag);
+        self.write_len(column.len());
+        for value in column.iter() {
+            self.write_scalar(&value);
+        }
+    }
+
+    fn write_scalar(&mut self, scalar: &ScalarRef) {
+        match scalar {
+            ScalarRef::Null => self.write_tag(0),
+            ScalarRef::Boolean(v) => {
+                self.write_tag(1);
+                self.write(&[*v as u8]);
+            }
+            ScalarRef::Number(v) => match v.integer_to_i128() {
+                Some(v) => {
+                    self.write_tag(2);
+                    self.write(&v.to_le_bytes());
+                }
+                None => self.write_float(v.float_to_f64().unwrap()),
+            },
+            ScalarRef::Decimal(DecimalScalar::Decimal128(v, size)) => {
+                self.write_decimal128(*v, size.scale)
+            }
+            ScalarRef::Decimal(DecimalScalar::Decimal256(v, size)) => {
+                let low = *v.low();
+                if i256::from(low) == *v {
+                    self.write_decimal128(low, size.scale);
+                } else {
+                    self.write_tag(5);
+                    self.write(&[size.scale]);
+                    self.write(&v.to_le_bytes());
+                }
+            }
+            ScalarRef::String(v) => self.write_bytes(6, v.as_bytes()),
+            ScalarRef::Binary(v) => self.write_bytes(7, v),
+            ScalarRef::Variant(v) => self.write_bytes(8, v),
+            ScalarRef::Bitmap(v) => self.write_bytes(9, v),
+            ScalarRef::Geometry(v) => self.write_bytes(10, v),
+            ScalarRef::Geography(v) => self.write_bytes(11, v.0),
+            ScalarRef::Timestamp(v) => {
+                self.write_tag(12);
+                self.write(&v.to_le_bytes());
+            }
+            ScalarRef::Date(v) => {
+                self.write_tag(13);
+                self.write(&(*v as i64).to_le_bytes());
+            }
+            ScalarRef::Interval(v) => {
+                self.write_tag(14);
+                self.write(&v.months().to_le_bytes());
+                self.write(&v.days().to_le_bytes());
+                self.write(&v.microseconds().to_le_bytes());
+            }
+            ScalarRef::EmptyArray => self.write_tag(15),
+            ScalarRef::EmptyMap => self.write_tag(16),
+            ScalarRef::Array(column) => self.write_column(17, column),
+            ScalarRef::Map(column) => self.write_column(18, column),
+            ScalarRef::Tuple(fields) => {
+                self.write_tag(19);
+                self.write_len(fields.len());
+                for field in fields {
+                    self.write_scalar(field);
+                }
+            }
+        }
+    }
+
+    fn write_float(&mut self, v: f64) {
+        // The zeros and the NaNs are equal, whatever their signs and payloads are.
+        let v = if v == 0.0 {
+            0.0
+        } else if v.is_nan() {
+            f64::NAN
+        } else {
+            v
+        };
+        self.write_tag(3);
+        self.write(&v.to_bits().to_le_bytes());
+    }
+
+    fn write_decimal128(&mut self, v: i128, scale: u8) {
+        self.write_tag(4);
+        self.write(&[scale]);
+        self.write(&v.to_le_bytes());
+    }
+}
+
 pub trait DFHash {
     fn hash<H: Hasher>(&self, state: &mut H);
 }
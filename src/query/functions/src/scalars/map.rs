@@ -17,6 +17,7 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use databend_common_expression::types::map::KvColumn;
+use databend_common_expression::types::map::KvPair;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::ArgType;
@@ -33,6 +34,7 @@ use databend_common_expression::types::NumberType;
 use databend_common_expression::types::SimpleDomain;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::vectorize_1_arg;
+use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::vectorize_with_builder_4_arg;
@@ -49,6 +51,8 @@ use siphasher::sip128::Hasher128;
 use siphasher::sip128::SipHasher24;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("map", &["map_from_arrays"]);
+
     registry
         .register_passthrough_nullable_2_arg::<EmptyArrayType, EmptyArrayType, EmptyMapType, _, _>(
             "map",
@@ -170,6 +174,56 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_1_arg_core::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    // The map is an array of the key-value tuples, so the entries share its column.
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<KvPair<GenericType<0>, GenericType<1>>>, _, _>(
+        "map_entries",
+        |_, domain| FunctionDomain::Domain(domain.clone()),
+        |map, _| match map {
+            Value::Scalar(map) => Value::Scalar(map),
+            Value::Column(map) => Value::Column(map),
+        },
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyMapType, _, _>(
+        "map_from_entries",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<KvPair<GenericType<0>, GenericType<1>>>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_from_entries",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<ArrayType<KvPair<GenericType<0>, GenericType<1>>>, MapType<GenericType<0>, GenericType<1>>>(
+            |entries, output, ctx| {
+                let key_type = &ctx.generics[0];
+                if !check_valid_map_key_type(key_type) {
+                    ctx.set_error(output.len(), format!("map keys can not be {}", key_type));
+                } else {
+                    let mut set: StackHashSet<u128, 16> =
+                        StackHashSet::with_capacity(entries.len());
+                    for (key, val) in entries.iter() {
+                        let mut hasher = SipHasher24::new();
+                        key.hash(&mut hasher);
+                        let hash_key = hasher.finish128().into();
+                        if set.contains(&hash_key) {
+                            ctx.set_error(output.len(), "map keys have to be unique");
+                            break;
+                        }
+                        let _ = set.set_insert(hash_key);
+                        output.put_item((key, val));
+                    }
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
     registry.register_2_arg::<EmptyMapType, EmptyMapType, EmptyMapType, _, _>(
         "map_cat",
         |_, _, _| FunctionDomain::Full,
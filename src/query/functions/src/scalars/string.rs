@@ -72,8 +72,10 @@ pub const ALL_STRING_FUNC_NAMES: &[&str] = &[
     "substr",
     "split",
     "split_part",
+    "string_split",
     "concat",
     "concat_ws",
+    "regexp_extract_all",
     "regexp_instr",
     "regexp_like",
     "regexp_replace",
@@ -758,6 +760,27 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "string_split",
+            |_, _, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<StringType, StringType, ArrayType<StringType>>(
+                |s, sep, output, _ctx| {
+                    if sep.is_empty() {
+                        // An empty delimiter splits the string into characters.
+                        for (i, c) in s.char_indices() {
+                            output.builder.put_and_commit(&s[i..i + c.len_utf8()]);
+                        }
+                    } else {
+                        for v in s.split(sep) {
+                            output.builder.put_and_commit(v);
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
     registry
         .register_passthrough_nullable_3_arg::<StringType, StringType, NumberType<i64>, StringType, _, _>(
             "split_part",
@@ -765,8 +788,15 @@ pub fn register(registry: &mut FunctionRegistry) {
             vectorize_with_builder_3_arg::<StringType, StringType, NumberType<i64>, StringType>(
                 |s, sep, part, output, _| {
                     if sep.is_empty() {
-                        if part == 0 || part == 1 || part == -1 {
-                            output.put_str(s);
+                        // An empty delimiter splits the string into characters.
+                        let mut chars = s.char_indices().map(|(i, c)| &s[i..i + c.len_utf8()]);
+                        let v = if part < 0 {
+                            chars.nth_back((part.unsigned_abs() - 1) as usize)
+                        } else {
+                            chars.nth((part.max(1) - 1) as usize)
+                        };
+                        if let Some(v) = v {
+                            output.put_str(v);
                         }
                     } else if s != sep {
                         if part < 0 {
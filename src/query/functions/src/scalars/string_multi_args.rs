@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_expression::passthrough_nullable;
+use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::NumberScalar;
@@ -416,6 +417,37 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    registry.register_function_factory("regexp_extract_all", |_, args_type| {
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let args_type = match args_type.len() {
+            2 => vec![DataType::String; 2],
+            3 => vec![
+                DataType::String,
+                DataType::String,
+                DataType::Number(NumberDataType::Int64),
+            ],
+            _ => return None,
+        };
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: "regexp_extract_all".to_string(),
+                args_type,
+                return_type: DataType::Array(Box::new(DataType::String)),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(regexp_extract_all_fn),
+            },
+        };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
 }
 
 fn concat_fn(args: &[Value<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -644,7 +676,8 @@ fn regexp_replace_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<An
 
     let cached_reg = match (&pat_arg, &mt_arg) {
         (Value::Scalar(pat), Some(Value::Scalar(mt))) => {
-            match regexp::build_regexp_from_pattern("regexp_replace", pat, Some(mt)) {
+            let (mt, _) = regexp::split_global_flag(mt);
+            match regexp::build_regexp_from_pattern("regexp_replace", pat, Some(&mt)) {
                 Ok(re) => Some(re),
                 _ => None,
             }
@@ -668,9 +701,14 @@ fn regexp_replace_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<An
         let occur = occur_arg
             .as_ref()
             .map(|occur_arg| unsafe { occur_arg.index_unchecked(idx) });
-        let mt = mt_arg
-            .as_ref()
-            .map(|mt_arg| unsafe { mt_arg.index_unchecked(idx) });
+        let (mt, global) = match mt_arg.as_ref() {
+            Some(mt_arg) => {
+                let (mt, global) =
+                    regexp::split_global_flag(unsafe { mt_arg.index_unchecked(idx) });
+                (Some(mt), global)
+            }
+            None => (None, false),
+        };
 
         if let Some(occur) = occur {
             if occur < 0 {
@@ -696,7 +734,7 @@ fn regexp_replace_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<An
 
         let mut local_re = None;
         if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_replace", pat, mt) {
+            match regexp::build_regexp_from_pattern("regexp_replace", pat, mt.as_deref()) {
                 Ok(re) => {
                     local_re = Some(re);
                 }
@@ -714,7 +752,7 @@ fn regexp_replace_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<An
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(0);
 
-        regexp::regexp_replace(source, re, repl, pos, occur, &mut builder);
+        regexp::regexp_replace(source, re, repl, pos, occur, global, &mut builder);
         builder.commit_row();
     }
     match len {
@@ -843,7 +881,90 @@ fn regexp_substr_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<Any
     }
 }
 
+fn regexp_extract_all_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        Value::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let source_arg = args[0].try_downcast::<StringType>().unwrap();
+    let pat_arg = args[1].try_downcast::<StringType>().unwrap();
+    let group_arg = if args.len() >= 3 {
+        Some(args[2].try_downcast::<Int64Type>().unwrap())
+    } else {
+        None
+    };
+
+    let cached_reg = match &pat_arg {
+        Value::Scalar(pat) => {
+            match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                Ok(re) => Some(re),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    let size = len.unwrap_or(1);
+    let mut builder = ArrayColumnBuilder::<StringType>::with_capacity(size, 0, &[]);
+    for idx in 0..size {
+        let source = unsafe { source_arg.index_unchecked(idx) };
+        let pat = unsafe { pat_arg.index_unchecked(idx) };
+        let group = group_arg
+            .as_ref()
+            .map(|group_arg| unsafe { group_arg.index_unchecked(idx) })
+            .unwrap_or(0);
+
+        if source.is_empty() || pat.is_empty() {
+            builder.commit_row();
+            continue;
+        }
+
+        let mut local_re = None;
+        if cached_reg.is_none() {
+            match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                Ok(re) => {
+                    local_re = Some(re);
+                }
+                Err(err) => {
+                    ctx.set_error(builder.len(), err);
+                    builder.commit_row();
+                    continue;
+                }
+            }
+        };
+        let re = cached_reg
+            .as_ref()
+            .unwrap_or_else(|| local_re.as_ref().unwrap());
+
+        // `captures_len` includes the implicit group 0 of the whole match.
+        let max_group = re.captures_len() as i64 - 1;
+        if group < 0 || group > max_group {
+            ctx.set_error(
+                builder.len(),
+                format!(
+                    "Incorrect arguments to regexp_extract_all: group must be between 0 and {}, but got {}",
+                    max_group, group
+                ),
+            );
+            builder.commit_row();
+            continue;
+        }
+
+        regexp::regexp_extract_all(source, re, group as usize, &mut builder.builder);
+        builder.commit_row();
+    }
+    match len {
+        Some(_) => Value::Column(ArrayType::<StringType>::upcast_column(builder.build())),
+        _ => Value::Scalar(ArrayType::<StringType>::upcast_scalar(
+            builder.build_scalar(),
+        )),
+    }
+}
+
 pub mod regexp {
+    use std::borrow::Cow;
+
     use databend_common_expression::types::string::StringColumnBuilder;
     use regex::Regex;
     use regex::RegexBuilder;
@@ -960,6 +1081,54 @@ pub mod regexp {
         0
     }
 
+    /// Splits the `g` flag of `regexp_replace` from the other match types,
+    /// returns the remaining match types and whether the `g` flag is set.
+    #[inline]
+    pub fn split_global_flag(mt: &str) -> (Cow<'_, str>, bool) {
+        if mt.contains('g') {
+            (Cow::Owned(mt.replace('g', "")), true)
+        } else {
+            (Cow::Borrowed(mt), false)
+        }
+    }
+
+    /// Converts the backreferences `\1`..`\9` of the replacement string into the
+    /// `${1}`..`${9}` syntax of the regex crate, `\\` stands for a literal backslash.
+    /// A literal dollar sign is written as `\$` or `$$`.
+    #[inline]
+    pub fn expand_backreferences(repl: &str) -> Cow<'_, str> {
+        if !repl.contains('\\') {
+            return Cow::Borrowed(repl);
+        }
+
+        let mut expanded = String::with_capacity(repl.len() + 4);
+        let mut chars = repl.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                expanded.push(c);
+                continue;
+            }
+            match chars.peek() {
+                Some(d) if d.is_ascii_digit() => {
+                    expanded.push_str("${");
+                    expanded.push(*d);
+                    expanded.push('}');
+                    chars.next();
+                }
+                Some('\\') => {
+                    expanded.push('\\');
+                    chars.next();
+                }
+                Some('$') => {
+                    expanded.push_str("$$");
+                    chars.next();
+                }
+                _ => expanded.push('\\'),
+            }
+        }
+        Cow::Owned(expanded)
+    }
+
     #[inline]
     pub fn regexp_replace(
         s: &str,
@@ -967,6 +1136,7 @@ pub mod regexp {
         repl: &str,
         pos: i64,
         occur: i64,
+        global: bool,
         builder: &mut StringColumnBuilder,
     ) {
         let pos = (pos - 1) as usize;
@@ -984,17 +1154,40 @@ pub mod regexp {
         let (before_trimmed, trimmed) = (&s[..char_pos], &s[char_pos..]);
         builder.put_str(before_trimmed);
 
-        // means we should replace all matched strings
-        if occur == 0 {
-            builder.put_str(&re.replace_all(trimmed, repl));
-        } else if let Some(capature) = re.captures_iter(trimmed).nth((occur - 1) as _) {
+        // occurrence 0 means we should replace all matched strings, the `g` flag
+        // replaces the given occurrence and all the following ones.
+        let (skip, take) = match occur {
+            0 => (0, usize::MAX),
+            _ if global => ((occur - 1) as usize, usize::MAX),
+            _ => ((occur - 1) as usize, 1),
+        };
+        let repl = expand_backreferences(repl);
+        let mut last_end = 0;
+        let mut replaced = String::new();
+        for capture in re.captures_iter(trimmed).skip(skip).take(take) {
             // unwrap on 0 is OK because captures only reports matches.
-            let m = capature.get(0).unwrap();
-            builder.put_str(&trimmed[0..m.start()]);
-            builder.put_str(repl);
-            builder.put_str(&trimmed[m.end()..]);
-        } else {
-            builder.put_str(trimmed);
+            let m = capture.get(0).unwrap();
+            builder.put_str(&trimmed[last_end..m.start()]);
+            replaced.clear();
+            capture.expand(&repl, &mut replaced);
+            builder.put_str(&replaced);
+            last_end = m.end();
+        }
+        builder.put_str(&trimmed[last_end..]);
+    }
+
+    /// Pushes the `group` of all the non-overlapping matches in `s`, a group which
+    /// doesn't participate in a match is extracted as an empty string.
+    #[inline]
+    pub fn regexp_extract_all(
+        s: &str,
+        re: &Regex,
+        group: usize,
+        builder: &mut StringColumnBuilder,
+    ) {
+        for capture in re.captures_iter(s) {
+            let value = capture.get(group).map(|m| m.as_str()).unwrap_or("");
+            builder.put_and_commit(value);
         }
     }
 
@@ -57,6 +57,7 @@ use databend_common_expression::vectorize_1_arg;
 use databend_common_expression::vectorize_2_arg;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::EvalContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionProperty;
@@ -115,6 +116,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // convert_timezone( target_timezone, 'timestamp')
     register_convert_timezone(registry);
+
+    // convert_tz('timestamp', source_timezone, target_timezone)
+    register_convert_tz(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -209,6 +213,75 @@ fn register_convert_timezone(registry: &mut FunctionRegistry) {
     );
 }
 
+fn register_convert_tz(registry: &mut FunctionRegistry) {
+    // 3 arguments function [src_timestamp, source_timezone, target_timezone]
+    registry.register_combine_nullable_3_arg::<TimestampType, StringType, StringType, TimestampType, _, _>(
+        "convert_tz",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<TimestampType, StringType, StringType, NullableType<TimestampType>>(
+            |src_timestamp, source_tz, target_tz, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.push_null();
+                        return;
+                    }
+                }
+                match convert_tz(
+                    src_timestamp,
+                    source_tz,
+                    target_tz,
+                    &ctx.func_ctx.tz,
+                    ctx.func_ctx.convert_tz_null_on_dst_transition,
+                ) {
+                    Ok(Some(ts)) => output.push(ts),
+                    Ok(None) => output.push_null(),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push_null();
+                    }
+                }
+            },
+        ),
+    );
+}
+
+/// Interpret the wall clock time of `src_timestamp` (as displayed in the session timezone)
+/// in `source_tz`, and return the timestamp displaying the same instant in `target_tz`.
+///
+/// A wall clock time skipped or repeated by a DST transition of `source_tz` returns `None`
+/// if `null_on_dst_transition` is set, otherwise it is resolved like `TimeZone::to_zoned`:
+/// a skipped time is moved forward by the length of the gap, a repeated time takes the
+/// earlier offset.
+fn convert_tz(
+    src_timestamp: i64,
+    source_tz: &str,
+    target_tz: &str,
+    session_tz: &TimeZone,
+    null_on_dst_transition: bool,
+) -> Result<Option<i64>, String> {
+    let source_tz =
+        TimeZone::get(source_tz).map_err(|e| format!("cannot parse source `timezone`. {}", e))?;
+    let target_tz =
+        TimeZone::get(target_tz).map_err(|e| format!("cannot parse target `timezone`. {}", e))?;
+
+    let datetime = src_timestamp.to_timestamp(session_tz.clone()).datetime();
+    let source = source_tz.to_ambiguous_zoned(datetime);
+    if source.is_ambiguous() && null_on_dst_transition {
+        return Ok(None);
+    }
+    let source = source.compatible().map_err(|e| e.to_string())?;
+
+    let target = source.with_time_zone(target_tz).datetime();
+    let mut ts = session_tz
+        .to_ambiguous_zoned(target)
+        .compatible()
+        .map_err(|e| e.to_string())?
+        .timestamp()
+        .as_microsecond();
+    clamp_timestamp(&mut ts);
+    Ok(Some(ts))
+}
+
 fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
     registry.register_aliases("to_date", &["str_to_date", "date"]);
     registry.register_aliases("to_year", &["str_to_year", "year"]);
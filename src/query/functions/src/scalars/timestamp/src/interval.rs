@@ -26,6 +26,7 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::EvalContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
@@ -39,6 +40,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     // data/timestamp/interval +/- interval
     register_interval_add_sub(registry);
     register_number_to_interval(registry);
+    // date_bin(stride, source[, origin])
+    register_date_bin(registry);
 }
 
 fn register_string_to_interval(registry: &mut FunctionRegistry) {
@@ -180,6 +183,56 @@ fn register_interval_add_sub(registry: &mut FunctionRegistry) {
         );
 }
 
+fn register_date_bin(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "date_bin",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |stride, source, output, ctx| match date_bin(stride, source, 0) {
+                    Ok(t) => output.push(t),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_3_arg::<IntervalType, TimestampType, TimestampType, TimestampType, _, _>(
+        "date_bin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<IntervalType, TimestampType, TimestampType, TimestampType>(
+            |stride, source, origin, output, ctx| match date_bin(stride, source, origin) {
+                Ok(t) => output.push(t),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
+
+/// Truncate `source` to the start of the bin of width `stride` it falls in, where the
+/// bins are aligned to `origin` (the Unix epoch if omitted). The bin start is always
+/// at or before `source`, also for timestamps before the origin.
+fn date_bin(stride: months_days_micros, source: i64, origin: i64) -> Result<i64, String> {
+    if stride.months() != 0 {
+        return Err("date_bin doesn't support strides containing months or years".to_string());
+    }
+    let stride = (stride.days() as i64)
+        .checked_mul(months_days_micros::MICROS_PER_DAY)
+        .and_then(|days| days.checked_add(stride.microseconds()))
+        .filter(|stride| *stride > 0)
+        .ok_or_else(|| "date_bin stride must be greater than zero".to_string())?;
+    source
+        .checked_sub(origin)
+        .and_then(|delta| delta.div_euclid(stride).checked_mul(stride))
+        .and_then(|delta| origin.checked_add(delta))
+        .ok_or_else(|| "date_bin result is out of range".to_string())
+}
+
 fn register_number_to_interval(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
         "epoch",
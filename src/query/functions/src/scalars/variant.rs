@@ -14,7 +14,7 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeSet;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::iter::once;
 use std::sync::Arc;
 
@@ -98,6 +98,7 @@ use jsonb::is_string;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::jsonpath::JsonPath;
 use jsonb::keypath::parse_key_paths;
+use jsonb::object_each;
 use jsonb::object_keys;
 use jsonb::parse_value;
 use jsonb::path_exists;
@@ -113,7 +114,6 @@ use jsonb::to_u64;
 use jsonb::type_of;
 
 pub fn register(registry: &mut FunctionRegistry) {
-    registry.register_aliases("json_object_keys", &["object_keys"]);
     registry.register_aliases("to_string", &["json_to_string"]);
 
     registry.register_passthrough_nullable_1_arg::<VariantType, VariantType, _, _>(
@@ -266,6 +266,58 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    // The keys and values of the top-level object, in the order they are stored.
+    // NULL if the value is not an object.
+    registry.register_combine_nullable_1_arg::<VariantType, ArrayType<StringType>, _, _>(
+        "object_keys",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, NullableType<ArrayType<StringType>>>(
+            |val, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.push_null();
+                        return;
+                    }
+                }
+                match object_each(val) {
+                    Some(items) => {
+                        for (key, _) in items {
+                            output.builder.put_item(&String::from_utf8_lossy(&key));
+                        }
+                        output.builder.commit_row();
+                        output.validity.push(true);
+                    }
+                    None => output.push_null(),
+                }
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_1_arg::<VariantType, ArrayType<VariantType>, _, _>(
+        "object_values",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, NullableType<ArrayType<VariantType>>>(
+            |val, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.push_null();
+                        return;
+                    }
+                }
+                match object_each(val) {
+                    Some(items) => {
+                        for (_, value) in items {
+                            output.builder.put_item(&value);
+                        }
+                        output.builder.commit_row();
+                        output.validity.push(true);
+                    }
+                    None => output.push_null(),
+                }
+            },
+        ),
+    );
+
     registry.register_function_factory("get_by_keypath", |_, args_type| {
         if args_type.len() != 2 {
             return None;
@@ -1570,6 +1622,34 @@ pub fn register(registry: &mut FunctionRegistry) {
         Some(Arc::new(f.error_to_null()))
     });
 
+    registry.register_function_factory("object_construct", |_, args_type| {
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "object_construct".to_string(),
+                args_type: (0..args_type.len()).map(DataType::Generic).collect(),
+                return_type: DataType::Variant,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(object_construct_fn),
+            },
+        }))
+    });
+
+    registry.register_function_factory("object_construct_keep_null", |_, args_type| {
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "object_construct_keep_null".to_string(),
+                args_type: (0..args_type.len()).map(DataType::Generic).collect(),
+                return_type: DataType::Variant,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(object_construct_keep_null_fn),
+            },
+        }))
+    });
+
     registry.register_function_factory("json_array", |_, args_type| {
         Some(Arc::new(Function {
             signature: FunctionSignature {
@@ -1797,17 +1877,28 @@ fn json_array_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyTyp
 }
 
 fn json_object_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
-    json_object_impl_fn(args, ctx, false)
+    json_object_impl_fn(args, ctx, false, false)
 }
 
 fn json_object_keep_null_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
-    json_object_impl_fn(args, ctx, true)
+    json_object_impl_fn(args, ctx, true, false)
+}
+
+fn object_construct_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    json_object_impl_fn(args, ctx, false, true)
 }
 
+fn object_construct_keep_null_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    json_object_impl_fn(args, ctx, true, true)
+}
+
+// If `overwrite_duplicate` is true, the last value of a duplicate key wins,
+// otherwise duplicate keys are an error.
 fn json_object_impl_fn(
     args: &[Value<AnyType>],
     ctx: &mut EvalContext,
     keep_null: bool,
+    overwrite_duplicate: bool,
 ) -> Value<AnyType> {
     let (columns, len) = prepare_args_columns(args, ctx);
     let cap = len.unwrap_or(1);
@@ -1818,7 +1909,7 @@ fn json_object_impl_fn(
             builder.commit_row();
         }
     } else {
-        let mut set = HashSet::new();
+        let mut set = HashMap::new();
         let mut kvs = Vec::with_capacity(columns.len() / 2);
         for idx in 0..cap {
             set.clear();
@@ -1830,31 +1921,47 @@ fn json_object_impl_fn(
                     continue;
                 }
                 let v = unsafe { columns[i + 1].index_unchecked(idx) };
-                if v == ScalarRef::Null && !keep_null {
+                let skip_null = v == ScalarRef::Null && !keep_null;
+                // The NULL values are skipped, but if the last value of a duplicate key wins,
+                // a NULL value still removes the previous value of its key.
+                if skip_null && !overwrite_duplicate {
                     continue;
                 }
                 let key = match k {
                     ScalarRef::String(v) => v,
+                    _ if skip_null => continue,
                     _ => {
                         has_err = true;
                         ctx.set_error(builder.len(), "Key must be a string value");
                         break;
                     }
                 };
-                if set.contains(&key) {
-                    has_err = true;
-                    ctx.set_error(builder.len(), "Keys have to be unique");
-                    break;
+                let val = (!skip_null).then(|| {
+                    let mut val = vec![];
+                    cast_scalar_to_variant(v, &ctx.func_ctx.tz, &mut val);
+                    val
+                });
+                match set.get(&key) {
+                    Some(pos) if overwrite_duplicate => {
+                        kvs[*pos] = (key, val);
+                    }
+                    Some(_) => {
+                        has_err = true;
+                        ctx.set_error(builder.len(), "Keys have to be unique");
+                        break;
+                    }
+                    None if val.is_none() => {}
+                    None => {
+                        set.insert(key, kvs.len());
+                        kvs.push((key, val));
+                    }
                 }
-                set.insert(key);
-                let mut val = vec![];
-                cast_scalar_to_variant(v, &ctx.func_ctx.tz, &mut val);
-                kvs.push((key, val));
             }
             if !has_err {
-                if let Err(err) =
-                    build_object(kvs.iter().map(|(k, v)| (k, &v[..])), &mut builder.data)
-                {
+                let kvs = kvs
+                    .iter()
+                    .filter_map(|(k, v)| v.as_ref().map(|v| (k, &v[..])));
+                if let Err(err) = build_object(kvs, &mut builder.data) {
                     ctx.set_error(builder.len(), err.to_string());
                 }
             }
@@ -27,14 +27,26 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::fmt;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use bumpalo::Bump;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_block_value_eq;
 use databend_common_expression::block_debug::assert_block_value_sort_eq;
 use databend_common_expression::get_states_layout;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::number::NumberColumnBuilder;
 use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalColumn;
 use databend_common_expression::types::DecimalDataType;
 use databend_common_expression::types::DecimalSize;
 use databend_common_expression::types::DecimalType;
@@ -46,19 +58,136 @@ use databend_common_expression::types::Int64Type;
 use databend_common_expression::types::Int8Type;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
+use databend_common_expression::AggrState;
+use databend_common_expression::AggrStateLoc;
+use databend_common_expression::AggrStateRegistry;
+use databend_common_expression::AggrStateType;
+use databend_common_expression::AggregateFunction;
+use databend_common_expression::AggregateFunctionRef;
 use databend_common_expression::AggregateHashTable;
+use databend_common_expression::BlockCompactor;
+use databend_common_expression::BlockThresholds;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::FlushColumnKind;
 use databend_common_expression::FromData;
 use databend_common_expression::HashTableConfig;
+use databend_common_expression::InputColumns;
 use databend_common_expression::PayloadFlushState;
 use databend_common_expression::ProbeState;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::StateAddr;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::aggregates::DecimalSumState;
 use ethnum::I256;
 use itertools::Itertools;
+use jsonb::parse_value;
+use jsonb::to_string;
+
+// Creates a hash table of `aggrs` grouped by `group_columns`, and adds all the rows.
+fn new_hashtable(
+    group_columns: &[Column],
+    aggrs: Vec<AggregateFunctionRef>,
+    params: &[Vec<Column>],
+    config: HashTableConfig,
+) -> AggregateHashTable {
+    let group_types = group_columns.iter().map(|c| c.data_type()).collect_vec();
+    let mut hashtable = AggregateHashTable::new(group_types, aggrs, config, Arc::new(Bump::new()));
+    let params = params.iter().map(|v| v.into()).collect_vec();
+    hashtable
+        .add_groups(
+            &mut ProbeState::default(),
+            group_columns.into(),
+            &params,
+            (&[]).into(),
+            group_columns[0].len(),
+        )
+        .unwrap();
+    hashtable
+}
+
+fn aggregate_function(name: &str, argument: DataType) -> AggregateFunctionRef {
+    AggregateFunctionFactory::instance()
+        .get(name, vec![], vec![argument], vec![])
+        .unwrap()
+}
+
+// The keys of `n` rows, `m` distinct keys in total.
+fn int64_keys(n: usize, m: usize) -> Column {
+    Int64Type::from_data((0..n).map(|x| (x % m) as i64).collect_vec())
+}
+
+fn int64_values(n: usize) -> Column {
+    Int64Type::from_data((0..n as i64).collect_vec())
+}
+
+// Merges all the results of `hashtable` into one block, the group columns followed by
+// the aggregate results.
+fn merge_all(hashtable: &mut AggregateHashTable, flush_state: &mut PayloadFlushState) -> DataBlock {
+    let mut blocks = Vec::new();
+    while hashtable.merge_result(flush_state).unwrap() {
+        let mut columns = flush_state.take_group_columns();
+        columns.extend_from_slice(&flush_state.take_aggregate_results());
+        blocks.push(DataBlock::new_from_columns(columns));
+    }
+    DataBlock::concat(&blocks).unwrap()
+}
+
+// The results of `sum` and `count` over the values of `int64_values(n)` grouped by
+// `int64_keys(n, m)`.
+fn sum_count_by_int64_keys(n: usize, m: usize) -> DataBlock {
+    DataBlock::new_from_columns(vec![
+        Int64Type::from_data((0..m as i64).collect_vec()),
+        Int64Type::from_data_with_validity(
+            (0..m as i64)
+                .map(|k| (0..(n / m) as i64).map(|i| k + i * m as i64).sum())
+                .collect_vec(),
+            vec![true; m],
+        ),
+        UInt64Type::from_data(vec![(n / m) as u64; m]),
+    ])
+}
+
+const DECIMAL256_SIZE: DecimalSize = DecimalSize {
+    precision: 50,
+    scale: 2,
+};
+
+const DECIMAL128_SIZE: DecimalSize = DecimalSize {
+    precision: 38,
+    scale: 2,
+};
+
+fn decimal256_column(values: Vec<I256>) -> Column {
+    Column::Decimal(DecimalColumn::Decimal256(values.into(), DECIMAL256_SIZE))
+}
+
+// The sorted values of the `index`-th column of `blocks`, narrowed to `DECIMAL128_SIZE`.
+fn narrowed_values(blocks: &[DataBlock], index: usize) -> Vec<i128> {
+    let mut values = blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block
+                .get_by_offset(index)
+                .value
+                .as_column()
+                .unwrap()
+                .clone();
+            let Column::Decimal(DecimalColumn::Decimal128(values, size)) = column else {
+                unreachable!()
+            };
+            assert_eq!(size, DECIMAL128_SIZE);
+            values.iter().copied().collect_vec()
+        })
+        .collect_vec();
+    values.sort();
+    values
+}
 
 // cargo test --package databend-common-functions --test it -- aggregates::agg_hashtable::test_agg_hashtable --exact --nocapture
 #[test]
@@ -214,3 +343,745 @@ fn test_layout() {
         Layout::from_size_align(16, 16).unwrap()
     );
 }
+
+#[test]
+fn test_agg_hashtable_any_value() {
+    let n = 10_000;
+    let m = 300;
+
+    // `any_value` only keeps the first value, the state is no larger than the value itself.
+    let aggr = aggregate_function("any_value", Int64Type::data_type());
+    let states_layout = get_states_layout(&[aggr]).unwrap();
+    assert_eq!(states_layout.layout, Layout::new::<Option<i64>>());
+
+    // The values of a group are in `[key * n, key * n + n)`, some of them are null.
+    let params = vec![vec![Int64Type::from_opt_data(
+        (0..n)
+            .map(|x| (x >= m).then_some(((x % m) * n + x) as i64))
+            .collect_vec(),
+    )]];
+    let nullable_aggr = aggregate_function("any_value", Int64Type::data_type().wrap_nullable());
+    let mut hashtable = new_hashtable(
+        &[int64_keys(n, m)],
+        vec![nullable_aggr],
+        &params,
+        HashTableConfig::default(),
+    );
+
+    let block = merge_all(&mut hashtable, &mut PayloadFlushState::default());
+    assert_eq!(block.num_rows(), m);
+    for row in 0..block.num_rows() {
+        let key = block.get_by_offset(0).value.index(row).unwrap();
+        let key = *key.as_number().unwrap().as_int64().unwrap();
+        // The first row of each group is null, the first non-null value is picked.
+        let value = block.get_by_offset(1).value.index(row).unwrap();
+        let value = *value.as_number().unwrap().as_int64().unwrap();
+        assert!(value >= key * n as i64 && value < (key + 1) * n as i64);
+    }
+}
+
+/// A user defined aggregate function computing the bitwise OR of UInt64 values,
+/// which counts the groups finalized through `flush_result`.
+#[derive(Default)]
+struct BitOrFunction {
+    flushed_rows: AtomicUsize,
+}
+
+struct BitOrState {
+    value: u64,
+}
+
+impl BitOrFunction {
+    fn values(columns: InputColumns) -> Vec<u64> {
+        UInt64Type::try_downcast_column(&columns[0])
+            .unwrap()
+            .iter()
+            .copied()
+            .collect()
+    }
+}
+
+impl AggregateFunction for BitOrFunction {
+    fn name(&self) -> &str {
+        "BitOrFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(UInt64Type::data_type())
+    }
+
+    fn init_state(&self, place: AggrState) {
+        place.write(|| BitOrState { value: 0 });
+    }
+
+    fn register_state(&self, registry: &mut AggrStateRegistry) {
+        registry.register(AggrStateType::Custom(Layout::new::<BitOrState>()));
+    }
+
+    fn accumulate(
+        &self,
+        place: AggrState,
+        columns: InputColumns,
+        _validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<BitOrState>();
+        for value in Self::values(columns) {
+            state.value |= value;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        loc: &[AggrStateLoc],
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (place, value) in places.iter().zip(Self::values(columns)) {
+            AggrState::new(*place, loc).get::<BitOrState>().value |= value;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: AggrState, columns: InputColumns, row: usize) -> Result<()> {
+        place.get::<BitOrState>().value |= Self::values(columns)[row];
+        Ok(())
+    }
+
+    fn serialize(&self, place: AggrState, writer: &mut Vec<u8>) -> Result<()> {
+        writer.extend_from_slice(&place.get::<BitOrState>().value.to_le_bytes());
+        Ok(())
+    }
+
+    fn merge(&self, place: AggrState, reader: &mut &[u8]) -> Result<()> {
+        let (bytes, rest) = reader.split_at(8);
+        place.get::<BitOrState>().value |= u64::from_le_bytes(bytes.try_into().unwrap());
+        *reader = rest;
+        Ok(())
+    }
+
+    fn merge_states(&self, place: AggrState, rhs: AggrState) -> Result<()> {
+        place.get::<BitOrState>().value |= rhs.get::<BitOrState>().value;
+        Ok(())
+    }
+
+    fn merge_result(&self, place: AggrState, builder: &mut ColumnBuilder) -> Result<()> {
+        match builder {
+            ColumnBuilder::Number(NumberColumnBuilder::UInt64(builder)) => {
+                builder.push(place.get::<BitOrState>().value);
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    fn flush_result(
+        &self,
+        places: &[StateAddr],
+        loc: Box<[AggrStateLoc]>,
+        builder: &mut ColumnBuilder,
+    ) -> Result<()> {
+        self.flushed_rows.fetch_add(places.len(), Ordering::Relaxed);
+        for place in places {
+            self.merge_result(AggrState::new(*place, &loc), builder)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BitOrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bit_or")
+    }
+}
+
+#[test]
+fn test_agg_hashtable_flush_udaf() {
+    let keys = Int64Type::from_data(vec![0i64, 1, 0, 1, 2, 0]);
+    let values = UInt64Type::from_data(vec![1u64, 2, 4, 8, 16, 1]);
+
+    let bit_or = Arc::new(BitOrFunction::default());
+    let aggrs: Vec<AggregateFunctionRef> = vec![bit_or.clone()];
+    let mut hashtable = new_hashtable(&[keys], aggrs, &[vec![values]], HashTableConfig::default());
+
+    let mut flush_state = PayloadFlushState::default();
+    let mut blocks = Vec::new();
+    while hashtable.merge_result(&mut flush_state).unwrap() {
+        assert_eq!(flush_state.state_places().len(), flush_state.row_count);
+        let mut columns = flush_state.take_group_columns();
+        columns.extend_from_slice(&flush_state.take_aggregate_results());
+        blocks.push(DataBlock::new_from_columns(columns));
+    }
+    let block = DataBlock::concat(&blocks).unwrap();
+
+    let expected = DataBlock::new_from_columns(vec![
+        Int64Type::from_data(vec![0i64, 1, 2]),
+        UInt64Type::from_data(vec![5u64, 10, 16]),
+    ]);
+    assert_block_value_sort_eq(&block, &expected);
+    assert_eq!(bit_or.flushed_rows.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_agg_hashtable_flush_completed_partitions() {
+    let n = 10_000;
+    let m = 1000;
+
+    let aggrs = vec![
+        aggregate_function("sum", Int64Type::data_type()),
+        aggregate_function("count", Int64Type::data_type()),
+    ];
+    let config = HashTableConfig::default().with_initial_radix_bits(3);
+    let mut hashtable = AggregateHashTable::new(
+        vec![Int64Type::data_type()],
+        aggrs,
+        config,
+        Arc::new(Bump::new()),
+    );
+
+    // Simulate a streaming shuffle which sends the rows ordered by their partitions.
+    let mut hashes = vec![0u64; n];
+    let keys = vec![int64_keys(n, m)];
+    group_hash_columns((&keys).into(), &mut hashes);
+    let rows = (0..n)
+        .sorted_by_key(|row| hashtable.payload.partition_of(hashes[*row]))
+        .collect_vec();
+    let partitions = rows
+        .iter()
+        .map(|row| hashtable.payload.partition_of(hashes[*row]))
+        .collect_vec();
+    let keys = Int64Type::from_data(rows.iter().map(|x| (x % m) as i64).collect_vec());
+    let values = Int64Type::from_data(rows.iter().map(|x| *x as i64).collect_vec());
+
+    let mut probe_state = ProbeState::default();
+    let mut flush_state = PayloadFlushState::default();
+    let mut blocks = Vec::new();
+    let mut completed = 0;
+    for start in (0..n).step_by(300) {
+        let end = (start + 300).min(n);
+        let group_columns = vec![keys.slice(start..end)];
+        let params = vec![vec![values.slice(start..end)]; 2];
+        let params = params.iter().map(|v| v.into()).collect_vec();
+        hashtable
+            .add_groups(
+                &mut probe_state,
+                (&group_columns).into(),
+                &params,
+                (&[]).into(),
+                end - start,
+            )
+            .unwrap();
+
+        // The partitions before the one of the next row will not receive more rows.
+        let next_partition = match partitions.get(end) {
+            Some(partition) => *partition,
+            None => hashtable.payload.partition_count(),
+        };
+        while completed < next_partition {
+            hashtable.complete_partition(completed);
+            completed += 1;
+        }
+
+        let flushed_rows = blocks.iter().map(DataBlock::num_rows).sum::<usize>();
+        while hashtable
+            .flush_completed_partitions(&mut flush_state)
+            .unwrap()
+        {
+            let mut columns = flush_state.take_group_columns();
+            columns.extend_from_slice(&flush_state.take_aggregate_results());
+            blocks.push(DataBlock::new_from_columns(columns));
+        }
+        let newly_flushed = blocks.iter().map(DataBlock::num_rows).sum::<usize>() - flushed_rows;
+        let expected = (0..next_partition)
+            .map(|partition| hashtable.payload.payloads[partition].len())
+            .sum::<usize>();
+        assert_eq!(flushed_rows + newly_flushed, expected);
+    }
+    assert!(blocks.len() > 1);
+
+    // All the partitions were flushed early, nothing is left for the final merge.
+    assert!(!hashtable.merge_result(&mut flush_state).unwrap());
+
+    let block = DataBlock::concat(&blocks).unwrap();
+    assert_block_value_sort_eq(&block, &sum_count_by_int64_keys(n, m));
+
+    // The flushed partitions belong to the hash table, the same state can be reused by
+    // another hash table whose partitions are not flushed yet.
+    let mut other = new_hashtable(
+        &[keys],
+        vec![aggregate_function("count", Int64Type::data_type())],
+        &[vec![values]],
+        HashTableConfig::default().with_initial_radix_bits(3),
+    );
+    flush_state.clear();
+    let mut num_groups = 0;
+    while other.merge_result(&mut flush_state).unwrap() {
+        num_groups += flush_state.row_count;
+    }
+    assert_eq!(num_groups, m);
+}
+
+#[test]
+fn test_agg_hashtable_combine_with_hash_seed() {
+    let m = 100;
+    let n = 1000;
+
+    let seeded_hashtable = |hash_seed: u64| {
+        let config = HashTableConfig::default()
+            .with_initial_radix_bits(2)
+            .with_hash_seed(hash_seed);
+        new_hashtable(
+            &[int64_keys(n, m)],
+            vec![aggregate_function("count", Int64Type::data_type())],
+            &[vec![int64_values(n)]],
+            config,
+        )
+    };
+
+    // The payloads hashed with the same seed are merged.
+    let mut flush_state = PayloadFlushState::default();
+    let mut hashtable = seeded_hashtable(42);
+    hashtable
+        .combine(seeded_hashtable(42), &mut flush_state)
+        .unwrap();
+    assert_eq!(hashtable.len(), m);
+
+    let block = merge_all(&mut hashtable, &mut PayloadFlushState::default());
+    let expected = DataBlock::new_from_columns(vec![
+        Int64Type::from_data((0..m as i64).collect_vec()),
+        UInt64Type::from_data(vec![(2 * n / m) as u64; m]),
+    ]);
+    assert_block_value_sort_eq(&block, &expected);
+
+    // The groups hashed with different seeds cannot be merged.
+    let mut flush_state = PayloadFlushState::default();
+    let mut hashtable = seeded_hashtable(1);
+    let err = hashtable
+        .combine(seeded_hashtable(2), &mut flush_state)
+        .unwrap_err();
+    assert!(err.message().contains("aggregate_hash_seed"));
+}
+
+#[test]
+fn test_agg_hashtable_max_partition_count() {
+    let m = 500;
+    let n = 5000;
+
+    let aggregate = |max_partition_count: usize| {
+        let aggrs = vec![
+            aggregate_function("sum", Int64Type::data_type()),
+            aggregate_function("count", Int64Type::data_type()),
+        ];
+        let config = HashTableConfig::default()
+            .with_initial_radix_bits(6)
+            .with_max_partition_count(max_partition_count);
+        let mut hashtable = AggregateHashTable::new(
+            vec![Int64Type::data_type()],
+            aggrs,
+            config,
+            Arc::new(Bump::new()),
+        );
+        assert_eq!(hashtable.payload.partition_count(), max_partition_count);
+
+        let keys = int64_keys(n, m);
+        let values = int64_values(n);
+        let mut probe_state = ProbeState::default();
+        for start in (0..n).step_by(1000) {
+            let end = (start + 1000).min(n);
+            let group_columns = vec![keys.slice(start..end)];
+            let params = vec![vec![values.slice(start..end)]; 2];
+            let params = params.iter().map(|v| v.into()).collect_vec();
+            hashtable
+                .add_groups(
+                    &mut probe_state,
+                    (&group_columns).into(),
+                    &params,
+                    (&[]).into(),
+                    end - start,
+                )
+                .unwrap();
+        }
+        merge_all(&mut hashtable, &mut PayloadFlushState::default())
+    };
+
+    // A single partition flushes all the groups, the same as many partitions.
+    let expected = sum_count_by_int64_keys(n, m);
+    for max_partition_count in [1, 4, 64] {
+        assert_block_value_sort_eq(&aggregate(max_partition_count), &expected);
+    }
+}
+
+#[test]
+fn test_agg_hashtable_flush_metrics() {
+    let m = 100;
+    let n = 1000;
+
+    let group_columns = vec![
+        int64_keys(n, m),
+        StringType::from_data((0..n).map(|x| format!("{}", x % m)).collect_vec()),
+    ];
+    let mut hashtable = new_hashtable(
+        &group_columns,
+        vec![aggregate_function("count", Int64Type::data_type())],
+        &[vec![int64_values(n)]],
+        HashTableConfig::default(),
+    );
+
+    // Nothing is collected unless enabled.
+    let mut flush_state = PayloadFlushState::default();
+    while hashtable.merge_result(&mut flush_state).unwrap() {}
+    assert!(flush_state.metrics.is_none());
+
+    let mut flush_state = PayloadFlushState::default();
+    flush_state.enable_metrics();
+    let mut string_bytes = 0;
+    while hashtable.merge_result(&mut flush_state).unwrap() {
+        let columns = flush_state.take_group_columns();
+        string_bytes += columns[1].memory_size();
+    }
+
+    let metrics = flush_state.metrics.as_ref().unwrap();
+    let number = metrics.get(FlushColumnKind::Number);
+    assert_eq!(number.rows, m);
+    assert_eq!(number.bytes, m * 8);
+    let string = metrics.get(FlushColumnKind::String);
+    assert_eq!(string.rows, m);
+    assert_eq!(string.bytes, string_bytes);
+    assert_eq!(metrics.get(FlushColumnKind::Decimal).rows, 0);
+
+    let total = metrics.total();
+    assert_eq!(total.rows, m);
+    assert_eq!(total.bytes, m * 8 + string_bytes);
+    assert_eq!(total.time, number.time + string.time);
+}
+
+#[test]
+fn test_payload_flush_into_compactor() {
+    let n = 10_000;
+    let m = 3000;
+
+    let aggrs = vec![
+        aggregate_function("sum", Int64Type::data_type()),
+        aggregate_function("count", Int64Type::data_type()),
+    ];
+    let hashtable = new_hashtable(
+        &[int64_keys(n, m)],
+        aggrs.clone(),
+        &[vec![int64_values(n)], vec![int64_values(n)]],
+        HashTableConfig::default(),
+    );
+
+    let mut data_types = aggrs
+        .iter()
+        .map(|aggr| aggr.return_type().unwrap())
+        .collect_vec();
+    data_types.push(Int64Type::data_type());
+    let thresholds = BlockThresholds::new(1000, 700, usize::MAX);
+
+    // Flush into columns, assemble the blocks, and then feed them to the compactor.
+    let mut two_step = BlockCompactor::new(data_types.clone(), thresholds);
+    for payload in hashtable.payload.payloads.iter() {
+        let states_layout = payload.states_layout.as_ref().unwrap();
+        let mut state = PayloadFlushState::default();
+        while payload.flush(&mut state) {
+            let mut columns = Vec::new();
+            for (aggr, loc) in payload
+                .aggrs
+                .iter()
+                .zip(states_layout.states_loc.iter().cloned())
+            {
+                let return_type = aggr.return_type().unwrap();
+                let mut builder = ColumnBuilder::with_capacity(&return_type, state.row_count);
+                aggr.flush_result(state.state_places(), loc, &mut builder)
+                    .unwrap();
+                columns.push(builder.build());
+            }
+            columns.extend_from_slice(&state.take_group_columns());
+            two_step
+                .append_block(&DataBlock::new_from_columns(columns))
+                .unwrap();
+        }
+    }
+    let expected = two_step.finish();
+
+    let mut compactor = BlockCompactor::new(data_types.clone(), thresholds);
+    for payload in hashtable.payload.payloads.iter() {
+        let mut state = PayloadFlushState::default();
+        payload
+            .flush_into_compactor(&mut state, &mut compactor)
+            .unwrap();
+    }
+    let blocks = compactor.finish();
+
+    assert_eq!(blocks.len(), expected.len());
+    for (block, expected) in blocks.iter().zip(expected.iter()) {
+        assert_block_value_eq(block, expected);
+    }
+    assert_eq!(blocks.iter().map(DataBlock::num_rows).sum::<usize>(), m);
+    assert!(blocks[..blocks.len() - 1]
+        .iter()
+        .all(|block| block.num_rows() >= 700));
+
+    // The aggregate return types must match the target schema.
+    let mut mismatched = BlockCompactor::new(
+        vec![Int64Type::data_type(), Int64Type::data_type()],
+        thresholds,
+    );
+    let err = hashtable.payload.payloads[0]
+        .flush_into_compactor(&mut PayloadFlushState::default(), &mut mismatched)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TABLE_SCHEMA_MISMATCH);
+}
+
+#[test]
+fn test_payload_flush_into_compactor_narrow_decimal() {
+    let max = I256::from(i128::max_for_precision(38));
+    let fitting = vec![max, -max, I256::from(-12345), I256::ZERO, I256::from(1)];
+
+    let flush = |values: Vec<I256>| {
+        let n = values.len();
+        let hashtable = new_hashtable(
+            &[decimal256_column(values)],
+            vec![aggregate_function("count", Int64Type::data_type())],
+            &[vec![int64_values(n)]],
+            HashTableConfig::default(),
+        );
+
+        let mut compactor = BlockCompactor::new(
+            vec![
+                UInt64Type::data_type(),
+                DataType::Decimal(DecimalDataType::Decimal128(DECIMAL128_SIZE)),
+            ],
+            BlockThresholds::new(1000, 1, usize::MAX),
+        );
+        for payload in hashtable.payload.payloads.iter() {
+            payload.flush_into_compactor(&mut PayloadFlushState::default(), &mut compactor)?;
+        }
+        Ok::<_, ErrorCode>(narrowed_values(&compactor.finish(), 1))
+    };
+
+    // The values within the precision of the target type, including the bounds.
+    let mut expected = fitting.iter().map(|v| *v.low()).collect_vec();
+    expected.sort();
+    assert_eq!(flush(fitting).unwrap(), expected);
+
+    // A value exceeds the precision of the target type, but is still in the range of i128.
+    let err = flush(vec![I256::from(1), max + I256::ONE]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OVERFLOW);
+    assert!(err.message().contains(&(max + I256::ONE).display(2)));
+
+    // A negative value near the lower bound of i128.
+    let err = flush(vec![-max, I256::from(i128::MIN)]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OVERFLOW);
+    assert!(err.message().contains(&I256::from(i128::MIN).display(2)));
+
+    // A value out of the range of i128.
+    let err = flush(vec![I256::from(i128::MAX) * I256::from(2)]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OVERFLOW);
+}
+
+#[test]
+fn test_payload_flush_into_compactor_rollback() {
+    let max = I256::from(i128::max_for_precision(38));
+
+    // The last group doesn't fit, it's flushed in a later batch than the first groups.
+    let n = 3000;
+    let mut values = (0..n as i64 - 1).map(I256::from).collect_vec();
+    values.push(max + I256::ONE);
+    let hashtable = new_hashtable(
+        &[decimal256_column(values)],
+        vec![aggregate_function("count", Int64Type::data_type())],
+        &[vec![int64_values(n)]],
+        HashTableConfig::default().with_initial_radix_bits(0),
+    );
+
+    // Every batch of rows is emitted in a block as soon as it's committed.
+    let mut compactor = BlockCompactor::new(
+        vec![
+            UInt64Type::data_type(),
+            DataType::Decimal(DecimalDataType::Decimal128(DECIMAL128_SIZE)),
+        ],
+        BlockThresholds::new(1000, 1, usize::MAX),
+    );
+    let block = DataBlock::new_from_columns(vec![
+        UInt64Type::from_data(vec![1, 2]),
+        DecimalType::<i128>::from_data_with_size(vec![10i128, 20], DECIMAL128_SIZE),
+    ]);
+    compactor.append_block(&block).unwrap();
+
+    let err = hashtable.payload.payloads[0]
+        .flush_into_compactor(&mut PayloadFlushState::default(), &mut compactor)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OVERFLOW);
+
+    // None of the flushed rows are kept, including the ones of the emitted blocks.
+    let blocks = compactor.finish();
+    assert_eq!(blocks.len(), 1);
+    assert_block_value_eq(&blocks[0], &block);
+}
+
+#[test]
+fn test_payload_flush_into_compactor_narrow_decimal_sum() {
+    let max = I256::from(i128::max_for_precision(38));
+
+    // The result of `SUM` is a `Decimal256`, it's narrowed even if the arguments fit.
+    let flush = |keys: Vec<i32>, values: Vec<I256>| {
+        let aggr = aggregate_function(
+            "sum",
+            DataType::Decimal(DecimalDataType::Decimal256(DECIMAL256_SIZE)),
+        );
+        let hashtable = new_hashtable(
+            &[Int32Type::from_data(keys)],
+            vec![aggr],
+            &[vec![decimal256_column(values)]],
+            HashTableConfig::default(),
+        );
+
+        let mut compactor = BlockCompactor::new(
+            vec![
+                DataType::Decimal(DecimalDataType::Decimal128(DECIMAL128_SIZE)),
+                Int32Type::data_type(),
+            ],
+            BlockThresholds::new(1000, 1, usize::MAX),
+        );
+        for payload in hashtable.payload.payloads.iter() {
+            payload.flush_into_compactor(&mut PayloadFlushState::default(), &mut compactor)?;
+        }
+        Ok::<_, ErrorCode>(narrowed_values(&compactor.finish(), 0))
+    };
+
+    let sums = flush(vec![1, 1, 2], vec![max - I256::ONE, I256::ONE, -max]).unwrap();
+    assert_eq!(sums, vec![*(-max).low(), *max.low()]);
+
+    // Both arguments fit in the target type, but their sum doesn't.
+    let err = flush(vec![1, 1, 2], vec![max, I256::ONE, I256::ONE]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OVERFLOW);
+}
+
+#[test]
+fn test_agg_hashtable_group_by_variant() {
+    let large = format!("[{}]", (0..100_000).join(","));
+    let values = [
+        Some(r#"{"a":1,"b":[true,null]}"#),
+        Some(r#"{ "b" : [true, null], "a" : 1 }"#),
+        Some("[1,2,3]"),
+        None,
+        Some(r#""text""#),
+        Some(large.as_str()),
+        Some("null"),
+    ];
+    let n = 7000;
+
+    // Equal JSON values have the same JSONB encoding, whatever the whitespaces and key orders are.
+    let group_columns = vec![VariantType::from_opt_data(
+        (0..n)
+            .map(|x| values[x % values.len()].map(|v| parse_value(v.as_bytes()).unwrap().to_vec()))
+            .collect_vec(),
+    )];
+    let mut hashtable = new_hashtable(
+        &group_columns,
+        vec![aggregate_function("count", Int64Type::data_type())],
+        &[vec![int64_values(n)]],
+        HashTableConfig::default(),
+    );
+
+    let mut flush_state = PayloadFlushState::default();
+    let mut groups = Vec::new();
+    while hashtable.merge_result(&mut flush_state).unwrap() {
+        let columns = flush_state.take_group_columns();
+        let counts = flush_state.take_aggregate_results();
+        for row in 0..columns[0].len() {
+            let value = match columns[0].index(row).unwrap() {
+                ScalarRef::Variant(v) => Some(to_string(v)),
+                ScalarRef::Null => None,
+                other => unreachable!("{other:?}"),
+            };
+            let count = *counts[0]
+                .index(row)
+                .unwrap()
+                .as_number()
+                .unwrap()
+                .as_u_int64()
+                .unwrap();
+            groups.push((value, count));
+        }
+    }
+    groups.sort();
+
+    let per_value = (n / values.len()) as u64;
+    let mut expected = vec![
+        (None, per_value),
+        (Some(r#""text""#.to_string()), per_value),
+        (Some("[1,2,3]".to_string()), per_value),
+        (Some(large), per_value),
+        (Some("null".to_string()), per_value),
+        (
+            Some(r#"{"a":1,"b":[true,null]}"#.to_string()),
+            per_value * 2,
+        ),
+    ];
+    expected.sort();
+    assert_eq!(groups, expected);
+}
+
+#[test]
+fn test_payload_flush_rows() {
+    let n = 10_000;
+    let m = 3000;
+
+    // The nullable and variable-length group columns.
+    let group_columns = vec![
+        int64_keys(n, m),
+        StringType::from_opt_data(
+            (0..n)
+                .map(|x| (x % 5 != 0).then(|| format!("value-{}", "x".repeat(x % m % 40))))
+                .collect_vec(),
+        ),
+        Int32Type::from_opt_data(
+            (0..n)
+                .map(|x| (x % 3 != 0).then_some((x % m) as i32))
+                .collect_vec(),
+        ),
+        BinaryType::from_data((0..n).map(|x| (x % m).to_le_bytes().to_vec()).collect_vec()),
+    ];
+    let aggrs = vec![
+        aggregate_function("sum", Int64Type::data_type()),
+        aggregate_function("count", Int64Type::data_type()),
+    ];
+    let hashtable = new_hashtable(
+        &group_columns,
+        aggrs,
+        &[vec![int64_values(n)], vec![int64_values(n)]],
+        HashTableConfig::default(),
+    );
+
+    let mut total_rows = 0;
+    for payload in hashtable.payload.payloads.iter() {
+        // Transpose the columnar flush into rows, the aggregate results first.
+        let mut expected = Vec::new();
+        let mut state = PayloadFlushState::default();
+        while payload.flush(&mut state) {
+            payload.flush_aggregate_results(&mut state).unwrap();
+            let mut columns = state.take_aggregate_results();
+            columns.extend(state.take_group_columns());
+            for row in 0..state.row_count {
+                let row = columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect_vec();
+                expected.push(row);
+            }
+        }
+
+        let mut state = PayloadFlushState::default();
+        let rows = payload
+            .flush_rows(&mut state)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows, expected);
+        total_rows += rows.len();
+    }
+    assert_eq!(total_rows, m);
+}
@@ -39,6 +39,7 @@ fn test_array() {
     test_array_indexof(file);
     test_array_unique(file);
     test_array_distinct(file);
+    test_array_flatten(file);
     test_array_sum(file);
     test_array_avg(file);
     test_array_count(file);
@@ -318,6 +319,7 @@ fn test_array_unique(file: &mut impl Write) {
 
 fn test_array_distinct(file: &mut impl Write) {
     run_ast(file, "array_distinct([])", &[]);
+    run_ast(file, "array_distinct([NULL, NULL])", &[]);
     run_ast(file, "array_distinct([1, 1, 2, 2, 3, NULL])", &[]);
     run_ast(
         file,
@@ -333,6 +335,12 @@ fn test_array_distinct(file: &mut impl Write) {
     ]);
 }
 
+fn test_array_flatten(file: &mut impl Write) {
+    run_ast(file, "array_flatten([[1, 2], [3]])", &[]);
+    run_ast(file, "array_flatten([[[1, 2], [3]], [[4]]])", &[]);
+    run_ast(file, "array_flatten(2)([[[1, 2], [3]], [[4]]], 2)", &[]);
+}
+
 fn test_array_sum(file: &mut impl Write) {
     run_ast(file, "array_sum([])", &[]);
     run_ast(file, "array_sum([1, 2, 3, 4, 5, 6, 7])", &[]);
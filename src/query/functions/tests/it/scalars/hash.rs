@@ -33,6 +33,7 @@ fn test_hash() {
     test_siphash64(file);
     test_xxhash64(file);
     test_xxhash32(file);
+    test_stable_hash(file);
 }
 
 fn test_md5(file: &mut impl Write) {
@@ -141,3 +142,16 @@ fn test_xxhash32(file: &mut impl Write) {
         StringType::from_data(vec!["Dobrý den", "ß😀山"]),
     )]);
 }
+
+fn test_stable_hash(file: &mut impl Write) {
+    run_ast(file, "hash(1)", &[]);
+    run_ast(file, "hash('abc')", &[]);
+    run_ast(file, "hash(1, 'abc')", &[]);
+    run_ast(file, "hash(NULL)", &[]);
+    run_ast(file, "hash('ab', 'c')", &[]);
+    run_ast(file, "hash('a', 'bc')", &[]);
+    run_ast(file, "hash(a, b)", &[
+        ("a", Int64Type::from_data(vec![0i64, 1, 2])),
+        ("b", StringType::from_data(vec!["a", "b", "c"])),
+    ]);
+}
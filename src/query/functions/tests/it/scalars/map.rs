@@ -34,7 +34,8 @@ fn test_map() {
     test_map_delete(file);
     test_map_contains_key(file);
     test_map_pick(file);
-    test_map_insert(file)
+    test_map_insert(file);
+    test_map_entries(file)
 }
 
 fn test_map_cat(file: &mut impl Write) {
@@ -468,3 +469,10 @@ fn test_map_insert(file: &mut impl Write) {
         &columns,
     );
 }
+
+fn test_map_entries(file: &mut impl Write) {
+    run_ast(file, "map_entries({})", &[]);
+    run_ast(file, "map_entries({'a':1,'b':2})", &[]);
+    run_ast(file, "map_from_entries(map_entries({'a':1,'b':2}))", &[]);
+    run_ast(file, "map_from_entries(arrays_zip(['a','a'],[1,2]))", &[]);
+}
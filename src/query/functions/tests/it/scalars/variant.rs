@@ -72,6 +72,7 @@ fn test_variant() {
     test_json_object_insert(file);
     test_json_object_delete(file);
     test_json_object_pick(file);
+    test_object_keys_values(file);
 }
 
 fn test_parse_json(file: &mut impl Write) {
@@ -2131,3 +2132,20 @@ fn test_json_object_pick(file: &mut impl Write) {
         ),
     )]);
 }
+
+fn test_object_keys_values(file: &mut impl Write) {
+    run_ast(file, r#"object_keys(parse_json('{"k1":"v1","k2":2}'))"#, &[
+    ]);
+    run_ast(file, "object_keys(parse_json('[1,2,3]'))", &[]);
+    run_ast(file, "object_keys(parse_json(s))", &[(
+        "s",
+        StringType::from_data(vec!["[1,2]", r#"{"a":1,"b":2}"#, r#"{"c":3}"#]),
+    )]);
+
+    run_ast(
+        file,
+        r#"object_values(parse_json('{"k1":"v1","k2":2}'))"#,
+        &[],
+    );
+    run_ast(file, "object_values(parse_json('1'))", &[]);
+}
@@ -129,7 +129,11 @@ impl PlanProfile {
 
     pub fn accumulate(&mut self, profile: &Profile) {
         for index in 0..std::mem::variant_count::<ProfileStatisticsName>() {
-            self.statistics[index] += profile.statistics[index].load(Ordering::SeqCst);
+            self.statistics[index] = ProfileStatisticsName::merge_value(
+                index,
+                self.statistics[index],
+                profile.statistics[index].load(Ordering::SeqCst),
+            );
         }
 
         self.errors.extend(Self::get_profile_error(profile));
@@ -141,7 +145,11 @@ impl PlanProfile {
         }
 
         for index in 0..std::mem::variant_count::<ProfileStatisticsName>() {
-            self.statistics[index] += profile.statistics[index];
+            self.statistics[index] = ProfileStatisticsName::merge_value(
+                index,
+                self.statistics[index],
+                profile.statistics[index],
+            );
         }
 
         for errors in &profile.errors {
@@ -22,6 +22,7 @@ use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::seq_value::SeqV;
+use databend_common_storages_system::AccessHistoryTable;
 use databend_common_storages_system::BackgroundJobTable;
 use databend_common_storages_system::BackgroundTaskTable;
 use databend_common_storages_system::BacktraceTable;
@@ -29,6 +30,7 @@ use databend_common_storages_system::BuildOptionsTable;
 use databend_common_storages_system::CachesTable;
 use databend_common_storages_system::CatalogsTable;
 use databend_common_storages_system::ClusteringHistoryTable;
+use databend_common_storages_system::ClusteringInformationTable;
 use databend_common_storages_system::ClustersTable;
 use databend_common_storages_system::ColumnsTable;
 use databend_common_storages_system::ConfigsTable;
@@ -47,6 +49,7 @@ use databend_common_storages_system::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
 use databend_common_storages_system::MallocStatsTotalsTable;
 use databend_common_storages_system::MetricsTable;
+use databend_common_storages_system::NetworkPoliciesTable;
 use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
@@ -59,6 +62,7 @@ use databend_common_storages_system::QueryLogTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
 use databend_common_storages_system::StagesTable;
+use databend_common_storages_system::TableConstraintsTable;
 use databend_common_storages_system::TableFunctionsTable;
 use databend_common_storages_system::TablesTableWithHistory;
 use databend_common_storages_system::TablesTableWithoutHistory;
@@ -125,6 +129,11 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(AccessHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_access_history_size,
+            )),
+            ClusteringInformationTable::create(sys_db_meta.next_table_id()),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -134,6 +143,7 @@ impl SystemDatabase {
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
             CachesTable::create(sys_db_meta.next_table_id()),
             IndexesTable::create(sys_db_meta.next_table_id()),
+            TableConstraintsTable::create(sys_db_meta.next_table_id()),
             BackgroundTaskTable::create(sys_db_meta.next_table_id()),
             BackgroundJobTable::create(sys_db_meta.next_table_id()),
             BacktraceTable::create(sys_db_meta.next_table_id()),
@@ -152,6 +162,7 @@ impl SystemDatabase {
             TemporaryTablesTable::create(sys_db_meta.next_table_id()),
             ProceduresTable::create(sys_db_meta.next_table_id()),
             DictionariesTable::create(sys_db_meta.next_table_id()),
+            NetworkPoliciesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
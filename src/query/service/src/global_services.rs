@@ -44,6 +44,7 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::history::QueryHistory;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
@@ -158,6 +159,7 @@ impl GlobalServices {
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        QueryHistory::init(config)?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
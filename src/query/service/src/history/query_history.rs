@@ -0,0 +1,333 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::UpsertKV;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::InsertValue;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storages_system::QueryLogElement;
+use databend_common_storages_system::SystemLogElement;
+use databend_common_tracing::HistoryLogConfig;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub const QUERY_HISTORY_DATABASE: &str = "system_history";
+pub const QUERY_HISTORY_TABLE: &str = "query_history";
+
+/// The expired logs are removed at most once per interval across the cluster.
+const QUERY_HISTORY_RETENTION_INTERVAL: Duration = Duration::from_secs(3600);
+const QUERY_HISTORY_RETENTION_LEASE_PREFIX: &str = "__fd_query_history_retention";
+
+/// Persists the query log into the fuse table `system_history.query_history`.
+///
+/// The query logger only appends to an in-memory buffer, a background task moves
+/// the buffered logs into the table every `interval` seconds and removes the logs
+/// older than `retention` hours, so the history survives restarts and supports
+/// time travel like any other fuse table.
+///
+/// The table lives in its own database rather than in `system`, because the
+/// tables of `system` are built in memory by each node and can't be written.
+pub struct QueryHistory {
+    config: HistoryLogConfig,
+    // At most `max_pending` logs are buffered, like the ring buffer of `system.query_log`,
+    // the oldest ones are dropped if the flushes keep failing.
+    pending: Mutex<VecDeque<QueryLogElement>>,
+    max_pending: usize,
+    dropped: AtomicUsize,
+    table_created: AtomicBool,
+    last_retention: Mutex<Option<Instant>>,
+}
+
+impl QueryHistory {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let history = Arc::new(QueryHistory {
+            config: config.log.history.clone(),
+            pending: Mutex::new(VecDeque::new()),
+            max_pending: config.query.max_query_log_size,
+            dropped: AtomicUsize::new(0),
+            table_created: AtomicBool::new(false),
+            last_retention: Mutex::new(None),
+        });
+
+        if history.config.on {
+            let interval = Duration::from_secs(history.config.interval);
+            GlobalIORuntime::instance().spawn({
+                let history = history.clone();
+                async move {
+                    loop {
+                        tokio::time::sleep(interval).await;
+                        if let Err(cause) = history.flush().await {
+                            warn!("Failed to flush query history, cause: {:?}", cause);
+                        }
+                    }
+                }
+            });
+        }
+
+        GlobalInstance::set(history);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryHistory> {
+        GlobalInstance::get()
+    }
+
+    fn session_type() -> SessionType {
+        SessionType::HTTPAPI("QueryHistory".to_string())
+    }
+
+    pub fn append(&self, element: &QueryLogElement) {
+        // The queries issued by the flush itself are not recorded, otherwise every
+        // flush would produce new logs to flush.
+        if !self.config.on || element.handler_type == Self::session_type().to_string() {
+            return;
+        }
+        let mut pending = self.pending.lock();
+        pending.push_back(element.clone());
+        self.drop_oldest(&mut pending);
+    }
+
+    fn drop_oldest(&self, pending: &mut VecDeque<QueryLogElement>) {
+        if pending.len() > self.max_pending {
+            let dropped = pending.len() - self.max_pending;
+            pending.drain(..dropped);
+            self.dropped.fetch_add(dropped, Ordering::Relaxed);
+        }
+    }
+
+    /// Writes the buffered logs into `system_history.query_history` and removes
+    /// the expired ones.
+    ///
+    /// The logs are put back into the buffer if they can't be written, so they
+    /// are retried by the next flush, unless they are dropped as the oldest ones.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) -> Result<()> {
+        let dropped = self.dropped.swap(0, Ordering::Relaxed);
+        if dropped > 0 {
+            warn!(
+                "Dropped {} query logs not flushed into query history, the buffer is full",
+                dropped
+            );
+        }
+
+        let session = Self::create_session().await?;
+        let elements = Vec::from(std::mem::take(&mut *self.pending.lock()));
+
+        if let Err(cause) = self.write(&session, &elements).await {
+            let mut pending = self.pending.lock();
+            let appended = std::mem::replace(&mut *pending, elements.into());
+            pending.extend(appended);
+            self.drop_oldest(&mut pending);
+            return Err(cause);
+        }
+
+        self.remove_expired(&session).await
+    }
+
+    /// Flushes the logs of the queries finished before the shutdown.
+    #[async_backtrace::framed]
+    pub async fn shutdown(&self) {
+        if !self.config.on {
+            return;
+        }
+        if let Err(cause) = self.flush().await {
+            warn!(
+                "Failed to flush query history on shutdown, cause: {:?}",
+                cause
+            );
+        }
+    }
+
+    async fn write(&self, session: &Arc<Session>, elements: &[QueryLogElement]) -> Result<()> {
+        if !self.table_created.load(Ordering::Acquire) {
+            Self::create_table(session).await?;
+            self.table_created.store(true, Ordering::Release);
+        }
+
+        if !elements.is_empty() {
+            if let Err(cause) = Self::insert(session, elements).await {
+                // The table may have been dropped, create it again on the next flush.
+                self.table_created.store(false, Ordering::Release);
+                return Err(cause);
+            }
+        }
+        Ok(())
+    }
+
+    async fn remove_expired(&self, session: &Arc<Session>) -> Result<()> {
+        if self.config.retention == 0 {
+            return Ok(());
+        }
+
+        {
+            let mut last_retention = self.last_retention.lock();
+            if last_retention.is_some_and(|last| last.elapsed() < QUERY_HISTORY_RETENTION_INTERVAL)
+            {
+                return Ok(());
+            }
+            *last_retention = Some(Instant::now());
+        }
+
+        if !Self::acquire_retention_lease(&session.get_current_tenant()).await? {
+            return Ok(());
+        }
+
+        let sql = format!(
+            "DELETE FROM {QUERY_HISTORY_DATABASE}.{QUERY_HISTORY_TABLE} WHERE event_time < subtract_hours(now(), {})",
+            self.config.retention
+        );
+        Self::execute_sql(session, &sql).await
+    }
+
+    /// Only the node which inserts the lease removes the expired logs, the lease
+    /// expires after `QUERY_HISTORY_RETENTION_INTERVAL` for the next round.
+    async fn acquire_retention_lease(tenant: &Tenant) -> Result<bool> {
+        let key = format!(
+            "{QUERY_HISTORY_RETENTION_LEASE_PREFIX}/{}",
+            tenant.tenant_name()
+        );
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let change = meta_store
+            .upsert_kv(UpsertKV::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(MetaSpec::new_ttl(QUERY_HISTORY_RETENTION_INTERVAL)),
+            ))
+            .await?;
+        Ok(change.prev.is_none())
+    }
+
+    async fn create_session() -> Result<Arc<Session>> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(Self::session_type()).await?;
+        let session = session_manager.register_session(session)?;
+
+        let mut user = UserInfo::new_no_auth("root", "%");
+        user.grants.grant_privileges(
+            &GrantObject::Global,
+            UserPrivilegeSet::available_privileges_on_global(),
+        );
+        session.set_authed_user(user, None).await?;
+        Ok(session)
+    }
+
+    async fn create_table(session: &Arc<Session>) -> Result<()> {
+        let sql = format!("CREATE DATABASE IF NOT EXISTS {QUERY_HISTORY_DATABASE}");
+        Self::execute_sql(session, &sql).await?;
+
+        let columns = QueryLogElement::schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                format!(
+                    "`{}` {}",
+                    field.name(),
+                    field.data_type().sql_name_explicit_null()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS {QUERY_HISTORY_DATABASE}.{QUERY_HISTORY_TABLE} ({columns})"
+        );
+        Self::execute_sql(session, &sql).await
+    }
+
+    async fn insert(session: &Arc<Session>, elements: &[QueryLogElement]) -> Result<()> {
+        let schema = QueryLogElement::schema();
+        let mut builders = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let data_type: DataType = field.data_type().into();
+            builders.push(ColumnBuilder::with_capacity(&data_type, elements.len()));
+        }
+        for element in elements {
+            element.fill_to_data_block(&mut builders)?;
+        }
+        let columns = builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+
+        let rows = (0..elements.len())
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect()
+            })
+            .collect();
+
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: CATALOG_DEFAULT.to_string(),
+            database: QUERY_HISTORY_DATABASE.to_string(),
+            table: QUERY_HISTORY_TABLE.to_string(),
+            schema,
+            overwrite: false,
+            source: InsertInputSource::Values(InsertValue::Values { rows }),
+            table_info: None,
+        }));
+        let ctx = session.create_query_context().await?;
+        Self::execute_plan(ctx, &plan).await
+    }
+
+    async fn execute_sql(session: &Arc<Session>, sql: &str) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        Self::execute_plan(ctx, &plan).await
+    }
+
+    async fn execute_plan(ctx: Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let _ = stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+}
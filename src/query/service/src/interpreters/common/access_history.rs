@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_pipeline_core::always_callback;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::Metadata;
+use databend_common_sql::MetadataRef;
+use databend_common_storages_system::AccessHistoryLogElement;
+use databend_common_storages_system::AccessHistoryQueue;
+use databend_common_storages_system::AccessObject;
+use log::error;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterAccessHistory {
+    element: Option<AccessHistoryLogElement>,
+}
+
+impl InterpreterAccessHistory {
+    /// Collects the tables read by the scans of `plan` and the `modified` objects,
+    /// nothing is recorded if no object is accessed.
+    ///
+    /// The scanned tables are resolved by the `metadata` the plan is built with.
+    pub fn create(
+        ctx: &QueryContext,
+        plan: Option<(&MetadataRef, &PhysicalPlan)>,
+        modified: Vec<AccessObject>,
+    ) -> Self {
+        let element = Self::create_element(ctx, plan, modified).unwrap_or_else(|e| {
+            error!("fail to collect access_history {:?}", e);
+            None
+        });
+        InterpreterAccessHistory { element }
+    }
+
+    /// Records the collected objects into `system.access_history` once `pipeline`
+    /// finishes successfully, the failed or aborted queries are not recorded.
+    pub fn log_on_finished(self, pipeline: &mut Pipeline) {
+        let Some(element) = self.element else {
+            return;
+        };
+
+        // Always callbacks run after the commits of the normal callbacks,
+        // so a failed commit is seen in `info.res`.
+        pipeline.set_on_finished(always_callback(move |info: &ExecutionInfo| {
+            if info.res.is_ok() {
+                AccessHistoryQueue::instance()
+                    .and_then(|queue| queue.append_data(element))
+                    .unwrap_or_else(|e| error!("fail to write access_history {:?}", e));
+            }
+            Ok(())
+        }));
+    }
+
+    fn create_element(
+        ctx: &QueryContext,
+        plan: Option<(&MetadataRef, &PhysicalPlan)>,
+        modified: Vec<AccessObject>,
+    ) -> Result<Option<AccessHistoryLogElement>> {
+        let mut base_objects = vec![];
+        let mut direct_objects = vec![];
+        if let Some((metadata, plan)) = plan {
+            collect_scanned_objects(
+                &metadata.read(),
+                plan,
+                &mut base_objects,
+                &mut direct_objects,
+            );
+        }
+        if base_objects.is_empty() && modified.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(AccessHistoryLogElement {
+            query_id: ctx.get_id(),
+            query_start_time: convert_query_log_timestamp(ctx.get_created_time()),
+            user_name: ctx.get_current_user()?.name,
+            base_objects_accessed: AccessObject::merge(base_objects),
+            direct_objects_accessed: AccessObject::merge(direct_objects),
+            objects_modified: AccessObject::merge(modified),
+        }))
+    }
+}
+
+#[recursive::recursive]
+fn collect_scanned_objects(
+    metadata: &Metadata,
+    plan: &PhysicalPlan,
+    base_objects: &mut Vec<AccessObject>,
+    direct_objects: &mut Vec<AccessObject>,
+) {
+    if let PhysicalPlan::TableScan(scan) = plan {
+        if let Some(table_index) = scan.table_index {
+            let table = metadata.table(table_index);
+            let columns = scan
+                .source
+                .output_schema
+                .fields()
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect();
+            let object = AccessObject::new_table(table.database(), table.name(), columns);
+            // The tables of the views are only accessed through the views.
+            if !table.is_source_of_view() {
+                direct_objects.push(object.clone());
+            }
+            base_objects.push(object);
+        }
+    }
+
+    for child in plan.children() {
+        collect_scanned_objects(metadata, child, base_objects, direct_objects);
+    }
+}
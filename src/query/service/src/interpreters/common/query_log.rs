@@ -28,6 +28,7 @@ use log::error;
 use log::info;
 use serde_json;
 
+use crate::history::QueryHistory;
 use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -71,6 +72,7 @@ impl InterpreterQueryLog {
         info!(target: "databend::log::query", "{}", event_str);
         // log the query event in the system log
         info!("query: {} becomes {:?}", event.query_id, event.log_type);
+        QueryHistory::instance().append(&event);
         QueryLogQueue::instance()?.append_data(event)
     }
 
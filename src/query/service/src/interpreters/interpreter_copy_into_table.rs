@@ -52,6 +52,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyIntoTablePlan;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 use crate::stream::DataBlockStream;
 
 pub struct CopyIntoTableInterpreter {
@@ -191,11 +192,13 @@ impl CopyIntoTableInterpreter {
     }
 
     fn get_copy_into_table_result(&self) -> Result<Vec<DataBlock>> {
+        // Only the files with errors are reported by the validation.
         let return_all = !self
             .plan
             .stage_table_info
             .copy_into_table_options
-            .return_failed_only;
+            .return_failed_only
+            && self.plan.validation_mode == ValidationMode::None;
         let cs = self.ctx.get_copy_status();
 
         let mut results = cs.files.iter().collect::<Vec<_>>();
@@ -385,6 +388,11 @@ impl Interpreter for CopyIntoTableInterpreter {
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
 
+        // Nothing is written by the validation, so there is nothing to commit.
+        if self.plan.validation_mode != ValidationMode::None {
+            return Ok(build_res);
+        }
+
         // Build commit insertion pipeline.
         {
             let files_to_copy = self
@@ -34,6 +34,7 @@ use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::binder::ExplainConfig;
 use databend_common_sql::executor::format_partial_tree;
 use databend_common_sql::executor::MutationBuildInfo;
+use databend_common_sql::executor::PlanDecision;
 use databend_common_sql::optimizer::ColumnSet;
 use databend_common_sql::plans::Mutation;
 use databend_common_sql::BindContext;
@@ -534,9 +535,33 @@ impl ExplainInterpreter {
         // we should not use `dry_run` mode to build the physical plan.
         // It's because we need to get the same partitions as the original selecting plan.
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
+        if self.config.decisions {
+            builder.record_decisions();
+        }
         let plan = builder.build(s_expr, bind_context.column_set()).await?;
-        self.explain_physical_plan(&plan, metadata, formatted_ast)
-            .await
+        let mut blocks = self
+            .explain_physical_plan(&plan, metadata, formatted_ast)
+            .await?;
+        if self.config.decisions {
+            blocks.push(Self::decisions_to_block(&builder.take_decisions()));
+        }
+        Ok(blocks)
+    }
+
+    fn decisions_to_block(decisions: &[PlanDecision]) -> DataBlock {
+        let mut lines = vec!["".to_string(), "Decisions:".to_string()];
+        if decisions.is_empty() {
+            lines.push("└── no decisions".to_string());
+        }
+        for (i, decision) in decisions.iter().enumerate() {
+            let prefix = if i + 1 == decisions.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            lines.push(format!("{prefix} {decision}"));
+        }
+        DataBlock::new_from_columns(vec![StringType::from_data(lines)])
     }
 
     async fn explain_merge_fragments(
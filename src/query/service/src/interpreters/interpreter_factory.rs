@@ -332,6 +332,9 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableConstraint(add_table_constraint) => Ok(Arc::new(
+                AddTableConstraintInterpreter::try_create(ctx, *add_table_constraint.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -381,6 +384,9 @@ impl InterpreterFactory {
                 ctx,
                 *analyze_table.clone(),
             )?)),
+            Plan::ChecksumTable(checksum_table) => Ok(Arc::new(
+                ChecksumTableInterpreter::try_create(ctx, *checksum_table.clone())?,
+            )),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
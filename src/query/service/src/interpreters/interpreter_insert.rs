@@ -33,10 +33,12 @@ use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::NameResolutionContext;
+use databend_common_storages_system::AccessObject;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
+use crate::interpreters::common::InterpreterAccessHistory;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -76,6 +78,17 @@ impl InsertInterpreter {
         let cast_needed = select_schema.as_ref() != &DataSchema::from(output_schema.as_ref());
         Ok(cast_needed)
     }
+
+    fn modified_object(&self) -> AccessObject {
+        let columns = self
+            .plan
+            .schema
+            .fields()
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect();
+        AccessObject::new_table(&self.plan.database, &self.plan.table, columns)
+    }
 }
 
 #[async_trait::async_trait]
@@ -220,6 +233,11 @@ impl Interpreter for InsertInterpreter {
                 let mut build_res =
                     build_query_pipeline_without_render_result_set(&self.ctx, &insert_select_plan)
                         .await?;
+                let access_history = InterpreterAccessHistory::create(
+                    &self.ctx,
+                    Some((metadata, &insert_select_plan)),
+                    vec![self.modified_object()],
+                );
 
                 table.commit_insertion(
                     self.ctx.clone(),
@@ -244,11 +262,15 @@ impl Interpreter for InsertInterpreter {
                     );
                     hook_operator.execute(&mut build_res.main_pipeline).await;
                 }
+                access_history.log_on_finished(&mut build_res.main_pipeline);
 
                 return Ok(build_res);
             }
         };
 
+        let access_history =
+            InterpreterAccessHistory::create(&self.ctx, None, vec![self.modified_object()]);
+
         PipelineBuilder::build_append2table_with_commit_pipeline(
             self.ctx.clone(),
             &mut build_res.main_pipeline,
@@ -273,6 +295,7 @@ impl Interpreter for InsertInterpreter {
             );
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
+        access_history.log_on_finished(&mut build_res.main_pipeline);
 
         Ok(build_res)
     }
@@ -42,11 +42,13 @@ use databend_common_sql::plans::Mutation;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_system::AccessObject;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
+use crate::interpreters::common::InterpreterAccessHistory;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -104,6 +106,12 @@ impl Interpreter for MutationInterpreter {
 
         info!("Query physical plan: \n{}", query_plan);
 
+        let access_history = InterpreterAccessHistory::create(
+            &self.ctx,
+            Some((&self.metadata, &physical_plan)),
+            vec![self.modified_object(&mutation)],
+        );
+
         // Build pipeline.
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
@@ -117,6 +125,7 @@ impl Interpreter for MutationInterpreter {
         self.execute_hook(&mutation, &mut build_res).await;
 
         build_res.main_pipeline.add_lock_guard(mutation.lock_guard);
+        access_history.log_on_finished(&mut build_res.main_pipeline);
 
         Ok(build_res)
     }
@@ -179,6 +188,37 @@ impl MutationInterpreter {
             .await
     }
 
+    /// The target table of the mutation, with the updated columns, or all the columns
+    /// if rows are deleted or inserted.
+    fn modified_object(&self, mutation: &Mutation) -> AccessObject {
+        let metadata = mutation.metadata.read();
+        let schema = metadata
+            .table(mutation.target_table_index)
+            .table()
+            .schema_with_stream();
+        let modify_all = !mutation.unmatched_evaluators.is_empty()
+            || mutation
+                .matched_evaluators
+                .iter()
+                .any(|evaluator| evaluator.update.is_none());
+        let columns = if modify_all {
+            schema
+                .fields()
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect()
+        } else {
+            mutation
+                .matched_evaluators
+                .iter()
+                .filter_map(|evaluator| evaluator.update.as_ref())
+                .flat_map(|update| update.keys())
+                .map(|index| schema.field(*index).name().to_string())
+                .collect()
+        };
+        AccessObject::new_table(&mutation.database_name, &mutation.table_name, columns)
+    }
+
     fn get_mutation_table_result(&self) -> Result<Vec<DataBlock>> {
         let binding = self.ctx.get_mutation_status();
         let status = binding.read();
@@ -45,6 +45,7 @@ use log::error;
 use log::info;
 
 use crate::interpreters::common::query_build_update_stream_req;
+use crate::interpreters::common::InterpreterAccessHistory;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
@@ -260,36 +261,14 @@ impl SelectInterpreter {
             )
         }
     }
-}
 
-#[async_trait::async_trait]
-impl Interpreter for SelectInterpreter {
-    fn name(&self) -> &str {
-        "SelectInterpreterV2"
-    }
-
-    fn is_ddl(&self) -> bool {
-        false
-    }
-
-    /// This method will create a new pipeline
-    /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
-    #[fastrace::trace]
+    /// Builds the pipeline of `physical_plan`, reading the result from or writing it to
+    /// the query result cache if it is enabled.
     #[async_backtrace::framed]
-    async fn execute2(&self) -> Result<PipelineBuildResult> {
-        self.attach_tables_to_ctx();
-
-        self.ctx.set_status_info("preparing plan");
-
-        // 0. Need to build physical plan first to get the partitions.
-        let physical_plan = self.build_physical_plan().await?;
-
-        let query_plan = physical_plan
-            .format(self.metadata.clone(), Default::default())?
-            .format_pretty()?;
-
-        info!("Query physical plan: \n{}", query_plan);
-
+    async fn build_pipeline_with_result_cache(
+        &self,
+        physical_plan: PhysicalPlan,
+    ) -> Result<PipelineBuildResult> {
         if self.ctx.get_settings().get_enable_query_result_cache()?
             && self.ctx.get_cacheable()
             && self.formatted_ast.is_some()
@@ -353,3 +332,43 @@ impl Interpreter for SelectInterpreter {
         self.build_pipeline(physical_plan).await
     }
 }
+
+#[async_trait::async_trait]
+impl Interpreter for SelectInterpreter {
+    fn name(&self) -> &str {
+        "SelectInterpreterV2"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    /// This method will create a new pipeline
+    /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.attach_tables_to_ctx();
+
+        self.ctx.set_status_info("preparing plan");
+
+        // 0. Need to build physical plan first to get the partitions.
+        let physical_plan = self.build_physical_plan().await?;
+
+        let query_plan = physical_plan
+            .format(self.metadata.clone(), Default::default())?
+            .format_pretty()?;
+
+        info!("Query physical plan: \n{}", query_plan);
+
+        let access_history = InterpreterAccessHistory::create(
+            &self.ctx,
+            Some((&self.metadata, &physical_plan)),
+            vec![],
+        );
+
+        let mut build_res = self.build_pipeline_with_result_cache(physical_plan).await?;
+        access_history.log_on_finished(&mut build_res.main_pipeline);
+        Ok(build_res)
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableConstraintType;
+use databend_common_sql::plans::AddTableConstraintPlan;
+use databend_common_sql::Planner;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::interpreter_table_add_column::commit_table_meta;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableConstraintPlan,
+}
+
+impl AddTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableConstraintPlan) -> Result<Self> {
+        Ok(AddTableConstraintInterpreter { ctx, plan })
+    }
+
+    // The check constraint can only be added if all the existing rows satisfy it.
+    #[async_backtrace::framed]
+    async fn check_existing_rows(&self, check_expr: &str) -> Result<()> {
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        let query = format!(
+            "SELECT 1 FROM {}.{}.{} WHERE NOT ({}) LIMIT 1",
+            QuotedIdent(&self.plan.catalog, quote),
+            QuotedIdent(&self.plan.database, quote),
+            QuotedIdent(&self.plan.table, quote),
+            check_expr
+        );
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&query).await?;
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(self.ctx.clone()).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        if blocks.iter().any(|block| block.num_rows() > 0) {
+            return Err(ErrorCode::CheckConstraintViolated(format!(
+                "check constraint `{}` ({}) is violated by some existing rows of table `{}`.`{}`",
+                self.plan.constraint.name, check_expr, self.plan.database, self.plan.table
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "AddTableConstraintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)
+            .await?
+            .get_table(&self.ctx.get_tenant(), db_name, tbl_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let engine = table_info.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support alter",
+                &self.plan.database, &self.plan.table, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let constraint = &self.plan.constraint;
+        if table_info.meta.constraints.contains_key(&constraint.name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Duplicated constraint name: {}",
+                constraint.name
+            )));
+        }
+        if constraint.constraint_type == TableConstraintType::Check {
+            if let Some(check_expr) = &constraint.check_expr {
+                self.check_existing_rows(check_expr).await?;
+            }
+        }
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .constraints
+            .insert(constraint.name.clone(), constraint.clone());
+
+        // The meta is committed with the version of the table read above, so it fails if
+        // other rows are committed after the existing rows are checked.
+        commit_table_meta(
+            &self.ctx,
+            table.as_ref(),
+            table_info,
+            new_table_meta,
+            catalog,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -13,18 +13,23 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::TableField;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregatePartial;
+use databend_common_sql::executor::physical_plans::AggregateSorted;
 use databend_common_sql::executor::physical_plans::EvalScalar;
 use databend_common_sql::executor::physical_plans::Filter;
 use databend_common_sql::executor::physical_plans::Sort;
+use databend_common_sql::executor::physical_plans::SortedFirstRows;
 use databend_common_sql::executor::physical_plans::Window;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
@@ -115,6 +120,25 @@ impl Interpreter for AnalyzeTableInterpreter {
             Ok(v) => v,
         };
 
+        // `ANALYZE TABLE ... FOR COLUMNS (...)` only collects the statistics of the given
+        // columns, the statistics of the other columns are kept.
+        let table_schema = table.schema();
+        let is_partial = !plan.columns.is_empty();
+        let mut column_ids = HashSet::with_capacity(plan.columns.len());
+        for column in plan.columns.iter() {
+            let field = table_schema.field_with_name(column).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "Cannot find column {} in table {}.{}",
+                    column, plan.database, plan.table
+                ))
+            })?;
+            column_ids.insert(field.column_id());
+        }
+        let is_analyzed = |f: &TableField| {
+            RangeIndex::supported_type(&f.data_type().into())
+                && (!is_partial || column_ids.contains(&f.column_id()))
+        };
+
         if let Some(snapshot) = snapshot_opt {
             // plan sql
             let _table_info = table.get_table_info();
@@ -123,22 +147,40 @@ impl Interpreter for AnalyzeTableInterpreter {
                 .read_table_snapshot_statistics(Some(&snapshot))
                 .await?;
 
+            // The statistics of the other columns are still based on the snapshot of the
+            // previous analyze, keep it so that the next analyze can collect the added blocks.
+            // It's harmless for the analyzed columns, the NDV states are idempotent on merging.
+            let statistics_snapshot_id = match &table_statistics {
+                Some(table_statistics) if is_partial => table_statistics.snapshot_id,
+                _ => snapshot.snapshot_id,
+            };
+
             let (is_full, temporal_str) = if let Some(table_statistics) = &table_statistics {
-                let is_full = match table
-                    .navigate_to_point(
-                        &NavigationPoint::SnapshotID(
-                            table_statistics.snapshot_id.simple().to_string(),
-                        ),
-                        self.ctx.clone().get_abort_checker(),
-                    )
-                    .await
-                {
-                    Ok(t) => !t
-                        .read_table_snapshot()
+                // The columns which are never analyzed, e.g. after a partial analyze,
+                // need to collect all the blocks.
+                let is_covered = snapshot
+                    .schema
+                    .fields()
+                    .iter()
+                    .filter(|f| is_analyzed(f))
+                    .all(|f| table_statistics.hll.contains_key(&f.column_id()));
+                let is_full = is_partial
+                    || !is_covered
+                    || match table
+                        .navigate_to_point(
+                            &NavigationPoint::SnapshotID(
+                                table_statistics.snapshot_id.simple().to_string(),
+                            ),
+                            self.ctx.clone().get_abort_checker(),
+                        )
                         .await
-                        .is_ok_and(|s| s.is_some_and(|s| s.prev_table_seq.is_some())),
-                    Err(_) => true,
-                };
+                    {
+                        Ok(t) => !t
+                            .read_table_snapshot()
+                            .await
+                            .is_ok_and(|s| s.is_some_and(|s| s.prev_table_seq.is_some())),
+                        Err(_) => true,
+                    };
 
                 let temporal_str = if is_full {
                     format!("AT (snapshot => '{}')", snapshot.snapshot_id.simple())
@@ -172,7 +214,7 @@ impl Interpreter for AnalyzeTableInterpreter {
                 .schema
                 .fields()
                 .iter()
-                .filter(|f| RangeIndex::supported_type(&f.data_type().into()))
+                .filter(|f| is_analyzed(f))
                 .map(|f| {
                     format!(
                         "approx_count_distinct_state({DISTINCT_ERROR_RATE})({quote}{}{quote}) as ndv_{}",
@@ -181,6 +223,9 @@ impl Interpreter for AnalyzeTableInterpreter {
                     )
                 })
                 .join(", ");
+            if ndv_select_expr.is_empty() {
+                return Ok(PipelineBuildResult::create());
+            }
 
             let sql = format!(
                 "SELECT {ndv_select_expr}, {is_full} as is_full from {}.{} {temporal_str}",
@@ -197,11 +242,10 @@ impl Interpreter for AnalyzeTableInterpreter {
             // We add a setting `enable_analyze_histogram` to control whether to compute histogram(default is closed).
             let mut histogram_info_receivers = HashMap::new();
             if self.ctx.get_settings().get_enable_analyze_histogram()? {
-                let histogram_sqls = table
-                    .schema()
+                let histogram_sqls = table_schema
                     .fields()
                     .iter()
-                    .filter(|f| RangeIndex::supported_type(&f.data_type().into()))
+                    .filter(|f| is_analyzed(f))
                     .map(|f| {
                         let col_name = format!("{quote}{}{quote}", f.name);
                         (
@@ -258,7 +302,8 @@ impl Interpreter for AnalyzeTableInterpreter {
                 &self.plan.catalog,
                 &self.plan.database,
                 &self.plan.table,
-                snapshot.snapshot_id,
+                statistics_snapshot_id,
+                is_partial,
                 &mut build_res.main_pipeline,
                 histogram_info_receivers,
             )?;
@@ -316,6 +361,14 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 group_by_display: plan.group_by_display,
                 stat_info: plan.stat_info,
             }),
+            PhysicalPlan::AggregateSorted(plan) => PhysicalPlan::AggregateSorted(AggregateSorted {
+                plan_id: plan.plan_id,
+                input: Box::new(traverse(*plan.input)),
+                group_by: plan.group_by,
+                agg_funcs: plan.agg_funcs,
+                group_by_display: plan.group_by_display,
+                stat_info: plan.stat_info,
+            }),
             PhysicalPlan::Window(plan) => PhysicalPlan::Window(Window {
                 plan_id: plan.plan_id,
                 index: plan.index,
@@ -326,6 +379,13 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 window_frame: plan.window_frame,
                 limit: plan.limit,
             }),
+            PhysicalPlan::SortedFirstRows(plan) => PhysicalPlan::SortedFirstRows(SortedFirstRows {
+                plan_id: plan.plan_id,
+                input: Box::new(traverse(*plan.input)),
+                group_by: plan.group_by,
+                ties_by: plan.ties_by,
+                stat_info: plan.stat_info,
+            }),
             PhysicalPlan::Sort(plan) => PhysicalPlan::Sort(Sort {
                 plan_id: plan.plan_id,
                 input: Box::new(traverse(*plan.input)),
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::executor::physical_plans::Checksum;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::ChecksumTablePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// `CHECKSUM TABLE`, reduces the rows of the table to the XOR of the row hashes,
+/// so two tables with the same rows have the same checksum regardless of the
+/// order of the rows.
+///
+/// The checksums recorded in the block metas of a fuse table are used if they
+/// cover all the blocks, the rows are only read otherwise.
+pub struct ChecksumTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ChecksumTablePlan,
+}
+
+impl ChecksumTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ChecksumTablePlan) -> Result<Self> {
+        Ok(ChecksumTableInterpreter { ctx, plan })
+    }
+
+    async fn plan_sql(&self, sql: &str) -> Result<PhysicalPlan> {
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        match &plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder =
+                    PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+                builder.build(s_expr, bind_context.column_set()).await
+            }
+            plan => Err(ErrorCode::Internal(format!(
+                "Checksum table expects a query plan, but got {}",
+                plan.kind()
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ChecksumTableInterpreter {
+    fn name(&self) -> &str {
+        "ChecksumTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            if let Some(checksum) = fuse_table
+                .checksum_from_block_metas(self.ctx.clone())
+                .await?
+            {
+                return PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                    UInt64Type::from_data(vec![checksum]),
+                ])]);
+            }
+        }
+
+        // The virtual computed columns are not stored, so they are not part of the checksum.
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        let columns = table
+            .schema()
+            .remove_virtual_computed_fields()
+            .fields()
+            .iter()
+            .map(|field| QuotedIdent(field.name(), quote).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "SELECT {} FROM {}.{}.{}",
+            columns,
+            QuotedIdent(&plan.catalog, quote),
+            QuotedIdent(&plan.database, quote),
+            QuotedIdent(&plan.table, quote)
+        );
+        info!("Checksum table via sql: {sql}");
+
+        let input = self.plan_sql(&sql).await?;
+        let mut physical_plan = PhysicalPlan::Checksum(Checksum {
+            plan_id: 0,
+            input: Box::new(input),
+        });
+        physical_plan.adjust_plan_id(&mut 0);
+
+        build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await
+    }
+}
@@ -64,6 +64,7 @@ use crate::interpreters::common::table_option_validation::is_valid_bloom_index_c
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_enable_block_checksum;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::hook::vacuum_hook::hook_clear_m_cte_temp_table;
@@ -413,6 +414,7 @@ impl CreateTableInterpreter {
             statistics: statistics.unwrap_or_default(),
             comment: comment.unwrap_or_default(),
             indexes: self.plan.inverted_indexes.clone().unwrap_or_default(),
+            constraints: self.plan.constraints.clone(),
             ..Default::default()
         };
 
@@ -421,6 +423,7 @@ impl CreateTableInterpreter {
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_enable_block_checksum(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
         // check table level data_retention_period_in_hours
@@ -110,6 +110,11 @@ impl Interpreter for DropTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
+        // The constraints on the column are dropped with it, including the check
+        // constraints that refer to it.
+        new_table_meta
+            .constraints
+            .retain(|_, constraint| !constraint.column_ids.contains(&field.column_id));
 
         // update table options
         let opts = &mut new_table_meta.options;
@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_meta_app::schema::DatabaseType;
 use databend_common_sql::plans::RenameTableColumnPlan;
+use databend_common_sql::rename_check_constraint_column;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
@@ -106,6 +107,25 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
+            // The check constraints keep their expressions as text, refer to the new name.
+            let column_id = table_info
+                .schema()
+                .field_with_name(self.plan.old_column.as_str())?
+                .column_id;
+            for constraint in new_table_meta.constraints.values_mut() {
+                if !constraint.column_ids.contains(&column_id) {
+                    continue;
+                }
+                if let Some(check_expr) = &constraint.check_expr {
+                    constraint.check_expr = Some(rename_check_constraint_column(
+                        self.ctx.clone(),
+                        check_expr,
+                        &self.plan.old_column,
+                        &self.plan.new_column,
+                    )?);
+                }
+            }
+
             // update table options
             let opts = &mut new_table_meta.options;
             if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
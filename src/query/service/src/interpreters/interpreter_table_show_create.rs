@@ -26,6 +26,7 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableConstraintType;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ATTACH_COLUMN_IDS;
 use databend_common_storages_stream::stream_table::StreamTable;
@@ -265,6 +266,43 @@ impl ShowCreateTableInterpreter {
                 create_defs.push(index_str);
             }
 
+            for constraint in table_info.meta.constraints.values() {
+                let mut column_names = Vec::with_capacity(constraint.column_ids.len());
+                for column_id in constraint.column_ids.iter() {
+                    let field = schema.field_of_column_id(*column_id)?;
+                    column_names.push(display_ident(
+                        field.name(),
+                        force_quoted_ident,
+                        quoted_ident_case_sensitive,
+                        sql_dialect,
+                    ));
+                }
+                // The check constraint is shown with its expression instead of the columns.
+                let definition = match &constraint.check_expr {
+                    Some(check_expr) => check_expr.clone(),
+                    None => column_names.join(", "),
+                };
+                let mut constraint_str = format!(
+                    "  CONSTRAINT {} {} ({})",
+                    display_ident(
+                        &constraint.name,
+                        force_quoted_ident,
+                        quoted_ident_case_sensitive,
+                        sql_dialect
+                    ),
+                    constraint.constraint_type,
+                    definition
+                );
+                if constraint.constraint_type == TableConstraintType::ForeignKey {
+                    constraint_str.push_str(&format!(" REFERENCES {}", constraint.reference_table));
+                    if !constraint.reference_columns.is_empty() {
+                        constraint_str
+                            .push_str(&format!(" ({})", constraint.reference_columns.join(", ")));
+                    }
+                }
+                create_defs.push(constraint_str);
+            }
+
             // Format is:
             //  (
             //      x,
@@ -106,7 +106,9 @@ mod interpreter_stream_drop;
 mod interpreter_suspend_warehouse;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
+mod interpreter_table_add_constraint;
 mod interpreter_table_analyze;
+mod interpreter_table_checksum;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
@@ -162,6 +164,7 @@ mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
 mod util;
 
+pub use access::Accessor;
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
@@ -221,7 +224,9 @@ pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_constraint::AddTableConstraintInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_checksum::ChecksumTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
@@ -47,6 +47,7 @@ pub mod auth;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
+pub mod history;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
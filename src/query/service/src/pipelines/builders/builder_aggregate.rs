@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::AggregateFunctionRef;
 use databend_common_expression::DataField;
@@ -31,6 +32,7 @@ use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregateFunctionDesc;
 use databend_common_sql::executor::physical_plans::AggregatePartial;
+use databend_common_sql::executor::physical_plans::AggregateSorted;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::UDFType;
 use databend_common_sql::IndexType;
@@ -46,6 +48,7 @@ use crate::pipelines::processors::transforms::aggregator::PartialSingleStateAggr
 use crate::pipelines::processors::transforms::aggregator::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupingSets;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialAggregate;
+use crate::pipelines::processors::transforms::aggregator::TransformSortedAggregate;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -101,6 +104,9 @@ impl PipelineBuilder {
         let max_block_size = self.settings.get_max_block_size()?;
         let max_threads = self.settings.get_max_threads()?;
         let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
+        let hash_seed = self.settings.get_aggregate_hash_seed()?;
+        let max_arena_bytes = self.settings.get_aggregate_spilling_arena_bytes()?;
+        let max_partition_count = self.settings.get_aggregate_max_partition_count()?;
 
         let enable_experimental_aggregate_hashtable = self
             .settings
@@ -114,6 +120,7 @@ impl PipelineBuilder {
             self.is_exchange_neighbor,
             max_block_size as usize,
             max_spill_io_requests as usize,
+            hash_seed,
         )?;
 
         if params.group_columns.is_empty() {
@@ -125,12 +132,17 @@ impl PipelineBuilder {
         let schema_before_group_by = params.input_schema.clone();
 
         // Need a global atomic to read the max current radix bits hint
-        let partial_agg_config = if !self.is_exchange_neighbor {
+        let mut partial_agg_config = if !self.is_exchange_neighbor {
             HashTableConfig::default().with_partial(true, max_threads as usize)
         } else {
             HashTableConfig::default()
                 .cluster_with_partial(true, self.ctx.get_cluster().nodes.len())
-        };
+        }
+        .with_hash_seed(params.hash_seed)
+        .with_max_arena_bytes(max_arena_bytes);
+        if max_partition_count != 0 {
+            partial_agg_config = partial_agg_config.with_max_partition_count(max_partition_count);
+        }
 
         // For rank limit, we can filter data using sort with rank before partial
         if let Some(rank_limit) = &aggregate.rank_limit {
@@ -192,6 +204,7 @@ impl PipelineBuilder {
             .settings
             .get_enable_experimental_aggregate_hashtable()?;
         let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
+        let hash_seed = self.settings.get_aggregate_hash_seed()?;
 
         let params = Self::build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
@@ -201,6 +214,7 @@ impl PipelineBuilder {
             self.is_exchange_neighbor,
             max_block_size as usize,
             max_spill_io_requests as usize,
+            hash_seed,
         )?;
 
         if params.group_columns.is_empty() {
@@ -223,7 +237,42 @@ impl PipelineBuilder {
         }
         self.build_pipeline(&aggregate.input)?;
         self.exchange_injector = old_inject;
-        build_partition_bucket(&mut self.main_pipeline, params.clone())
+        build_partition_bucket(self.ctx.clone(), &mut self.main_pipeline, params.clone())
+    }
+
+    pub(crate) fn build_aggregate_sorted(&mut self, aggregate: &AggregateSorted) -> Result<()> {
+        self.build_pipeline(&aggregate.input)?;
+
+        // AggregateSorted is only planned on top of a Sort that merges its output into
+        // a single sorted stream, see `is_sorted_on_group_keys`.
+        if self.main_pipeline.output_len() != 1 {
+            return Err(ErrorCode::Internal(format!(
+                "AggregateSorted expects a single sorted input stream, but got {}",
+                self.main_pipeline.output_len()
+            )));
+        }
+
+        let max_block_size = self.settings.get_max_block_size()?;
+        let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
+        let hash_seed = self.settings.get_aggregate_hash_seed()?;
+        let params = Self::build_aggregator_params(
+            aggregate.input.output_schema()?,
+            &aggregate.group_by,
+            &aggregate.agg_funcs,
+            false,
+            false,
+            max_block_size as usize,
+            max_spill_io_requests as usize,
+            hash_seed,
+        )?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSortedAggregate::try_create(
+                input,
+                output,
+                params.clone(),
+            )?))
+        })
     }
 
     fn build_aggregator_params(
@@ -234,6 +283,7 @@ impl PipelineBuilder {
         cluster_aggregator: bool,
         max_block_size: usize,
         max_spill_io_requests: usize,
+        hash_seed: u64,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -328,6 +378,7 @@ impl PipelineBuilder {
             cluster_aggregator,
             max_block_size,
             max_spill_io_requests,
+            hash_seed,
         )?;
 
         log::debug!("aggregate states layout: {:?}", params.states_layout);
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -26,6 +28,7 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_storages_fuse::operations::TransformSerializeBlock;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -44,6 +47,17 @@ impl PipelineBuilder {
         let table = self
             .ctx
             .build_table_by_table_info(&column_mutation.table_info, None)?;
+        if matches!(column_mutation.mutation_kind, MutationKind::Update)
+            && !TransformCheckConstraints::check_constraints(table.as_ref()).is_empty()
+        {
+            // The updated rows are in the order of the stored columns, followed by the
+            // stream columns.
+            let schema = table.schema_with_stream().remove_virtual_computed_fields();
+            let schema: DataSchemaRef = Arc::new((&schema).into());
+            self.main_pipeline.try_add_transformer(|| {
+                TransformCheckConstraints::try_new(self.ctx.clone(), schema.clone(), table.as_ref())
+            })?;
+        }
         let table = FuseTable::try_from_table(table.as_ref())?;
 
         let block_thresholds = table.get_block_thresholds();
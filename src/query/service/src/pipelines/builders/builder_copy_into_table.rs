@@ -29,17 +29,21 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::ParquetFileFormatParams;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
 use databend_common_sql::plans::CopyIntoTableMode;
+use databend_common_sql::plans::ValidationMode;
 use databend_common_storage::StageFileInfo;
 use log::debug;
 use log::info;
 
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformNullIf;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -171,6 +175,27 @@ impl PipelineBuilder {
             )?;
         }
 
+        // The validation only parses the files, nothing is written to the table.
+        match plan.validation_mode {
+            ValidationMode::None => {}
+            ValidationMode::ReturnNRows(n) => {
+                main_pipeline.try_resize(1)?;
+                return main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(TransformLimit::try_create(
+                        Some(n as usize),
+                        0,
+                        input,
+                        output,
+                    )?))
+                });
+            }
+            // The errors are collected in the copy status while parsing.
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                return main_pipeline
+                    .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))));
+            }
+        }
+
         // append data without commit.
         match plan_write_mode {
             CopyIntoTableMode::Insert { overwrite: _ } => {
@@ -21,6 +21,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -63,6 +64,17 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Check the constraints on the filled rows.
+        if !TransformCheckConstraints::check_constraints(table.as_ref()).is_empty() {
+            pipeline.try_add_transformer(|| {
+                TransformCheckConstraints::try_new(
+                    ctx.clone(),
+                    computed_schema.clone(),
+                    table.as_ref(),
+                )
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_sql::executor::physical_plans::MatchRecognize;
+use databend_common_sql::plans::MatchMeasureKind;
+
+use crate::pipelines::processors::transforms::MatchMeasureInfo;
+use crate::pipelines::processors::transforms::TransformMatchRecognize;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_match_recognize(&mut self, plan: &MatchRecognize) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let input_schema = plan.input.output_schema()?;
+        let offset_of = |index: &usize| input_schema.index_of(&index.to_string());
+        let partition_by = plan
+            .partition_by
+            .iter()
+            .map(offset_of)
+            .collect::<Result<Vec<_>>>()?;
+        let prev_columns = plan
+            .prev_columns
+            .iter()
+            .map(|prev| offset_of(&prev.source))
+            .collect::<Result<Vec<_>>>()?;
+        let defines = plan
+            .defines
+            .iter()
+            .map(|define| define.as_ref().map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS)))
+            .collect::<Vec<_>>();
+        let measures = plan
+            .measures
+            .iter()
+            .map(|measure| {
+                let kind = match &measure.kind {
+                    MatchMeasureKind::First { variable, column } => MatchMeasureKind::First {
+                        variable: *variable,
+                        column: offset_of(column)?,
+                    },
+                    MatchMeasureKind::Last { variable, column } => MatchMeasureKind::Last {
+                        variable: *variable,
+                        column: offset_of(column)?,
+                    },
+                    MatchMeasureKind::Count { variable, column } => MatchMeasureKind::Count {
+                        variable: *variable,
+                        column: column.as_ref().map(offset_of).transpose()?,
+                    },
+                    MatchMeasureKind::MatchNumber => MatchMeasureKind::MatchNumber,
+                };
+                Ok(MatchMeasureInfo {
+                    kind,
+                    data_type: measure.data_type.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The rows are matched in order, `TransformMatchRecognize` is a pipeline breaker.
+        let func_ctx = self.func_ctx.clone();
+        let old_output_len = self.main_pipeline.output_len();
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformMatchRecognize::create(
+                input,
+                output,
+                func_ctx.clone(),
+                partition_by.clone(),
+                prev_columns.clone(),
+                defines.clone(),
+                plan.pattern.clone(),
+                measures.clone(),
+            )))
+        })?;
+        self.main_pipeline.try_resize(old_output_len)
+    }
+}
@@ -40,6 +40,7 @@ use databend_common_storages_fuse::operations::UnMatchedExprs;
 use databend_common_storages_fuse::FuseTable;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
 
@@ -231,6 +232,26 @@ impl PipelineBuilder {
             }
             self.main_pipeline.add_pipe(builder.finalize());
         }
+
+        // check constraints
+        if !TransformCheckConstraints::check_constraints(tbl.as_ref()).is_empty() {
+            builder = self
+                .main_pipeline
+                .try_create_transform_pipeline_builder_with_len(
+                    || {
+                        TransformCheckConstraints::try_new(
+                            self.ctx.clone(),
+                            computed_schema.clone(),
+                            tbl.as_ref(),
+                        )
+                    },
+                    transform_len,
+                )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+        }
         Ok(())
     }
 
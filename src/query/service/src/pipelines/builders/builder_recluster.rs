@@ -86,6 +86,7 @@ impl PipelineBuilder {
                     internal_columns: None,
                     base_block_ids: None,
                     update_stream_columns: table.change_tracking_enabled(),
+                    ordered_output: false,
                     data_mask_policy: None,
                     table_index: usize::MAX,
                     scan_id: usize::MAX,
@@ -31,6 +31,7 @@ impl PipelineBuilder {
                     self.func_ctx.clone(),
                     udf.udf_funcs.clone(),
                     runtimes.clone(),
+                    udf.error_policy,
                 ))
             })
         } else {
@@ -42,6 +43,7 @@ impl PipelineBuilder {
                     udf.udf_funcs.clone(),
                     semaphore.clone(),
                     endpoints.clone(),
+                    udf.error_policy,
                 )
             })
         }
@@ -24,16 +24,20 @@ use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_transforms::MemorySettings;
+use databend_common_sql::executor::physical_plans::SortedFirstRows;
 use databend_common_sql::executor::physical_plans::Window;
 use databend_common_sql::executor::physical_plans::WindowPartition;
 use databend_storages_common_cache::TempDirManager;
+use log::info;
 use opendal::services::Fs;
 use opendal::Operator;
 
 use crate::pipelines::memory_settings::MemorySettingsExt;
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformSortedFirstRows;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::transforms::TransformWindowPartitionCollect;
+use crate::pipelines::processors::transforms::WindowBatchStrategy;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::transforms::WindowPartitionExchange;
 use crate::pipelines::processors::transforms::WindowPartitionTopNExchange;
@@ -74,6 +78,9 @@ impl PipelineBuilder {
             self.main_pipeline.try_resize(1)?;
         }
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
+        let exclusion = window.window_frame.exclusion;
+        let batch_strategy =
+            WindowBatchStrategy::create(&window.func, &window.window_frame, &input_schema)?;
         // Window
         self.main_pipeline.add_transform(|input, output| {
             // The transform can only be created here, because it cannot be cloned.
@@ -81,14 +88,18 @@ impl PipelineBuilder {
             let transform = if window.window_frame.units.is_rows() {
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u64>::try_create_rows(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u64>::try_create_rows(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_frame_exclusion(exclusion)
+                    .with_batch_strategy(batch_strategy),
+                ) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
                     // If the length of order_by is 1, there may be a RANGE frame.
@@ -109,7 +120,8 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
-                                )?,
+                                )?
+                                .with_frame_exclusion(exclusion),
                             )
                                 as Box<dyn Processor>));
                         }
@@ -121,14 +133,17 @@ impl PipelineBuilder {
                 // So we can use any number type to create the transform.
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u8>::try_create_range(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u8>::try_create_range(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_frame_exclusion(exclusion),
+                ) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
         })?;
@@ -138,6 +153,40 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_sorted_first_rows(&mut self, first_rows: &SortedFirstRows) -> Result<()> {
+        self.build_pipeline(&first_rows.input)?;
+
+        // The input must be a single sorted stream, otherwise the rows of a partition
+        // could be split into several streams. The rows are then passed through, the
+        // `WindowPartition` above still computes the window of all of them.
+        if self.main_pipeline.output_len() != 1 {
+            info!(
+                "SortedFirstRows is skipped, the input has {} streams",
+                self.main_pipeline.output_len()
+            );
+            return Ok(());
+        }
+
+        let input_schema = first_rows.input.output_schema()?;
+        let offsets = |columns: &[usize]| {
+            columns
+                .iter()
+                .map(|index| input_schema.index_of(&index.to_string()))
+                .collect::<Result<Vec<_>>>()
+        };
+        let group_by = offsets(&first_rows.group_by)?;
+        let ties_by = offsets(&first_rows.ties_by)?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(TransformSortedFirstRows::create(
+                input,
+                output,
+                group_by.clone(),
+                ties_by.clone(),
+            ))
+        })
+    }
+
     pub(crate) fn build_window_partition(
         &mut self,
         window_partition: &WindowPartition,
@@ -16,6 +16,7 @@ mod builder_add_stream_column;
 mod builder_aggregate;
 mod builder_append_table;
 mod builder_async_function;
+mod builder_checksum;
 mod builder_column_mutation;
 mod builder_commit;
 mod builder_compact;
@@ -29,6 +30,7 @@ mod builder_hilbert_serialize;
 mod builder_insert_multi_table;
 mod builder_join;
 mod builder_limit;
+mod builder_match_recognize;
 mod builder_mutation;
 mod builder_mutation_manipulate;
 mod builder_mutation_organize;
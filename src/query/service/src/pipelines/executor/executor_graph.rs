@@ -744,16 +744,22 @@ impl RunningGraph {
                     Entry::Occupied(mut v) => {
                         let plan_profile = v.get_mut();
                         for index in 0..std::mem::variant_count::<ProfileStatisticsName>() {
-                            plan_profile.statistics[index] +=
-                                profile.statistics[index].fetch_min(0, Ordering::SeqCst);
+                            plan_profile.statistics[index] = ProfileStatisticsName::merge_value(
+                                index,
+                                plan_profile.statistics[index],
+                                profile.statistics[index].fetch_min(0, Ordering::SeqCst),
+                            );
                         }
                     }
                     Entry::Vacant(v) => {
                         let plan_profile = v.insert(PlanProfile::create(profile));
 
                         for index in 0..std::mem::variant_count::<ProfileStatisticsName>() {
-                            plan_profile.statistics[index] +=
-                                profile.statistics[index].fetch_min(0, Ordering::SeqCst);
+                            plan_profile.statistics[index] = ProfileStatisticsName::merge_value(
+                                index,
+                                plan_profile.statistics[index],
+                                profile.statistics[index].fetch_min(0, Ordering::SeqCst),
+                            );
                         }
 
                         let node_id = node_id.as_ref();
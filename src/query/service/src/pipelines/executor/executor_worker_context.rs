@@ -165,11 +165,13 @@ impl ExecutorWorkerContext {
         let _guard = ThreadTracker::tracking(payload.clone());
 
         let instant = Instant::now();
+        let start_nanos = Profile::now_nanos();
 
         proc.processor.process()?;
         let nanos = instant.elapsed().as_nanos();
         assume(nanos < 18446744073709551615_u128);
         Profile::record_usize_profile(ProfileStatisticsName::CpuTime, nanos as usize);
+        Profile::record_process_time(start_nanos, start_nanos + nanos as usize);
         Ok(Some((proc.processor.id(), proc.graph)))
     }
 
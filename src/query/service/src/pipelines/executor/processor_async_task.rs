@@ -199,6 +199,7 @@ impl Future for ProcessorAsyncTask {
         let wait_nanos = before_poll_nanos - last_nanos;
         Profile::record_usize_profile(ProfileStatisticsName::WaitTime, wait_nanos);
 
+        let start_nanos = Profile::now_nanos();
         let poll_res = catch_unwind(move || inner.poll(cx));
 
         let after_poll_nanos = elapsed_nanos(last_instant);
@@ -206,6 +207,10 @@ impl Future for ProcessorAsyncTask {
             ProfileStatisticsName::CpuTime,
             after_poll_nanos - before_poll_nanos,
         );
+        Profile::record_process_time(
+            start_nanos,
+            start_nanos + (after_poll_nanos - before_poll_nanos),
+        );
 
         match poll_res {
             Ok(Poll::Pending) => {
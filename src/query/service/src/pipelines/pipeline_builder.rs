@@ -170,12 +170,18 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateExpand(aggregate) => self.build_aggregate_expand(aggregate),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
+            PhysicalPlan::AggregateSorted(aggregate) => self.build_aggregate_sorted(aggregate),
             PhysicalPlan::Window(window) => self.build_window(window),
+            PhysicalPlan::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(match_recognize)
+            }
             PhysicalPlan::WindowPartition(window_partition) => {
                 self.build_window_partition(window_partition)
             }
+            PhysicalPlan::SortedFirstRows(first_rows) => self.build_sorted_first_rows(first_rows),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::Checksum(checksum) => self.build_checksum(checksum),
             PhysicalPlan::RowFetch(row_fetch) => self.build_row_fetch(row_fetch),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
@@ -86,12 +86,13 @@ fn scatter_payload(mut payload: Payload, buckets: usize) -> Result<Vec<Payload>>
     let mut state = PayloadFlushState::default();
 
     for _ in 0..buckets.capacity() {
-        let p = Payload::new(
+        let mut p = Payload::new(
             payload.arena.clone(),
             group_types.clone(),
             aggrs.clone(),
             payload.states_layout.clone(),
         );
+        p.hash_seed = payload.hash_seed;
         buckets.push(p);
     }
 
@@ -124,23 +125,28 @@ fn scatter_partitioned_payload(
     let mut state = PayloadFlushState::default();
 
     for _ in 0..buckets.capacity() {
-        buckets.push(PartitionedPayload::new(
-            group_types.clone(),
-            aggrs.clone(),
-            partition_count,
-            partitioned_payload.arenas.clone(),
-        ));
+        buckets.push(
+            PartitionedPayload::new(
+                group_types.clone(),
+                aggrs.clone(),
+                partition_count,
+                partitioned_payload.arenas.clone(),
+            )
+            .with_hash_seed(partitioned_payload.hash_seed),
+        );
     }
 
     let mut payloads = Vec::with_capacity(buckets.len());
 
     for _ in 0..payloads.capacity() {
-        payloads.push(Payload::new(
+        let mut payload = Payload::new(
             Arc::new(Bump::new()),
             group_types.clone(),
             aggrs.clone(),
             partitioned_payload.states_layout.clone(),
-        ));
+        );
+        payload.hash_seed = partitioned_payload.hash_seed;
+        payloads.push(payload);
     }
 
     for mut payload in partitioned_payload.payloads.into_iter() {
@@ -37,6 +37,7 @@ pub struct SerializedPayload {
     pub data_block: DataBlock,
     // use for new agg_hashtable
     pub max_partition_count: usize,
+    pub hash_seed: u64,
 }
 
 impl SerializedPayload {
@@ -56,7 +57,9 @@ impl SerializedPayload {
     ) -> Result<AggregateHashTable> {
         let rows_num = self.data_block.num_rows();
         let capacity = AggregateHashTable::get_capacity_for_count(rows_num);
-        let config = HashTableConfig::default().with_initial_radix_bits(radix_bits);
+        let config = HashTableConfig::default()
+            .with_initial_radix_bits(radix_bits)
+            .with_hash_seed(self.hash_seed);
         let mut state = ProbeState::default();
         let group_len = group_types.len();
         let mut hashtable = AggregateHashTable::new_directly(
@@ -112,6 +115,7 @@ pub struct BucketSpilledPayload {
     pub data_range: Range<u64>,
     pub columns_layout: Vec<u64>,
     pub max_partition_count: usize,
+    pub hash_seed: u64,
 }
 
 pub struct AggregatePayload {
@@ -152,11 +156,13 @@ impl AggregateMeta {
         bucket: isize,
         block: DataBlock,
         max_partition_count: usize,
+        hash_seed: u64,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateMeta::Serialized(SerializedPayload {
             bucket,
             data_block: block,
             max_partition_count,
+            hash_seed,
         }))
     }
 
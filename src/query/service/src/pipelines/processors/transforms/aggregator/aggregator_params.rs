@@ -41,6 +41,8 @@ pub struct AggregatorParams {
     pub cluster_aggregator: bool,
     pub max_block_size: usize,
     pub max_spill_io_requests: usize,
+    // The seed of the group hashes, must be the same on all the nodes of the cluster.
+    pub hash_seed: u64,
 }
 
 impl AggregatorParams {
@@ -54,6 +56,7 @@ impl AggregatorParams {
         cluster_aggregator: bool,
         max_block_size: usize,
         max_spill_io_requests: usize,
+        hash_seed: u64,
     ) -> Result<Arc<AggregatorParams>> {
         let states_layout = if !agg_funcs.is_empty() {
             Some(get_states_layout(agg_funcs)?)
@@ -72,6 +75,7 @@ impl AggregatorParams {
             cluster_aggregator,
             max_block_size,
             max_spill_io_requests,
+            hash_seed,
         }))
     }
 
@@ -95,6 +99,17 @@ impl AggregatorParams {
         DataBlock::new_from_columns(columns)
     }
 
+    /// The types of the final results, the aggregate results followed by the group columns.
+    pub fn output_data_types(&self) -> Result<Vec<DataType>> {
+        let mut data_types =
+            Vec::with_capacity(self.aggregate_functions.len() + self.group_data_types.len());
+        for function in self.aggregate_functions.iter() {
+            data_types.push(function.return_type()?);
+        }
+        data_types.extend(self.group_data_types.iter().cloned());
+        Ok(data_types)
+    }
+
     pub fn num_states(&self) -> usize {
         self.aggregate_functions.len()
     }
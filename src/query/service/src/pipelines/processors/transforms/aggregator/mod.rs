@@ -20,6 +20,7 @@ mod serde;
 mod transform_aggregate_expand;
 mod transform_aggregate_final;
 mod transform_aggregate_partial;
+mod transform_aggregate_sorted;
 mod transform_single_key;
 mod udaf_script;
 
@@ -30,6 +31,7 @@ pub use new_transform_partition_bucket::build_partition_bucket;
 pub use transform_aggregate_expand::TransformExpandGroupingSets;
 pub use transform_aggregate_final::TransformFinalAggregate;
 pub use transform_aggregate_partial::TransformPartialAggregate;
+pub use transform_aggregate_sorted::TransformSortedAggregate;
 pub use transform_single_key::FinalSingleStateAggregator;
 pub use transform_single_key::PartialSingleStateAggregator;
 pub use udaf_script::*;
@@ -41,6 +41,7 @@ use super::TransformFinalAggregate;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::SerializedPayload;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::sessions::QueryContext;
 
 static SINGLE_LEVEL_BUCKET_NUM: isize = -1;
 static MAX_PARTITION_COUNT: usize = 128;
@@ -370,13 +371,17 @@ impl NewTransformPartitionBucket {
             0,
             Arc::new(Bump::new()),
         )?;
+        for payload in p.payloads.iter() {
+            payload.check_hash_seed(self.params.hash_seed)?;
+        }
 
         let mut partitioned_payload = PartitionedPayload::new(
             self.params.group_data_types.clone(),
             self.params.aggregate_functions.clone(),
             self.max_partition_count as u64,
             p.arenas.clone(),
-        );
+        )
+        .with_hash_seed(self.params.hash_seed);
         partitioned_payload.combine(p, &mut self.flush_state);
 
         for (bucket, payload) in partitioned_payload.payloads.into_iter().enumerate() {
@@ -410,13 +415,15 @@ impl NewTransformPartitionBucket {
         }
 
         // need repartition
+        payload.payload.check_hash_seed(self.params.hash_seed)?;
         let mut blocks = Vec::with_capacity(self.max_partition_count);
         let mut partitioned_payload = PartitionedPayload::new(
             self.params.group_data_types.clone(),
             self.params.aggregate_functions.clone(),
             self.max_partition_count as u64,
             vec![payload.payload.arena.clone()],
-        );
+        )
+        .with_hash_seed(self.params.hash_seed);
 
         partitioned_payload.combine_single(payload.payload, &mut self.flush_state, None);
 
@@ -577,6 +584,7 @@ impl Processor for NewTransformPartitionBucket {
 }
 
 pub fn build_partition_bucket(
+    ctx: Arc<QueryContext>,
     pipeline: &mut Pipeline,
     params: Arc<AggregatorParams>,
 ) -> Result<()> {
@@ -603,6 +611,7 @@ pub fn build_partition_bucket(
 
     pipeline.add_transform(|input, output| {
         Ok(ProcessorPtr::create(TransformFinalAggregate::try_create(
+            ctx.clone(),
             input,
             output,
             params.clone(),
@@ -32,12 +32,15 @@ pub struct AggregateSerdeMeta {
     // use for new agg hashtable
     pub max_partition_count: usize,
     pub is_empty: bool,
+    // the seed of the group hashes in the payload, see `HashTableConfig::hash_seed`
+    pub hash_seed: u64,
 }
 
 impl AggregateSerdeMeta {
     pub fn create_agg_payload(
         bucket: isize,
         max_partition_count: usize,
+        hash_seed: u64,
         is_empty: bool,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateSerdeMeta {
@@ -48,6 +51,7 @@ impl AggregateSerdeMeta {
             columns_layout: vec![],
             max_partition_count,
             is_empty,
+            hash_seed,
         })
     }
 
@@ -66,6 +70,7 @@ impl AggregateSerdeMeta {
             data_range: Some(data_range),
             max_partition_count: 0,
             is_empty,
+            hash_seed: 0,
         })
     }
 
@@ -75,6 +80,7 @@ impl AggregateSerdeMeta {
         data_range: Range<u64>,
         columns_layout: Vec<usize>,
         max_partition_count: usize,
+        hash_seed: u64,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateSerdeMeta {
             typ: SPILLED_TYPE,
@@ -84,6 +90,7 @@ impl AggregateSerdeMeta {
             data_range: Some(data_range),
             max_partition_count,
             is_empty: false,
+            hash_seed,
         })
     }
 }
@@ -233,6 +233,7 @@ impl SerializeAggregateStream {
                             AggregateSerdeMeta::create_agg_payload(
                                 p.bucket,
                                 p.max_partition_count,
+                                p.payload.hash_seed,
                                 false,
                             ),
                         ))?))
@@ -246,6 +247,7 @@ impl SerializeAggregateStream {
                                 AggregateSerdeMeta::create_agg_payload(
                                     p.bucket,
                                     p.max_partition_count,
+                                    p.payload.hash_seed,
                                     true,
                                 ),
                             ))?))
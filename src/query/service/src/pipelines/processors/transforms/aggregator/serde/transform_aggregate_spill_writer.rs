@@ -26,6 +26,7 @@ use databend_common_expression::arrow::serialize_column;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::PartitionedPayload;
+use databend_common_expression::Payload;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -63,14 +64,7 @@ impl TransformAggregateSpillWriter {
         params: Arc<AggregatorParams>,
         location_prefix: String,
     ) -> Result<Box<dyn Processor>> {
-        let config = SpillerConfig {
-            spiller_type: SpillerType::Aggregation,
-            location_prefix,
-            disk_spill: None,
-            use_parquet: ctx.get_settings().get_spilling_file_format()?.is_parquet(),
-        };
-
-        let spiller = Spiller::create(ctx.clone(), operator, config.clone())?;
+        let spiller = create_aggregate_spiller(&ctx, operator, location_prefix)?;
         Ok(Box::new(TransformAggregateSpillWriter {
             ctx,
             input,
@@ -84,6 +78,20 @@ impl TransformAggregateSpillWriter {
     }
 }
 
+pub fn create_aggregate_spiller(
+    ctx: &Arc<QueryContext>,
+    operator: Operator,
+    location_prefix: String,
+) -> Result<Spiller> {
+    let config = SpillerConfig {
+        spiller_type: SpillerType::Aggregation,
+        location_prefix,
+        disk_spill: None,
+        use_parquet: ctx.get_settings().get_spilling_file_format()?.is_parquet(),
+    };
+    Spiller::create(ctx.clone(), operator, config)
+}
+
 #[async_trait::async_trait]
 impl Processor for TransformAggregateSpillWriter {
     fn name(&self) -> String {
@@ -186,14 +194,38 @@ pub fn agg_spilling_aggregate_payload(
     spiller: Arc<Spiller>,
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
-    let mut write_size = 0;
     let partition_count = partitioned_payload.partition_count();
-    let mut write_data = Vec::with_capacity(partition_count);
-    let mut spilled_buckets_payloads = Vec::with_capacity(partition_count);
+    let hash_seed = partitioned_payload.hash_seed;
+    let payloads = partitioned_payload
+        .payloads
+        .into_iter()
+        .enumerate()
+        .map(|(bucket, payload)| (bucket as isize, payload));
+    let spilling = agg_spilling_payloads(ctx, spiller, payloads, partition_count, hash_seed)?;
+
+    Ok(Box::pin(async move {
+        Ok(DataBlock::empty_with_meta(AggregateMeta::create_spilled(
+            spilling.await?,
+        )))
+    }))
+}
+
+// Spills the payloads of the buckets into one file, the payloads are serialized before
+// the returned future is polled, so their memory is released as soon as it returns.
+pub fn agg_spilling_payloads(
+    ctx: Arc<QueryContext>,
+    spiller: Arc<Spiller>,
+    payloads: impl Iterator<Item = (isize, Payload)>,
+    max_partition_count: usize,
+    hash_seed: u64,
+) -> Result<BoxFuture<'static, Result<Vec<BucketSpilledPayload>>>> {
+    let mut write_size = 0;
+    let mut write_data = Vec::new();
+    let mut spilled_buckets_payloads = Vec::new();
     // Record how many rows are spilled.
     let mut rows = 0;
     let location = spiller.create_unique_location();
-    for (bucket, payload) in partitioned_payload.payloads.into_iter().enumerate() {
+    for (bucket, payload) in payloads {
         if payload.len() == 0 {
             continue;
         }
@@ -215,11 +247,12 @@ pub fn agg_spilling_aggregate_payload(
 
         write_data.push(columns_data);
         spilled_buckets_payloads.push(BucketSpilledPayload {
-            bucket: bucket as isize,
+            bucket,
             location: location.clone(),
             data_range: begin..write_size,
             columns_layout,
-            max_partition_count: partition_count,
+            max_partition_count,
+            hash_seed,
         });
     }
 
@@ -257,8 +290,6 @@ pub fn agg_spilling_aggregate_payload(
             );
         }
 
-        Ok(DataBlock::empty_with_meta(AggregateMeta::create_spilled(
-            spilled_buckets_payloads,
-        )))
+        Ok(spilled_buckets_payloads)
     }))
 }
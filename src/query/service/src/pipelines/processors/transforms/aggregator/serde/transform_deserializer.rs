@@ -106,6 +106,7 @@ impl TransformDeserializer {
                                     meta.bucket,
                                     block,
                                     meta.max_partition_count,
+                                    meta.hash_seed,
                                 ),
                             ))
                         }
@@ -151,6 +152,7 @@ impl TransformDeserializer {
                                             ..columns_layout.offsets[index + 1] as usize]
                                             .to_vec(),
                                         max_partition_count: meta.max_partition_count,
+                                        hash_seed: meta.hash_seed,
                                     });
                                 }
                             }
@@ -187,6 +187,7 @@ fn exchange_agg_spilling_aggregate_payload(
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let partition_count = partitioned_payload.partition_count();
+    let hash_seed = partitioned_payload.hash_seed;
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(partition_count);
     let mut buckets_column_data = Vec::with_capacity(partition_count);
@@ -277,6 +278,7 @@ fn exchange_agg_spilling_aggregate_payload(
                 0..0,
                 vec![],
                 partition_count,
+                hash_seed,
             )))?;
 
             let write_options = exchange_defines::spilled_write_options();
@@ -294,7 +294,7 @@ impl TransformSpillReader {
         })))
     }
 
-    fn deserialize(payload: BucketSpilledPayload, data: Vec<u8>) -> AggregateMeta {
+    pub fn deserialize(payload: BucketSpilledPayload, data: Vec<u8>) -> AggregateMeta {
         let mut begin = 0;
         let mut columns = Vec::with_capacity(payload.columns_layout.len());
 
@@ -307,6 +307,7 @@ impl TransformSpillReader {
             bucket: payload.bucket,
             data_block: DataBlock::new_from_columns(columns),
             max_partition_count: payload.max_partition_count,
+            hash_seed: payload.hash_seed,
         })
     }
 }
@@ -15,118 +15,345 @@
 use std::sync::Arc;
 
 use bumpalo::Bump;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::AggregateHashTable;
+use databend_common_expression::BlockCompactor;
+use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
 use databend_common_expression::HashTableConfig;
+use databend_common_expression::PartitionedPayload;
+use databend_common_expression::Payload;
 use databend_common_expression::PayloadFlushState;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_transforms::processors::BlockMetaTransform;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
+use databend_common_pipeline_transforms::MemorySettings;
+use databend_common_storage::DataOperator;
+use log::warn;
+use opendal::Operator;
 
+use crate::pipelines::memory_settings::MemorySettingsExt;
+use crate::pipelines::processors::transforms::aggregator::agg_spilling_payloads;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::create_aggregate_spiller;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::pipelines::processors::transforms::aggregator::BucketSpilledPayload;
+use crate::pipelines::processors::transforms::aggregator::TransformSpillReader;
+use crate::sessions::QueryContext;
+use crate::spillers::Spiller;
+
+// The partitions are split by the hash bits following the bucket bits, at most
+// 16 hash bits are used for partitioning.
+const MAX_REPARTITION_RADIX_BITS: u64 = 16;
 
 pub struct TransformFinalAggregate {
+    ctx: Arc<QueryContext>,
     params: Arc<AggregatorParams>,
     flush_state: PayloadFlushState,
+    output_data_types: Vec<DataType>,
+    thresholds: BlockThresholds,
+    settings: MemorySettings,
+    repartition_radix_bits_incr: u64,
+    // Spills the sub-partitions not merged yet, see `aggregate_partition`.
+    spiller: Arc<Spiller>,
+    operator: Operator,
+    // The spill files of the sub-partitions not restored yet, they are deleted once
+    // restored, or when the transform is dropped.
+    spilled_files: Vec<String>,
 }
 
 impl TransformFinalAggregate {
     pub fn try_create(
+        ctx: Arc<QueryContext>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
 
         params: Arc<AggregatorParams>,
     ) -> Result<Box<dyn Processor>> {
+        // The results of a partition are emitted in blocks of about `max_block_size` rows.
+        let thresholds = BlockThresholds::new(
+            params.max_block_size,
+            params.max_block_size,
+            BlockThresholds::default().max_bytes_per_block,
+        );
+        let operator = DataOperator::instance().spill_operator();
+        let spiller =
+            create_aggregate_spiller(&ctx, operator.clone(), ctx.query_id_spill_prefix())?;
         Ok(BlockMetaTransformer::create(
             input,
             output,
             TransformFinalAggregate {
+                output_data_types: params.output_data_types()?,
                 params,
                 flush_state: PayloadFlushState::default(),
+                thresholds,
+                settings: MemorySettings::from_aggregate_settings(&ctx)?,
+                repartition_radix_bits_incr: HashTableConfig::default().repartition_radix_bits_incr,
+                spiller: Arc::new(spiller),
+                operator,
+                spilled_files: vec![],
+                ctx,
             },
         ))
     }
 
-    fn transform_agg_hashtable(&mut self, meta: AggregateMeta) -> Result<DataBlock> {
-        let mut agg_hashtable: Option<AggregateHashTable> = None;
+    fn transform_agg_hashtable(&mut self, meta: AggregateMeta) -> Result<Vec<DataBlock>> {
+        let mut payloads = vec![];
+        let mut partition_count = 1;
         if let AggregateMeta::Partitioned { bucket, data } = meta {
             for bucket_data in data {
                 match bucket_data {
-                    AggregateMeta::Serialized(payload) => match agg_hashtable.as_mut() {
-                        Some(ht) => {
-                            debug_assert!(bucket == payload.bucket);
-
-                            let payload = payload.convert_to_partitioned_payload(
-                                self.params.group_data_types.clone(),
-                                self.params.aggregate_functions.clone(),
-                                self.params.num_states(),
-                                0,
-                                Arc::new(Bump::new()),
-                            )?;
-                            ht.combine_payloads(&payload, &mut self.flush_state)?;
-                        }
-                        None => {
-                            debug_assert!(bucket == payload.bucket);
-                            agg_hashtable = Some(payload.convert_to_aggregate_table(
-                                self.params.group_data_types.clone(),
-                                self.params.aggregate_functions.clone(),
-                                self.params.num_states(),
-                                0,
-                                Arc::new(Bump::new()),
-                                true,
-                            )?);
-                        }
-                    },
-                    AggregateMeta::AggregatePayload(payload) => match agg_hashtable.as_mut() {
-                        Some(ht) => {
-                            debug_assert!(bucket == payload.bucket);
-                            ht.combine_payload(&payload.payload, &mut self.flush_state)?;
-                        }
-                        None => {
-                            debug_assert!(bucket == payload.bucket);
-                            let capacity =
-                                AggregateHashTable::get_capacity_for_count(payload.payload.len());
-                            let mut hashtable = AggregateHashTable::new_with_capacity(
-                                self.params.group_data_types.clone(),
-                                self.params.aggregate_functions.clone(),
-                                HashTableConfig::default().with_initial_radix_bits(0),
-                                capacity,
-                                Arc::new(Bump::new()),
-                            );
-                            hashtable.combine_payload(&payload.payload, &mut self.flush_state)?;
-                            agg_hashtable = Some(hashtable);
+                    AggregateMeta::Serialized(payload) => {
+                        debug_assert!(bucket == payload.bucket);
+                        partition_count = partition_count.max(payload.max_partition_count);
+
+                        let partitioned_payload = payload.convert_to_partitioned_payload(
+                            self.params.group_data_types.clone(),
+                            self.params.aggregate_functions.clone(),
+                            self.params.num_states(),
+                            0,
+                            Arc::new(Bump::new()),
+                        )?;
+                        for payload in partitioned_payload.payloads.iter() {
+                            payload.check_hash_seed(self.params.hash_seed)?;
                         }
-                    },
+                        payloads.extend(partitioned_payload.payloads);
+                    }
+                    AggregateMeta::AggregatePayload(payload) => {
+                        debug_assert!(bucket == payload.bucket);
+                        partition_count = partition_count.max(payload.max_partition_count);
+                        payload.payload.check_hash_seed(self.params.hash_seed)?;
+                        payloads.push(payload.payload);
+                    }
                     _ => unreachable!(),
                 }
             }
         }
 
-        if let Some(mut ht) = agg_hashtable {
-            let mut blocks = vec![];
-            self.flush_state.clear();
-
-            loop {
-                if ht.merge_result(&mut self.flush_state)? {
-                    let mut cols = self.flush_state.take_aggregate_results();
-                    cols.extend_from_slice(&self.flush_state.take_group_columns());
-                    blocks.push(DataBlock::new_from_columns(cols));
-                } else {
-                    break;
+        let mut compactor = BlockCompactor::new(self.output_data_types.clone(), self.thresholds);
+        let radix_bits = partition_count.trailing_zeros() as u64;
+        self.aggregate_partition(payloads, radix_bits, usize::MAX, &mut compactor)?;
+
+        let blocks = compactor.finish();
+        if blocks.is_empty() {
+            return Ok(vec![self.params.empty_result_block()]);
+        }
+        Ok(blocks)
+    }
+
+    // Merges the payloads of one partition, `radix_bits` is the number of the leading
+    // hash bits shared by all the rows of the partition.
+    //
+    // If the memory limit is exceeded while merging, the hash table and the payloads
+    // not merged yet are split into sub-partitions by the following hash bits. The groups
+    // of different sub-partitions never overlap, so they are merged one by one and only
+    // the hash table of one sub-partition is built at a time, the others are spilled until
+    // they are merged.
+    //
+    // `split_bytes` is the size of the hash table of the parent partition when it was split,
+    // a sub-partition is not split any more if its hash table is not smaller than that, as
+    // the groups are skewed to the same hash bits and splitting would not free any memory.
+    fn aggregate_partition(
+        &mut self,
+        payloads: Vec<Payload>,
+        radix_bits: u64,
+        split_bytes: usize,
+        compactor: &mut BlockCompactor,
+    ) -> Result<()> {
+        let mut payloads = payloads.into_iter();
+        let mut agg_hashtable: Option<AggregateHashTable> = None;
+
+        while let Some(payload) = payloads.next() {
+            let hashtable = agg_hashtable.get_or_insert_with(|| {
+                let capacity = AggregateHashTable::get_capacity_for_count(payload.len());
+                AggregateHashTable::new_with_capacity(
+                    self.params.group_data_types.clone(),
+                    self.params.aggregate_functions.clone(),
+                    HashTableConfig::default()
+                        .with_initial_radix_bits(0)
+                        .with_hash_seed(self.params.hash_seed),
+                    capacity,
+                    Arc::new(Bump::new()),
+                )
+            });
+            hashtable.combine_payload(&payload, &mut self.flush_state)?;
+            drop(payload);
+
+            let sub_radix_bits = radix_bits + self.repartition_radix_bits_incr;
+            if payloads.len() != 0
+                && sub_radix_bits <= MAX_REPARTITION_RADIX_BITS
+                && hashtable.allocated_bytes() < split_bytes
+                && self.settings.check_spill()
+            {
+                let hashtable = agg_hashtable.take().unwrap();
+                let allocated_bytes = hashtable.allocated_bytes();
+                let mut partitions = self
+                    .split_partition(
+                        hashtable.payload.payloads.into_iter().chain(payloads),
+                        radix_bits,
+                    )
+                    .into_iter();
+
+                // Only the first sub-partition is kept in memory, the others are spilled
+                // and restored one by one.
+                let first = partitions.next().unwrap_or_default();
+                let spilled = self.spill_partitions(partitions, sub_radix_bits)?;
+                let location = spilled.iter().flatten().next().map(|p| p.location.clone());
+
+                self.aggregate_partition(first, sub_radix_bits, allocated_bytes, compactor)?;
+                for spilled in spilled {
+                    let partition = self.restore_partition(spilled)?;
+                    self.aggregate_partition(
+                        partition,
+                        sub_radix_bits,
+                        allocated_bytes,
+                        compactor,
+                    )?;
                 }
+                if let Some(location) = location {
+                    self.delete_spilled_file(location)?;
+                }
+                return Ok(());
+            }
+        }
+
+        // The results are flushed into the compactor directly, without assembling a block
+        // for each batch of the flushed rows.
+        if let Some(ht) = agg_hashtable {
+            for payload in ht.payload.payloads.iter() {
+                self.flush_state.clear();
+                payload.flush_into_compactor(&mut self.flush_state, compactor)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Spills each of the sub-partitions through the aggregate spiller, the sub-partitions
+    // are spilled into one file, and each of them is restored by its own range of the file.
+    fn spill_partitions(
+        &mut self,
+        partitions: impl Iterator<Item = Vec<Payload>>,
+        radix_bits: u64,
+    ) -> Result<Vec<Vec<BucketSpilledPayload>>> {
+        let payloads = partitions
+            .enumerate()
+            .flat_map(|(index, partition)| {
+                partition
+                    .into_iter()
+                    .map(move |payload| (index as isize, payload))
+            })
+            .collect::<Vec<_>>();
+        let partition_count = (1 << self.repartition_radix_bits_incr) - 1;
+
+        let spilling = agg_spilling_payloads(
+            self.ctx.clone(),
+            self.spiller.clone(),
+            payloads.into_iter(),
+            1 << radix_bits,
+            self.params.hash_seed,
+        )?;
+        let spilled = GlobalIORuntime::instance().block_on(spilling)?;
+        if let Some(payload) = spilled.first() {
+            self.spilled_files.push(payload.location.clone());
+        }
+
+        let mut partitions = (0..partition_count).map(|_| vec![]).collect::<Vec<_>>();
+        for payload in spilled {
+            partitions[payload.bucket as usize].push(payload);
+        }
+        Ok(partitions)
+    }
+
+    fn restore_partition(&mut self, spilled: Vec<BucketSpilledPayload>) -> Result<Vec<Payload>> {
+        let mut payloads = Vec::with_capacity(spilled.len());
+        for spilled in spilled {
+            let operator = self.operator.clone();
+            let location = spilled.location.clone();
+            let data_range = spilled.data_range.clone();
+            let data = GlobalIORuntime::instance().block_on(async move {
+                let data = operator.read_with(&location).range(data_range).await?;
+                Ok::<_, ErrorCode>(data.to_vec())
+            })?;
+
+            let AggregateMeta::Serialized(payload) =
+                TransformSpillReader::deserialize(spilled, data)
+            else {
+                unreachable!()
+            };
+            let partitioned_payload = payload.convert_to_partitioned_payload(
+                self.params.group_data_types.clone(),
+                self.params.aggregate_functions.clone(),
+                self.params.num_states(),
+                0,
+                Arc::new(Bump::new()),
+            )?;
+            payloads.extend(partitioned_payload.payloads);
+        }
+        Ok(payloads)
+    }
+
+    fn delete_spilled_file(&mut self, location: String) -> Result<()> {
+        self.spilled_files.retain(|file| file != &location);
+        let operator = self.operator.clone();
+        GlobalIORuntime::instance().block_on(async move {
+            operator.delete(&location).await?;
+            Ok::<_, ErrorCode>(())
+        })
+    }
+
+    fn split_partition(
+        &mut self,
+        payloads: impl Iterator<Item = Payload>,
+        radix_bits: u64,
+    ) -> Vec<Vec<Payload>> {
+        let partition_count = 1 << self.repartition_radix_bits_incr;
+        let mut partitions = (0..partition_count).map(|_| vec![]).collect::<Vec<_>>();
+
+        for payload in payloads {
+            if payload.len() == 0 {
+                continue;
             }
 
-            if blocks.is_empty() {
-                return Ok(self.params.empty_result_block());
+            let mut partitioned_payload = PartitionedPayload::new_with_radix_offset(
+                self.params.group_data_types.clone(),
+                self.params.aggregate_functions.clone(),
+                partition_count as u64,
+                radix_bits,
+                vec![payload.arena.clone()],
+            )
+            .with_hash_seed(self.params.hash_seed);
+            partitioned_payload.combine_single(payload, &mut self.flush_state, None);
+
+            for (index, payload) in partitioned_payload.payloads.into_iter().enumerate() {
+                if payload.len() != 0 {
+                    partitions[index].push(payload);
+                }
             }
-            return DataBlock::concat(&blocks);
         }
 
-        Ok(self.params.empty_result_block())
+        partitions
+    }
+}
+
+impl Drop for TransformFinalAggregate {
+    fn drop(&mut self) {
+        // The spill files left by a failed or aborted merge.
+        for location in std::mem::take(&mut self.spilled_files) {
+            let operator = self.operator.clone();
+            GlobalIORuntime::instance().spawn(async move {
+                if let Err(cause) = operator.delete(&location).await {
+                    warn!("Failed to delete the aggregate spill file {location}, cause: {cause:?}");
+                }
+            });
+        }
     }
 }
 
@@ -134,6 +361,27 @@ impl BlockMetaTransform<AggregateMeta> for TransformFinalAggregate {
     const NAME: &'static str = "TransformFinalAggregate";
 
     fn transform(&mut self, meta: AggregateMeta) -> Result<Vec<DataBlock>> {
-        Ok(vec![self.transform_agg_hashtable(meta)?])
+        // The flush metrics are timed, only collected if reported in the profile of the plan.
+        if self.flush_state.metrics.is_none() && Profile::is_plan_profiled() {
+            self.flush_state.enable_metrics();
+        }
+        self.transform_agg_hashtable(meta)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        if let Some(metrics) = self.flush_state.metrics.take() {
+            let total = metrics.total();
+            Profile::record_usize_profile(ProfileStatisticsName::AggregateFlushRows, total.rows);
+            Profile::record_usize_profile(ProfileStatisticsName::AggregateFlushBytes, total.bytes);
+            Profile::record_usize_profile(
+                ProfileStatisticsName::AggregateFlushTime,
+                total.time.as_nanos() as usize,
+            );
+            log::debug!(
+                "Aggregate flush metrics by group column kind: {:?}",
+                metrics
+            );
+        }
+        Ok(())
     }
 }
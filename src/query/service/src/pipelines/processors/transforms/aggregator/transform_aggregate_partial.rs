@@ -190,7 +190,12 @@ impl AccumulatingTransform for TransformPartialAggregate {
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
         self.execute_one_block(block)?;
 
-        if self.settings.check_spill() {
+        // Spill instead of growing the arenas further for the high-cardinality string keys.
+        let exceeds_arena_limit = match &self.hash_table {
+            HashTable::MovedOut => unreachable!(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.exceeds_arena_limit(),
+        };
+        if exceeds_arena_limit || self.settings.check_spill() {
             if let HashTable::AggregateHashTable(v) = std::mem::take(&mut self.hash_table) {
                 let group_types = v.payload.group_types.clone();
                 let aggrs = v.payload.aggrs.clone();
@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_exception::Result;
+use databend_common_expression::AggrState;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+
+/// Aggregates an input sorted on all the group keys.
+///
+/// The rows of a group are adjacent, so only the states of the current group are kept,
+/// and the group is emitted once a row with another key arrives.
+pub struct TransformSortedAggregate {
+    params: Arc<AggregatorParams>,
+    arena: Bump,
+    // The states of the current group, they are allocated by the first group and reused by
+    // all the groups.
+    addr: Option<StateAddr>,
+    // The group keys of the current group, `None` if no row is aggregated yet.
+    current_key: Option<Vec<Scalar>>,
+
+    result_builders: Vec<ColumnBuilder>,
+    group_builders: Vec<ColumnBuilder>,
+    num_groups: usize,
+}
+
+impl TransformSortedAggregate {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        params: Arc<AggregatorParams>,
+    ) -> Result<Box<dyn Processor>> {
+        let mut transform = TransformSortedAggregate {
+            params,
+            arena: Bump::new(),
+            addr: None,
+            current_key: None,
+            result_builders: vec![],
+            group_builders: vec![],
+            num_groups: 0,
+        };
+        (transform.result_builders, transform.group_builders) = transform.create_builders()?;
+
+        Ok(AccumulatingTransformer::create(input, output, transform))
+    }
+
+    fn create_builders(&self) -> Result<(Vec<ColumnBuilder>, Vec<ColumnBuilder>)> {
+        let capacity = self.params.max_block_size;
+        let result_builders = self
+            .params
+            .aggregate_functions
+            .iter()
+            .map(|func| Ok(ColumnBuilder::with_capacity(&func.return_type()?, capacity)))
+            .collect::<Result<Vec<_>>>()?;
+        let group_builders = self
+            .params
+            .group_data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, capacity))
+            .collect();
+        Ok((result_builders, group_builders))
+    }
+
+    fn states(&self) -> impl Iterator<Item = AggrState> + '_ {
+        let addr = self.addr;
+        self.params
+            .states_layout
+            .iter()
+            .flat_map(|layout| layout.states_loc.iter())
+            .map(move |loc| AggrState::new(addr.unwrap(), loc))
+    }
+
+    fn is_current_group(&self, group_columns: &[Column], row: usize) -> bool {
+        self.current_key.as_ref().is_some_and(|key| {
+            key.iter()
+                .zip(group_columns)
+                .all(|(scalar, column)| column.index(row) == Some(scalar.as_ref()))
+        })
+    }
+
+    fn start_group(&mut self, group_columns: &[Column], row: usize) {
+        let key = group_columns
+            .iter()
+            .map(|column| column.index(row).unwrap().to_owned())
+            .collect();
+        self.current_key = Some(key);
+
+        if self.addr.is_none() {
+            self.addr = self
+                .params
+                .states_layout
+                .as_ref()
+                .map(|layout| self.arena.alloc_layout(layout.layout).into());
+        }
+        for (func, place) in self.params.aggregate_functions.iter().zip(self.states()) {
+            func.init_state(place);
+        }
+    }
+
+    fn finish_group(&mut self) -> Result<()> {
+        let Some(key) = self.current_key.take() else {
+            return Ok(());
+        };
+
+        // The states are dropped even if merging the result fails, the first error is returned.
+        let states = self.states().collect::<Vec<_>>();
+        let mut result = Ok(());
+        for ((func, place), builder) in self
+            .params
+            .aggregate_functions
+            .iter()
+            .zip(states)
+            .zip(self.result_builders.iter_mut())
+        {
+            if result.is_ok() {
+                result = func.merge_result(place, builder);
+            }
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(place) }
+            }
+        }
+        result?;
+        for (scalar, builder) in key.iter().zip(self.group_builders.iter_mut()) {
+            builder.push(scalar.as_ref());
+        }
+        self.num_groups += 1;
+        Ok(())
+    }
+
+    fn accumulate(&self, block: &DataBlock, start: usize, end: usize) -> Result<()> {
+        if self.params.aggregate_functions.is_empty() {
+            return Ok(());
+        }
+
+        let block = block.slice(start..end);
+        for ((func, place), args) in self
+            .params
+            .aggregate_functions
+            .iter()
+            .zip(self.states())
+            .zip(self.params.aggregate_functions_arguments.iter())
+        {
+            let columns = InputColumns::new_block_proxy(args.as_slice(), &block);
+            func.accumulate(place, columns, None, block.num_rows())?;
+        }
+        Ok(())
+    }
+
+    fn build_block(&mut self) -> Result<DataBlock> {
+        let (result_builders, group_builders) = self.create_builders()?;
+        let result_builders = std::mem::replace(&mut self.result_builders, result_builders);
+        let group_builders = std::mem::replace(&mut self.group_builders, group_builders);
+        self.num_groups = 0;
+
+        let columns = result_builders
+            .into_iter()
+            .chain(group_builders)
+            .map(|builder| builder.build())
+            .collect();
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl AccumulatingTransform for TransformSortedAggregate {
+    const NAME: &'static str = "TransformSortedAggregate";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let block = block.consume_convert_to_full();
+        let num_rows = block.num_rows();
+        let group_columns = self
+            .params
+            .group_columns
+            .iter()
+            .map(|offset| {
+                block
+                    .get_by_offset(*offset)
+                    .value
+                    .as_column()
+                    .unwrap()
+                    .clone()
+            })
+            .collect::<Vec<_>>();
+
+        let mut start = 0;
+        while start < num_rows {
+            if !self.is_current_group(&group_columns, start) {
+                self.finish_group()?;
+                self.start_group(&group_columns, start);
+            }
+
+            let mut end = start + 1;
+            while end < num_rows && self.is_current_group(&group_columns, end) {
+                end += 1;
+            }
+            self.accumulate(&block, start, end)?;
+            start = end;
+        }
+
+        if self.num_groups >= self.params.max_block_size {
+            return Ok(vec![self.build_block()?]);
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+
+        self.finish_group()?;
+        if self.num_groups == 0 {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.build_block()?])
+    }
+}
+
+impl Drop for TransformSortedAggregate {
+    fn drop(&mut self) {
+        // The states of the unfinished group.
+        if self.current_key.is_some() {
+            for (func, place) in self.params.aggregate_functions.iter().zip(self.states()) {
+                if func.need_manual_drop_state() {
+                    unsafe { func.drop_state(place) }
+                }
+            }
+        }
+    }
+}
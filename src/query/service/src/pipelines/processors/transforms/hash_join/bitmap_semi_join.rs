@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_column::bitmap::Bitmap;
+use databend_common_column::bitmap::MutableBitmap;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::Column;
+
+// The max number of bits of the bitmap, 2MB of memory.
+const MAX_BITMAP_DOMAIN: u128 = 1 << 24;
+// The domain of the build keys is dense if it's at most this times the number of the build keys.
+const MAX_DOMAIN_PER_BUILD_KEY: u128 = 16;
+
+/// The build keys of a semi join on a single integer key, stored as a bitmap over
+/// the domain `[min, max]` of the build keys.
+///
+/// It's only created if the domain is dense enough, in which case a membership test
+/// is a bit lookup instead of a hash table probe.
+pub struct BitmapSemiJoinTable {
+    min: i128,
+    bitmap: Bitmap,
+}
+
+impl BitmapSemiJoinTable {
+    /// Create the table from the build key columns, returns `None` if the keys are not
+    /// integers, contain NULLs, or their domain is too sparse, then the hash table
+    /// should be used instead.
+    pub fn try_create(columns: &[Column]) -> Option<Self> {
+        let mut min = i128::MAX;
+        let mut max = i128::MIN;
+        let mut num_keys = 0;
+        for column in columns {
+            let is_integer = for_each_integer(column, |_, key| {
+                min = min.min(key);
+                max = max.max(key);
+            });
+            if !is_integer {
+                return None;
+            }
+            num_keys += column.len();
+        }
+        if num_keys == 0 {
+            return None;
+        }
+
+        let domain = (max - min + 1) as u128;
+        if domain > MAX_BITMAP_DOMAIN || domain > num_keys as u128 * MAX_DOMAIN_PER_BUILD_KEY {
+            return None;
+        }
+
+        let mut bitmap = MutableBitmap::from_len_zeroed(domain as usize);
+        for column in columns {
+            for_each_integer(column, |_, key| bitmap.set((key - min) as usize, true));
+        }
+        Some(BitmapSemiJoinTable {
+            min,
+            bitmap: bitmap.into(),
+        })
+    }
+
+    /// Push the indexes of the probe rows whose key is one of the build keys into
+    /// `matched`. NULL keys and keys outside the domain never match.
+    pub fn probe(&self, column: &Column, matched: &mut Vec<u32>) {
+        let (column, validity) = match column {
+            Column::Nullable(column) => (&column.column, Some(&column.validity)),
+            column => (column, None),
+        };
+        let domain = self.bitmap.len() as i128;
+        for_each_integer(column, |row, key| {
+            let offset = key - self.min;
+            if offset >= 0
+                && offset < domain
+                && validity.is_none_or(|validity| validity.get_bit(row))
+                && self.bitmap.get_bit(offset as usize)
+            {
+                matched.push(row as u32);
+            }
+        });
+    }
+}
+
+// Call `f` with the row index and the value of each row of a not null integer column,
+// returns false if the column is of another type.
+fn for_each_integer(column: &Column, mut f: impl FnMut(usize, i128)) -> bool {
+    match column {
+        Column::Number(column) => with_integer_mapped_type!(|NUM_TYPE| match column {
+            NumberColumn::NUM_TYPE(values) => {
+                for (row, value) in values.iter().enumerate() {
+                    f(row, *value as i128);
+                }
+                true
+            }
+            _ => false,
+        }),
+        Column::Nullable(column) if column.validity.null_count() == 0 => {
+            for_each_integer(&column.column, f)
+        }
+        _ => false,
+    }
+}
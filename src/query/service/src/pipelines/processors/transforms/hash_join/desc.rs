@@ -14,6 +14,7 @@
 
 use databend_common_exception::Result;
 use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
 use databend_common_expression::Expr;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -114,4 +115,17 @@ impl HashJoinDesc {
             other => other,
         }
     }
+
+    /// Whether the build keys can be stored as a bitmap instead of a hash table,
+    /// it requires a semi join on a single integer key without other conditions.
+    pub fn support_bitmap_semi_join(&self) -> bool {
+        self.join_type == JoinType::LeftSemi
+            && self.other_predicate.is_none()
+            && self.build_keys.len() == 1
+            && !self.is_null_equal[0]
+            && matches!(
+                self.build_keys[0].data_type().remove_nullable(),
+                DataType::Number(number_type) if number_type.is_integer()
+            )
+    }
 }
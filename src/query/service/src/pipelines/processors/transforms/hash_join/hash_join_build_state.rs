@@ -21,6 +21,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Barrier;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
@@ -65,6 +67,7 @@ use parking_lot::RwLock;
 use xorf::BinaryFuse16;
 
 use crate::pipelines::memory_settings::MemorySettingsExt;
+use crate::pipelines::processors::transforms::hash_join::bitmap_semi_join::BitmapSemiJoinTable;
 use crate::pipelines::processors::transforms::hash_join::common::wrap_true_validity;
 use crate::pipelines::processors::transforms::hash_join::desc::MARKER_KIND_FALSE;
 use crate::pipelines::processors::transforms::hash_join::transform_hash_join_build::HashTableType;
@@ -326,6 +329,11 @@ impl HashJoinBuildState {
                 self.set_bloom_filter_ready(false)?;
             }
 
+            // If the build keys are stored as a bitmap, the hash table is not needed.
+            if self.try_build_bitmap_semi_join(&build_chunks)? {
+                return Ok(());
+            }
+
             // Divide the finalize phase into multiple tasks.
             self.generate_finalize_task()?;
 
@@ -396,6 +404,41 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    // Try to store the build keys of a semi join on a dense integer key as a bitmap,
+    // returns false if the hash table should be built instead.
+    fn try_build_bitmap_semi_join(&self, build_chunks: &[DataBlock]) -> Result<bool> {
+        if !self.hash_join_state.enable_bitmap_semi_join
+            || self
+                .hash_join_state
+                .is_spill_happened
+                .load(Ordering::Acquire)
+        {
+            return Ok(false);
+        }
+
+        let build_key = &self.hash_join_state.hash_join_desc.build_keys[0];
+        let keys_columns = build_chunks
+            .iter()
+            .map(|chunk| {
+                let evaluator = Evaluator::new(chunk, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(evaluator
+                    .run(build_key)?
+                    .convert_to_full_column(build_key.data_type(), chunk.num_rows()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let Some(bitmap_semi_join) = BitmapSemiJoinTable::try_create(&keys_columns) else {
+            return Ok(false);
+        };
+        let num_keys = keys_columns
+            .iter()
+            .map(|column| column.len())
+            .sum::<usize>();
+        info!("build bitmap semi join with {num_keys} keys instead of hash table");
+        Profile::record_usize_profile(ProfileStatisticsName::BitmapSemiJoinBuildKeys, num_keys);
+        unsafe { *self.hash_join_state.bitmap_semi_join.get() = Some(bitmap_semi_join) };
+        Ok(true)
+    }
+
     /// Divide the finalize phase into multiple tasks.
     pub fn generate_finalize_task(&self) -> Result<()> {
         let task_num = unsafe { &*self.hash_join_state.build_state.get() }
@@ -646,6 +689,7 @@ impl HashJoinBuildState {
             let ty = expr.data_type();
             ty.is_nullable() || ty.is_null()
         });
+        let mut no_valid_keys = false;
         let valids = if !may_null {
             None
         } else {
@@ -663,9 +707,9 @@ impl HashJoinBuildState {
                 });
             match valids {
                 ControlFlow::Continue(Some(valids)) | ControlFlow::Break(Some(valids)) => {
-                    if valids.null_count() == valids.len() {
-                        return Ok(());
-                    }
+                    // A NULL in any key column prevents the row from matching, if no row
+                    // can match, only the markers of the chunk are initialized below.
+                    no_valid_keys = valids.null_count() == valids.len();
                     if valids.null_count() != 0 {
                         Some(valids)
                     } else {
@@ -704,6 +748,10 @@ impl HashJoinBuildState {
             _ => {}
         };
 
+        if no_valid_keys {
+            return Ok(());
+        }
+
         keys_columns
             .iter_mut()
             .zip(is_null_equal.iter().copied())
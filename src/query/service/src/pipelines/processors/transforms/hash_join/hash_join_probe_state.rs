@@ -247,6 +247,20 @@ impl HashJoinProbeState {
             None
         };
 
+        // The build keys are stored as a bitmap, only the membership of the probe keys is tested.
+        let bitmap_semi_join = unsafe { &*self.hash_join_state.bitmap_semi_join.get() };
+        if let Some(bitmap_semi_join) = bitmap_semi_join {
+            input = input.project(&self.probe_projections);
+            probe_state.generation_state.is_probe_projected = input.num_columns() > 0;
+
+            let mut matched = Vec::with_capacity(input_num_rows);
+            bitmap_semi_join.probe(&keys_columns[0], &mut matched);
+            if matched.is_empty() {
+                return Ok(vec![]);
+            }
+            return Ok(vec![DataBlock::take(&input, &matched)?]);
+        }
+
         keys_columns
             .iter_mut()
             .zip(is_null_equal.iter().copied())
@@ -39,6 +39,7 @@ use databend_common_sql::ColumnSet;
 use ethnum::U256;
 use parking_lot::RwLock;
 
+use super::bitmap_semi_join::BitmapSemiJoinTable;
 use super::merge_into_hash_join_optimization::MergeIntoState;
 use crate::pipelines::processors::transforms::hash_join::build_state::BuildState;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
@@ -129,6 +130,11 @@ pub struct HashJoinState {
     pub(crate) column_map: HashMap<usize, usize>,
     // The index of the next cache block to be read.
     pub(crate) next_cache_block_index: AtomicUsize,
+
+    /// Whether to try storing the build keys as a bitmap, see `BitmapSemiJoinTable`.
+    pub(crate) enable_bitmap_semi_join: bool,
+    /// The bitmap of the build keys, if it's built the hash table is not built.
+    pub(crate) bitmap_semi_join: SyncUnsafeCell<Option<BitmapSemiJoinTable>>,
 }
 
 impl HashJoinState {
@@ -155,6 +161,11 @@ impl HashJoinState {
         let spill_partition_bits = settings.get_join_spilling_partition_bits()?;
         let spill_buffer_threshold = settings.get_join_spilling_buffer_threshold_per_proc()?;
 
+        let enable_bitmap_semi_join = settings.get_enable_bitmap_semi_join()?
+            && !enable_merge_into_optimization
+            && build_side_cache_info.is_none()
+            && hash_join_desc.support_bitmap_semi_join();
+
         let column_map = if let Some((_, column_map)) = build_side_cache_info {
             column_map
         } else {
@@ -186,6 +197,8 @@ impl HashJoinState {
             },
             column_map,
             next_cache_block_index: AtomicUsize::new(0),
+            enable_bitmap_semi_join,
+            bitmap_semi_join: SyncUnsafeCell::new(None),
         }))
     }
 
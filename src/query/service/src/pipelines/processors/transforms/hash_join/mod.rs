@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bitmap_semi_join;
 mod build_state;
 mod common;
 mod desc;
@@ -23,17 +23,21 @@ mod transform_add_stream_columns;
 mod transform_async_function;
 mod transform_cache_scan;
 mod transform_cast_schema;
+mod transform_check_constraints;
+mod transform_checksum;
 mod transform_create_sets;
 mod transform_dictionary;
 mod transform_expression_scan;
 mod transform_filter;
 mod transform_limit;
+mod transform_match_recognize;
 mod transform_merge_block;
 mod transform_null_if;
 mod transform_recursive_cte_scan;
 mod transform_recursive_cte_source;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
+mod transform_sorted_first_rows;
 mod transform_srf;
 mod transform_stream_sort_spill;
 mod transform_udf_script;
@@ -50,16 +54,23 @@ pub use transform_cache_scan::CacheSourceState;
 pub use transform_cache_scan::HashJoinCacheState;
 pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_check_constraints::TransformCheckConstraints;
+pub use transform_checksum::BlockChecksumMeta;
+pub use transform_checksum::TransformBlockChecksum;
+pub use transform_checksum::TransformMergeChecksum;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
 pub use transform_limit::TransformLimit;
+pub use transform_match_recognize::MatchMeasureInfo;
+pub use transform_match_recognize::TransformMatchRecognize;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_null_if::TransformNullIf;
 pub use transform_recursive_cte_scan::TransformRecursiveCteScan;
 pub use transform_recursive_cte_source::TransformRecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
+pub use transform_sorted_first_rows::TransformSortedFirstRows;
 pub use transform_srf::TransformSRF;
 pub use transform_stream_sort_spill::*;
 pub use transform_udf_script::TransformUdfScript;
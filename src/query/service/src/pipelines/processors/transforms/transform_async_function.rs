@@ -13,30 +13,72 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_cache::MemSized;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_sql::planner::query_executor::QueryExecutor;
 use databend_common_storages_fuse::TableContext;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
 
+use crate::interpreters::Accessor;
 use crate::pipelines::processors::transforms::transform_dictionary::DictionaryOperator;
+use crate::schedulers::ServiceQueryExecutor;
 use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::AsyncFunctionDesc;
+use crate::sql::executor::PhysicalPlanBuilder;
+use crate::sql::executor::QueryCost;
 use crate::sql::plans::AsyncFunctionArgument;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+
+// The max number of the cached results of a correlated scalar subquery, the outer
+// values are usually repeated in the nearby rows.
+const MAX_SCALAR_SUBQUERY_RESULTS: usize = 1024;
+
+struct CachedScalar(Scalar);
+
+impl MemSized for CachedScalar {
+    fn mem_bytes(&self) -> usize {
+        0
+    }
+}
 
 pub struct TransformAsyncFunction {
     ctx: Arc<QueryContext>,
     // key is the index of async_func_desc
     pub(crate) operators: BTreeMap<usize, Arc<DictionaryOperator>>,
     async_func_descs: Vec<AsyncFunctionDesc>,
+    // key is the index of async_func_desc, value is the checksum of the table
+    checksums: BTreeMap<usize, Scalar>,
+    // key is the index of async_func_desc, value is the recent results of the correlated
+    // scalar subquery by its SQL with the outer values filled
+    scalar_subquery_results: BTreeMap<usize, LruCache<String, CachedScalar>>,
 }
 
 impl TransformAsyncFunction {
@@ -49,6 +91,8 @@ impl TransformAsyncFunction {
             ctx,
             async_func_descs,
             operators,
+            checksums: BTreeMap::new(),
+            scalar_subquery_results: BTreeMap::new(),
         }
     }
 
@@ -81,6 +125,215 @@ impl TransformAsyncFunction {
 
         Ok(())
     }
+
+    // transform add correlated scalar subquery column, the subquery is executed once
+    // for each distinct combination of the outer values across the blocks.
+    async fn transform_scalar_subquery(
+        &self,
+        data_block: &mut DataBlock,
+        sql: &str,
+        arg_indices: &[usize],
+        data_type: &DataType,
+        results: &mut LruCache<String, CachedScalar>,
+    ) -> Result<()> {
+        let dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let (stmt, _) = parse_sql(&tokenize_sql(sql)?, dialect)?;
+
+        let num_rows = data_block.num_rows();
+        let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+        for row in 0..num_rows {
+            let mut values = Vec::with_capacity(arg_indices.len());
+            for index in arg_indices {
+                let entry = data_block.get_by_offset(*index);
+                let value = entry.value.index(row).unwrap();
+                values.push(scalar_to_ast(value, &entry.data_type, dialect)?);
+            }
+            let sql = fill_outer_values(&stmt, values)?.to_string();
+
+            let value = match results.get(&sql) {
+                Some(value) => value.0.clone(),
+                None => {
+                    let value = self.execute_scalar_subquery(&sql).await?;
+                    results.insert(sql, CachedScalar(value.clone()));
+                    value
+                }
+            };
+            builder.push(value.as_ref());
+        }
+
+        let entry = BlockEntry {
+            data_type: data_type.clone(),
+            value: Value::Column(builder.build()),
+        };
+        data_block.add_column(entry);
+
+        Ok(())
+    }
+
+    async fn execute_scalar_subquery(&self, sql: &str) -> Result<Scalar> {
+        let executor = ServiceQueryExecutor::new(QueryContext::create_from(self.ctx.as_ref()));
+        let blocks = executor.execute_query_with_sql_string(sql).await?;
+
+        let mut value = None;
+        for block in blocks.iter() {
+            if block.num_rows() == 0 {
+                continue;
+            }
+            if value.is_some() || block.num_rows() > 1 {
+                return Err(ErrorCode::Internal(
+                    "Scalar subquery can't return more than one row",
+                ));
+            }
+            value = Some(block.get_by_offset(0).value.index(0).unwrap().to_owned());
+        }
+        // A scalar subquery returns NULL if it returns no rows.
+        Ok(value.unwrap_or(Scalar::Null))
+    }
+
+    // transform add the estimated cost column of the query texts, the same query
+    // is planned only once.
+    async fn transform_estimate_query_cost(
+        &self,
+        data_block: &mut DataBlock,
+        arg_indices: &[usize],
+        data_type: &DataType,
+    ) -> Result<()> {
+        let num_rows = data_block.num_rows();
+        let entry = data_block.get_by_offset(arg_indices[0]).clone();
+        let mut results: HashMap<String, Scalar> = HashMap::new();
+        let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+        for row in 0..num_rows {
+            let value = match entry.value.index(row).unwrap() {
+                ScalarRef::String(sql) => match results.get(sql) {
+                    Some(value) => value.clone(),
+                    None => {
+                        let value = self.estimate_query_cost(sql).await?;
+                        results.insert(sql.to_string(), value.clone());
+                        value
+                    }
+                },
+                _ => Scalar::Null,
+            };
+            builder.push(value.as_ref());
+        }
+
+        data_block.add_column(BlockEntry {
+            data_type: data_type.clone(),
+            value: Value::Column(builder.build()),
+        });
+
+        Ok(())
+    }
+
+    async fn estimate_query_cost(&self, sql: &str) -> Result<Scalar> {
+        let ctx = QueryContext::create_from(self.ctx.as_ref());
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        // The estimation reads the statistics of the tables, so it requires
+        // the same privileges as running the query.
+        Accessor::create(ctx.clone()).check(&plan).await?;
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = plan
+        else {
+            return Err(ErrorCode::BadArguments(format!(
+                "system$estimate_query_cost only supports queries, but got: {}",
+                sql
+            )));
+        };
+
+        let mut builder = PhysicalPlanBuilder::new(metadata, ctx.clone(), true);
+        let physical_plan = builder.build(&s_expr, bind_context.column_set()).await?;
+        let cost = QueryCost::estimate(ctx.as_ref(), &physical_plan)?;
+        Ok(Scalar::Variant(cost.to_jsonb_value().to_vec()))
+    }
+}
+
+fn scalar_to_ast(value: ScalarRef, data_type: &DataType, dialect: Dialect) -> Result<Expr> {
+    let literal = match value {
+        ScalarRef::Null => {
+            return Ok(Expr::Literal {
+                span: None,
+                value: Literal::Null,
+            })
+        }
+        ScalarRef::String(s) => string_literal(s),
+        // The non-finite floats have no numeric literals, they're cast from strings.
+        ScalarRef::Number(NumberScalar::Float32(v)) if !v.is_finite() => {
+            string_literal(non_finite_float_name(v.0 as f64))
+        }
+        ScalarRef::Number(NumberScalar::Float64(v)) if !v.is_finite() => {
+            string_literal(non_finite_float_name(v.0))
+        }
+        value => value.to_string(),
+    };
+    let sql = format!(
+        "CAST({literal} AS {})",
+        data_type.remove_nullable().sql_name()
+    );
+    parse_expr(&tokenize_sql(&sql)?, dialect)
+}
+
+fn string_literal(s: &str) -> String {
+    Expr::Literal {
+        span: None,
+        value: Literal::String(s.to_string()),
+    }
+    .to_string()
+}
+
+fn non_finite_float_name(v: f64) -> &'static str {
+    if v.is_nan() {
+        "nan"
+    } else if v > 0.0 {
+        "inf"
+    } else {
+        "-inf"
+    }
+}
+
+// Fill the holes `:outer_<n>` of the subquery with the n-th outer value.
+fn fill_outer_values(stmt: &Statement, values: Vec<Expr>) -> Result<Statement> {
+    #[derive(VisitorMut)]
+    #[visitor(Expr(enter))]
+    struct OuterValueVisitor {
+        values: Vec<Expr>,
+        error: Option<ErrorCode>,
+    }
+
+    impl OuterValueVisitor {
+        fn enter_expr(&mut self, expr: &mut Expr) {
+            if let Expr::Hole { span, name } = expr {
+                let value = name
+                    .strip_prefix("outer_")
+                    .and_then(|position| position.parse::<usize>().ok())
+                    .and_then(|position| self.values.get(position));
+                match value {
+                    Some(value) => *expr = value.clone(),
+                    None => {
+                        self.error = Some(
+                            ErrorCode::Internal(format!("Unknown outer value :{name}"))
+                                .set_span(*span),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    let mut stmt = stmt.clone();
+    let mut visitor = OuterValueVisitor {
+        values,
+        error: None,
+    };
+    stmt.drive_mut(&mut visitor);
+    match visitor.error {
+        Some(e) => Err(e),
+        None => Ok(stmt),
+    }
 }
 
 #[async_trait::async_trait]
@@ -109,6 +362,40 @@ impl AsyncTransform for TransformAsyncFunction {
                     )
                     .await?;
                 }
+                AsyncFunctionArgument::ScalarSubquery(sql) => {
+                    let mut results =
+                        self.scalar_subquery_results.remove(&i).unwrap_or_else(|| {
+                            LruCache::with_items_capacity(MAX_SCALAR_SUBQUERY_RESULTS)
+                        });
+                    self.transform_scalar_subquery(
+                        &mut data_block,
+                        sql,
+                        &async_func_desc.arg_indices,
+                        &async_func_desc.data_type,
+                        &mut results,
+                    )
+                    .await?;
+                    self.scalar_subquery_results.insert(i, results);
+                }
+                AsyncFunctionArgument::TableChecksum(sql) => {
+                    // The checksum is computed once and shared by all the rows.
+                    if !self.checksums.contains_key(&i) {
+                        let value = self.execute_scalar_subquery(sql).await?;
+                        self.checksums.insert(i, value);
+                    }
+                    data_block.add_column(BlockEntry {
+                        data_type: *async_func_desc.data_type.clone(),
+                        value: Value::Scalar(self.checksums[&i].clone()),
+                    });
+                }
+                AsyncFunctionArgument::EstimateQueryCost => {
+                    self.transform_estimate_query_cost(
+                        &mut data_block,
+                        &async_func_desc.arg_indices,
+                        &async_func_desc.data_type,
+                    )
+                    .await?;
+                }
             }
         }
         Ok(data_block)
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::parse_computed_expr;
+
+use crate::sessions::QueryContext;
+
+struct CheckConstraint {
+    name: String,
+    sql: String,
+    expr: Expr,
+}
+
+/// Rejects the blocks having a row that violates a check constraint of the table.
+///
+/// A row satisfies the constraint if the expression is true or NULL.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    constraints: Vec<CheckConstraint>,
+}
+
+impl TransformCheckConstraints
+where Self: Transform
+{
+    pub fn try_new(
+        ctx: Arc<QueryContext>,
+        input_schema: DataSchemaRef,
+        table: &dyn Table,
+    ) -> Result<Self> {
+        let mut constraints = Vec::new();
+        for (name, sql) in Self::check_constraints(table) {
+            let expr = parse_computed_expr(ctx.clone(), input_schema.clone(), &sql)?;
+            constraints.push(CheckConstraint { name, sql, expr });
+        }
+
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            constraints,
+        })
+    }
+
+    /// The names and the expressions of the check constraints of the table.
+    pub fn check_constraints(table: &dyn Table) -> Vec<(String, String)> {
+        table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .filter_map(|constraint| {
+                constraint
+                    .check_expr
+                    .as_ref()
+                    .map(|check_expr| (constraint.name.clone(), check_expr.clone()))
+            })
+            .collect()
+    }
+
+    // Whether any row is evaluated to false.
+    fn has_violated_row(column: &Column) -> bool {
+        match column {
+            Column::Boolean(bitmap) => bitmap.null_count() > 0,
+            Column::Nullable(column) => match column.column.as_boolean() {
+                Some(bitmap) => bitmap
+                    .iter()
+                    .zip(column.validity.iter())
+                    .any(|(value, valid)| valid && !value),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for constraint in self.constraints.iter() {
+            let data_type = constraint.expr.data_type();
+            if data_type == &DataType::Null {
+                continue;
+            }
+            let column = evaluator
+                .run(&constraint.expr)?
+                .convert_to_full_column(data_type, num_rows);
+            if Self::has_violated_row(&column) {
+                return Err(ErrorCode::CheckConstraintViolated(format!(
+                    "new row violates check constraint `{}` ({})",
+                    constraint.name, constraint.sql
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
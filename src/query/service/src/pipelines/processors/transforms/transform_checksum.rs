@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_storages_fuse::io::block_checksum;
+
+/// The checksum of the rows of a single block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BlockChecksumMeta {
+    pub checksum: u64,
+}
+
+impl BlockChecksumMeta {
+    pub fn create(checksum: u64) -> BlockMetaInfoPtr {
+        Box::new(BlockChecksumMeta { checksum })
+    }
+}
+
+#[typetag::serde(name = "block_checksum")]
+impl BlockMetaInfo for BlockChecksumMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        BlockChecksumMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Reduces each block to the XOR of the hashes of its rows like the checksums
+/// recorded in the `BlockMeta`s, the result is carried by a `BlockChecksumMeta`
+/// without any column.
+pub struct TransformBlockChecksum;
+
+impl Transform for TransformBlockChecksum {
+    const NAME: &'static str = "BlockChecksum";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let checksum = block_checksum(&data, data.num_columns());
+        Ok(DataBlock::empty_with_meta(BlockChecksumMeta::create(
+            checksum,
+        )))
+    }
+}
+
+/// XORs the checksums of all the blocks into a single row, the checksum of an
+/// empty input is 0.
+#[derive(Default)]
+pub struct TransformMergeChecksum {
+    checksum: u64,
+}
+
+impl AccumulatingTransform for TransformMergeChecksum {
+    const NAME: &'static str = "MergeChecksum";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let Some(meta) = data
+            .get_owned_meta()
+            .and_then(BlockChecksumMeta::downcast_from)
+        else {
+            return Err(ErrorCode::Internal(
+                "MergeChecksum expects blocks with BlockChecksumMeta",
+            ));
+        };
+        self.checksum ^= meta.checksum;
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        Ok(vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![self.checksum]),
+        ])])
+    }
+}
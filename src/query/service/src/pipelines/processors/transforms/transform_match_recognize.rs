@@ -0,0 +1,407 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_sql::plans::MatchMeasureKind;
+use databend_common_sql::plans::MatchPattern;
+
+/// A measure of `MATCH_RECOGNIZE`, the columns of `kind` are offsets of the input block.
+#[derive(Clone, Debug)]
+pub struct MatchMeasureInfo {
+    pub kind: MatchMeasureKind,
+    pub data_type: DataType,
+}
+
+/// Row pattern matching over the rows sorted by the partition keys and the order keys.
+///
+/// The rows of a partition are buffered until the first row of the next partition
+/// arrives, then the matches of the partition are searched from its first row. Each
+/// match produces one row, and the search resumes after the last row of the match.
+pub struct TransformMatchRecognize {
+    func_ctx: FunctionContext,
+    partition_by: Vec<usize>,
+    prev_columns: Vec<usize>,
+    defines: Vec<Option<Expr>>,
+    pattern: MatchPattern,
+    measures: Vec<MatchMeasureInfo>,
+
+    // The rows whose partitions may continue in the next block.
+    pending: Vec<DataBlock>,
+}
+
+impl TransformMatchRecognize {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        partition_by: Vec<usize>,
+        prev_columns: Vec<usize>,
+        defines: Vec<Option<Expr>>,
+        pattern: MatchPattern,
+        measures: Vec<MatchMeasureInfo>,
+    ) -> Box<dyn Processor> {
+        AccumulatingTransformer::create(input, output, TransformMatchRecognize {
+            func_ctx,
+            partition_by,
+            prev_columns,
+            defines,
+            pattern,
+            measures,
+            pending: vec![],
+        })
+    }
+
+    fn is_same_partition(&self, block: &DataBlock, left: usize, right: usize) -> bool {
+        self.partition_by.iter().all(|offset| {
+            let value = &block.get_by_offset(*offset).value;
+            value.index(left) == value.index(right)
+        })
+    }
+
+    fn process(&self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let block = block.consume_convert_to_full();
+        let num_rows = block.num_rows();
+
+        let mut partitions = vec![];
+        let mut start = 0;
+        for row in 1..num_rows {
+            if !self.is_same_partition(&block, row - 1, row) {
+                partitions.push(start..row);
+                start = row;
+            }
+        }
+        partitions.push(start..num_rows);
+
+        // `PREV(column)` is the value of the column in the previous row of the partition.
+        let mut define_block = block.clone();
+        for offset in self.prev_columns.iter() {
+            let entry = block.get_by_offset(*offset);
+            let data_type = entry.data_type.wrap_nullable();
+            let mut builder = ColumnBuilder::with_capacity(&data_type, num_rows);
+            for partition in partitions.iter() {
+                builder.push_default();
+                for row in partition.start + 1..partition.end {
+                    builder.push(entry.value.index(row - 1).unwrap());
+                }
+            }
+            define_block.add_column(BlockEntry::new(data_type, Value::Column(builder.build())));
+        }
+
+        let evaluator = Evaluator::new(&define_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let defines = self
+            .defines
+            .iter()
+            .map(|define| {
+                define
+                    .as_ref()
+                    .map(|expr| {
+                        Ok(evaluator
+                            .run(expr)?
+                            .convert_to_full_column(&DataType::Boolean, num_rows)
+                            .as_boolean()
+                            .cloned()
+                            .unwrap())
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let is_variable = |row: usize, variable: usize| {
+            defines[variable]
+                .as_ref()
+                .is_none_or(|define| define.get_bit(row))
+        };
+
+        let mut builders = self
+            .partition_by
+            .iter()
+            .map(|offset| ColumnBuilder::with_capacity(&block.get_by_offset(*offset).data_type, 0))
+            .chain(
+                self.measures
+                    .iter()
+                    .map(|measure| ColumnBuilder::with_capacity(&measure.data_type, 0)),
+            )
+            .collect::<Vec<_>>();
+        let mut num_matches = 0;
+
+        for partition in partitions {
+            let mut match_number = 0;
+            let mut row = partition.start;
+            while row < partition.end {
+                if self.pattern.start_anchor && row != partition.start {
+                    break;
+                }
+                // Empty matches are skipped.
+                let Some(variables) = find_match(&self.pattern, row, partition.end, &is_variable)
+                    .filter(|variables| !variables.is_empty())
+                else {
+                    row += 1;
+                    continue;
+                };
+
+                match_number += 1;
+                num_matches += 1;
+                for (builder, offset) in builders.iter_mut().zip(self.partition_by.iter()) {
+                    let value = &block.get_by_offset(*offset).value;
+                    builder.push(value.index(row).unwrap());
+                }
+                for (builder, measure) in builders[self.partition_by.len()..]
+                    .iter_mut()
+                    .zip(self.measures.iter())
+                {
+                    let value =
+                        self.compute_measure(&block, &measure.kind, row, &variables, match_number);
+                    builder.push(value);
+                }
+                row += variables.len();
+            }
+        }
+
+        if num_matches == 0 {
+            return Ok(vec![]);
+        }
+        let columns = builders
+            .into_iter()
+            .map(|builder| {
+                let column = builder.build();
+                BlockEntry::new(column.data_type(), Value::Column(column))
+            })
+            .collect();
+        Ok(vec![DataBlock::new(columns, num_matches)])
+    }
+
+    fn compute_measure<'a>(
+        &self,
+        block: &'a DataBlock,
+        kind: &MatchMeasureKind,
+        start: usize,
+        variables: &[usize],
+        match_number: u64,
+    ) -> ScalarRef<'a> {
+        // The rows of the match which are mapped to `variable`.
+        let rows = |variable: &Option<usize>| {
+            let variable = *variable;
+            variables
+                .iter()
+                .enumerate()
+                .filter(move |(_, v)| variable.is_none_or(|variable| **v == variable))
+                .map(move |(i, _)| start + i)
+        };
+        let value_at = |column: usize, row: Option<usize>| match row {
+            Some(row) => block.get_by_offset(column).value.index(row).unwrap(),
+            None => ScalarRef::Null,
+        };
+        match kind {
+            MatchMeasureKind::First { variable, column } => {
+                value_at(*column, rows(variable).next())
+            }
+            MatchMeasureKind::Last { variable, column } => value_at(*column, rows(variable).last()),
+            MatchMeasureKind::Count { variable, column } => {
+                let count = rows(variable)
+                    .filter(|row| {
+                        column.is_none_or(|column| value_at(column, Some(*row)) != ScalarRef::Null)
+                    })
+                    .count();
+                ScalarRef::Number(NumberScalar::UInt64(count as u64))
+            }
+            MatchMeasureKind::MatchNumber => ScalarRef::Number(NumberScalar::UInt64(match_number)),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformMatchRecognize {
+    const NAME: &'static str = "TransformMatchRecognize";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(vec![]);
+        }
+        if self.partition_by.is_empty() {
+            self.pending.push(data);
+            return Ok(vec![]);
+        }
+
+        // Find the first row of the last partition in the block, all the rows before
+        // it belong to the partitions which are complete.
+        let data = data.consume_convert_to_full();
+        let mut start = num_rows - 1;
+        while start > 0 && self.is_same_partition(&data, start - 1, num_rows - 1) {
+            start -= 1;
+        }
+        if start == 0 {
+            self.pending.push(data);
+            return Ok(vec![]);
+        }
+
+        let mut blocks = std::mem::take(&mut self.pending);
+        blocks.push(data.slice(0..start));
+        self.pending.push(data.slice(start..num_rows));
+        self.process(DataBlock::concat(&blocks)?)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.pending.is_empty() {
+            return Ok(vec![]);
+        }
+        let blocks = std::mem::take(&mut self.pending);
+        self.process(DataBlock::concat(&blocks)?)
+    }
+}
+
+/// Find the match of `pattern` which starts at `start`, the rows of the partition end
+/// at `end`. Returns the variable each row of the match is mapped to.
+///
+/// Quantifiers are greedy, the longest match is preferred and shorter ones are tried
+/// by backtracking when the rest of the pattern fails.
+pub fn find_match(
+    pattern: &MatchPattern,
+    start: usize,
+    end: usize,
+    is_variable: &impl Fn(usize, usize) -> bool,
+) -> Option<Vec<usize>> {
+    fn match_terms(
+        pattern: &MatchPattern,
+        term: usize,
+        row: usize,
+        end: usize,
+        is_variable: &impl Fn(usize, usize) -> bool,
+        variables: &mut Vec<usize>,
+    ) -> bool {
+        let Some(current) = pattern.terms.get(term) else {
+            return !pattern.end_anchor || row == end;
+        };
+        let max = current.max.unwrap_or(usize::MAX);
+        let mut count = 0;
+        while count < max && row + count < end && is_variable(row + count, current.variable) {
+            count += 1;
+        }
+        if count < current.min {
+            return false;
+        }
+
+        let len = variables.len();
+        variables.extend(std::iter::repeat_n(current.variable, count));
+        loop {
+            if match_terms(pattern, term + 1, row + count, end, is_variable, variables) {
+                return true;
+            }
+            if count == current.min {
+                variables.truncate(len);
+                return false;
+            }
+            count -= 1;
+            variables.pop();
+        }
+    }
+
+    let mut variables = vec![];
+    match_terms(pattern, 0, start, end, is_variable, &mut variables).then_some(variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_sql::plans::MatchPattern;
+    use databend_common_sql::plans::MatchPatternTerm;
+
+    use super::find_match;
+
+    const STRT: usize = 0;
+    const DOWN: usize = 1;
+    const UP: usize = 2;
+
+    fn v_shape() -> MatchPattern {
+        let term = |variable, min, max| MatchPatternTerm { variable, min, max };
+        MatchPattern {
+            start_anchor: false,
+            end_anchor: false,
+            terms: vec![
+                term(STRT, 1, Some(1)),
+                term(DOWN, 1, None),
+                term(UP, 1, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_find_match() {
+        let prices = [10, 8, 6, 7, 9, 5, 4, 6];
+        let is_variable = |row: usize, variable: usize| match variable {
+            DOWN => row > 0 && prices[row] < prices[row - 1],
+            UP => row > 0 && prices[row] > prices[row - 1],
+            _ => true,
+        };
+        let pattern = v_shape();
+
+        assert_eq!(
+            find_match(&pattern, 0, prices.len(), &is_variable),
+            Some(vec![STRT, DOWN, DOWN, UP, UP])
+        );
+        assert_eq!(
+            find_match(&pattern, 4, prices.len(), &is_variable),
+            Some(vec![STRT, DOWN, DOWN, UP])
+        );
+        assert_eq!(find_match(&pattern, 6, prices.len(), &is_variable), None);
+
+        let mut anchored = pattern.clone();
+        anchored.end_anchor = true;
+        assert_eq!(find_match(&anchored, 0, prices.len(), &is_variable), None);
+        assert_eq!(
+            find_match(&anchored, 4, prices.len(), &is_variable),
+            Some(vec![STRT, DOWN, DOWN, UP])
+        );
+    }
+
+    #[test]
+    fn test_find_match_backtracking() {
+        // `A* A`: the greedy `A*` gives back the last row.
+        let pattern = MatchPattern {
+            start_anchor: false,
+            end_anchor: false,
+            terms: vec![
+                MatchPatternTerm {
+                    variable: 0,
+                    min: 0,
+                    max: None,
+                },
+                MatchPatternTerm {
+                    variable: 0,
+                    min: 1,
+                    max: Some(1),
+                },
+            ],
+        };
+        let is_variable = |_: usize, _: usize| true;
+        assert_eq!(find_match(&pattern, 0, 3, &is_variable), Some(vec![0; 3]));
+        assert_eq!(find_match(&pattern, 3, 3, &is_variable), None);
+    }
+}
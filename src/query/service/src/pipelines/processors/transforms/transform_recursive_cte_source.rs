@@ -240,18 +240,30 @@ async fn create_memory_table_for_cte_scan(
         PhysicalPlan::AggregateFinal(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::AggregateSorted(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::Window(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::MatchRecognize(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::WindowPartition(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::SortedFirstRows(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::Sort(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
         PhysicalPlan::Limit(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::Checksum(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::RowFetch(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
@@ -306,6 +318,7 @@ async fn create_memory_table_for_cte_scan(
                 cluster_key: None,
                 as_select: None,
                 inverted_indexes: None,
+                constraints: Default::default(),
                 attached_columns: None,
             };
             let create_table_interpreter =
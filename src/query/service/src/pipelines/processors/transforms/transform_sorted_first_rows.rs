@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_column::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+
+/// Keeps the first row of each group of an input sorted on the group keys, and the rows
+/// of the group with the same `ties_by` keys as the first row.
+///
+/// The rows of a group are adjacent, so only the keys of the current group are kept.
+pub struct TransformSortedFirstRows {
+    group_by: Vec<usize>,
+    ties_by: Vec<usize>,
+    // The group keys of the current group, `None` if no row is seen yet.
+    current_key: Option<Vec<Scalar>>,
+    // The ties keys of the first row of the current group.
+    first_ties: Vec<Scalar>,
+}
+
+impl TransformSortedFirstRows {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        group_by: Vec<usize>,
+        ties_by: Vec<usize>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            TransformSortedFirstRows {
+                group_by,
+                ties_by,
+                current_key: None,
+                first_ties: vec![],
+            },
+        ))
+    }
+}
+
+fn columns_of(block: &DataBlock, offsets: &[usize]) -> Vec<Column> {
+    offsets
+        .iter()
+        .map(|offset| {
+            block
+                .get_by_offset(*offset)
+                .value
+                .as_column()
+                .unwrap()
+                .clone()
+        })
+        .collect()
+}
+
+fn is_same_key(key: &[Scalar], columns: &[Column], row: usize) -> bool {
+    key.iter()
+        .zip(columns)
+        .all(|(scalar, column)| column.index(row) == Some(scalar.as_ref()))
+}
+
+fn key_of(columns: &[Column], row: usize) -> Vec<Scalar> {
+    columns
+        .iter()
+        .map(|column| column.index(row).unwrap().to_owned())
+        .collect()
+}
+
+impl Transform for TransformSortedFirstRows {
+    const NAME: &'static str = "TransformSortedFirstRows";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let block = block.consume_convert_to_full();
+        let num_rows = block.num_rows();
+        let group_columns = columns_of(&block, &self.group_by);
+        let ties_columns = columns_of(&block, &self.ties_by);
+
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let same_group = self
+                .current_key
+                .as_ref()
+                .is_some_and(|key| is_same_key(key, &group_columns, row));
+            if !same_group {
+                self.current_key = Some(key_of(&group_columns, row));
+                self.first_ties = key_of(&ties_columns, row);
+                bitmap.push(true);
+                continue;
+            }
+            // Without ties keys, only the first row of the group is kept.
+            bitmap.push(
+                !self.ties_by.is_empty() && is_same_key(&self.first_ties, &ties_columns, row),
+            );
+        }
+
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
@@ -47,6 +47,8 @@ pub struct TransformSRF {
     projections: ColumnSet,
     func_ctx: FunctionContext,
     srf_exprs: Vec<Expr>,
+    /// Whether to output an input row without results once, with NULL results.
+    outer: bool,
     /// The output of each set-returning function for each input row.
     srf_results: Vec<VecDeque<(Value<AnyType>, usize)>>,
     /// The output number of rows for each input row.
@@ -61,6 +63,7 @@ impl TransformSRF {
         func_ctx: FunctionContext,
         projections: ColumnSet,
         srf_exprs: Vec<Expr>,
+        outer: bool,
         max_block_size: usize,
     ) -> Box<dyn Processor> {
         let srf_results = vec![VecDeque::new(); srf_exprs.len()];
@@ -69,6 +72,7 @@ impl TransformSRF {
             projections,
             func_ctx,
             srf_exprs,
+            outer,
             srf_results,
             num_rows: VecDeque::new(),
             max_block_size,
@@ -99,6 +103,12 @@ impl BlockingTransform for TransformSRF {
             self.srf_results[i] = VecDeque::from(res);
         }
         debug_assert_eq!(max_nums_per_row.len(), input_num_rows);
+        if self.outer {
+            // The results of the rows without results are padded with NULLs.
+            for num_rows in max_nums_per_row.iter_mut() {
+                *num_rows = (*num_rows).max(1);
+            }
+        }
         debug_assert!(self.input.is_none());
 
         self.num_rows = VecDeque::from(max_nums_per_row);
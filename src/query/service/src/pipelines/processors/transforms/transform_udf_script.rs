@@ -26,11 +26,14 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Value;
 use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::physical_plans::UdfErrorPolicy;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 use databend_common_sql::plans::UDFLanguage;
 use databend_common_sql::plans::UDFScriptCode;
@@ -229,6 +232,7 @@ mod python_pool {
 pub struct TransformUdfScript {
     funcs: Vec<UdfFunctionDesc>,
     script_runtimes: BTreeMap<String, Arc<ScriptRuntime>>,
+    error_policy: UdfErrorPolicy,
 }
 
 impl TransformUdfScript {
@@ -236,10 +240,12 @@ impl TransformUdfScript {
         _func_ctx: FunctionContext,
         funcs: Vec<UdfFunctionDesc>,
         script_runtimes: BTreeMap<String, Arc<ScriptRuntime>>,
+        error_policy: UdfErrorPolicy,
     ) -> Self {
         Self {
             funcs,
             script_runtimes,
+            error_policy,
         }
     }
 }
@@ -255,12 +261,23 @@ impl Transform for TransformUdfScript {
         }
 
         for func in &self.funcs {
-            let num_rows = data_block.num_rows();
-            let block_entries = self.prepare_block_entries(func, &data_block)?;
-            let input_batch = self.create_input_batch(block_entries, num_rows)?;
-            let runtime = self.script_runtimes.get(&func.name).unwrap();
-            let result_batch = runtime.handle_execution(func, &input_batch)?;
-            self.update_datablock(func, result_batch, &mut data_block)?;
+            let col = match self.execute_func(func, &data_block) {
+                Ok(col) => col,
+                // NULL can only be returned if the UDF returns a nullable type.
+                Err(err)
+                    if self.error_policy == UdfErrorPolicy::Null
+                        && func.data_type.is_nullable() =>
+                {
+                    log::warn!(
+                        "UDF {:?} failed on a block of {} rows, retry it row by row: {err}",
+                        func.name,
+                        data_block.num_rows()
+                    );
+                    self.execute_func_by_row(func, &data_block)?
+                }
+                Err(err) => return Err(err),
+            };
+            data_block.add_column(col);
         }
         Ok(data_block)
     }
@@ -290,6 +307,41 @@ impl TransformUdfScript {
         Ok(script_runtimes)
     }
 
+    fn execute_func(&self, func: &UdfFunctionDesc, data_block: &DataBlock) -> Result<BlockEntry> {
+        let num_rows = data_block.num_rows();
+        let block_entries = self.prepare_block_entries(func, data_block)?;
+        let input_batch = self.create_input_batch(block_entries, num_rows)?;
+        let runtime = self.script_runtimes.get(&func.name).unwrap();
+        let result_batch = runtime.handle_execution(func, &input_batch)?;
+        self.result_column(func, result_batch, num_rows)
+    }
+
+    /// Execute the UDF on each row separately, the rows which fail get NULL.
+    fn execute_func_by_row(
+        &self,
+        func: &UdfFunctionDesc,
+        data_block: &DataBlock,
+    ) -> Result<BlockEntry> {
+        let num_rows = data_block.num_rows();
+        let mut builder = ColumnBuilder::with_capacity(&func.data_type, num_rows);
+        for row in 0..num_rows {
+            match self.execute_func(func, &data_block.slice(row..row + 1)) {
+                Ok(col) => builder.push(col.value.index(0).unwrap()),
+                Err(err) => {
+                    log::warn!(
+                        "UDF {:?} failed on row {row}, return NULL: {err}",
+                        func.name
+                    );
+                    builder.push_default();
+                }
+            }
+        }
+        Ok(BlockEntry::new(
+            func.data_type.as_ref().clone(),
+            Value::Column(builder.build()),
+        ))
+    }
+
     fn prepare_block_entries(
         &self,
         func: &UdfFunctionDesc,
@@ -340,12 +392,12 @@ impl TransformUdfScript {
         Ok(input_batch)
     }
 
-    fn update_datablock(
+    fn result_column(
         &self,
         func: &UdfFunctionDesc,
         result_batch: RecordBatch,
-        data_block: &mut DataBlock,
-    ) -> Result<()> {
+        num_rows: usize,
+    ) -> Result<BlockEntry> {
         // Convert to DataBlock and add column to data_block
         let schema = DataSchema::try_from(&(*result_batch.schema())).map_err(|err| {
             ErrorCode::UDFDataError(format!(
@@ -384,7 +436,14 @@ impl TransformUdfScript {
                 func.name, col.data_type, func.data_type
             )));
         }
-        data_block.add_column(col);
-        Ok(())
+        if result_block.num_rows() != num_rows {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Function {:?} should return {} rows, but it returned {} rows",
+                func.name,
+                num_rows,
+                result_block.num_rows()
+            )));
+        }
+        Ok(col)
     }
 }
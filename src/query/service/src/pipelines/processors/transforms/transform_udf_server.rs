@@ -29,9 +29,11 @@ use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
 use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_expression::Value;
 use databend_common_metrics::external_server::record_connect_external_duration;
 use databend_common_metrics::external_server::record_error_external;
 use databend_common_metrics::external_server::record_request_external_batch_rows;
@@ -40,6 +42,7 @@ use databend_common_metrics::external_server::record_retry_external;
 use databend_common_metrics::external_server::record_running_requests_external_finish;
 use databend_common_metrics::external_server::record_running_requests_external_start;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_sql::executor::physical_plans::UdfErrorPolicy;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 use tokio::sync::Semaphore;
 use tonic::transport::Endpoint;
@@ -58,6 +61,7 @@ pub struct TransformUdfServer {
     // key is the server address of udf, value is the endpoint.
     endpoints: BTreeMap<String, Arc<Endpoint>>,
     retry_times: usize,
+    error_policy: UdfErrorPolicy,
 }
 
 impl TransformUdfServer {
@@ -93,6 +97,7 @@ impl TransformUdfServer {
         funcs: Vec<UdfFunctionDesc>,
         semaphore: Arc<Semaphore>,
         endpoints: BTreeMap<String, Arc<Endpoint>>,
+        error_policy: UdfErrorPolicy,
     ) -> Result<Self> {
         let settings = ctx.get_settings();
         let connect_timeout = settings.get_external_server_connect_timeout_secs()?;
@@ -107,6 +112,7 @@ impl TransformUdfServer {
             semaphore,
             endpoints,
             retry_times,
+            error_policy,
         })
     }
 
@@ -221,6 +227,49 @@ impl TransformUdfServer {
         drop(permit);
         Ok(data_block)
     }
+
+    /// Send the rows of a failed batch to the server one by one, the rows which fail get NULL.
+    ///
+    /// This also covers the server which returns fewer rows than requested for a batch.
+    async fn transform_by_row(
+        &self,
+        func: &UdfFunctionDesc,
+        endpoint: &Arc<Endpoint>,
+        mut data_block: DataBlock,
+    ) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut builder = ColumnBuilder::with_capacity(&func.data_type, num_rows);
+        for row in 0..num_rows {
+            let result = Self::transform_inner(
+                self.ctx.clone(),
+                endpoint.clone(),
+                self.semaphore.clone(),
+                self.connect_timeout,
+                func.clone(),
+                data_block.slice(row..row + 1),
+            )
+            .await;
+            match result {
+                Ok(block) => {
+                    let col = block.get_by_offset(block.num_columns() - 1);
+                    builder.push(col.value.index(0).unwrap());
+                }
+                Err(err) => {
+                    record_error_external(func.name.clone(), error_kind(&err.message()));
+                    log::warn!(
+                        "UDF {:?} failed on row {row}, return NULL: {err}",
+                        func.name
+                    );
+                    builder.push_default();
+                }
+            }
+        }
+        data_block.add_column(BlockEntry::new(
+            func.data_type.as_ref().clone(),
+            Value::Column(builder.build()),
+        ));
+        Ok(data_block)
+    }
 }
 
 fn retry_on(err: &databend_common_exception::ErrorCode) -> bool {
@@ -250,7 +299,8 @@ impl AsyncTransform for TransformUdfServer {
             let server_addr = func.udf_type.as_server().unwrap();
             let endpoint = self.endpoints.get(server_addr).unwrap();
             let tasks: Vec<_> = batch_blocks
-                .into_iter()
+                .iter()
+                .cloned()
                 .map(|mini_batch| {
                     databend_common_base::runtime::spawn({
                         let ctx = self.ctx.clone();
@@ -291,17 +341,29 @@ impl AsyncTransform for TransformUdfServer {
                 .collect();
 
             let task_results = futures::future::join_all(tasks).await;
-            batch_blocks = task_results
-                .into_iter()
-                .map(|b| b.unwrap())
-                .map(|b| match b {
-                    Ok(b) => Ok(b),
+            let mut result_blocks = Vec::with_capacity(batch_blocks.len());
+            for (result, mini_batch) in task_results.into_iter().zip(batch_blocks) {
+                match result.unwrap() {
+                    Ok(block) => result_blocks.push(block),
                     Err(err) => {
                         record_error_external(func.name.clone(), error_kind(&err.message()));
-                        Err(err)
+                        // NULL can only be returned if the UDF returns a nullable type.
+                        if self.error_policy == UdfErrorPolicy::Abort
+                            || !func.data_type.is_nullable()
+                        {
+                            return Err(err);
+                        }
+                        log::warn!(
+                            "UDF {:?} failed on a batch of {} rows, retry it row by row: {err}",
+                            func.name,
+                            mini_batch.num_rows()
+                        );
+                        let block = self.transform_by_row(func, endpoint, mini_batch).await?;
+                        result_blocks.push(block);
                     }
-                })
-                .collect::<Result<Vec<_>>>()?;
+                }
+            }
+            batch_blocks = result_blocks;
         }
         data_block = DataBlock::concat(&batch_blocks)?;
         Ok(data_block)
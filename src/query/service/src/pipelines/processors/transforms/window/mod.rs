@@ -15,9 +15,11 @@
 mod frame_bound;
 mod partition;
 mod transform_window;
+mod window_batch_strategy;
 mod window_function;
 
 pub use frame_bound::FrameBound;
 pub use partition::*;
 pub use transform_window::*;
+pub use window_batch_strategy::WindowBatchStrategy;
 pub use window_function::WindowFunctionInfo;
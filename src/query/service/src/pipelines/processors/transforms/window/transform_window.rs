@@ -38,10 +38,14 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_sql::executor::physical_plans::LagLeadDefault;
+use databend_common_sql::plans::WindowFuncFrameExclusion;
 use databend_common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
+use super::window_batch_strategy::SlidingWindowAggregate;
+use super::window_batch_strategy::WindowBatchStrategy;
 use super::window_function::WindowFuncAggImpl;
+use super::window_function::WindowFuncNthValueImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
 
@@ -123,6 +127,13 @@ pub struct TransformWindow<T: Number> {
     rows_start_bound: usize,
     rows_end_bound: usize,
 
+    // The rows of the frame which are skipped when aggregating, relative to the current row.
+    exclusion: WindowFuncFrameExclusion,
+
+    // If set, the aggregate function is computed by sliding the frame instead of `func`,
+    // see `WindowBatchStrategy::Incremental`.
+    sliding_aggregate: Option<SlidingWindowAggregate>,
+
     // NULL frame is a special RANGE frame, we need to check if the frame is a null frame.
     need_check_null_frame: bool,
     // If current frame is a null frame. This is only used when `need_check_null_frame` is true.
@@ -489,7 +500,12 @@ impl<T: Number> TransformWindow<T> {
         debug_assert!(self.partition_start <= self.frame_start);
         debug_assert!(self.frame_end <= self.partition_end);
 
-        let (rows_start, rows_end, reset) = if self.frame_start == self.prev_frame_start {
+        let (rows_start, rows_end, reset) = if self.exclusion != WindowFuncFrameExclusion::NoOthers
+        {
+            // The excluded rows move with the current row, so the aggregation state of the
+            // previous frame cannot be reused.
+            (self.frame_start, self.frame_end, true)
+        } else if self.frame_start == self.prev_frame_start {
             (self.prev_frame_end, self.frame_end, false)
         } else {
             (self.frame_start, self.frame_end, true)
@@ -519,6 +535,9 @@ impl<T: Number> TransformWindow<T> {
             };
             let cols = agg.arg_columns(data);
             for row in start_row..end_row {
+                if self.is_excluded_from_frame(&RowPtr::new(block, row)) {
+                    continue;
+                }
                 agg.accumulate_row(cols, row)?;
             }
         }
@@ -526,9 +545,95 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    /// If the row in the frame is excluded by the `EXCLUDE` clause of the frame.
+    #[inline]
+    fn is_excluded_from_frame(&self, row: &RowPtr) -> bool {
+        match self.exclusion {
+            WindowFuncFrameExclusion::NoOthers => false,
+            WindowFuncFrameExclusion::CurrentRow => *row == self.current_row,
+            WindowFuncFrameExclusion::Group => self.are_peers(&self.current_row, row, false),
+            WindowFuncFrameExclusion::Ties => {
+                *row != self.current_row && self.are_peers(&self.current_row, row, false)
+            }
+        }
+    }
+
+    /// Set the rows to exclude from the frame of each row, e.g. `EXCLUDE CURRENT ROW`.
+    pub fn with_frame_exclusion(mut self, exclusion: WindowFuncFrameExclusion) -> Self {
+        self.exclusion = exclusion;
+        self
+    }
+
+    /// Set how the aggregate window function computes the aggregation of each frame.
+    pub fn with_batch_strategy(mut self, strategy: WindowBatchStrategy) -> Self {
+        self.sliding_aggregate = match strategy {
+            WindowBatchStrategy::Naive => None,
+            WindowBatchStrategy::Incremental { kind, arg } => {
+                Some(SlidingWindowAggregate::new(kind, arg))
+            }
+        };
+        self
+    }
+
+    fn apply_sliding_aggregate(&self, sliding: &mut SlidingWindowAggregate) {
+        debug_assert!(self.prev_frame_start <= self.frame_start);
+        debug_assert!(self.prev_frame_end <= self.frame_end);
+
+        if self.frame_start >= self.prev_frame_end {
+            // The frame doesn't overlap the previous one.
+            sliding.reset();
+            self.slide_rows(sliding, self.frame_start, self.frame_end, true);
+        } else {
+            self.slide_rows(sliding, self.prev_frame_start, self.frame_start, false);
+            self.slide_rows(sliding, self.prev_frame_end, self.frame_end, true);
+        }
+    }
+
+    // Add the rows [`start`, `end`) into the sliding frame, or remove them from it.
+    fn slide_rows(
+        &self,
+        sliding: &mut SlidingWindowAggregate,
+        start: RowPtr,
+        end: RowPtr,
+        is_add: bool,
+    ) {
+        let end_block = if end.row == 0 {
+            end.block
+        } else {
+            end.block + 1
+        };
+
+        for block in start.block..end_block {
+            let data = &self.blocks[block - self.first_block].block;
+            let start_row = if block == start.block { start.row } else { 0 };
+            let end_row = if block == end.block {
+                end.row
+            } else {
+                data.num_rows()
+            };
+            let column = sliding
+                .arg()
+                .map(|arg| data.get_by_offset(arg).value.as_column().unwrap());
+            for row in start_row..end_row {
+                if is_add {
+                    sliding.add_row(column, row);
+                } else {
+                    sliding.remove_row(column, row);
+                }
+            }
+        }
+    }
+
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
         match &self.func {
+            WindowFunctionImpl::Aggregate(_) if self.sliding_aggregate.is_some() => {
+                let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+                self.sliding_aggregate
+                    .as_ref()
+                    .unwrap()
+                    .merge_result(builder);
+            }
             WindowFunctionImpl::Aggregate(agg) => {
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
                 agg.merge_result(builder)?;
@@ -587,6 +692,8 @@ impl<T: Number> TransformWindow<T> {
             WindowFunctionImpl::NthValue(func) => {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
+                } else if self.exclusion != WindowFuncFrameExclusion::NoOthers {
+                    self.get_nth_value_with_exclusion(func)
                 } else if let Some(mut n) = func.n {
                     let mut cur = self.frame_start;
                     // n is counting from 1
@@ -667,6 +774,45 @@ impl<T: Number> TransformWindow<T> {
         false
     }
 
+    /// The value of `NTH_VALUE`, `FIRST_VALUE` or `LAST_VALUE` if the frame has an `EXCLUDE`
+    /// clause, the excluded rows are skipped as if they were not in the frame.
+    fn get_nth_value_with_exclusion(&self, func: &WindowFuncNthValueImpl) -> Scalar {
+        let mut n = func.n.unwrap_or(1);
+        // `LAST_VALUE` searches the frame backward from its end.
+        let mut cur = match func.n {
+            Some(_) => self.frame_start,
+            None => self.frame_end,
+        };
+        loop {
+            let row = match func.n {
+                Some(_) if cur < self.frame_end => {
+                    let row = cur;
+                    cur = self.advance_row(cur);
+                    row
+                }
+                None if cur > self.frame_start => {
+                    cur = self.goback_row(cur);
+                    cur
+                }
+                _ => return Scalar::Null,
+            };
+            if self.is_excluded_from_frame(&row) {
+                continue;
+            }
+            if n > 1 {
+                n -= 1;
+                continue;
+            }
+            let value = match &self.block_at(&row).get_by_offset(func.arg).value {
+                Value::Scalar(scalar) => scalar.clone(),
+                Value::Column(col) => unsafe { col.index_unchecked(row.row) }.to_owned(),
+            };
+            if !func.ignore_null || value != Scalar::Null {
+                return value;
+            }
+        }
+    }
+
     #[inline]
     fn get_nth_value_by_ignoring_nulls(
         &self,
@@ -785,6 +931,8 @@ impl TransformWindow<u64> {
             end_bound,
             rows_start_bound,
             rows_end_bound,
+            exclusion: WindowFuncFrameExclusion::NoOthers,
+            sliding_aggregate: None,
             need_check_null_frame: false,
             is_null_frame: false,
             frame_start: RowPtr::default(),
@@ -862,6 +1010,8 @@ where T: Number + ResultTypeOfUnary
             end_bound,
             rows_start_bound: 0,
             rows_end_bound: 0,
+            exclusion: WindowFuncFrameExclusion::NoOthers,
+            sliding_aggregate: None,
             need_check_null_frame,
             is_null_frame: false,
             frame_start: RowPtr::default(),
@@ -1005,6 +1155,12 @@ where T: Number + ResultTypeOfUnary
 
     fn compute_on_frame(&mut self) -> Result<()> {
         match &self.func {
+            WindowFunctionImpl::Aggregate(_) if self.sliding_aggregate.is_some() => {
+                let mut sliding = self.sliding_aggregate.take().unwrap();
+                self.apply_sliding_aggregate(&mut sliding);
+                self.sliding_aggregate = Some(sliding);
+                Ok(())
+            }
             WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg),
             _ => Ok(()),
         }
@@ -1141,6 +1297,9 @@ where T: Number + ResultTypeOfUnary
             {
                 // reset function
                 self.func.reset();
+                if let Some(sliding) = &mut self.sliding_aggregate {
+                    sliding.reset();
+                }
 
                 // reset partition
                 self.partition_start = self.partition_end;
@@ -1357,10 +1516,12 @@ where T: Number + ResultTypeOfUnary
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use std::time::Instant;
 
     use databend_common_base::base::tokio;
     use databend_common_exception::Result;
     use databend_common_expression::block_debug::assert_blocks_eq;
+    use databend_common_expression::block_debug::pretty_format_blocks;
     use databend_common_expression::types::DataType;
     use databend_common_expression::types::Int32Type;
     use databend_common_expression::types::NumberDataType;
@@ -1374,13 +1535,16 @@ mod tests {
     use databend_common_pipeline_core::processors::InputPort;
     use databend_common_pipeline_core::processors::OutputPort;
     use databend_common_pipeline_core::processors::Processor;
+    use databend_common_sql::plans::WindowFuncFrameExclusion;
     use databend_common_sql::plans::WindowFuncFrameUnits;
 
     use super::TransformWindow;
     use super::WindowBlock;
     use super::WindowSortDesc;
     use crate::pipelines::processors::transforms::window::transform_window::RowPtr;
+    use crate::pipelines::processors::transforms::window::window_batch_strategy::SlidingAggregateKind;
     use crate::pipelines::processors::transforms::window::FrameBound;
+    use crate::pipelines::processors::transforms::window::WindowBatchStrategy;
     use crate::pipelines::processors::transforms::window::WindowFunctionInfo;
 
     fn get_ranking_transform_window(
@@ -1817,6 +1981,120 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_frame_exclusion() -> Result<()> {
+        let run = |bounds: (FrameBound<u64>, FrameBound<u64>),
+                   exclusion: WindowFuncFrameExclusion|
+         -> Result<Vec<DataBlock>> {
+            let mut transform = get_transform_window_without_partition(
+                WindowFuncFrameUnits::Rows,
+                bounds,
+                DataType::Number(NumberDataType::Int32),
+            )?
+            .with_frame_exclusion(exclusion);
+
+            // The peer group of `2` spans two blocks.
+            transform.add_block(Some(DataBlock::new_from_columns(vec![
+                Int32Type::from_data(vec![1, 2, 2]),
+            ])))?;
+            transform.add_block(Some(DataBlock::new_from_columns(vec![
+                Int32Type::from_data(vec![2, 3, 3]),
+            ])))?;
+            transform.input_is_finished = true;
+            transform.add_block(None)?;
+            transform.check_outputs();
+
+            Ok(transform.outputs.drain(..).collect())
+        };
+        let unbounded = || (FrameBound::Preceding(None), FrameBound::Following(None));
+
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+",
+                "| Column 0 | Column 1 |",
+                "+----------+----------+",
+                "| 1        | 13       |",
+                "| 2        | 13       |",
+                "| 2        | 13       |",
+                "| 2        | 13       |",
+                "| 3        | 13       |",
+                "| 3        | 13       |",
+                "+----------+----------+",
+            ],
+            &run(unbounded(), WindowFuncFrameExclusion::NoOthers)?,
+        );
+
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+",
+                "| Column 0 | Column 1 |",
+                "+----------+----------+",
+                "| 1        | 12       |",
+                "| 2        | 11       |",
+                "| 2        | 11       |",
+                "| 2        | 11       |",
+                "| 3        | 10       |",
+                "| 3        | 10       |",
+                "+----------+----------+",
+            ],
+            &run(unbounded(), WindowFuncFrameExclusion::CurrentRow)?,
+        );
+
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+",
+                "| Column 0 | Column 1 |",
+                "+----------+----------+",
+                "| 1        | 12       |",
+                "| 2        | 7        |",
+                "| 2        | 7        |",
+                "| 2        | 7        |",
+                "| 3        | 7        |",
+                "| 3        | 7        |",
+                "+----------+----------+",
+            ],
+            &run(unbounded(), WindowFuncFrameExclusion::Group)?,
+        );
+
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+",
+                "| Column 0 | Column 1 |",
+                "+----------+----------+",
+                "| 1        | 13       |",
+                "| 2        | 9        |",
+                "| 2        | 9        |",
+                "| 2        | 9        |",
+                "| 3        | 10       |",
+                "| 3        | 10       |",
+                "+----------+----------+",
+            ],
+            &run(unbounded(), WindowFuncFrameExclusion::Ties)?,
+        );
+
+        // The excluded peers are limited to the rows within the frame.
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+",
+                "| Column 0 | Column 1 |",
+                "+----------+----------+",
+                "| 1        | 1        |",
+                "| 2        | 3        |",
+                "| 2        | 3        |",
+                "| 2        | 3        |",
+                "| 3        | 10       |",
+                "| 3        | 10       |",
+                "+----------+----------+",
+            ],
+            &run(
+                (FrameBound::Preceding(None), FrameBound::CurrentRow),
+                WindowFuncFrameExclusion::Ties,
+            )?,
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_block() -> Result<()> {
         {
@@ -2567,4 +2845,212 @@ mod tests {
 
         Ok(())
     }
+
+    fn run_with_batch_strategy(
+        func_name: &str,
+        arg_type: Option<DataType>,
+        preceding: u64,
+        strategy: WindowBatchStrategy,
+        blocks: &[DataBlock],
+    ) -> Result<Vec<DataBlock>> {
+        let (arg_types, args) = match arg_type {
+            Some(arg_type) => (vec![arg_type], vec![1]),
+            None => (vec![], vec![]),
+        };
+        let agg = AggregateFunctionFactory::instance().get(func_name, vec![], arg_types, vec![])?;
+        let mut transform = TransformWindow::try_create_rows(
+            InputPort::create(),
+            OutputPort::create(),
+            WindowFunctionInfo::Aggregate(agg, args),
+            vec![0],
+            vec![],
+            (
+                FrameBound::Preceding(Some(preceding)),
+                FrameBound::CurrentRow,
+            ),
+        )?
+        .with_batch_strategy(strategy);
+
+        for block in blocks {
+            transform.add_block(Some(block.clone()))?;
+        }
+        transform.input_is_finished = true;
+        transform.add_block(None)?;
+        transform.check_outputs();
+
+        Ok(transform.outputs.drain(..).collect())
+    }
+
+    #[test]
+    fn test_batch_strategy() -> Result<()> {
+        // The partition `2` spans two blocks.
+        let blocks = vec![
+            DataBlock::new_from_columns(vec![
+                Int32Type::from_data(vec![1, 1, 1, 1, 2, 2]),
+                Int32Type::from_opt_data(vec![Some(3), None, Some(5), Some(1), Some(-2), Some(7)]),
+            ]),
+            DataBlock::new_from_columns(vec![
+                Int32Type::from_data(vec![2, 2, 2, 3]),
+                Int32Type::from_opt_data(vec![Some(7), None, None, Some(4)]),
+            ]),
+        ];
+        let arg_type = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)));
+
+        assert_blocks_eq(
+            vec![
+                "+----------+----------+----------+",
+                "| Column 0 | Column 1 | Column 2 |",
+                "+----------+----------+----------+",
+                "| 1        | 3        | 3        |",
+                "| 1        | NULL     | 3        |",
+                "| 1        | 5        | 5        |",
+                "| 1        | 1        | 5        |",
+                "| 2        | -2       | -2       |",
+                "| 2        | 7        | 7        |",
+                "| 2        | 7        | 7        |",
+                "| 2        | NULL     | 7        |",
+                "| 2        | NULL     | NULL     |",
+                "| 3        | 4        | 4        |",
+                "+----------+----------+----------+",
+            ],
+            &run_with_batch_strategy(
+                "max",
+                Some(arg_type.clone()),
+                1,
+                WindowBatchStrategy::Incremental {
+                    kind: SlidingAggregateKind::Max,
+                    arg: Some(1),
+                },
+                &blocks,
+            )?,
+        );
+
+        // The results must be the same as accumulating each frame.
+        let funcs = [
+            ("sum", SlidingAggregateKind::Sum, true),
+            ("count", SlidingAggregateKind::Count, true),
+            ("count", SlidingAggregateKind::Count, false),
+            ("min", SlidingAggregateKind::Min, true),
+            ("max", SlidingAggregateKind::Max, true),
+        ];
+        for (func_name, kind, has_arg) in funcs {
+            for preceding in [0, 1, 2, 5] {
+                let arg_type = has_arg.then(|| arg_type.clone());
+                let strategy = WindowBatchStrategy::Incremental {
+                    kind,
+                    arg: has_arg.then_some(1),
+                };
+                let incremental = run_with_batch_strategy(
+                    func_name,
+                    arg_type.clone(),
+                    preceding,
+                    strategy,
+                    &blocks,
+                )?;
+                let naive = run_with_batch_strategy(
+                    func_name,
+                    arg_type,
+                    preceding,
+                    WindowBatchStrategy::Naive,
+                    &blocks,
+                )?;
+                assert_eq!(
+                    pretty_format_blocks(&incremental)?,
+                    pretty_format_blocks(&naive)?,
+                    "{func_name} over {preceding} preceding rows, has argument: {has_arg}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // The rows of one partition, with the values cycling in [0, 1000).
+    fn one_partition_blocks(num_rows: usize, block_rows: usize) -> Vec<DataBlock> {
+        (0..num_rows)
+            .step_by(block_rows)
+            .map(|start| {
+                let end = (start + block_rows).min(num_rows);
+                DataBlock::new_from_columns(vec![
+                    Int32Type::from_data(vec![0; end - start]),
+                    Int32Type::from_data((start..end).map(|v| (v % 1000) as i32).collect()),
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_strategy_many_blocks() -> Result<()> {
+        // 100K rows in one partition spanning many blocks, with a frame of 100 rows.
+        let blocks = one_partition_blocks(100_000, 8192);
+        let arg_type = DataType::Number(NumberDataType::Int32);
+
+        let mut outputs = vec![];
+        for strategy in [
+            WindowBatchStrategy::Naive,
+            WindowBatchStrategy::Incremental {
+                kind: SlidingAggregateKind::Sum,
+                arg: Some(1),
+            },
+        ] {
+            let output =
+                run_with_batch_strategy("sum", Some(arg_type.clone()), 99, strategy, &blocks)?;
+            // Each input block is output once with all its rows.
+            assert_eq!(
+                output
+                    .iter()
+                    .map(|block| block.num_rows())
+                    .collect::<Vec<_>>(),
+                blocks
+                    .iter()
+                    .map(|block| block.num_rows())
+                    .collect::<Vec<_>>(),
+                "{strategy:?}"
+            );
+            outputs.push(output);
+        }
+        assert_eq!(
+            pretty_format_blocks(&outputs[0])?,
+            pretty_format_blocks(&outputs[1])?
+        );
+
+        Ok(())
+    }
+
+    // cargo test --package databend-query --lib pipelines::processors::transforms::window::transform_window::tests::test_batch_strategy_performance -- --ignored --exact --nocapture
+    #[test]
+    #[ignore]
+    fn test_batch_strategy_performance() -> Result<()> {
+        // 1M rows in one partition, with a frame of 100 rows.
+        let num_rows = 1_000_000;
+        let blocks = one_partition_blocks(num_rows, 65536);
+        let arg_type = DataType::Number(NumberDataType::Int32);
+
+        let mut elapsed = vec![];
+        for strategy in [
+            WindowBatchStrategy::Naive,
+            WindowBatchStrategy::Incremental {
+                kind: SlidingAggregateKind::Sum,
+                arg: Some(1),
+            },
+        ] {
+            let start = Instant::now();
+            let outputs =
+                run_with_batch_strategy("sum", Some(arg_type.clone()), 99, strategy, &blocks)?;
+            elapsed.push(start.elapsed());
+            eprintln!("{:?}: {:?}", strategy, start.elapsed());
+            assert_eq!(
+                outputs.iter().map(|block| block.num_rows()).sum::<usize>(),
+                num_rows
+            );
+        }
+        assert!(
+            elapsed[0] > elapsed[1] * 10,
+            "naive: {:?}, incremental: {:?}",
+            elapsed[0],
+            elapsed[1]
+        );
+
+        Ok(())
+    }
 }
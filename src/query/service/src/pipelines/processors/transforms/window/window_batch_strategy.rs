@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::physical_plans::WindowFunction;
+use databend_common_sql::plans::WindowFuncFrame;
+use databend_common_sql::plans::WindowFuncFrameBound;
+use databend_common_sql::plans::WindowFuncFrameExclusion;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlidingAggregateKind {
+    Sum,
+    Count,
+    Min,
+    Max,
+}
+
+/// How an aggregate window function computes the aggregation of the frame of each row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum WindowBatchStrategy {
+    /// Accumulate the rows of the frame into the aggregate state, the state is reset
+    /// whenever the start of the frame moves, so each row costs O(frame size).
+    #[default]
+    Naive,
+    /// Slide the frame over the partition: the rows entering the frame are added and the
+    /// rows leaving the frame are removed, so each row costs amortized O(1).
+    /// SUM and COUNT keep a running sum, MIN and MAX keep a monotonic deque.
+    Incremental {
+        kind: SlidingAggregateKind,
+        // The offset of the argument, `None` for `COUNT(*)`.
+        arg: Option<usize>,
+    },
+}
+
+impl WindowBatchStrategy {
+    /// `Incremental` is used for `SUM`, `COUNT`, `MIN` and `MAX` over the frame
+    /// `ROWS BETWEEN n PRECEDING AND CURRENT ROW`, otherwise `Naive`.
+    pub fn create(
+        func: &WindowFunction,
+        frame: &WindowFuncFrame,
+        schema: &DataSchema,
+    ) -> Result<Self> {
+        let WindowFunction::Aggregate(agg) = func else {
+            return Ok(Self::Naive);
+        };
+        if !frame.units.is_rows()
+            || frame.exclusion != WindowFuncFrameExclusion::NoOthers
+            || !matches!(frame.start_bound, WindowFuncFrameBound::Preceding(Some(_)))
+            || frame.end_bound != WindowFuncFrameBound::CurrentRow
+            || agg.sig.udaf.is_some()
+            || !agg.sig.params.is_empty()
+            || !agg.sig.sort_descs.is_empty()
+        {
+            return Ok(Self::Naive);
+        }
+
+        let kind = match agg.sig.name.as_str() {
+            "sum" => SlidingAggregateKind::Sum,
+            "count" => SlidingAggregateKind::Count,
+            "min" => SlidingAggregateKind::Min,
+            "max" => SlidingAggregateKind::Max,
+            _ => return Ok(Self::Naive),
+        };
+        let arg = match agg.arg_indices.as_slice() {
+            [] if kind == SlidingAggregateKind::Count => None,
+            [arg] => Some(schema.index_of(&arg.to_string())?),
+            _ => return Ok(Self::Naive),
+        };
+        let supported = match (kind, agg.sig.args.first().map(|ty| ty.remove_nullable())) {
+            (SlidingAggregateKind::Count, _) => true,
+            // The sum of floats or decimals can't be maintained by subtraction exactly.
+            (SlidingAggregateKind::Sum, Some(DataType::Number(ty))) => ty.is_integer(),
+            (SlidingAggregateKind::Min | SlidingAggregateKind::Max, Some(ty)) => matches!(
+                ty,
+                DataType::Number(_)
+                    | DataType::Decimal(_)
+                    | DataType::String
+                    | DataType::Date
+                    | DataType::Timestamp
+            ),
+            _ => false,
+        };
+        if !supported {
+            return Ok(Self::Naive);
+        }
+        Ok(Self::Incremental { kind, arg })
+    }
+}
+
+/// The state of an aggregate window function computed by `WindowBatchStrategy::Incremental`.
+///
+/// The rows are numbered in the order they are added since the last reset, the rows of
+/// the frame are `[num_removed, num_added)`.
+pub struct SlidingWindowAggregate {
+    kind: SlidingAggregateKind,
+    arg: Option<usize>,
+
+    num_added: u64,
+    num_removed: u64,
+    // The number of non-NULL values in the frame.
+    count: u64,
+    // The sum of the values in the frame, wrapped into the result type like `SUM`.
+    sum: i128,
+    // For MIN and MAX, the rows of the frame whose value may become the result once the
+    // rows before them leave the frame, the values are monotonic from front to back.
+    candidates: VecDeque<(u64, Scalar)>,
+}
+
+impl SlidingWindowAggregate {
+    pub fn new(kind: SlidingAggregateKind, arg: Option<usize>) -> Self {
+        SlidingWindowAggregate {
+            kind,
+            arg,
+            num_added: 0,
+            num_removed: 0,
+            count: 0,
+            sum: 0,
+            candidates: VecDeque::new(),
+        }
+    }
+
+    #[inline]
+    pub fn arg(&self) -> Option<usize> {
+        self.arg
+    }
+
+    pub fn reset(&mut self) {
+        self.num_added = 0;
+        self.num_removed = 0;
+        self.count = 0;
+        self.sum = 0;
+        self.candidates.clear();
+    }
+
+    /// Add the row after the end of the frame, `column` is the argument column of the row.
+    pub fn add_row(&mut self, column: Option<&Column>, row: usize) {
+        let index = self.num_added;
+        self.num_added += 1;
+
+        let Some(value) = Self::value_at(column, row) else {
+            return;
+        };
+        self.count += 1;
+        match self.kind {
+            SlidingAggregateKind::Count => {}
+            SlidingAggregateKind::Sum => self.sum += integer_value(&value),
+            SlidingAggregateKind::Min => {
+                while self
+                    .candidates
+                    .back()
+                    .is_some_and(|(_, candidate)| candidate.as_ref() >= value)
+                {
+                    self.candidates.pop_back();
+                }
+                self.candidates.push_back((index, value.to_owned()));
+            }
+            SlidingAggregateKind::Max => {
+                while self
+                    .candidates
+                    .back()
+                    .is_some_and(|(_, candidate)| candidate.as_ref() <= value)
+                {
+                    self.candidates.pop_back();
+                }
+                self.candidates.push_back((index, value.to_owned()));
+            }
+        }
+    }
+
+    /// Remove the first row of the frame, `column` is the argument column of the row.
+    pub fn remove_row(&mut self, column: Option<&Column>, row: usize) {
+        self.num_removed += 1;
+
+        let Some(value) = Self::value_at(column, row) else {
+            return;
+        };
+        self.count -= 1;
+        match self.kind {
+            SlidingAggregateKind::Count => {}
+            SlidingAggregateKind::Sum => self.sum -= integer_value(&value),
+            SlidingAggregateKind::Min | SlidingAggregateKind::Max => {
+                while self
+                    .candidates
+                    .front()
+                    .is_some_and(|(index, _)| *index < self.num_removed)
+                {
+                    self.candidates.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Push the aggregation of the frame into `builder`, which has the type of the
+    /// result of the aggregate function.
+    pub fn merge_result(&self, builder: &mut ColumnBuilder) {
+        match self.kind {
+            SlidingAggregateKind::Count => {
+                builder.push(ScalarRef::Number(NumberScalar::UInt64(self.count)));
+            }
+            // Like the aggregate functions, the result of no values is NULL if nullable.
+            _ if self.count == 0 => builder.push_default(),
+            SlidingAggregateKind::Sum => {
+                let sum = match builder.data_type().remove_nullable() {
+                    DataType::Number(ty) if ty.is_signed() => NumberScalar::Int64(self.sum as i64),
+                    _ => NumberScalar::UInt64(self.sum as u64),
+                };
+                builder.push(ScalarRef::Number(sum));
+            }
+            SlidingAggregateKind::Min | SlidingAggregateKind::Max => {
+                let (_, value) = self.candidates.front().unwrap();
+                builder.push(value.as_ref());
+            }
+        }
+    }
+
+    // The value of the argument at `row`, `None` if it's NULL.
+    // `COUNT(*)` has no argument, all its rows are counted.
+    #[inline]
+    fn value_at(column: Option<&Column>, row: usize) -> Option<ScalarRef> {
+        match column {
+            Some(column) => {
+                let value = unsafe { column.index_unchecked(row) };
+                (!value.is_null()).then_some(value)
+            }
+            None => Some(ScalarRef::Null),
+        }
+    }
+}
+
+#[inline]
+fn integer_value(value: &ScalarRef) -> i128 {
+    match value {
+        ScalarRef::Number(number) => with_integer_mapped_type!(|NUM_TYPE| match number {
+            NumberScalar::NUM_TYPE(v) => *v as i128,
+            _ => unreachable!("sum of non-integer values can't be computed incrementally"),
+        }),
+        _ => unreachable!("sum of non-integer values can't be computed incrementally"),
+    }
+}
@@ -313,3 +313,37 @@ impl WindowFunctionImpl {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+
+    use super::WindowFuncNtileImpl;
+
+    #[test]
+    fn test_ntile_non_divisible_partitions() {
+        for num_partition_rows in 1..=20 {
+            for n in 1..=10 {
+                let ntile = WindowFuncNtileImpl {
+                    n,
+                    return_type: DataType::Number(NumberDataType::UInt64),
+                };
+                let buckets = (1..=num_partition_rows)
+                    .map(|row| ntile.compute_nitle(row, num_partition_rows))
+                    .collect::<Vec<_>>();
+
+                // The rows are divided into `min(n, rows)` groups as evenly as possible,
+                // the first `rows % n` groups get one extra row.
+                let num_buckets = n.min(num_partition_rows);
+                let mut expected = Vec::with_capacity(num_partition_rows);
+                for bucket in 1..=num_buckets {
+                    let extra = usize::from(bucket <= num_partition_rows % n);
+                    let size = num_partition_rows / n + extra;
+                    expected.extend(std::iter::repeat(bucket).take(size));
+                }
+                assert_eq!(buckets, expected, "rows: {num_partition_rows}, n: {n}");
+            }
+        }
+    }
+}
@@ -53,6 +53,10 @@ use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
 use crate::servers::flight::v1::packets::DataPacket;
 use crate::servers::flight::v1::packets::FragmentData;
 
+/// The blocks smaller than this are sent uncompressed, compressing them costs more
+/// CPU than it saves on the network.
+pub const MIN_COMPRESSED_BLOCK_BYTES: usize = 4 * 1024;
+
 pub struct ExchangeSerializeMeta {
     pub block_number: isize,
     pub packet: Vec<DataPacket>,
@@ -188,6 +192,17 @@ pub fn serialize_block(
         )));
     }
 
+    // The compression codec is recorded in the IPC message header, the receiver
+    // decompresses the data with it whatever the sender's setting is.
+    let uncompressed_options;
+    let options = match data_block.memory_size() < MIN_COMPRESSED_BLOCK_BYTES {
+        true => {
+            uncompressed_options = IpcWriteOptions::default();
+            &uncompressed_options
+        }
+        false => options,
+    };
+
     let mut meta = vec![];
     meta.write_scalar_own(data_block.num_rows() as u32)?;
     bincode_serialize_into_buf(&mut meta, &data_block.get_meta())
@@ -20,5 +20,6 @@ pub use exchange_deserializer::ExchangeDeserializeMeta;
 pub use exchange_deserializer::TransformExchangeDeserializer;
 pub use exchange_serializer::serialize_block;
 pub use exchange_serializer::ExchangeSerializeMeta;
+pub use exchange_serializer::MIN_COMPRESSED_BLOCK_BYTES;
 pub use exchange_serializer::TransformExchangeSerializer;
 pub use exchange_serializer::TransformScatterExchangeSerializer;
@@ -31,6 +31,7 @@ use log::info;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::clusters::ClusterDiscovery;
+use crate::history::QueryHistory;
 use crate::sessions::SessionManager;
 
 pub type ListeningStream = Abortable<TcpListenerStream>;
@@ -75,6 +76,7 @@ impl ShutdownHandle {
             .unregister_to_metastore(&mut signal)
             .await;
         self.sessions.graceful_shutdown(signal, timeout).await;
+        QueryHistory::instance().shutdown().await;
         self.shutdown_services(false).await;
     }
 
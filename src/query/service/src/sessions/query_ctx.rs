@@ -933,6 +933,7 @@ impl TableContext for QueryContext {
         let enable_strict_datetime_parser = settings.get_enable_strict_datetime_parser()?;
         let query_config = &GlobalConfig::instance().query;
         let random_function_seed = settings.get_random_function_seed()?;
+        let convert_tz_null_on_dst_transition = settings.get_convert_tz_null_on_dst_transition()?;
 
         Ok(FunctionContext {
             now,
@@ -951,6 +952,7 @@ impl TableContext for QueryContext {
             parse_datetime_ignore_remainder,
             enable_strict_datetime_parser,
             random_function_seed,
+            convert_tz_null_on_dst_transition,
         })
     }
 
@@ -797,12 +797,27 @@ async fn show_object_grant(
 
     let mut names = vec![];
     let mut privileges = vec![];
+    let mut grant_tos = vec![];
     for role in roles {
         for entry in role.grants.entries() {
             if entry.matches_entry(&object) {
                 let privilege: UserPrivilegeSet = (*entry.privileges()).into();
                 privileges.push(privilege.to_string());
                 names.push(role.name.to_string());
+                grant_tos.push("ROLE".to_string());
+            }
+        }
+    }
+
+    // Privileges can also be granted to users directly.
+    let users = user_api.get_users(&tenant).await?;
+    for user in users {
+        for entry in user.grants.entries() {
+            if entry.matches_entry(&object) {
+                let privilege: UserPrivilegeSet = (*entry.privileges()).into();
+                privileges.push(privilege.to_string());
+                names.push(user.name.to_string());
+                grant_tos.push("USER".to_string());
             }
         }
     }
@@ -812,12 +827,12 @@ async fn show_object_grant(
         if ownership.data.object == owner_object {
             privileges.push("OWNERSHIP".to_string());
             names.push(ownership.data.role);
+            grant_tos.push("ROLE".to_string());
         }
     }
 
     let object_ids = vec![object_id; privileges.len()];
     let object_names = vec![object_name; privileges.len()];
-    let grant_tos: Vec<String> = vec!["ROLE".to_string(); privileges.len()];
     let grant_list = vec!["".to_string(); privileges.len()];
     Ok(Some(DataBlock::new_from_columns(vec![
         StringType::from_data(privileges),
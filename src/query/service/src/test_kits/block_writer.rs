@@ -109,6 +109,7 @@ impl<'a> BlockWriter<'a> {
             None,
             Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
         );
         Ok((block_meta, meta))
     }
@@ -348,6 +348,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            constraints: Default::default(),
             attached_columns: None,
         }
     }
@@ -373,6 +374,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
             attached_columns: None,
         }
     }
@@ -409,6 +411,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
             attached_columns: None,
         }
     }
@@ -445,6 +448,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
             attached_columns: None,
         }
     }
@@ -490,6 +494,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
             attached_columns: None,
         }
     }
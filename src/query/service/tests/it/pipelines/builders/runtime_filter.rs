@@ -69,7 +69,9 @@ fn find_join(plan: &PhysicalPlan) -> Result<HashJoin> {
         PhysicalPlan::AggregateExpand(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::AggregatePartial(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::AggregateFinal(plan) => find_join(plan.input.as_ref()),
+        PhysicalPlan::AggregateSorted(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::Window(plan) => find_join(plan.input.as_ref()),
+        PhysicalPlan::SortedFirstRows(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::Sort(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::Limit(plan) => find_join(plan.input.as_ref()),
         PhysicalPlan::RowFetch(plan) => find_join(plan.input.as_ref()),
@@ -14,3 +14,4 @@
 
 mod executor_graph;
 mod pipeline_executor;
+mod plan_profile;
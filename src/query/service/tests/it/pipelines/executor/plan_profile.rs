@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::PlanProfile;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_analyze_plan_timing() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let blocks = fixture
+        .execute_query(
+            "explain analyze select number % 3, sum(number) from numbers(100000) where number % 2 = 0 group by number % 3",
+        )
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let mut lines = vec![];
+    for block in blocks {
+        let column = block.get_by_offset(0).value.as_column().unwrap();
+        for row in 0..block.num_rows() {
+            let value = column.index(row).unwrap();
+            lines.push(value.as_string().unwrap().to_string());
+        }
+    }
+
+    // Every plan node has the estimated rows, and the nonzero time of its processors.
+    let nodes = lines
+        .iter()
+        .filter(|line| line.contains("estimated rows:"))
+        .count();
+    let cpu_times = lines
+        .iter()
+        .filter(|line| line.contains("cpu time:"))
+        .count();
+    let wall_times = lines
+        .iter()
+        .filter(|line| line.contains("wall time:"))
+        .count();
+    assert!(nodes >= 4, "{}", lines.join("\n"));
+    assert_eq!(nodes, cpu_times, "{}", lines.join("\n"));
+    assert_eq!(nodes, wall_times, "{}", lines.join("\n"));
+
+    Ok(())
+}
+
+#[test]
+fn test_plan_profile_merge_wall_time() {
+    let processor_profile = |cpu_time: usize, start: usize, end: usize| {
+        let profile = Profile::create(
+            0,
+            "processor".to_string(),
+            Some(1),
+            Some("plan".to_string()),
+            None,
+            Arc::new("plan".to_string()),
+            Arc::new(vec![]),
+            None,
+        );
+        profile.statistics[ProfileStatisticsName::CpuTime as usize]
+            .store(cpu_time, Ordering::SeqCst);
+        profile.statistics[ProfileStatisticsName::WallTime as usize]
+            .store(end - start, Ordering::SeqCst);
+        profile
+    };
+
+    // Two processors of the same plan run in parallel.
+    let first = processor_profile(30, 0, 100);
+    let second = processor_profile(50, 20, 80);
+
+    let mut plan_profile = PlanProfile::create(&first);
+    plan_profile.accumulate(&first);
+    plan_profile.accumulate(&second);
+
+    let cpu_time = ProfileStatisticsName::CpuTime as usize;
+    let wall_time = ProfileStatisticsName::WallTime as usize;
+    assert_eq!(plan_profile.statistics[cpu_time], 80);
+    assert_eq!(plan_profile.statistics[wall_time], 100);
+
+    // The profiles of the same plan from another node.
+    let mut other = PlanProfile::create(&second);
+    other.accumulate(&processor_profile(10, 0, 150));
+    plan_profile.merge(&other);
+    assert_eq!(plan_profile.statistics[cpu_time], 90);
+    assert_eq!(plan_profile.statistics[wall_time], 150);
+}
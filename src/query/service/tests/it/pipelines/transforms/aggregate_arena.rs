@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+// Returns the groups of the query and the rows spilled by the aggregation.
+async fn aggregate_with_arena_bytes(
+    fixture: &TestFixture,
+    query: &str,
+    max_arena_bytes: usize,
+) -> Result<(usize, usize)> {
+    let ctx = fixture.new_query_ctx().await?;
+    let settings = ctx.get_settings();
+    settings.set_setting("max_threads".to_string(), "1".to_string())?;
+    settings.set_setting(
+        "aggregate_spilling_arena_bytes".to_string(),
+        max_arena_bytes.to_string(),
+    )?;
+
+    let blocks = execute_query(ctx.clone(), query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let num_groups = blocks.iter().map(DataBlock::num_rows).sum();
+    Ok((num_groups, ctx.get_aggregate_spill_progress_value().rows))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_aggregate_arena_limit_spills() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // About 10MB of distinct long string keys.
+    let query = "select s, count(*) from (select concat(repeat('x', 1000), number::string) as s \
+        from numbers(10000)) t group by s";
+
+    let (num_groups, spilled_rows) = aggregate_with_arena_bytes(&fixture, query, 0).await?;
+    assert_eq!(num_groups, 10000);
+    assert_eq!(spilled_rows, 0);
+
+    let (num_groups, spilled_rows) =
+        aggregate_with_arena_bytes(&fixture, query, 1024 * 1024).await?;
+    assert_eq!(num_groups, 10000);
+    assert!(spilled_rows > 0);
+
+    // A key larger than the cap is still stored.
+    let query = "select length(s), count(*) from (select concat(repeat('x', 2 * 1024 * 1024), number::string) as s \
+        from numbers(3)) t group by s";
+    let (num_groups, spilled_rows) =
+        aggregate_with_arena_bytes(&fixture, query, 1024 * 1024).await?;
+    assert_eq!(num_groups, 3);
+    assert!(spilled_rows > 0);
+
+    Ok(())
+}
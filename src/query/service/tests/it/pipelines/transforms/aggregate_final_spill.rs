@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::ThreadTracker;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+// Returns the groups of the query and the peak memory usage of the query.
+async fn aggregate_with_spilling(
+    fixture: &TestFixture,
+    query: &str,
+    spilling: bool,
+) -> Result<(usize, i64)> {
+    let mut tracking_payload = ThreadTracker::new_tracking_payload();
+    tracking_payload.mem_stat = Some(MemStat::create("aggregate_final_spill".to_string()));
+    let _guard = ThreadTracker::tracking(tracking_payload);
+
+    ThreadTracker::tracking_future(async {
+        let ctx = fixture.new_query_ctx().await?;
+        let settings = ctx.get_settings();
+        settings.set_setting("max_threads".to_string(), "1".to_string())?;
+        if spilling {
+            settings.set_setting("max_query_memory_usage".to_string(), "1".to_string())?;
+            settings.set_setting(
+                "query_out_of_memory_behavior".to_string(),
+                "spilling".to_string(),
+            )?;
+        }
+
+        let blocks = execute_query(ctx.clone(), query)
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+        let num_groups = blocks.iter().map(DataBlock::num_rows).sum();
+        let peak_memory_usage = ctx
+            .get_query_memory_tracking()
+            .unwrap()
+            .get_peek_memory_usage();
+        Ok((num_groups, peak_memory_usage))
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_aggregate_final_spill_reduces_memory() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // About 100MB of distinct long string keys, the partitions are split and spilled
+    // while they are merged if the memory limit is exceeded.
+    let query = "select s, count(*) from (select concat(repeat('x', 1000), number::string) as s \
+        from numbers(100000)) t group by s";
+
+    let (num_groups, peak_memory_usage) = aggregate_with_spilling(&fixture, query, false).await?;
+    assert_eq!(num_groups, 100000);
+
+    let (num_groups, spilling_peak_memory_usage) =
+        aggregate_with_spilling(&fixture, query, true).await?;
+    assert_eq!(num_groups, 100000);
+    assert!(spilling_peak_memory_usage < peak_memory_usage);
+
+    Ok(())
+}
@@ -12,4 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregate_arena;
+mod aggregate_final_spill;
 mod sort;
+mod sorted_first_rows;
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_window_top_one_of_sorted_input() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let query =
+        "select k, v from (select k, v, row_number() over (partition by k order by v desc) as rn \
+        from (select number % 10 as k, number as v from numbers(1000) order by k, v desc) t) t2 \
+        where rn = 1 order by k";
+    let blocks = execute_query(ctx.clone(), query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 10);
+    for row in 0..block.num_rows() {
+        let k = block.get_by_offset(0).value.index(row).unwrap();
+        let v = block.get_by_offset(1).value.index(row).unwrap();
+        assert_eq!(k.to_string(), row.to_string());
+        assert_eq!(v.to_string(), (990 + row).to_string());
+    }
+
+    // Only the first row of each partition goes through the window.
+    let profiles = ctx.get_query_profiles();
+    let output_rows = |name: &str| {
+        profiles
+            .iter()
+            .filter(|profile| profile.name.as_deref() == Some(name))
+            .map(|profile| profile.statistics[ProfileStatisticsName::OutputRows as usize])
+            .sum::<usize>()
+    };
+    assert_eq!(output_rows("SortedFirstRows"), 10);
+    assert_eq!(output_rows("Window"), 10);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_ipc::CompressionType;
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_query::servers::flight::v1::exchange::serde::deserialize_block;
+use databend_query::servers::flight::v1::exchange::serde::serialize_block;
+use databend_query::servers::flight::v1::exchange::serde::ExchangeSerializeMeta;
+use databend_query::servers::flight::v1::exchange::serde::MIN_COMPRESSED_BLOCK_BYTES;
+use databend_query::servers::flight::v1::packets::DataPacket;
+
+fn test_block(num_rows: usize) -> DataBlock {
+    DataBlock::new_from_columns(vec![
+        Int32Type::from_data((0..num_rows as i32).map(|i| i % 7).collect::<Vec<_>>()),
+        StringType::from_data(
+            (0..num_rows)
+                .map(|i| format!("databend-{}", i % 3))
+                .collect::<Vec<_>>(),
+        ),
+    ])
+}
+
+// Serialize the block, returns the size of the data on the wire and the deserialized block.
+fn round_trip(block: &DataBlock, options: &IpcWriteOptions) -> Result<(usize, DataBlock)> {
+    let schema = block.infer_schema();
+    let arrow_schema = Arc::new(ArrowSchema::from(&schema));
+
+    let serialized = serialize_block(0, block.clone(), options)?;
+    let meta = ExchangeSerializeMeta::downcast_from(serialized.get_owned_meta().unwrap()).unwrap();
+
+    let mut dicts = vec![];
+    let mut fragment = None;
+    for packet in meta.packet {
+        match packet {
+            DataPacket::FragmentData(data) => fragment = Some(data),
+            packet => dicts.push(packet),
+        }
+    }
+    let fragment = fragment.unwrap();
+    let size = fragment.data.data_body.len();
+    let block = deserialize_block(dicts, fragment, &schema, arrow_schema)?;
+    Ok((size, block))
+}
+
+#[test]
+fn test_exchange_compression_round_trip() -> Result<()> {
+    let block = test_block(10000);
+    let (uncompressed_size, uncompressed) = round_trip(&block, &IpcWriteOptions::default())?;
+    assert_eq!(block.columns(), uncompressed.columns());
+
+    for compression in [CompressionType::LZ4_FRAME, CompressionType::ZSTD] {
+        let options = IpcWriteOptions::default().try_with_compression(Some(compression))?;
+        let (size, decompressed) = round_trip(&block, &options)?;
+
+        assert!(size < uncompressed_size);
+        assert_eq!(block.num_rows(), decompressed.num_rows());
+        assert_eq!(block.columns(), decompressed.columns());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_exchange_compression_skip_small_block() -> Result<()> {
+    let block = test_block(10);
+    assert!(block.memory_size() < MIN_COMPRESSED_BLOCK_BYTES);
+
+    let (uncompressed_size, _) = round_trip(&block, &IpcWriteOptions::default())?;
+    let options = IpcWriteOptions::default().try_with_compression(Some(CompressionType::ZSTD))?;
+    let (size, decompressed) = round_trip(&block, &options)?;
+
+    // The small block is sent as is.
+    assert_eq!(uncompressed_size, size);
+    assert_eq!(block.columns(), decompressed.columns());
+
+    Ok(())
+}
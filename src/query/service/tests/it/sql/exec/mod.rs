@@ -170,4 +170,36 @@ pub async fn test_snapshot_consistency() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+pub async fn test_distinct_on_without_order_warning() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let sql_warnings = |sql: &'static str| {
+        let fixture = &fixture;
+        async move {
+            let ctx = fixture.new_query_ctx().await?;
+            let mut planner = Planner::new(ctx.clone());
+            planner.plan_sql(sql).await?;
+            Ok::<_, ErrorCode>(ctx.pop_warnings())
+        }
+    };
+
+    // Any row of a group may be kept.
+    let warnings = sql_warnings(
+        "select distinct on (number % 3) number % 3, number from numbers(10) order by number % 3",
+    )
+    .await?;
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("nondeterministic"), "{}", warnings[0]);
+
+    let warnings = sql_warnings(
+        "select distinct on (number % 3) number from numbers(10) order by number % 3, number desc",
+    )
+    .await?;
+    assert!(warnings.is_empty(), "{:?}", warnings);
+
+    Ok(())
+}
+
 mod get_table_bind_test;
+mod physical_plan_validate_test;
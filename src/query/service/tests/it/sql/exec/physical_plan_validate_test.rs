@@ -0,0 +1,159 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::schedulers::Fragmenter;
+use databend_query::schedulers::PlanFragment;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::*;
+
+async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+            builder.build(&s_expr, bind_context.column_set()).await
+        }
+        _ => unreachable!("Query plan expected"),
+    }
+}
+
+fn contains(plan: &PhysicalPlan, name: &str) -> bool {
+    plan.name() == name || plan.children().any(|child| contains(child, name))
+}
+
+fn find_hash_join(plan: &mut PhysicalPlan) -> Option<&mut PhysicalPlan> {
+    if matches!(plan, PhysicalPlan::HashJoin(_)) {
+        return Some(plan);
+    }
+    let input = match plan {
+        PhysicalPlan::EvalScalar(plan) => &mut plan.input,
+        PhysicalPlan::Filter(plan) => &mut plan.input,
+        PhysicalPlan::Sort(plan) => &mut plan.input,
+        PhysicalPlan::Limit(plan) => &mut plan.input,
+        PhysicalPlan::Exchange(plan) => &mut plan.input,
+        _ => return None,
+    };
+    find_hash_join(input)
+}
+
+fn collect_fragments<'a>(
+    fragment: &'a PlanFragment,
+    fragments: &mut Vec<(usize, &'a PhysicalPlan)>,
+) {
+    fragments.push((fragment.fragment_id, &fragment.plan));
+    for source in fragment.source_fragments.iter() {
+        collect_fragments(source, fragments);
+    }
+}
+
+fn validate_fragments(root: &PlanFragment) -> Result<()> {
+    let mut fragments = vec![];
+    collect_fragments(root, &mut fragments);
+    PhysicalPlan::validate_fragments(&fragments)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validate_built_plans() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("CREATE TABLE t1(a int, b int)")
+        .await?;
+    fixture
+        .execute_command("CREATE TABLE t2(a int, c string)")
+        .await?;
+    fixture.execute_command("CREATE SEQUENCE seq").await?;
+
+    // The nodes below an async function have their plan ids assigned.
+    let plan = physical_plan(
+        fixture.new_query_ctx().await?,
+        "SELECT nextval(seq), a FROM t1 WHERE b > 1",
+    )
+    .await?;
+    assert!(contains(&plan, "AsyncFunction"));
+    plan.validate()?;
+
+    let sql = "SELECT t1.a, t2.c FROM t1 JOIN t2 ON t1.a = t2.a";
+    let mut plan = physical_plan(fixture.new_query_ctx().await?, sql).await?;
+    plan.validate()?;
+
+    let Some(PhysicalPlan::HashJoin(join)) = find_hash_join(&mut plan) else {
+        unreachable!("HashJoin expected");
+    };
+    join.probe_keys.pop();
+    let err = plan.validate().unwrap_err();
+    assert!(
+        err.message()
+            .contains("build keys, 0 probe keys and 1 null equal flags"),
+        "{}",
+        err.message()
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validate_distributed_plans() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("CREATE TABLE t1(a int, b int)")
+        .await?;
+    fixture
+        .execute_command("CREATE TABLE t2(a int, c string)")
+        .await?;
+
+    // The plan is only built and fragmented, the nodes are never connected.
+    let cluster = ClusterDescriptor::new()
+        .with_node("node1", "127.0.0.1:9091")
+        .with_node("node2", "127.0.0.1:9092")
+        .with_local_id("node1");
+    let ctx = fixture.new_query_ctx_with_cluster(cluster).await?;
+    let plan = physical_plan(
+        ctx.clone(),
+        "SELECT t2.c, count(*) FROM t1 JOIN t2 ON t1.a = t2.a GROUP BY t2.c",
+    )
+    .await?;
+    assert!(contains(&plan, "Exchange"));
+    plan.validate()?;
+
+    let mut root = Fragmenter::try_create(ctx)?.build_fragment(&plan)?;
+    assert!(!root.source_fragments.is_empty());
+    validate_fragments(&root)?;
+
+    // The source fragment sends to another fragment than the one reading from it.
+    let PhysicalPlan::ExchangeSink(sink) = &mut root.source_fragments[0].plan else {
+        unreachable!("ExchangeSink expected");
+    };
+    sink.destination_fragment_id = usize::MAX;
+    let err = validate_fragments(&root).unwrap_err();
+    assert!(
+        err.message()
+            .contains(&format!("which sends to fragment {}", usize::MAX)),
+        "{}",
+        err.message()
+    );
+    Ok(())
+}
@@ -79,6 +79,7 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
             None,
             meta::Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
         ));
 
         let statistics = reduce_block_metas(
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+// Returns the rows of the query and the bytes scanned by it.
+async fn scan_with_prewhere(
+    fixture: &TestFixture,
+    query: &str,
+    enable_prewhere: bool,
+) -> Result<(usize, usize)> {
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "enable_fuse_prewhere".to_string(),
+        (enable_prewhere as u64).to_string(),
+    )?;
+
+    let blocks = execute_query(ctx.clone(), query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let num_rows = blocks.iter().map(DataBlock::num_rows).sum();
+    let scan_bytes = ctx
+        .get_query_profiles()
+        .iter()
+        .map(|profile| profile.statistics[ProfileStatisticsName::ScanBytes as usize])
+        .sum();
+    Ok((num_rows, scan_bytes))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_prewhere_reads_less_wide_columns() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command(
+            "create table default.t_prewhere(k int, a string, b string, c string, d string)",
+        )
+        .await?;
+
+    // Ten blocks with the same range of `k`, none of them can be pruned, while only
+    // the rows of the first block pass the filter.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_compact_after_write".to_string(), "0".to_string())?;
+    for i in 0..10 {
+        let offset = if i == 0 { 3 } else { 0 };
+        execute_command(
+            ctx.clone(),
+            &format!(
+                "insert into default.t_prewhere select number * 7 + {offset}, repeat('a', 200), repeat('b', 200), repeat('c', 200), repeat('d', 200) from numbers(1000)"
+            ),
+        )
+        .await?;
+    }
+
+    let query = "select * from default.t_prewhere where k % 7 = 3";
+    let (plain_rows, plain_bytes) = scan_with_prewhere(&fixture, query, false).await?;
+    let (prewhere_rows, prewhere_bytes) = scan_with_prewhere(&fixture, query, true).await?;
+    assert_eq!(plain_rows, 1000);
+    assert_eq!(prewhere_rows, 1000);
+    // The wide columns are only read from the first block.
+    assert!(
+        prewhere_bytes * 4 < plain_bytes,
+        "prewhere scanned {} bytes, plain scan scanned {} bytes",
+        prewhere_bytes,
+        plain_bytes
+    );
+
+    // The filter reads most of the columns, it is not worth the prewhere.
+    let query = "select k, a from default.t_prewhere where k % 7 = 3 and length(a) > 100";
+    let (plain_rows, plain_bytes) = scan_with_prewhere(&fixture, query, false).await?;
+    let (prewhere_rows, prewhere_bytes) = scan_with_prewhere(&fixture, query, true).await?;
+    assert_eq!(plain_rows, 1000);
+    assert_eq!(prewhere_rows, 1000);
+    assert_eq!(prewhere_bytes, plain_bytes);
+
+    Ok(())
+}
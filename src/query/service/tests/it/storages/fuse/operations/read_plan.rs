@@ -23,13 +23,17 @@ use databend_common_base::base::tokio;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::ColumnId;
+use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_common_storage::ColumnNode;
 use databend_common_storage::ColumnNodes;
 use databend_common_storages_fuse::FuseBlockPartInfo;
 use databend_query::storages::fuse::FuseTable;
+use databend_query::stream::ReadDataBlockStream;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta;
 use databend_storages_common_table_meta::meta::BlockMeta;
@@ -105,6 +109,7 @@ fn test_to_partitions() -> Result<()> {
         None,
         meta::Compression::Lz4Raw,
         Some(Utc::now()),
+        None,
     ));
 
     let blocks_metas = (0..num_of_block)
@@ -195,3 +200,65 @@ async fn test_fuse_table_exact_statistic() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_ordered_output() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(8)?;
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    // Each append commits a new segment with a single block whose ids are all `i`.
+    let num_blocks = 16;
+    for i in 0..num_blocks {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream_ex(1, 3, i);
+        let blocks = stream.try_collect().await?;
+        fixture
+            .append_commit_blocks(table, blocks, false, true)
+            .await?;
+    }
+
+    let read_ids = |push_downs: Option<PushDownInfo>| {
+        let fixture = &fixture;
+        let ctx = ctx.clone();
+        async move {
+            let table = fixture.latest_default_table().await?;
+            let mut plan = table
+                .read_plan(ctx.clone(), push_downs, None, false, true)
+                .await?;
+            plan.ordered_output = true;
+            let blocks = table
+                .read_data_block_stream(ctx, &plan)
+                .await?
+                .try_collect::<Vec<DataBlock>>()
+                .await?;
+            Result::Ok(
+                blocks
+                    .iter()
+                    .map(|block| block.get_by_offset(0).value.index(0).unwrap().to_owned())
+                    .collect::<Vec<_>>(),
+            )
+        }
+    };
+
+    // The newest segment comes first in the snapshot.
+    let expected = (0..num_blocks)
+        .rev()
+        .map(|i| Scalar::Number(NumberScalar::Int32(i)))
+        .collect::<Vec<_>>();
+    assert_eq!(read_ids(None).await?, expected);
+
+    // Limit still prunes the parts, the output is a prefix of the ordered parts.
+    let push_downs = PushDownInfo {
+        limit: Some(5),
+        ..Default::default()
+    };
+    let ids = read_ids(Some(push_downs)).await?;
+    assert!(!ids.is_empty() && ids.len() < expected.len());
+    assert_eq!(ids, expected[..ids.len()]);
+
+    Ok(())
+}
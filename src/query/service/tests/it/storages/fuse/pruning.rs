@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_ast::ast::Engine;
 use databend_common_base::base::tokio;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt64Type;
@@ -35,6 +36,7 @@ use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_fuse::pruning::create_segment_location_vector;
 use databend_common_storages_fuse::pruning::FusePruner;
+use databend_common_storages_fuse::pruning::PruneLevel;
 use databend_common_storages_fuse::FuseStorageFormat;
 use databend_common_storages_fuse::FuseTable;
 use databend_query::interpreters::CreateTableInterpreter;
@@ -46,6 +48,7 @@ use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_query::test_kits::*;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_pruner::BlockMetaIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
@@ -60,18 +63,22 @@ async fn apply_block_pruning(
     ctx: Arc<QueryContext>,
     op: Operator,
     bloom_index_cols: BloomIndexColumns,
+    prune_level: PruneLevel,
 ) -> Result<Vec<Arc<BlockMeta>>> {
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
-    FusePruner::create(
+    FusePruner::create_with_pages(
         &ctx,
         op,
         schema,
         push_down,
+        None,
+        vec![],
         bloom_index_cols,
         None,
         FuseStorageFormat::Parquet,
+        prune_level,
     )?
     .read_pruning(segment_locs)
     .await
@@ -116,6 +123,7 @@ async fn test_block_pruner() -> Result<()> {
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        constraints: Default::default(),
         attached_columns: None,
     };
 
@@ -240,6 +248,26 @@ async fn test_block_pruner() -> Result<()> {
         ..Default::default()
     };
 
+    // The bounds of `BETWEEN SYMMETRIC` are swapped if needed: b in [4, 7].
+    let e6 = PushDownInfo {
+        filters: Some(parse_to_filters(
+            ctx.clone(),
+            table.clone(),
+            "b between symmetric 7 and 4",
+        )?),
+        ..Default::default()
+    };
+
+    // while they are not for `BETWEEN`, the range is empty.
+    let e7 = PushDownInfo {
+        filters: Some(parse_to_filters(
+            ctx.clone(),
+            table.clone(),
+            "b between 7 and 4",
+        )?),
+        ..Default::default()
+    };
+
     let extras = vec![
         (None, num_blocks, num_blocks * row_per_block),
         (Some(e1), 0, 0),
@@ -247,6 +275,8 @@ async fn test_block_pruner() -> Result<()> {
         (Some(e3), 3, 3 * row_per_block),
         (Some(e4), 4, 4 * row_per_block),
         (Some(e5), 2, 2 * row_per_block),
+        (Some(e6), 4, 4 * row_per_block),
+        (Some(e7), 0, 0),
     ];
 
     for (extra, expected_blocks, expected_rows) in extras {
@@ -257,6 +287,7 @@ async fn test_block_pruner() -> Result<()> {
             ctx.clone(),
             fuse_table.get_operator(),
             fuse_table.bloom_index_cols(),
+            PruneLevel::Full,
         )
         .await?;
 
@@ -267,3 +298,149 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_pruner_prune_level() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t_prune_level(a int, b int)"))
+        .await?;
+    // Each insertion is a block, the min/max of `b` of every block is [0, 20],
+    // while the other value of `b` is 2 * i.
+    for i in 0..10 {
+        fixture
+            .execute_command(&format!(
+                "insert into {db}.t_prune_level values(1, 0), (1, 20), (1, {})",
+                i * 2
+            ))
+            .await?;
+    }
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(&fixture.default_tenant(), &db, "t_prune_level")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+
+    for filter in ["b = 5", "b = 4", "b > 15", "a = 1 and b = 6", "b > 30"] {
+        let push_down = Some(PushDownInfo {
+            filters: Some(parse_to_filters(ctx.clone(), table.clone(), filter)?),
+            ..Default::default()
+        });
+        let mut pruned = Vec::with_capacity(2);
+        for prune_level in [PruneLevel::Fast, PruneLevel::Full] {
+            let blocks = apply_block_pruning(
+                snapshot.clone(),
+                table.get_table_info().schema(),
+                &push_down,
+                ctx.clone(),
+                fuse_table.get_operator(),
+                fuse_table.bloom_index_cols(),
+                prune_level,
+            )
+            .await?;
+            pruned.push(
+                blocks
+                    .iter()
+                    .map(|b| b.location.0.clone())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        let (fast, full) = (&pruned[0], &pruned[1]);
+
+        // `Fast` never drops a block kept by `Full`.
+        assert!(
+            full.iter().all(|location| fast.contains(location)),
+            "filter: {filter}"
+        );
+        // Only the min/max of `b` are used by `Fast`.
+        let expected = if filter == "b > 30" { 0 } else { 10 };
+        assert_eq!(fast.len(), expected, "filter: {filter}");
+        // The bloom index of `b` is used by `Full` for the equality filters.
+        match filter {
+            "b = 5" => assert!(full.is_empty(), "filter: {filter}"),
+            "b = 4" | "a = 1 and b = 6" => assert_eq!(full.len(), 1, "filter: {filter}"),
+            _ => assert_eq!(full.len(), fast.len(), "filter: {filter}"),
+        }
+    }
+
+    Ok(())
+}
+
+// The pruned blocks by their locations, with the format version of each block.
+async fn pruned_blocks(
+    fuse_table: &FuseTable,
+    ctx: Arc<QueryContext>,
+) -> Result<Vec<(BlockMetaIndex, u64)>> {
+    let ctx: Arc<dyn TableContext> = ctx;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let segment_locs =
+        create_segment_location_vector(snapshot.segments.clone(), fuse_table.snapshot_loc());
+    let blocks = FusePruner::create(
+        &ctx,
+        fuse_table.get_operator(),
+        fuse_table.schema(),
+        &None,
+        fuse_table.bloom_index_cols(),
+        None,
+        FuseStorageFormat::Parquet,
+    )?
+    .read_pruning(segment_locs)
+    .await?;
+    let mut blocks = blocks
+        .into_iter()
+        .map(|(index, meta)| (index, meta.location.1))
+        .collect::<Vec<_>>();
+    blocks.sort_by(|(a, _), (b, _)| a.block_location.cmp(&b.block_location));
+    Ok(blocks)
+}
+
+async fn append_sample_blocks(fixture: &TestFixture, start: i32) -> Result<Arc<dyn Table>> {
+    let table = fixture.latest_default_table().await?;
+    let (_, blocks) = TestFixture::gen_sample_blocks(2, start);
+    let blocks = blocks.into_iter().collect::<Result<Vec<_>>>()?;
+    fixture
+        .append_commit_blocks(table, blocks, false, true)
+        .await?;
+    fixture.latest_default_table().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pruned_blocks_across_snapshots() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let table_v1 = append_sample_blocks(&fixture, 1).await?;
+    let table_v1 = FuseTable::try_from_table(table_v1.as_ref())?;
+    let blocks_v1 = pruned_blocks(table_v1, ctx.clone()).await?;
+    assert_eq!(blocks_v1.len(), 2);
+
+    // pruning the same snapshot twice yields the same blocks
+    assert_eq!(blocks_v1, pruned_blocks(table_v1, ctx.clone()).await?);
+
+    let table_v2 = append_sample_blocks(&fixture, 3).await?;
+    let table_v2 = FuseTable::try_from_table(table_v2.as_ref())?;
+    let blocks_v2 = pruned_blocks(table_v2, ctx.clone()).await?;
+    assert_eq!(blocks_v2.len(), 4);
+    assert_eq!(blocks_v2, pruned_blocks(table_v2, ctx.clone()).await?);
+
+    // the blocks of the first snapshot are still referenced by the second one,
+    // but they are told apart by the snapshot they are read through
+    for (index_v1, version_v1) in blocks_v1.iter() {
+        let (index_v2, version_v2) = blocks_v2
+            .iter()
+            .find(|(index, _)| index.block_location == index_v1.block_location)
+            .unwrap();
+        assert_eq!(version_v1, version_v2);
+        assert_ne!(index_v1.snapshot_location, index_v2.snapshot_location);
+    }
+
+    Ok(())
+}
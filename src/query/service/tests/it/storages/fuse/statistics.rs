@@ -627,6 +627,7 @@ fn test_reduce_block_meta() -> databend_common_exception::Result<()> {
             None,
             Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
         );
         blocks.push(block_meta);
     }
@@ -14,5 +14,6 @@
 
 mod fuse;
 mod null;
+mod query_history;
 mod statistics;
 mod system;
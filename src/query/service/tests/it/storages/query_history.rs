@@ -0,0 +1,138 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_query::history::QueryHistory;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+async fn query_count(fixture: &TestFixture, sql: &str) -> Result<Scalar> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    Ok(block.get_by_offset(0).value.index(0).unwrap().to_owned())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_flush() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.log.history.on = true;
+    // Flush manually instead of waiting for the background task.
+    config.log.history.interval = 3600;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    fixture
+        .execute_command("SELECT 'query_history_marker'")
+        .await?;
+    QueryHistory::instance().flush().await?;
+
+    let count = query_count(
+        &fixture,
+        "SELECT count(*) FROM system_history.query_history \
+         WHERE query_text = 'SELECT \\'query_history_marker\\'' AND log_type_name = 'Finish'",
+    )
+    .await?;
+    assert_eq!(count, Scalar::Number(NumberScalar::UInt64(1)));
+
+    // The queries issued by the flush are not recorded.
+    QueryHistory::instance().flush().await?;
+    let count = query_count(
+        &fixture,
+        "SELECT count(*) FROM system_history.query_history \
+         WHERE handler_type = 'HTTPAPI(QueryHistory)'",
+    )
+    .await?;
+    assert_eq!(count, Scalar::Number(NumberScalar::UInt64(0)));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_flush_failure() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.log.history.on = true;
+    config.log.history.interval = 3600;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    QueryHistory::instance().flush().await?;
+    fixture
+        .execute_command("DROP DATABASE system_history")
+        .await?;
+
+    // The insert fails without the table, the logs are kept for the next flush.
+    fixture
+        .execute_command("SELECT 'query_history_retry_marker'")
+        .await?;
+    assert!(QueryHistory::instance().flush().await.is_err());
+    // The table is created again by the next flush.
+    QueryHistory::instance().flush().await?;
+
+    let count = query_count(
+        &fixture,
+        "SELECT count(*) FROM system_history.query_history \
+         WHERE query_text = 'SELECT \\'query_history_retry_marker\\'' AND log_type_name = 'Finish'",
+    )
+    .await?;
+    assert_eq!(count, Scalar::Number(NumberScalar::UInt64(1)));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_drop_oldest() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.log.history.on = true;
+    config.log.history.interval = 3600;
+    // Only the logs of the last query are kept.
+    config.query.max_query_log_size = 2;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    QueryHistory::instance().flush().await?;
+    fixture
+        .execute_command("DROP DATABASE system_history")
+        .await?;
+
+    for marker in ["query_history_dropped_marker", "query_history_kept_marker"] {
+        fixture
+            .execute_command(&format!("SELECT '{marker}'"))
+            .await?;
+    }
+    assert!(QueryHistory::instance().flush().await.is_err());
+    QueryHistory::instance().flush().await?;
+
+    let count = query_count(
+        &fixture,
+        "SELECT count(*) FROM system_history.query_history \
+         WHERE query_text = 'SELECT \\'query_history_dropped_marker\\''",
+    )
+    .await?;
+    assert_eq!(count, Scalar::Number(NumberScalar::UInt64(0)));
+
+    let count = query_count(
+        &fixture,
+        "SELECT count(*) FROM system_history.query_history \
+         WHERE query_text = 'SELECT \\'query_history_kept_marker\\'' AND log_type_name = 'Finish'",
+    )
+    .await?;
+    assert_eq!(count, Scalar::Number(NumberScalar::UInt64(1)));
+
+    Ok(())
+}
@@ -422,6 +422,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_bitmap_semi_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables probing semi joins on a dense integer key with a bitmap of the build keys instead of a hash table.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -464,6 +471,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("broadcast_join_build_rows_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Broadcasts the build side of a join if its estimated rows are below this threshold, and shuffles both sides otherwise. Setting it to 0 leaves the choice to the cost model.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("single_node_rows_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Executes a query on the local node only if the estimated rows of all its operators are below this threshold. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -556,6 +577,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=100)),
                 }),
+                ("aggregate_spilling_arena_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum bytes of the arena of the group keys and states that an aggregator can use before spilling data to storage, 0 means unlimited.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("aggregate_max_partition_count", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of partitions of the hash table of an aggregator, rounded down to a power of two, fewer partitions flush fewer buffers at the same time, 0 means no limit.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1024)),
+                }),
                 ("window_partition_spilling_memory_ratio", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum memory ratio in bytes that a window partitioner can use before spilling data to storage during query execution.",
@@ -877,6 +912,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=256)),
                 }),
+                ("udf_error_policy", DefaultSettingValue {
+                    value: UserSettingValue::String("abort".to_owned()),
+                    desc: "Sets how to handle a row that fails in a UDF. 'abort' fails the query, 'null' returns NULL for the failed row.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::String(vec!["abort".into(), "null".into()])),
+                }),
                 ("enable_parquet_prewhere", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables parquet prewhere",
@@ -884,6 +926,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_fuse_prewhere", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reading the filtered columns of the Fuse tables in Parquet format first, the other columns are only fetched for the rows passing the filter. It is skipped if the filter reads more than half of the columns.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_aggregate_hashtable", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables experimental aggregate hashtable",
@@ -891,6 +940,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("aggregate_hash_seed", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the seed mixed into the group hashes of the aggregate hashtable, which also decide the partitions the groups are shuffled to. It must be the same on all the nodes of the cluster. Setting it to 0 keeps the unseeded hashes.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("numeric_cast_option", DefaultSettingValue {
                     value: UserSettingValue::String("rounding".to_string()),
                     desc: "Set numeric cast mode as \"rounding\" or \"truncating\".",
@@ -921,7 +977,7 @@ impl DefaultSettings {
                 }),
                 ("query_flight_compression", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("LZ4")),
-                    desc: "flight compression method",
+                    desc: "Sets the compression method (None, LZ4 or ZSTD) of the data exchanged between nodes, None disables the compression.",
                     mode: SettingMode::Both,
                     scope: SettingScope::Both,
                     range: Some(SettingRange::String(vec!["None".into(), "LZ4".into(), "ZSTD".into()])),
@@ -954,6 +1010,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("convert_tz_null_on_dst_transition", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "convert_tz returns NULL for times skipped or repeated by a DST transition of the source timezone, instead of resolving them to the DST-adjusted time.(disable by default)",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_strict_datetime_parser", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Strict datetime parser. Only support ISO 8601 as Default format.The best practice is to turn this parameter on.(enable by default)",
@@ -1179,6 +1242,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_fast_pruning", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Prunes the blocks of fuse tables by the min/max statistics only, without reading the bloom index.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("copy_dedup_full_path_by_default", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "The default value if table option `copy_dedup_full_path` is not set when creating table.",
@@ -357,6 +357,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_bitmap_semi_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_bitmap_semi_join")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -369,6 +373,14 @@ impl Settings {
         Ok(self.try_get_u64("enforce_shuffle_join")? != 0)
     }
 
+    pub fn get_broadcast_join_build_rows_threshold(&self) -> Result<u64> {
+        self.try_get_u64("broadcast_join_build_rows_threshold")
+    }
+
+    pub fn get_single_node_rows_threshold(&self) -> Result<u64> {
+        self.try_get_u64("single_node_rows_threshold")
+    }
+
     pub fn get_enable_merge_into_row_fetch(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_merge_into_row_fetch")? != 0)
     }
@@ -458,6 +470,14 @@ impl Settings {
         Ok(self.try_get_u64("aggregate_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_aggregate_spilling_arena_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("aggregate_spilling_arena_bytes")? as usize)
+    }
+
+    pub fn get_aggregate_max_partition_count(&self) -> Result<usize> {
+        Ok(self.try_get_u64("aggregate_max_partition_count")? as usize)
+    }
+
     pub fn get_window_partition_spilling_to_disk_bytes_limit(&self) -> Result<usize> {
         Ok(self.try_get_u64("window_partition_spilling_to_disk_bytes_limit")? as usize)
     }
@@ -498,6 +518,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
 
+    pub fn get_aggregate_hash_seed(&self) -> Result<u64> {
+        self.try_get_u64("aggregate_hash_seed")
+    }
+
     pub fn get_lazy_read_threshold(&self) -> Result<u64> {
         self.try_get_u64("lazy_read_threshold")
     }
@@ -659,6 +683,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
 
+    pub fn get_enable_fuse_prewhere(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_fuse_prewhere")? != 0)
+    }
+
     pub fn get_numeric_cast_option(&self) -> Result<String> {
         self.try_get_string("numeric_cast_option")
     }
@@ -698,6 +726,10 @@ impl Settings {
         self.try_get_u64("external_server_request_retry_times")
     }
 
+    pub fn get_udf_error_policy(&self) -> Result<String> {
+        self.try_get_string("udf_error_policy")
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
@@ -734,6 +766,10 @@ impl Settings {
     pub fn get_enable_dst_hour_fix(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dst_hour_fix")? != 0)
     }
+
+    pub fn get_convert_tz_null_on_dst_transition(&self) -> Result<bool> {
+        Ok(self.try_get_u64("convert_tz_null_on_dst_transition")? != 0)
+    }
     pub fn get_disable_variant_check(&self) -> Result<bool> {
         Ok(self.try_get_u64("disable_variant_check")? != 0)
     }
@@ -831,6 +867,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_prune_cache")? == 1)
     }
 
+    pub fn get_enable_fast_pruning(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_fast_pruning")? == 1)
+    }
+
     pub fn get_enable_distributed_pruning(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_distributed_pruning")? == 1)
     }
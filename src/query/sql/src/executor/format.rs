@@ -32,8 +32,10 @@ use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AggregateSorted;
 use crate::executor::physical_plans::AsyncFunction;
 use crate::executor::physical_plans::CacheScan;
+use crate::executor::physical_plans::Checksum;
 use crate::executor::physical_plans::ColumnMutation;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::ConstantTableScan;
@@ -49,6 +51,7 @@ use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::FragmentKind;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationManipulate;
 use crate::executor::physical_plans::MutationOrganize;
@@ -59,6 +62,7 @@ use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortedFirstRows;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -70,6 +74,7 @@ use crate::planner::Metadata;
 use crate::planner::MetadataRef;
 use crate::planner::DUMMY_TABLE_INDEX;
 use crate::plans::CacheSource;
+use crate::IndexType;
 
 impl PhysicalPlan {
     pub fn format(
@@ -190,7 +195,7 @@ impl PhysicalPlan {
     }
 }
 
-// The method will only collect scan,filter and join nodes
+// The method will only collect scan, filter, join and the row reducing nodes
 // It's only used to debug cardinality estimator.
 #[recursive::recursive]
 pub fn format_partial_tree(
@@ -245,6 +250,19 @@ pub fn format_partial_tree(
                 children,
             ))
         }
+        PhysicalPlan::SortedFirstRows(plan) => {
+            let mut children = vec![];
+            if let Some(info) = &plan.stat_info {
+                let items = plan_stats_info_to_format_tree(info);
+                children.extend(items);
+            }
+            append_output_rows_info(&mut children, profs, plan.plan_id);
+            children.push(format_partial_tree(&plan.input, metadata, profs)?);
+            Ok(FormatTreeNode::with_children(
+                "SortedFirstRows".to_string(),
+                children,
+            ))
+        }
         PhysicalPlan::HashJoin(plan) => {
             let build_child = format_partial_tree(&plan.build, metadata, profs)?;
             let probe_child = format_partial_tree(&plan.probe, metadata, profs)?;
@@ -254,6 +272,12 @@ pub fn format_partial_tree(
                 children.extend(items);
             }
             append_output_rows_info(&mut children, profs, plan.plan_id);
+            append_statistics_info(
+                &mut children,
+                profs,
+                plan.plan_id,
+                "bitmap semi join build keys",
+            );
             children.push(build_child);
             children.push(probe_child);
 
@@ -351,12 +375,20 @@ fn to_format_tree(
             aggregate_partial_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AggregateSorted(plan) => {
+            aggregate_sorted_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::MatchRecognize(plan) => match_recognize_to_format_tree(plan, metadata, profs),
         PhysicalPlan::WindowPartition(plan) => {
             window_partition_to_format_tree(plan, metadata, profs)
         }
+        PhysicalPlan::SortedFirstRows(plan) => {
+            sorted_first_rows_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Checksum(plan) => checksum_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profs),
@@ -511,10 +543,19 @@ fn append_output_rows_info(
     children: &mut Vec<FormatTreeNode<String>>,
     profs: &HashMap<u32, PlanProfile>,
     plan_id: u32,
+) {
+    append_statistics_info(children, profs, plan_id, "output rows");
+}
+
+fn append_statistics_info(
+    children: &mut Vec<FormatTreeNode<String>>,
+    profs: &HashMap<u32, PlanProfile>,
+    plan_id: u32,
+    display_name: &str,
 ) {
     if let Some(prof) = profs.get(&plan_id) {
         for (_, desc) in get_statistics_desc().iter() {
-            if desc.display_name != "output rows" {
+            if desc.display_name != display_name {
                 continue;
             }
             if prof.statistics[desc.index] != 0 {
@@ -1191,6 +1232,52 @@ fn aggregate_final_to_format_tree(
     ))
 }
 
+fn aggregate_sorted_to_format_tree(
+    plan: &AggregateSorted,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| {
+            let name = metadata.column(index).name();
+            Ok(name)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregateSorted".to_string(),
+        children,
+    ))
+}
+
 fn window_to_format_tree(
     plan: &Window,
     metadata: &Metadata,
@@ -1245,6 +1332,45 @@ fn window_to_format_tree(
     ))
 }
 
+fn match_recognize_to_format_tree(
+    plan: &MatchRecognize,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!(
+            "pattern: [{}]",
+            plan.pattern.display(&plan.variables)
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MatchRecognize".to_string(),
+        children,
+    ))
+}
+
 fn sort_to_format_tree(
     plan: &Sort,
     metadata: &Metadata,
@@ -1330,6 +1456,49 @@ fn window_partition_to_format_tree(
     ))
 }
 
+fn sorted_first_rows_to_format_tree(
+    plan: &SortedFirstRows,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let column_names = |columns: &[IndexType]| {
+        columns
+            .iter()
+            .map(|&index| metadata.column(index).name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{}]", column_names(&plan.group_by))),
+    ];
+
+    if !plan.ties_by.is_empty() {
+        children.push(FormatTreeNode::new(format!(
+            "ties by: [{}]",
+            column_names(&plan.ties_by)
+        )));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SortedFirstRows".to_string(),
+        children,
+    ))
+}
+
 fn limit_to_format_tree(
     plan: &Limit,
     metadata: &Metadata,
@@ -1360,6 +1529,23 @@ fn limit_to_format_tree(
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
 
+fn checksum_to_format_tree(
+    plan: &Checksum,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Checksum".to_string(),
+        children,
+    ))
+}
+
 fn row_fetch_to_format_tree(
     plan: &RowFetch,
     metadata: &Metadata,
@@ -1761,6 +1947,10 @@ fn project_set_to_format_tree(
             .join(", ")
     ))]);
 
+    if plan.outer {
+        children.push(FormatTreeNode::new("outer: true".to_string()));
+    }
+
     children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
 
     Ok(FormatTreeNode::with_children(
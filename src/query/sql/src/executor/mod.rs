@@ -16,6 +16,9 @@ mod explain;
 mod format;
 mod physical_plan;
 mod physical_plan_builder;
+mod physical_plan_cost;
+mod physical_plan_diff;
+mod physical_plan_validate;
 mod physical_plan_visitor;
 pub mod physical_plans;
 mod util;
@@ -26,5 +29,9 @@ pub use format::format_partial_tree;
 pub use physical_plan::PhysicalPlan;
 pub use physical_plan_builder::MutationBuildInfo;
 pub use physical_plan_builder::PhysicalPlanBuilder;
+pub use physical_plan_builder::PlanDecision;
+pub use physical_plan_cost::QueryCost;
+pub use physical_plan_diff::diff;
+pub use physical_plan_diff::PlanDiff;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
 pub use util::*;
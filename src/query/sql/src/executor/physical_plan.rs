@@ -33,8 +33,10 @@ use super::physical_plans::MutationSplit;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AggregateSorted;
 use crate::executor::physical_plans::AsyncFunction;
 use crate::executor::physical_plans::CacheScan;
+use crate::executor::physical_plans::Checksum;
 use crate::executor::physical_plans::ChunkAppendData;
 use crate::executor::physical_plans::ChunkCastSchema;
 use crate::executor::physical_plans::ChunkCommitInsert;
@@ -59,6 +61,7 @@ use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
@@ -70,6 +73,7 @@ use crate::executor::physical_plans::ReplaceInto;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Shuffle;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortedFirstRows;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -90,10 +94,14 @@ pub enum PhysicalPlan {
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
+    AggregateSorted(AggregateSorted),
     Window(Window),
+    MatchRecognize(MatchRecognize),
     Sort(Sort),
+    SortedFirstRows(SortedFirstRows),
     WindowPartition(WindowPartition),
     Limit(Limit),
+    Checksum(Checksum),
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
@@ -165,6 +173,7 @@ impl PhysicalPlan {
             PhysicalPlan::AsyncFunction(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
             }
             PhysicalPlan::TableScan(plan) => {
                 plan.plan_id = *next_id;
@@ -200,11 +209,26 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::AggregateSorted(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::Window(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::SortedFirstRows(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::MatchRecognize(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::WindowPartition(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -220,6 +244,11 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::Checksum(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::RowFetch(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -419,10 +448,14 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(v) => v.plan_id,
             PhysicalPlan::AggregatePartial(v) => v.plan_id,
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
+            PhysicalPlan::AggregateSorted(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
+            PhysicalPlan::MatchRecognize(v) => v.plan_id,
             PhysicalPlan::WindowPartition(v) => v.plan_id,
+            PhysicalPlan::SortedFirstRows(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
+            PhysicalPlan::Checksum(v) => v.plan_id,
             PhysicalPlan::RowFetch(v) => v.plan_id,
             PhysicalPlan::HashJoin(v) => v.plan_id,
             PhysicalPlan::RangeJoin(v) => v.plan_id,
@@ -473,10 +506,14 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
+            PhysicalPlan::AggregateSorted(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
+            PhysicalPlan::MatchRecognize(plan) => plan.output_schema(),
             PhysicalPlan::WindowPartition(plan) => plan.output_schema(),
+            PhysicalPlan::SortedFirstRows(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
+            PhysicalPlan::Checksum(plan) => plan.output_schema(),
             PhysicalPlan::RowFetch(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
@@ -534,10 +571,14 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(_) => "AggregateExpand".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
+            PhysicalPlan::AggregateSorted(_) => "AggregateSorted".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
+            PhysicalPlan::MatchRecognize(_) => "MatchRecognize".to_string(),
             PhysicalPlan::WindowPartition(_) => "WindowPartition".to_string(),
+            PhysicalPlan::SortedFirstRows(_) => "SortedFirstRows".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
+            PhysicalPlan::Checksum(_) => "Checksum".to_string(),
             PhysicalPlan::RowFetch(_) => "RowFetch".to_string(),
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
@@ -596,10 +637,14 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::AggregateSorted(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MatchRecognize(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::WindowPartition(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SortedFirstRows(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Checksum(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RowFetch(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
@@ -659,9 +704,12 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::EvalScalar(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Window(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MatchRecognize(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::WindowPartition(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::SortedFirstRows(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Sort(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Limit(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Checksum(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Exchange(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::ExchangeSink(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.input.try_find_single_data_source(),
@@ -676,6 +724,7 @@ impl PhysicalPlan {
             | PhysicalPlan::RangeJoin(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
+            | PhysicalPlan::AggregateSorted(_)
             | PhysicalPlan::AggregatePartial(_)
             | PhysicalPlan::CompactSource(_)
             | PhysicalPlan::CommitSink(_)
@@ -741,6 +790,9 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(v) => {
                 v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
             }
+            PhysicalPlan::AggregateSorted(v) => {
+                v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
+            }
             PhysicalPlan::Sort(v) => v
                 .order_by
                 .iter()
@@ -826,6 +878,9 @@ impl PhysicalPlan {
 
                 format!("partition by {}, order by {}", partition_by, order_by)
             }
+            PhysicalPlan::MatchRecognize(v) => {
+                format!("pattern ({})", v.pattern.display(&v.variables))
+            }
             PhysicalPlan::RowFetch(v) => {
                 let table_schema = v.source.source_info.schema();
                 let projected_schema = v.cols_to_fetch.project_schema(&table_schema);
@@ -953,6 +1008,18 @@ impl PhysicalPlan {
                     );
                 }
             }
+            PhysicalPlan::AggregateSorted(v) => {
+                if !v.group_by_display.is_empty() {
+                    labels.insert(String::from("Grouping keys"), v.group_by_display.clone());
+                }
+
+                if !v.agg_funcs.is_empty() {
+                    labels.insert(
+                        String::from("Aggregate Functions"),
+                        v.agg_funcs.iter().map(|x| x.display.clone()).collect(),
+                    );
+                }
+            }
             PhysicalPlan::HashJoin(v) => {
                 labels.insert(String::from("Join Type"), vec![v.join_type.to_string()]);
 
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PartStatistics;
@@ -39,6 +41,30 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // DataMutation info, used to build MergeInto physical plan
     pub(crate) mutation_build_info: Option<MutationBuildInfo>,
+    // The decisions made while building the plan, only recorded for `EXPLAIN(DECISIONS)`.
+    pub(crate) decisions: Option<Vec<PlanDecision>>,
+    // The depth of the nested `build` calls, the plan ids are only adjusted by the outermost one.
+    build_depth: usize,
+}
+
+/// Why a physical operator was chosen, or left out, while lowering the optimized
+/// `SExpr` into the physical plan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanDecision {
+    /// The name of the physical operator the decision is about.
+    pub plan: String,
+    /// The id of the physical operator, `None` if the operator was left out.
+    pub plan_id: Option<u32>,
+    pub reason: String,
+}
+
+impl Display for PlanDecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.plan_id {
+            Some(plan_id) => write!(f, "{} #{}: {}", self.plan, plan_id, self.reason),
+            None => write!(f, "{}: {}", self.plan, self.reason),
+        }
+    }
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +76,67 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             mutation_build_info: None,
+            decisions: None,
+            build_depth: 0,
+        }
+    }
+
+    /// Records the decisions made by the following builds, see `take_decisions`.
+    pub fn record_decisions(&mut self) {
+        self.decisions = Some(vec![]);
+    }
+
+    /// Takes the decisions recorded so far, in the order they were made.
+    pub fn take_decisions(&mut self) -> Vec<PlanDecision> {
+        self.decisions
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records a decision about a physical operator that is left out of the plan.
+    pub(crate) fn add_decision(&mut self, plan: &str, reason: impl FnOnce() -> String) {
+        if let Some(decisions) = self.decisions.as_mut() {
+            decisions.push(PlanDecision {
+                plan: plan.to_string(),
+                plan_id: None,
+                reason: reason(),
+            });
+        }
+    }
+
+    /// Records a decision about a physical operator that is built into the plan, the returned
+    /// id must be set as the `plan_id` of the operator, and is replaced by its final plan id
+    /// once the plan is built. Returns 0 if the decisions are not recorded.
+    pub(crate) fn add_plan_decision(&mut self, plan: &str, reason: impl FnOnce() -> String) -> u32 {
+        match self.decisions.as_mut() {
+            Some(decisions) => {
+                // The plan ids are all 0 before they are adjusted, so the index starts from 1.
+                let plan_id = decisions.len() as u32 + 1;
+                decisions.push(PlanDecision {
+                    plan: plan.to_string(),
+                    plan_id: Some(plan_id),
+                    reason: reason(),
+                });
+                plan_id
+            }
+            None => 0,
+        }
+    }
+
+    // Replaces the temporary plan ids of the decisions made since `start` by the adjusted ones,
+    // `old_ids` and `new_ids` are collected by `collect_plan_ids` before and after `adjust_plan_id`.
+    fn adjust_decision_plan_ids(&mut self, start: usize, old_ids: &[u32], new_ids: &[u32]) {
+        let Some(decisions) = self.decisions.as_mut() else {
+            return;
+        };
+        for decision in decisions.iter_mut().skip(start) {
+            if let Some(plan_id) = decision.plan_id {
+                decision.plan_id = old_ids
+                    .iter()
+                    .position(|id| *id == plan_id)
+                    .map(|index| new_ids[index]);
+            }
         }
     }
 
@@ -63,8 +150,25 @@ impl PhysicalPlanBuilder {
     }
 
     pub async fn build(&mut self, s_expr: &SExpr, required: ColumnSet) -> Result<PhysicalPlan> {
-        let mut plan = self.build_physical_plan(s_expr, required).await?;
+        let decisions_start = self.decisions.as_ref().map_or(0, Vec::len);
+        self.build_depth += 1;
+        let plan = self.build_physical_plan(s_expr, required).await;
+        self.build_depth -= 1;
+        let mut plan = plan?;
+        if self.build_depth > 0 {
+            return Ok(plan);
+        }
+
+        if self.decisions.is_none() {
+            plan.adjust_plan_id(&mut 0);
+            return Ok(plan);
+        }
+        let mut old_ids = vec![];
+        collect_plan_ids(&plan, &mut old_ids);
         plan.adjust_plan_id(&mut 0);
+        let mut new_ids = vec![];
+        collect_plan_ids(&plan, &mut new_ids);
+        self.adjust_decision_plan_ids(decisions_start, &old_ids, &new_ids);
 
         Ok(plan)
     }
@@ -94,6 +198,10 @@ impl PhysicalPlanBuilder {
             RelOperator::Window(window) => {
                 self.build_window(s_expr, window, required, stat_info).await
             }
+            RelOperator::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(s_expr, match_recognize, required, stat_info)
+                    .await
+            }
             RelOperator::Sort(sort) => self.build_sort(s_expr, sort, required, stat_info).await,
             RelOperator::Limit(limit) => self.build_limit(s_expr, limit, required, stat_info).await,
             RelOperator::Exchange(exchange) => {
@@ -153,3 +261,11 @@ pub struct MutationBuildInfo {
     pub statistics: PartStatistics,
     pub table_meta_timestamps: TableMetaTimestamps,
 }
+
+// Collects the plan ids in pre-order.
+fn collect_plan_ids(plan: &PhysicalPlan, ids: &mut Vec<u32>) {
+    ids.push(plan.get_id());
+    for child in plan.children() {
+        collect_plan_ids(child, ids);
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use jsonb::Value as JsonbValue;
+
+use crate::executor::PhysicalPlan;
+
+/// The estimated cost of a query, computed from its physical plan built in dry run mode,
+/// so the partitions are pruned but not read.
+///
+/// The costs are in the units of the cost model of the optimizer, weighted by the
+/// `cost_factor_*` settings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryCost {
+    pub bytes_scanned: u64,
+    pub rows_scanned: u64,
+    pub estimated_rows_output: f64,
+    pub join_cost: f64,
+    pub sort_cost: f64,
+    pub total_cost_units: f64,
+}
+
+impl QueryCost {
+    pub fn estimate(ctx: &dyn TableContext, plan: &PhysicalPlan) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let mut estimator = CostEstimator {
+            compute_per_row: 1.0,
+            hash_table_per_row: settings.get_cost_factor_hash_table_per_row()? as f64,
+            cost: QueryCost::default(),
+        };
+        estimator.visit(plan);
+
+        let mut cost = estimator.cost;
+        cost.estimated_rows_output = estimated_rows(plan);
+        cost.total_cost_units =
+            cost.rows_scanned as f64 * estimator.compute_per_row + cost.join_cost + cost.sort_cost;
+        Ok(cost)
+    }
+
+    pub fn to_jsonb_value(&self) -> JsonbValue<'static> {
+        JsonbValue::Object(
+            [
+                ("bytes_scanned", JsonbValue::from(self.bytes_scanned)),
+                ("rows_scanned", JsonbValue::from(self.rows_scanned)),
+                (
+                    "estimated_rows_output",
+                    JsonbValue::from(self.estimated_rows_output),
+                ),
+                ("join_cost", JsonbValue::from(self.join_cost)),
+                ("sort_cost", JsonbValue::from(self.sort_cost)),
+                ("total_cost_units", JsonbValue::from(self.total_cost_units)),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        )
+    }
+}
+
+struct CostEstimator {
+    compute_per_row: f64,
+    hash_table_per_row: f64,
+    cost: QueryCost,
+}
+
+impl CostEstimator {
+    #[recursive::recursive]
+    fn visit(&mut self, plan: &PhysicalPlan) {
+        match plan {
+            PhysicalPlan::TableScan(scan) => {
+                let statistics = &scan.source.statistics;
+                self.cost.bytes_scanned += statistics.read_bytes as u64;
+                self.cost.rows_scanned += statistics.read_rows as u64;
+            }
+            PhysicalPlan::HashJoin(join) => {
+                // The same as the cost model of the optimizer, the build side is inserted
+                // into the hash table and the probe side is computed.
+                self.cost.join_cost += estimated_rows(&join.build) * self.hash_table_per_row
+                    + estimated_rows(&join.probe) * self.compute_per_row;
+            }
+            PhysicalPlan::RangeJoin(join) => {
+                // Both sides are sorted by the range condition.
+                self.cost.join_cost += self.sort_cost(estimated_rows(&join.left))
+                    + self.sort_cost(estimated_rows(&join.right));
+            }
+            PhysicalPlan::Sort(sort) => {
+                self.cost.sort_cost += self.sort_cost(estimated_rows(&sort.input));
+            }
+            _ => {}
+        }
+
+        for child in plan.children() {
+            self.visit(child);
+        }
+    }
+
+    fn sort_cost(&self, rows: f64) -> f64 {
+        rows * rows.max(2.0).log2() * self.compute_per_row
+    }
+}
+
+/// The estimated rows of a plan, the operators without the stats info,
+/// like the exchanges, output as many rows as their input.
+fn estimated_rows(plan: &PhysicalPlan) -> f64 {
+    let stat_info = match plan {
+        PhysicalPlan::TableScan(plan) => &plan.stat_info,
+        PhysicalPlan::Filter(plan) => &plan.stat_info,
+        PhysicalPlan::EvalScalar(plan) => &plan.stat_info,
+        PhysicalPlan::ProjectSet(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateExpand(plan) => &plan.stat_info,
+        PhysicalPlan::AggregatePartial(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateFinal(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateSorted(plan) => &plan.stat_info,
+        PhysicalPlan::MatchRecognize(plan) => &plan.stat_info,
+        PhysicalPlan::Sort(plan) => &plan.stat_info,
+        PhysicalPlan::WindowPartition(plan) => &plan.stat_info,
+        PhysicalPlan::SortedFirstRows(plan) => &plan.stat_info,
+        PhysicalPlan::Limit(plan) => &plan.stat_info,
+        PhysicalPlan::RowFetch(plan) => &plan.stat_info,
+        PhysicalPlan::HashJoin(plan) => &plan.stat_info,
+        PhysicalPlan::RangeJoin(plan) => &plan.stat_info,
+        PhysicalPlan::UnionAll(plan) => &plan.stat_info,
+        PhysicalPlan::Udf(plan) => &plan.stat_info,
+        PhysicalPlan::AsyncFunction(plan) => &plan.stat_info,
+        PhysicalPlan::ConstantTableScan(plan) => return plan.num_rows as f64,
+        _ => &None,
+    };
+    match stat_info {
+        Some(info) => info.estimated_rows,
+        None => plan.children().next().map_or(0.0, estimated_rows),
+    }
+}
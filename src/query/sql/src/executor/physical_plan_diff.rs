@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::executor::PhysicalPlan;
+
+/// A structural difference between two physical plans.
+///
+/// Positions are the pre-order indexes of the nodes, the same numbering
+/// `PhysicalPlan::adjust_plan_id` assigns, so they do not depend on the `plan_id`
+/// already stored in the plans.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanDiff {
+    /// A node of the right plan which has no counterpart in the left plan.
+    Added { position: u32, name: String },
+    /// A node of the left plan which has no counterpart in the right plan.
+    Removed { position: u32, name: String },
+    /// A pair of nodes at the same place of both plans with a different
+    /// operator or different key attributes.
+    Changed {
+        left_position: u32,
+        right_position: u32,
+        left: String,
+        right: String,
+    },
+}
+
+/// Compute the structural diff from plan `a` to plan `b`.
+///
+/// Nodes are compared by operator name and description (e.g. the filter predicate or
+/// the sort keys). A single node inserted above or removed from a subtree is reported
+/// as `Added` / `Removed`, and the subtrees below it are still matched against each other.
+pub fn diff(a: &PhysicalPlan, b: &PhysicalPlan) -> Vec<PlanDiff> {
+    let mut diffs = vec![];
+    let mut left_position = 0;
+    let mut right_position = 0;
+    diff_node(a, b, &mut left_position, &mut right_position, &mut diffs);
+    diffs
+}
+
+fn describe(plan: &PhysicalPlan) -> String {
+    match plan.get_desc() {
+        Ok(desc) if !desc.is_empty() => format!("{}({})", plan.name(), desc),
+        _ => plan.name(),
+    }
+}
+
+fn diff_node(
+    a: &PhysicalPlan,
+    b: &PhysicalPlan,
+    left_position: &mut u32,
+    right_position: &mut u32,
+    diffs: &mut Vec<PlanDiff>,
+) {
+    if a.name() != b.name() {
+        // `b` has an extra node above a subtree matching `a`.
+        if let Some(child) = single_child(b).filter(|child| child.name() == a.name()) {
+            diffs.push(PlanDiff::Added {
+                position: *right_position,
+                name: describe(b),
+            });
+            *right_position += 1;
+            return diff_node(a, child, left_position, right_position, diffs);
+        }
+        // `a` has an extra node above a subtree matching `b`.
+        if let Some(child) = single_child(a).filter(|child| child.name() == b.name()) {
+            diffs.push(PlanDiff::Removed {
+                position: *left_position,
+                name: describe(a),
+            });
+            *left_position += 1;
+            return diff_node(child, b, left_position, right_position, diffs);
+        }
+    }
+
+    let (left, right) = (describe(a), describe(b));
+    if left != right {
+        diffs.push(PlanDiff::Changed {
+            left_position: *left_position,
+            right_position: *right_position,
+            left,
+            right,
+        });
+    }
+    *left_position += 1;
+    *right_position += 1;
+
+    let mut left_children = a.children();
+    let mut right_children = b.children();
+    loop {
+        match (left_children.next(), right_children.next()) {
+            (Some(a), Some(b)) => diff_node(a, b, left_position, right_position, diffs),
+            (Some(a), None) => removed_subtree(a, left_position, diffs),
+            (None, Some(b)) => added_subtree(b, right_position, diffs),
+            (None, None) => break,
+        }
+    }
+}
+
+fn single_child(plan: &PhysicalPlan) -> Option<&PhysicalPlan> {
+    let mut children = plan.children();
+    match (children.next(), children.next()) {
+        (Some(child), None) => Some(child),
+        _ => None,
+    }
+}
+
+fn added_subtree(plan: &PhysicalPlan, position: &mut u32, diffs: &mut Vec<PlanDiff>) {
+    diffs.push(PlanDiff::Added {
+        position: *position,
+        name: describe(plan),
+    });
+    *position += 1;
+    for child in plan.children() {
+        added_subtree(child, position, diffs);
+    }
+}
+
+fn removed_subtree(plan: &PhysicalPlan, position: &mut u32, diffs: &mut Vec<PlanDiff>) {
+    diffs.push(PlanDiff::Removed {
+        position: *position,
+        name: describe(plan),
+    });
+    *position += 1;
+    for child in plan.children() {
+        removed_subtree(child, position, diffs);
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::physical_plans::AggregateFinal;
+use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AggregateSorted;
+use crate::executor::physical_plans::ExchangeSink;
+use crate::executor::physical_plans::ExchangeSource;
+use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::HashJoin;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+impl PhysicalPlan {
+    /// Check the structural invariants of the plan, which are expected to be held by
+    /// the plans produced by `PhysicalPlanBuilder` (after `adjust_plan_id`) and the fragmenter:
+    ///
+    /// - Every node has a unique plan id, only the root may have the id 0.
+    /// - The schemas of both sides of a hash join can be resolved, and contain the
+    ///   columns projected by the join; the join keys are paired.
+    /// - The group by keys and aggregate functions of an aggregation are consistent
+    ///   with its input, and the final aggregation matches the partial one.
+    /// - An exchange is either an `Exchange`, or an `ExchangeSink` at the root of a
+    ///   fragment with `ExchangeSource`s as leaves, they are never mixed.
+    pub fn validate(&self) -> Result<()> {
+        let mut validator = PlanValidator::default();
+        validator.validate(self, true)
+    }
+
+    /// Check the fragments of a distributed plan, given as `(fragment_id, plan)` pairs.
+    ///
+    /// Besides the invariants of `validate` of each fragment, every `ExchangeSource`
+    /// must read from a fragment whose `ExchangeSink` sends to the fragment of the
+    /// source, with the same schema.
+    pub fn validate_fragments(fragments: &[(usize, &PhysicalPlan)]) -> Result<()> {
+        let sinks = fragments
+            .iter()
+            .filter_map(|(fragment_id, plan)| match plan {
+                PhysicalPlan::ExchangeSink(sink) => Some((*fragment_id, sink)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        for (fragment_id, plan) in fragments {
+            plan.validate()?;
+
+            let mut sources = vec![];
+            collect_exchange_sources(plan, &mut sources);
+            for source in sources {
+                let Some(sink) = sinks.get(&source.source_fragment_id) else {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid physical plan: ExchangeSource #{} reads from fragment {} which has no ExchangeSink",
+                        source.plan_id, source.source_fragment_id
+                    )));
+                };
+                if sink.destination_fragment_id != *fragment_id {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid physical plan: ExchangeSource #{} of fragment {} reads from ExchangeSink #{} which sends to fragment {}",
+                        source.plan_id, fragment_id, sink.plan_id, sink.destination_fragment_id
+                    )));
+                }
+                if sink.schema != source.schema {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid physical plan: the schema of ExchangeSource #{} doesn't match its ExchangeSink #{}",
+                        source.plan_id, sink.plan_id
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[recursive::recursive]
+fn collect_exchange_sources<'a>(plan: &'a PhysicalPlan, sources: &mut Vec<&'a ExchangeSource>) {
+    if let PhysicalPlan::ExchangeSource(source) = plan {
+        sources.push(source);
+    }
+    for child in plan.children() {
+        collect_exchange_sources(child, sources);
+    }
+}
+
+#[derive(Default)]
+struct PlanValidator {
+    // The name of the node of each plan id.
+    plan_ids: HashMap<u32, String>,
+    has_exchange: bool,
+    has_fragment_exchange: bool,
+}
+
+impl PlanValidator {
+    #[recursive::recursive]
+    fn validate(&mut self, plan: &PhysicalPlan, is_root: bool) -> Result<()> {
+        self.validate_plan_id(plan, is_root)?;
+
+        match plan {
+            PhysicalPlan::HashJoin(join) => Self::validate_hash_join(join)?,
+            PhysicalPlan::AggregatePartial(agg) => Self::validate_aggregate_partial(agg)?,
+            PhysicalPlan::AggregateFinal(agg) => Self::validate_aggregate_final(agg)?,
+            PhysicalPlan::AggregateSorted(agg) => Self::validate_aggregate_sorted(agg)?,
+            PhysicalPlan::Exchange(_) => self.has_exchange = true,
+            PhysicalPlan::ExchangeSource(_) => self.has_fragment_exchange = true,
+            PhysicalPlan::ExchangeSink(sink) => {
+                if !is_root {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid physical plan: ExchangeSink #{} is not the root of a fragment",
+                        sink.plan_id
+                    )));
+                }
+                self.has_fragment_exchange = true;
+                Self::validate_exchange_sink(sink)?;
+            }
+            _ => {}
+        }
+        if self.has_exchange && self.has_fragment_exchange {
+            return Err(ErrorCode::Internal(
+                "Invalid physical plan: Exchange is mixed with ExchangeSink or ExchangeSource of fragments",
+            ));
+        }
+
+        for child in plan.children() {
+            self.validate(child, false)?;
+        }
+        Ok(())
+    }
+
+    fn validate_plan_id(&mut self, plan: &PhysicalPlan, is_root: bool) -> Result<()> {
+        let plan_id = plan.get_id();
+        if plan_id == 0 && !is_root {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: the plan id of {} is not assigned",
+                plan.name()
+            )));
+        }
+        if let Some(name) = self.plan_ids.insert(plan_id, plan.name()) {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: duplicate plan id {} of {} and {}",
+                plan_id,
+                name,
+                plan.name()
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_hash_join(join: &HashJoin) -> Result<()> {
+        if join.build_keys.len() != join.probe_keys.len()
+            || join.build_keys.len() != join.is_null_equal.len()
+        {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: HashJoin #{} has {} build keys, {} probe keys and {} null equal flags",
+                join.plan_id,
+                join.build_keys.len(),
+                join.probe_keys.len(),
+                join.is_null_equal.len()
+            )));
+        }
+
+        let build_schema = Self::child_schema(&join.build, join.plan_id, "build side of HashJoin")?;
+        let probe_schema = Self::child_schema(&join.probe, join.plan_id, "probe side of HashJoin")?;
+        Self::check_columns(
+            &build_schema,
+            &join.build_projections,
+            join.plan_id,
+            "build projection of HashJoin",
+        )?;
+        Self::check_columns(
+            &probe_schema,
+            &join.probe_projections,
+            join.plan_id,
+            "probe projection of HashJoin",
+        )
+    }
+
+    fn validate_aggregate_partial(agg: &AggregatePartial) -> Result<()> {
+        Self::check_group_by_display(agg.plan_id, &agg.group_by, &agg.group_by_display)?;
+        let input_schema =
+            Self::child_schema(&agg.input, agg.plan_id, "input of AggregatePartial")?;
+        Self::check_columns(
+            &input_schema,
+            &agg.group_by,
+            agg.plan_id,
+            "group by key of AggregatePartial",
+        )?;
+        for func in agg.agg_funcs.iter() {
+            Self::check_columns(
+                &input_schema,
+                &func.arg_indices,
+                agg.plan_id,
+                "aggregate argument of AggregatePartial",
+            )?;
+        }
+        Ok(())
+    }
+
+    fn validate_aggregate_final(agg: &AggregateFinal) -> Result<()> {
+        Self::check_group_by_display(agg.plan_id, &agg.group_by, &agg.group_by_display)?;
+
+        // The partial aggregation may be below an exchange.
+        let mut input = agg.input.as_ref();
+        while let PhysicalPlan::Exchange(exchange) = input {
+            input = exchange.input.as_ref();
+        }
+        if let PhysicalPlan::AggregatePartial(partial) = input {
+            if partial.group_by != agg.group_by || partial.agg_funcs.len() != agg.agg_funcs.len() {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid physical plan: AggregateFinal #{} has {} group by keys and {} aggregate functions, but its AggregatePartial #{} has {} group by keys and {} aggregate functions",
+                    agg.plan_id,
+                    agg.group_by.len(),
+                    agg.agg_funcs.len(),
+                    partial.plan_id,
+                    partial.group_by.len(),
+                    partial.agg_funcs.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_aggregate_sorted(agg: &AggregateSorted) -> Result<()> {
+        Self::check_group_by_display(agg.plan_id, &agg.group_by, &agg.group_by_display)?;
+        let input_schema = Self::child_schema(&agg.input, agg.plan_id, "input of AggregateSorted")?;
+        Self::check_columns(
+            &input_schema,
+            &agg.group_by,
+            agg.plan_id,
+            "group by key of AggregateSorted",
+        )?;
+        for func in agg.agg_funcs.iter() {
+            Self::check_columns(
+                &input_schema,
+                &func.arg_indices,
+                agg.plan_id,
+                "aggregate argument of AggregateSorted",
+            )?;
+        }
+        Ok(())
+    }
+
+    fn validate_exchange_sink(sink: &ExchangeSink) -> Result<()> {
+        let input_schema = Self::child_schema(&sink.input, sink.plan_id, "input of ExchangeSink")?;
+        if input_schema != sink.schema {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: the schema of ExchangeSink #{} doesn't match its input",
+                sink.plan_id
+            )));
+        }
+        if sink.kind == FragmentKind::Normal && sink.keys.is_empty() {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: ExchangeSink #{} shuffles by hash without keys",
+                sink.plan_id
+            )));
+        }
+        Ok(())
+    }
+
+    fn child_schema(child: &PhysicalPlan, plan_id: u32, what: &str) -> Result<DataSchemaRef> {
+        child.output_schema().map_err(|e| {
+            ErrorCode::Internal(format!(
+                "Invalid physical plan: the schema of the {} #{} can't be resolved: {}",
+                what,
+                plan_id,
+                e.message()
+            ))
+        })
+    }
+
+    fn check_columns<'a>(
+        schema: &DataSchemaRef,
+        columns: impl IntoIterator<Item = &'a IndexType>,
+        plan_id: u32,
+        what: &str,
+    ) -> Result<()> {
+        for column in columns {
+            if schema.index_of(&column.to_string()).is_err() {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid physical plan: the {} #{} references column {} which is not in its input",
+                    what, plan_id, column
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_group_by_display(
+        plan_id: u32,
+        group_by: &[IndexType],
+        group_by_display: &[String],
+    ) -> Result<()> {
+        if group_by.len() != group_by_display.len() {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid physical plan: aggregation #{} has {} group by keys but {} displays",
+                plan_id,
+                group_by.len(),
+                group_by_display.len()
+            )));
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ use databend_common_exception::Result;
 
 use super::physical_plans::AddStreamColumn;
 use super::physical_plans::CacheScan;
+use super::physical_plans::Checksum;
 use super::physical_plans::ExpressionScan;
 use super::physical_plans::HilbertSerialize;
 use super::physical_plans::MutationManipulate;
@@ -26,6 +27,7 @@ use crate::executor::physical_plan::PhysicalPlan;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AggregateSorted;
 use crate::executor::physical_plans::AsyncFunction;
 use crate::executor::physical_plans::ChunkAppendData;
 use crate::executor::physical_plans::ChunkCastSchema;
@@ -50,6 +52,7 @@ use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationSource;
 use crate::executor::physical_plans::ProjectSet;
@@ -61,6 +64,7 @@ use crate::executor::physical_plans::ReplaceInto;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Shuffle;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortedFirstRows;
 use crate::executor::physical_plans::TableScan;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
@@ -77,10 +81,14 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
+            PhysicalPlan::AggregateSorted(plan) => self.replace_aggregate_sorted(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
+            PhysicalPlan::MatchRecognize(plan) => self.replace_match_recognize(plan),
             PhysicalPlan::WindowPartition(plan) => self.replace_window_partition(plan),
+            PhysicalPlan::SortedFirstRows(plan) => self.replace_sorted_first_rows(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
+            PhysicalPlan::Checksum(plan) => self.replace_checksum(plan),
             PhysicalPlan::RowFetch(plan) => self.replace_row_fetch(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
@@ -222,6 +230,19 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_aggregate_sorted(&mut self, plan: &AggregateSorted) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::AggregateSorted(AggregateSorted {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            group_by: plan.group_by.clone(),
+            agg_funcs: plan.agg_funcs.clone(),
+            group_by_display: plan.group_by_display.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -237,6 +258,15 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_match_recognize(&mut self, plan: &MatchRecognize) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_window_partition(&mut self, plan: &WindowPartition) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -251,6 +281,18 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sorted_first_rows(&mut self, plan: &SortedFirstRows) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::SortedFirstRows(SortedFirstRows {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            group_by: plan.group_by.clone(),
+            ties_by: plan.ties_by.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
         let build = self.replace(&plan.build)?;
         let probe = self.replace(&plan.probe)?;
@@ -323,6 +365,15 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_checksum(&mut self, plan: &Checksum) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::Checksum(Checksum {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+        }))
+    }
+
     fn replace_row_fetch(&mut self, plan: &RowFetch) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -521,6 +572,7 @@ pub trait PhysicalPlanReplacer {
             plan_id: plan.plan_id,
             input: Box::new(input),
             srf_exprs: plan.srf_exprs.clone(),
+            outer: plan.outer,
             projections: plan.projections.clone(),
             stat_info: plan.stat_info.clone(),
         }))
@@ -534,6 +586,7 @@ pub trait PhysicalPlanReplacer {
             udf_funcs: plan.udf_funcs.clone(),
             stat_info: plan.stat_info.clone(),
             script_udf: plan.script_udf,
+            error_policy: plan.error_policy,
         }))
     }
 
@@ -665,18 +718,30 @@ impl PhysicalPlan {
                 PhysicalPlan::AggregateFinal(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::AggregateSorted(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Window(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MatchRecognize(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::WindowPartition(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SortedFirstRows(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Sort(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Checksum(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::RowFetch(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -17,8 +17,10 @@ mod physical_add_stream_column;
 mod physical_aggregate_expand;
 mod physical_aggregate_final;
 mod physical_aggregate_partial;
+mod physical_aggregate_sorted;
 mod physical_async_func;
 mod physical_cache_scan;
+mod physical_checksum;
 mod physical_column_mutation;
 mod physical_commit_sink;
 mod physical_compact_source;
@@ -35,6 +37,7 @@ mod physical_filter;
 mod physical_hash_join;
 mod physical_join;
 mod physical_limit;
+mod physical_match_recognize;
 mod physical_multi_table_insert;
 mod physical_mutation;
 mod physical_mutation_into_organize;
@@ -51,6 +54,7 @@ mod physical_replace_deduplicate;
 mod physical_replace_into;
 mod physical_row_fetch;
 mod physical_sort;
+mod physical_sorted_first_rows;
 mod physical_table_scan;
 mod physical_udf;
 mod physical_union_all;
@@ -62,9 +66,12 @@ pub use physical_add_stream_column::AddStreamColumn;
 pub use physical_aggregate_expand::AggregateExpand;
 pub use physical_aggregate_final::AggregateFinal;
 pub use physical_aggregate_partial::AggregatePartial;
+pub use physical_aggregate_sorted::AggregateSorted;
 pub use physical_async_func::AsyncFunction;
 pub use physical_async_func::AsyncFunctionDesc;
 pub use physical_cache_scan::CacheScan;
+pub use physical_checksum::Checksum;
+pub use physical_checksum::CHECKSUM_COLUMN_NAME;
 pub use physical_column_mutation::ColumnMutation;
 pub use physical_commit_sink::*;
 pub use physical_compact_source::CompactSource;
@@ -81,6 +88,8 @@ pub use physical_filter::Filter;
 pub use physical_hash_join::HashJoin;
 pub use physical_join::PhysicalJoinType;
 pub use physical_limit::Limit;
+pub use physical_match_recognize::MatchMeasureDesc;
+pub use physical_match_recognize::MatchRecognize;
 pub use physical_multi_table_insert::*;
 pub use physical_mutation::*;
 pub use physical_mutation_into_organize::MutationOrganize;
@@ -98,8 +107,10 @@ pub use physical_replace_deduplicate::*;
 pub use physical_replace_into::ReplaceInto;
 pub use physical_row_fetch::RowFetch;
 pub use physical_sort::Sort;
+pub use physical_sorted_first_rows::SortedFirstRows;
 pub use physical_table_scan::TableScan;
 pub use physical_udf::Udf;
+pub use physical_udf::UdfErrorPolicy;
 pub use physical_udf::UdfFunctionDesc;
 pub use physical_union_all::UnionAll;
 pub use physical_window::*;
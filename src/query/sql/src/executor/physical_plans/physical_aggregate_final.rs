@@ -21,12 +21,14 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
 
+use super::physical_aggregate_sorted::is_sorted_on_group_keys;
 use super::SortDesc;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregateFunctionSignature;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AggregateSorted;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
@@ -492,6 +494,27 @@ impl PhysicalPlanBuilder {
                 }
 
                 match input {
+                    PhysicalPlan::AggregatePartial(partial)
+                        if agg.grouping_sets.is_none()
+                            && partial.rank_limit.is_none()
+                            && is_sorted_on_group_keys(&partial.input, &group_items) =>
+                    {
+                        let plan_id = self.add_plan_decision("AggregateSorted", || {
+                            format!(
+                                "the input is sorted on all the group keys [{}]",
+                                partial.group_by_display.join(", ")
+                            )
+                        });
+                        PhysicalPlan::AggregateSorted(AggregateSorted {
+                            plan_id,
+                            input: partial.input,
+                            group_by: group_items,
+                            agg_funcs,
+                            group_by_display: partial.group_by_display,
+                            stat_info: Some(stat_info),
+                        })
+                    }
+
                     PhysicalPlan::AggregatePartial(ref partial) => {
                         let before_group_by_schema = partial.input.output_schema()?;
 
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::AggregateFunctionDesc;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// Streaming aggregation over an input which is already sorted on all the group keys,
+/// the rows of a group are adjacent, so a group is emitted as soon as the key changes
+/// instead of being kept in a hash table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AggregateSorted {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub group_by: Vec<IndexType>,
+    pub agg_funcs: Vec<AggregateFunctionDesc>,
+    pub group_by_display: Vec<String>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AggregateSorted {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(self.agg_funcs.len() + self.group_by.len());
+        for agg in self.agg_funcs.iter() {
+            let data_type = agg.sig.return_type.clone();
+            fields.push(DataField::new(&agg.output_column.to_string(), data_type));
+        }
+        for id in self.group_by.iter() {
+            let data_type = input_schema
+                .field_with_name(&id.to_string())?
+                .data_type()
+                .clone();
+            fields.push(DataField::new(&id.to_string(), data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+/// Checks whether the output of `plan` is a single stream sorted on all the `group_by` columns.
+///
+/// The leading sort keys must be exactly the group keys (in any order and direction),
+/// sorting on a prefix of the group keys doesn't make the rows of a group adjacent.
+/// The pipeline of a Sort always merges its output into a single stream, and Filter,
+/// EvalScalar and Limit keep it, so the AggregateSorted built on top never falls back.
+pub(crate) fn is_sorted_on_group_keys(plan: &PhysicalPlan, group_by: &[IndexType]) -> bool {
+    match plan {
+        PhysicalPlan::Filter(plan) => is_sorted_on_group_keys(&plan.input, group_by),
+        PhysicalPlan::EvalScalar(plan) => is_sorted_on_group_keys(&plan.input, group_by),
+        PhysicalPlan::Limit(plan) => is_sorted_on_group_keys(&plan.input, group_by),
+        // The sort before exchange only sorts the data of each node.
+        PhysicalPlan::Sort(sort) if sort.after_exchange != Some(false) => {
+            let num_keys = group_by.len();
+            num_keys > 0
+                && sort.order_by.len() >= num_keys
+                && group_by.iter().enumerate().all(|(i, key)| {
+                    !group_by[..i].contains(key)
+                        && sort.order_by[..num_keys]
+                            .iter()
+                            .any(|desc| desc.order_by == *key)
+                })
+        }
+        _ => false,
+    }
+}
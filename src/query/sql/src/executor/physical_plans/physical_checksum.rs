@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::PhysicalPlan;
+
+pub const CHECKSUM_COLUMN_NAME: &str = "checksum";
+
+/// Computes the checksum of all the rows of the input, the XOR of the hashes
+/// of the rows, which doesn't depend on the order of the rows.
+///
+/// Each block is reduced to its own checksum first, then the block checksums
+/// are XOR-ed into a single row.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Checksum {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+}
+
+impl Checksum {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(DataSchemaRefExt::create(vec![DataField::new(
+            CHECKSUM_COLUMN_NAME,
+            DataType::Number(NumberDataType::UInt64),
+        )]))
+    }
+}
@@ -52,6 +52,9 @@ pub enum CopyIntoTableSource {
 
 impl CopyIntoTable {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
-        Ok(DataSchemaRefExt::create(vec![]))
+        match self.validation_mode {
+            ValidationMode::ReturnNRows(_) => Ok(self.required_values_schema.clone()),
+            _ => Ok(DataSchemaRefExt::create(vec![])),
+        }
     }
 }
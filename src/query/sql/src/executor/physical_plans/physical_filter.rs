@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use databend_common_exception::Result;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Expr;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
@@ -67,7 +70,8 @@ impl PhysicalPlanBuilder {
         });
 
         // 2. Build physical plan.
-        let input = Box::new(self.build(s_expr.child(0)?, used).await?);
+        let input = self.build(s_expr.child(0)?, used).await?;
+        let input = Box::new(self.build_window_top_one(filter, input, stat_info.clone()));
         required = required
             .union(self.metadata.read().get_retained_column())
             .cloned()
@@ -81,26 +85,105 @@ impl PhysicalPlanBuilder {
             }
         }
 
+        let predicates = filter
+            .predicates
+            .iter()
+            .map(|scalar| {
+                let expr = scalar
+                    .type_check(input_schema.as_ref())?
+                    .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
+                let expr = cast_expr_to_non_null_boolean(expr)?;
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(expr)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         Ok(PhysicalPlan::Filter(Filter {
             plan_id: 0,
             projections,
             input,
-            predicates: filter
-                .predicates
+            predicates: reorder_predicates(predicates)
                 .iter()
-                .map(|scalar| {
-                    let expr = scalar
-                        .type_check(input_schema.as_ref())?
-                        .project_column_ref(|index| {
-                            input_schema.index_of(&index.to_string()).unwrap()
-                        });
-                    let expr = cast_expr_to_non_null_boolean(expr)?;
-                    let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
-                    Ok(expr.as_remote_expr())
-                })
-                .collect::<Result<_>>()?,
+                .map(Expr::as_remote_expr)
+                .collect(),
 
             stat_info: Some(stat_info),
         }))
     }
 }
+
+/// Reorders the conjuncts to evaluate the cheap and selective ones first, the rows filtered
+/// out by a conjunct are not evaluated by the following ones.
+///
+/// A conjunct is never moved before a preceding one sharing columns with it, which may guard
+/// it, e.g. `x IS NOT NULL AND f(x)` where `f` fails on NULL.
+fn reorder_predicates(predicates: Vec<Expr>) -> Vec<Expr> {
+    let mut remaining = predicates
+        .into_iter()
+        .map(|expr| {
+            let rank = predicate_cost(&expr) / (1.0 - predicate_selectivity(&expr));
+            let columns = expr.column_refs().into_keys().collect::<HashSet<_>>();
+            (expr, rank, columns)
+        })
+        .collect::<Vec<_>>();
+
+    let mut reordered = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let mut best = 0;
+        for i in 1..remaining.len() {
+            let guarded = remaining[..i]
+                .iter()
+                .any(|(_, _, columns)| !columns.is_disjoint(&remaining[i].2));
+            if !guarded && remaining[i].1 < remaining[best].1 {
+                best = i;
+            }
+        }
+        // The conjuncts of similar ranks keep their order, they are reordered at runtime
+        // by the measured cost if possible.
+        let next = if remaining[best].1 * 2.0 < remaining[0].1 {
+            best
+        } else {
+            0
+        };
+        reordered.push(remaining.remove(next).0);
+    }
+    reordered
+}
+
+// The relative cost to evaluate the predicate for a row, the comparisons are the cheapest
+// while the string matchings are the most expensive.
+fn predicate_cost(expr: &Expr) -> f64 {
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => 0.0,
+        Expr::Cast { expr, .. } => 5.0 + predicate_cost(expr),
+        Expr::FunctionCall { function, args, .. } => {
+            let name = function.signature.name.as_str();
+            let cost = match name {
+                "eq" | "noteq" | "gt" | "gte" | "lt" | "lte" | "is_true" | "is_null"
+                | "is_not_null" | "and" | "or" | "not" | "and_filters" | "plus" | "minus"
+                | "multiply" => 1.0,
+                "like" | "ilike" | "rlike" | "glob" => 100.0,
+                _ if name.starts_with("regexp") || name.starts_with("json_") => 100.0,
+                _ => 10.0,
+            };
+            cost + args.iter().map(predicate_cost).sum::<f64>()
+        }
+        Expr::LambdaFunctionCall { args, .. } => {
+            100.0 + args.iter().map(predicate_cost).sum::<f64>()
+        }
+    }
+}
+
+// The estimated fraction of the rows kept by the predicate.
+fn predicate_selectivity(expr: &Expr) -> f64 {
+    match expr {
+        Expr::FunctionCall { function, args, .. } => match function.signature.name.as_str() {
+            "is_true" => predicate_selectivity(&args[0]),
+            "eq" | "is_null" => 0.1,
+            "gt" | "gte" | "lt" | "lte" => 0.33,
+            "noteq" | "is_not_null" => 0.9,
+            _ => 0.5,
+        },
+        _ => 0.5,
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
 
 use databend_common_exception::Result;
 
@@ -34,16 +36,60 @@ pub enum PhysicalJoinType {
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
 }
 
-// Choose physical join type by join conditions
-pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+// Why the physical join type is chosen, only formatted for `EXPLAIN(DECISIONS)`.
+pub enum PhysicalJoinReason {
+    EquiConditions(usize),
+    CachedBuildSide,
+    SingleRowBuildSide,
+    RangeConditions(usize),
+    NestedLoop,
+}
+
+impl Display for PhysicalJoinReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhysicalJoinReason::EquiConditions(n) => {
+                write!(f, "chose HashJoin over RangeJoin: {n} equi conditions")
+            }
+            PhysicalJoinReason::CachedBuildSide => {
+                write!(f, "chose HashJoin over RangeJoin: the build side is cached")
+            }
+            PhysicalJoinReason::SingleRowBuildSide => {
+                write!(
+                    f,
+                    "chose HashJoin over RangeJoin: the build side has only one row"
+                )
+            }
+            PhysicalJoinReason::RangeConditions(n) => write!(
+                f,
+                "chose RangeJoin over HashJoin: {n} range conditions without equi conditions"
+            ),
+            PhysicalJoinReason::NestedLoop => {
+                write!(
+                    f,
+                    "chose HashJoin as nested loop join: no equi or range conditions"
+                )
+            }
+        }
+    }
+}
+
+// Choose physical join type by join conditions, the reason of the choice is returned as well.
+pub fn physical_join(
+    join: &Join,
+    s_expr: &SExpr,
+) -> Result<(PhysicalJoinType, PhysicalJoinReason)> {
     if !join.equi_conditions.is_empty() {
         // Contain equi condition, use hash join
-        return Ok(PhysicalJoinType::Hash);
+        return Ok((
+            PhysicalJoinType::Hash,
+            PhysicalJoinReason::EquiConditions(join.equi_conditions.len()),
+        ));
     }
 
     if join.build_side_cache_info.is_some() {
         // There is a build side cache, use hash join.
-        return Ok(PhysicalJoinType::Hash);
+        return Ok((PhysicalJoinType::Hash, PhysicalJoinReason::CachedBuildSide));
     }
 
     let left_rel_expr = RelExpr::with_s_expr(s_expr.child(0)?);
@@ -53,7 +99,10 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         || right_stat_info.cardinality == 1.0
     {
         // If the output rows of build side is equal to 1, we use CROSS JOIN + FILTER instead of RANGE JOIN.
-        return Ok(PhysicalJoinType::Hash);
+        return Ok((
+            PhysicalJoinType::Hash,
+            PhysicalJoinReason::SingleRowBuildSide,
+        ));
     }
 
     let left_prop = left_rel_expr.derive_relational_prop()?;
@@ -71,14 +120,15 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
     }
 
     if !range_conditions.is_empty() && matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
-        return Ok(PhysicalJoinType::RangeJoin(
-            range_conditions,
-            other_conditions,
+        let reason = PhysicalJoinReason::RangeConditions(range_conditions.len());
+        return Ok((
+            PhysicalJoinType::RangeJoin(range_conditions, other_conditions),
+            reason,
         ));
     }
 
     // Leverage hash join to execute nested loop join
-    Ok(PhysicalJoinType::Hash)
+    Ok((PhysicalJoinType::Hash, PhysicalJoinReason::NestedLoop))
 }
 
 fn check_condition(
@@ -160,8 +210,31 @@ impl PhysicalPlanBuilder {
 
         // 2. Build physical plan.
         // Choose physical join type by join conditions
-        let physical_join = physical_join(join, s_expr)?;
-        match physical_join {
+        let (physical_join, reason) = physical_join(join, s_expr)?;
+        let plan = match &physical_join {
+            PhysicalJoinType::Hash => "HashJoin",
+            PhysicalJoinType::RangeJoin(..) => "RangeJoin",
+        };
+        let plan_id = self.add_plan_decision(plan, || {
+            if join.equi_conditions.is_empty() {
+                return reason.to_string();
+            }
+            // The fraction of the cartesian product kept by the equi conditions.
+            let cardinality = |index| {
+                s_expr
+                    .child(index)
+                    .and_then(|child| RelExpr::with_s_expr(child).derive_cardinality())
+                    .map_or(0.0, |stat_info| stat_info.cardinality)
+            };
+            let product = cardinality(0) * cardinality(1);
+            if product > 0.0 {
+                let selectivity = stat_info.estimated_rows / product;
+                format!("{reason}, equi-key selectivity {selectivity:.6}")
+            } else {
+                reason.to_string()
+            }
+        });
+        let mut plan = match physical_join {
             PhysicalJoinType::Hash => {
                 self.build_hash_join(
                     join,
@@ -178,6 +251,12 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+        }?;
+        match &mut plan {
+            PhysicalPlan::HashJoin(join) => join.plan_id = plan_id,
+            PhysicalPlan::RangeJoin(join) => join.plan_id = plan_id,
+            _ => {}
         }
+        Ok(plan)
     }
 }
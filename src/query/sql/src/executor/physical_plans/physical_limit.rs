@@ -66,6 +66,12 @@ impl PhysicalPlanBuilder {
         // 2. Build physical plan.
         let input_plan = self.build(s_expr.child(0)?, required).await?;
         let metadata = self.metadata.read().clone();
+        if limit.before_exchange && !metadata.lazy_columns().is_empty() {
+            self.add_decision("RowFetch", || {
+                "skipped: the Limit is before the exchange, the lazy columns are fetched after it"
+                    .to_string()
+            });
+        }
         if limit.before_exchange || metadata.lazy_columns().is_empty() {
             return Ok(PhysicalPlan::Limit(Limit {
                 plan_id: 0,
@@ -87,6 +93,9 @@ impl PhysicalPlanBuilder {
             .ok_or_else(|| ErrorCode::Internal("Internal column _row_id is not found"))?;
 
         if !input_schema.has_field(&row_id_col_index.to_string()) {
+            self.add_decision("RowFetch", || {
+                "skipped: the input of Limit has no row id column".to_string()
+            });
             return Ok(PhysicalPlan::Limit(Limit {
                 plan_id: 0,
                 input: Box::new(input_plan),
@@ -111,6 +120,9 @@ impl PhysicalPlanBuilder {
 
         if limit.before_exchange || lazy_columns.is_empty() {
             // If there is no lazy column, we don't need to build a `RowFetch` plan.
+            self.add_decision("RowFetch", || {
+                "skipped: the lazy columns are already read by the input of Limit".to_string()
+            });
             return Ok(PhysicalPlan::Limit(Limit {
                 plan_id: 0,
                 input: Box::new(input_plan),
@@ -148,8 +160,17 @@ impl PhysicalPlanBuilder {
             false,
         );
 
+        let plan_id = self.add_plan_decision("RowFetch", || {
+            format!(
+                "fetch {} lazy columns by row id after Limit {}",
+                lazy_columns.len(),
+                limit
+                    .limit
+                    .map_or("NONE".to_string(), |limit| limit.to_string())
+            )
+        });
         Ok(PhysicalPlan::RowFetch(RowFetch {
-            plan_id: 0,
+            plan_id,
             input: Box::new(PhysicalPlan::Limit(Limit {
                 plan_id: 0,
                 input: Box::new(input_plan),
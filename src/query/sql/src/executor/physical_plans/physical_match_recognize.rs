@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::cast_expr_to_non_null_boolean;
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::MatchMeasureKind;
+use crate::plans::MatchPattern;
+use crate::plans::MatchPrevColumn;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::TypeCheck;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchRecognize {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub partition_by: Vec<IndexType>,
+    pub prev_columns: Vec<MatchPrevColumn>,
+    pub variables: Vec<String>,
+    // The `DEFINE` condition of each variable, evaluated on the columns of
+    // the input followed by the `prev_columns`.
+    pub defines: Vec<Option<RemoteExpr>>,
+    pub pattern: MatchPattern,
+    pub measures: Vec<MatchMeasureDesc>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchMeasureDesc {
+    pub output_column: IndexType,
+    pub kind: MatchMeasureKind,
+    pub data_type: DataType,
+}
+
+impl MatchRecognize {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(self.partition_by.len() + self.measures.len());
+        for index in self.partition_by.iter() {
+            fields.push(input_schema.field_with_name(&index.to_string())?.clone());
+        }
+        for measure in self.measures.iter() {
+            fields.push(DataField::new(
+                &measure.output_column.to_string(),
+                measure.data_type.clone(),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_match_recognize(
+        &mut self,
+        s_expr: &SExpr,
+        match_recognize: &crate::plans::MatchRecognize,
+        _required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. DO NOT prune the operator even if none of its outputs are required,
+        // because it changes the cardinality of its input.
+        let required = match_recognize.input_columns();
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        let input_schema = input.output_schema()?;
+
+        let mut fields = input_schema.fields().clone();
+        for prev in match_recognize.prev_columns.iter() {
+            let source = input_schema.field_with_name(&prev.source.to_string())?;
+            fields.push(DataField::new(
+                &prev.index.to_string(),
+                source.data_type().wrap_nullable(),
+            ));
+        }
+        let define_schema = DataSchema::new(fields);
+
+        let defines = match_recognize
+            .variables
+            .iter()
+            .map(|variable| {
+                variable
+                    .define
+                    .as_ref()
+                    .map(|define| {
+                        let expr = define
+                            .type_check(&define_schema)?
+                            .project_column_ref(|index| {
+                                define_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let expr = cast_expr_to_non_null_boolean(expr)?;
+                        let (expr, _) =
+                            ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        Ok(expr.as_remote_expr())
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let measures = match_recognize
+            .measures
+            .iter()
+            .map(|measure| {
+                let data_type = match &measure.kind {
+                    MatchMeasureKind::First { column, .. }
+                    | MatchMeasureKind::Last { column, .. } => input_schema
+                        .field_with_name(&column.to_string())?
+                        .data_type()
+                        .wrap_nullable(),
+                    MatchMeasureKind::Count { .. } | MatchMeasureKind::MatchNumber => {
+                        DataType::Number(NumberDataType::UInt64)
+                    }
+                };
+                Ok(MatchMeasureDesc {
+                    output_column: measure.index,
+                    kind: measure.kind.clone(),
+                    data_type,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            plan_id: 0,
+            input: Box::new(input),
+            partition_by: match_recognize
+                .partition_by
+                .iter()
+                .map(|item| item.index)
+                .collect(),
+            prev_columns: match_recognize.prev_columns.clone(),
+            variables: match_recognize
+                .variables
+                .iter()
+                .map(|variable| variable.name.clone())
+                .collect(),
+            defines,
+            pattern: match_recognize.pattern.clone(),
+            measures,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -35,6 +35,8 @@ pub struct ProjectSet {
     pub projections: ColumnSet,
     pub input: Box<PhysicalPlan>,
     pub srf_exprs: Vec<(RemoteExpr, IndexType)>,
+    // Keep the input rows without results of the set-returning functions, see `plans::ProjectSet`.
+    pub outer: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -100,6 +102,7 @@ impl PhysicalPlanBuilder {
             plan_id: 0,
             input: Box::new(input),
             srf_exprs,
+            outer: project_set.outer,
             projections,
             stat_info: Some(stat_info),
         }))
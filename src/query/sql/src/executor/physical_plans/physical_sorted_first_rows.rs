@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::SortDesc;
+use crate::executor::physical_plans::WindowFunction;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::extract_top_n;
+use crate::IndexType;
+
+/// Keeps the first row of each group of an input sorted on the group keys, and the rows
+/// of the group tied with it on the `ties_by` keys.
+///
+/// The rows of a group are adjacent, so a group is done as soon as the key changes.
+///
+/// It only reduces the rows after the sort of its input, all the rows are still read
+/// and sorted, the sources are not known to be sorted on the keys.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortedFirstRows {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub group_by: Vec<IndexType>,
+    pub ties_by: Vec<IndexType>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortedFirstRows {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Puts `SortedFirstRows` under the window for the top 1 of each partition of a ranking
+    /// window, if the input of the window is already sorted by the partitions, so only one
+    /// row of each partition, or the rows tied with it for `RANK` and `DENSE_RANK`, goes
+    /// through the window. The `WindowPartition` of the window is kept, so the window is
+    /// still correct if the rows can't be reduced as a single sorted stream.
+    ///
+    /// `input` is the input of the `filter`, the other rows are still filtered by it, and
+    /// `stat_info` is the one of the `filter`.
+    pub(crate) fn build_window_top_one(
+        &mut self,
+        filter: &crate::plans::Filter,
+        input: PhysicalPlan,
+        stat_info: PlanStatsInfo,
+    ) -> PhysicalPlan {
+        let PhysicalPlan::Window(mut window) = input else {
+            return input;
+        };
+        let with_ties = match window.func {
+            WindowFunction::RowNumber => false,
+            // All the rows are tied without the order by.
+            WindowFunction::Rank | WindowFunction::DenseRank if !window.order_by.is_empty() => true,
+            _ => return PhysicalPlan::Window(window),
+        };
+        let top_one = filter
+            .predicates
+            .iter()
+            .any(|predicate| extract_top_n(window.index, predicate.clone()) == Some(1));
+        if !top_one {
+            return PhysicalPlan::Window(window);
+        }
+
+        let sorted_input = match window.input.as_ref() {
+            PhysicalPlan::WindowPartition(partition) => partition.input.as_ref(),
+            input => input,
+        };
+        if !is_sorted_on_partitions(sorted_input, &window.partition_by, &window.order_by) {
+            self.add_decision("SortedFirstRows", || {
+                "skipped: the input of Window is not sorted by the partitions".to_string()
+            });
+            return PhysicalPlan::Window(window);
+        }
+
+        let plan_id = self.add_plan_decision("SortedFirstRows", || {
+            format!(
+                "keep the first row{} of each partition before Window",
+                if with_ties { " and its ties" } else { "" }
+            )
+        });
+        let ties_by = if with_ties {
+            window.order_by.iter().map(|desc| desc.order_by).collect()
+        } else {
+            vec![]
+        };
+        let first_rows = |input: Box<PhysicalPlan>| {
+            Box::new(PhysicalPlan::SortedFirstRows(SortedFirstRows {
+                plan_id,
+                input,
+                group_by: window.partition_by.clone(),
+                ties_by,
+                stat_info: Some(stat_info),
+            }))
+        };
+        window.input = match *window.input {
+            PhysicalPlan::WindowPartition(mut partition) => {
+                partition.input = first_rows(partition.input);
+                Box::new(PhysicalPlan::WindowPartition(partition))
+            }
+            input => first_rows(Box::new(input)),
+        };
+        PhysicalPlan::Window(window)
+    }
+}
+
+/// Checks whether the output of `plan` is a single stream sorted on the `partition_by`
+/// columns (in any order and direction), and then on the `order_by` of the window.
+pub(crate) fn is_sorted_on_partitions(
+    plan: &PhysicalPlan,
+    partition_by: &[IndexType],
+    order_by: &[SortDesc],
+) -> bool {
+    match plan {
+        PhysicalPlan::Filter(plan) => is_sorted_on_partitions(&plan.input, partition_by, order_by),
+        PhysicalPlan::EvalScalar(plan) => {
+            is_sorted_on_partitions(&plan.input, partition_by, order_by)
+        }
+        PhysicalPlan::Limit(plan) => is_sorted_on_partitions(&plan.input, partition_by, order_by),
+        // The sort before exchange only sorts the data of each node.
+        PhysicalPlan::Sort(sort) if sort.after_exchange != Some(false) => {
+            let num_keys = partition_by.len();
+            sort.order_by.len() >= num_keys + order_by.len()
+                && partition_by.iter().enumerate().all(|(i, key)| {
+                    !partition_by[..i].contains(key)
+                        && sort.order_by[..num_keys]
+                            .iter()
+                            .any(|desc| desc.order_by == *key)
+                })
+                && order_by
+                    .iter()
+                    .zip(sort.order_by[num_keys..].iter())
+                    .all(|(expected, desc)| {
+                        expected.order_by == desc.order_by
+                            && expected.asc == desc.asc
+                            && expected.nulls_first == desc.nulls_first
+                    })
+        }
+        _ => false,
+    }
+}
@@ -41,6 +41,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
+use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
@@ -52,6 +53,8 @@ use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::executor::cast_expr_to_non_null_boolean;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AddStreamColumn;
+use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::RowFetch;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
@@ -149,6 +152,21 @@ impl PhysicalPlanBuilder {
             scan.prune_columns(used, prewhere)
         };
 
+        // Read the columns of the push down filter first, the other columns are
+        // fetched by the row ids of the rows passing the filter.
+        let prewhere_row_fetch = self.prewhere_row_fetch_columns(&scan)?;
+        let scan = match &prewhere_row_fetch {
+            Some((fetch_columns, row_id_index)) => {
+                let mut scan = scan;
+                for index in fetch_columns {
+                    scan.columns.remove(index);
+                }
+                scan.columns.insert(*row_id_index);
+                scan
+            }
+            None => scan,
+        };
+
         // 2. Build physical plan.
         let mut has_inner_column = false;
         let mut has_virtual_column = false;
@@ -291,10 +309,15 @@ impl PhysicalPlanBuilder {
             name_mapping,
             source: Box::new(source),
             table_index: Some(scan.table_index),
-            stat_info: Some(stat_info),
+            stat_info: Some(stat_info.clone()),
             internal_column,
         });
 
+        if let Some((fetch_columns, row_id_index)) = prewhere_row_fetch {
+            plan =
+                self.build_prewhere_row_fetch(&scan, plan, fetch_columns, row_id_index, stat_info)?;
+        }
+
         // Update stream columns if needed.
         if scan.update_stream_columns {
             plan = PhysicalPlan::AddStreamColumn(Box::new(AddStreamColumn::new(
@@ -308,6 +331,164 @@ impl PhysicalPlanBuilder {
         Ok(plan)
     }
 
+    /// The columns to fetch by the row ids after the push down filter of the scan, and the
+    /// index of the row id column, if the filter reads only a few of the scanned columns.
+    fn prewhere_row_fetch_columns(
+        &mut self,
+        scan: &crate::plans::Scan,
+    ) -> Result<Option<(Vec<IndexType>, IndexType)>> {
+        if !self.ctx.get_settings().get_enable_fuse_prewhere()?
+            || scan.prewhere.is_some()
+            || scan.agg_index.is_some()
+            || scan.sample.is_some()
+            || scan.update_stream_columns
+            || scan.is_lazy_table
+        {
+            return Ok(None);
+        }
+        let Some(predicates) = scan.push_down_predicates.as_ref().filter(|p| !p.is_empty()) else {
+            return Ok(None);
+        };
+
+        let metadata = self.metadata.read().clone();
+        let table = metadata.table(scan.table_index).table();
+        // The native format has its own prewhere, and the rows can only be fetched from fuse.
+        if table.support_prewhere()
+            || !table.supported_internal_column(ROW_ID_COLUMN_ID)
+            || !metadata.lazy_columns().is_empty()
+        {
+            return Ok(None);
+        }
+        let all_plain_columns = scan.columns.iter().all(|index| {
+            matches!(
+                metadata.column(*index),
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    path_indices: None,
+                    virtual_expr: None,
+                    ..
+                })
+            )
+        });
+        if !all_plain_columns {
+            return Ok(None);
+        }
+        // The filter is evaluated again by the `Filter` above the scan.
+        for predicate in predicates {
+            if !predicate
+                .as_raw_expr()
+                .type_check(&metadata)?
+                .is_deterministic(&BUILTIN_FUNCTIONS)
+            {
+                return Ok(None);
+            }
+        }
+
+        let filter_columns = predicates
+            .iter()
+            .flat_map(|predicate| predicate.used_columns())
+            .collect::<ColumnSet>();
+        if filter_columns.is_empty() || !filter_columns.is_subset(&scan.columns) {
+            return Ok(None);
+        }
+        if filter_columns.len() * 2 > scan.columns.len() {
+            self.add_decision("RowFetch", || {
+                format!(
+                    "skipped prewhere: the filter reads {} of the {} columns of the scan",
+                    filter_columns.len(),
+                    scan.columns.len()
+                )
+            });
+            return Ok(None);
+        }
+
+        let fetch_columns = scan
+            .columns
+            .difference(&filter_columns)
+            .sorted() // Needs sort because we need to make the order deterministic.
+            .cloned()
+            .collect::<Vec<_>>();
+        let internal_column = INTERNAL_COLUMN_FACTORY
+            .get_internal_column(ROW_ID_COL_NAME)
+            .unwrap();
+        let row_id_index = self
+            .metadata
+            .write()
+            .add_internal_column(scan.table_index, internal_column);
+        Ok(Some((fetch_columns, row_id_index)))
+    }
+
+    /// Filters the rows read by the prewhere `input` scan, and fetches the `fetch_columns`
+    /// of the remaining rows.
+    fn build_prewhere_row_fetch(
+        &mut self,
+        scan: &crate::plans::Scan,
+        input: PhysicalPlan,
+        fetch_columns: Vec<IndexType>,
+        row_id_index: IndexType,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let source = match &input {
+            PhysicalPlan::TableScan(table_scan) => table_scan.source.clone(),
+            _ => unreachable!("the input of the prewhere row fetch must be a table scan"),
+        };
+        let input_schema = input.output_schema()?;
+        let predicates = scan
+            .push_down_predicates
+            .iter()
+            .flatten()
+            .map(|scalar| {
+                let expr = scalar
+                    .type_check(input_schema.as_ref())?
+                    .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
+                let expr = cast_expr_to_non_null_boolean(expr)?;
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(expr.as_remote_expr())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let row_id_col_offset = input_schema.index_of(&row_id_index.to_string())?;
+        // The filter columns are the scanned columns that are not fetched.
+        let plan_id = self.add_plan_decision("RowFetch", || {
+            format!(
+                "prewhere: read {} filter columns first, fetch {} columns by row id",
+                scan.columns.len() - fetch_columns.len(),
+                fetch_columns.len()
+            )
+        });
+        let filter = PhysicalPlan::Filter(Filter {
+            plan_id: 0,
+            projections: (0..input_schema.num_fields()).collect(),
+            input: Box::new(input),
+            predicates,
+            stat_info: Some(stat_info.clone()),
+        });
+
+        let metadata = self.metadata.read();
+        let fetched_fields = fetch_columns
+            .iter()
+            .map(|index| DataField::new(&index.to_string(), metadata.column(*index).data_type()))
+            .collect();
+        let cols_to_fetch = Self::build_projection(
+            &metadata,
+            &source.source_info.schema(),
+            fetch_columns.iter(),
+            false,
+            true,
+            true,
+            false,
+        );
+
+        Ok(PhysicalPlan::RowFetch(RowFetch {
+            plan_id,
+            input: Box::new(filter),
+            source,
+            cols_to_fetch,
+            row_id_col_offset,
+            fetched_fields,
+            need_wrap_nullable: false,
+            stat_info: Some(stat_info),
+        }))
+    }
+
     pub(crate) async fn build_dummy_table_scan(&mut self) -> Result<PhysicalPlan> {
         let catalogs = CatalogManager::instance();
         let table = catalogs
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -36,6 +38,7 @@ pub struct Udf {
     pub input: Box<PhysicalPlan>,
     pub udf_funcs: Vec<UdfFunctionDesc>,
     pub script_udf: bool,
+    pub error_policy: UdfErrorPolicy,
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -53,6 +56,30 @@ impl Udf {
     }
 }
 
+/// How the rows which fail in a UDF are handled, set by the `udf_error_policy` setting.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum UdfErrorPolicy {
+    /// Fail the whole query.
+    #[default]
+    Abort,
+    /// Return NULL for the failed rows, the other rows of the batch are kept.
+    Null,
+}
+
+impl FromStr for UdfErrorPolicy {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "abort" => Ok(UdfErrorPolicy::Abort),
+            "null" => Ok(UdfErrorPolicy::Null),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid udf_error_policy {s:?}, expected 'abort' or 'null'"
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct UdfFunctionDesc {
     pub name: String,
@@ -142,11 +169,18 @@ impl PhysicalPlanBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let error_policy = self
+            .ctx
+            .get_settings()
+            .get_udf_error_policy()?
+            .parse::<UdfErrorPolicy>()?;
+
         Ok(PhysicalPlan::Udf(Udf {
             plan_id: 0,
             input: Box::new(input),
             udf_funcs,
             script_udf: udf_plan.script_udf,
+            error_policy,
             stat_info: Some(stat_info),
         }))
     }
@@ -275,6 +275,7 @@ impl ToReadDataSourcePlan for dyn Table {
             internal_columns,
             base_block_ids,
             update_stream_columns,
+            ordered_output: false,
             data_mask_policy,
             // Set a dummy id, will be set real id later
             table_index: usize::MAX,
@@ -28,6 +28,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::Scalar;
+use databend_common_expression::ROW_ID_COL_NAME;
 use indexmap::Equivalent;
 use itertools::Itertools;
 
@@ -230,6 +231,51 @@ impl<'a> AggregateRewriter<'a> {
         Ok(replaced_agg.into())
     }
 
+    /// Checks whether a non-grouped column is functionally dependent on the group items,
+    /// which is true if the `_row_id` of its table is grouped, each group has only one row
+    /// of the table, so the column can be aggregated by `any_value`.
+    fn is_functionally_dependent(&self, column: &ColumnBinding) -> bool {
+        let aggregate_info = &self.bind_context.aggregate_info;
+        let Some(table_index) = column.table_index else {
+            return false;
+        };
+        if aggregate_info.grouping_sets.is_some() || column.column_name == ROW_ID_COL_NAME {
+            return false;
+        }
+
+        let mut grouped_row_id = false;
+        for item in aggregate_info.group_items.iter() {
+            if let ScalarExpr::BoundColumnRef(column_ref) = &item.scalar {
+                if column_ref.column.index == column.index {
+                    return false;
+                }
+                grouped_row_id |= column_ref.column.table_index == Some(table_index)
+                    && column_ref.column.column_name == ROW_ID_COL_NAME;
+            }
+        }
+        grouped_row_id
+    }
+
+    /// Replace a functionally dependent column with `any_value(column)`.
+    fn replace_dependent_column(&mut self, column_ref: &BoundColumnRef) -> Result<ScalarExpr> {
+        let column = &column_ref.column;
+        let column_name = match &column.table_name {
+            Some(table_name) => format!("{}.{}", table_name, column.column_name),
+            None => column.column_name.clone(),
+        };
+        let aggregate = AggregateFunction {
+            span: column_ref.span,
+            display_name: format!("any_value({})", column_name),
+            func_name: "any_value".to_string(),
+            distinct: false,
+            params: vec![],
+            args: vec![column_ref.clone().into()],
+            return_type: column.data_type.clone(),
+            sort_descs: vec![],
+        };
+        self.replace_aggregate_function(&aggregate)
+    }
+
     fn replace_udaf_call(&mut self, udaf: &UDAFCall) -> Result<ScalarExpr> {
         if let Some(column) = find_replaced_aggregate_function(
             &self.bind_context.aggregate_info,
@@ -487,6 +533,12 @@ impl<'a> VisitorMut<'a> for AggregateRewriter<'a> {
                 *expr = self.replace_udaf_call(udaf)?;
                 Ok(())
             }
+            ScalarExpr::BoundColumnRef(column_ref)
+                if self.is_functionally_dependent(&column_ref.column) =>
+            {
+                *expr = self.replace_dependent_column(column_ref)?;
+                Ok(())
+            }
             _ => walk_expr_mut(self, expr),
         }
     }
@@ -85,15 +85,32 @@ impl Binder {
                     "Duplicate common table expression: {table_name}"
                 )));
             }
-            let column_name = cte
+            let mut column_name: Vec<String> = cte
                 .alias
                 .columns
                 .iter()
                 .map(|ident| self.normalize_identifier(ident).name)
                 .collect();
+            let query = if let Some(cycle) = &cte.cycle {
+                if !with.recursive || cte.materialized {
+                    return Err(ErrorCode::SemanticError(
+                        "CYCLE clause is only supported for non-materialized recursive CTE"
+                            .to_string(),
+                    )
+                    .set_span(cycle.span));
+                }
+                let query = self.rewrite_cte_cycle(&table_name, &column_name, &cte.query, cycle)?;
+                if !column_name.is_empty() {
+                    column_name.push(self.normalize_identifier(&cycle.mark_column).name);
+                    column_name.push(self.normalize_identifier(&cycle.path_column).name);
+                }
+                query
+            } else {
+                *cte.query.clone()
+            };
             let cte_info = CteInfo {
                 columns_alias: column_name,
-                query: *cte.query.clone(),
+                query,
                 recursive: with.recursive,
                 cte_idx: idx,
                 columns: vec![],
@@ -0,0 +1,261 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::CteCycle;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::planner::binder::Binder;
+
+impl Binder {
+    /// Rewrite a recursive CTE with a `CYCLE <columns> SET <mark> USING <path>` clause.
+    ///
+    /// The anchor term starts the path with the cycle columns of each row, and the
+    /// recursive term appends the new cycle columns to the path of the row it extends.
+    /// A row whose cycle columns are already in the path is emitted with `mark = true`,
+    /// but it is not expanded any more, so the recursion terminates on cyclic graphs:
+    ///
+    /// ```sql
+    /// WITH RECURSIVE t(id) AS (
+    ///     SELECT 1 UNION ALL SELECT e.dst FROM e, t WHERE e.src = t.id
+    /// ) CYCLE id SET is_cycle USING path
+    /// -- is rewritten to
+    /// WITH RECURSIVE t(id, is_cycle, path) AS (
+    ///     SELECT 1, false AS is_cycle, [1] AS path
+    ///     UNION ALL
+    ///     SELECT e.dst, contains(t.path, e.dst) AS is_cycle, array_append(t.path, e.dst) AS path
+    ///     FROM e, t WHERE e.src = t.id AND NOT t.is_cycle
+    /// )
+    /// ```
+    pub(crate) fn rewrite_cte_cycle(
+        &self,
+        cte_name: &str,
+        columns_alias: &[String],
+        query: &Query,
+        cycle: &CteCycle,
+    ) -> Result<Query> {
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation)
+                if set_operation.op == SetOperator::Union && set_operation.all =>
+            {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "CYCLE clause requires the recursive CTE to be a UNION ALL query".to_string(),
+                )
+                .set_span(cycle.span));
+            }
+        };
+        let (mut anchor, mut recursive) = match (&*set_operation.left, &*set_operation.right) {
+            (SetExpr::Select(left), SetExpr::Select(right)) => (left.clone(), right.clone()),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "CYCLE clause requires both sides of the recursive CTE to be SELECT statements"
+                        .to_string(),
+                )
+                .set_span(cycle.span));
+            }
+        };
+
+        // Resolve the positions of the cycle columns in the output of the CTE.
+        let column_names = if columns_alias.is_empty() {
+            anchor
+                .select_list
+                .iter()
+                .map(|target| match target {
+                    SelectTarget::AliasedExpr {
+                        alias: Some(alias), ..
+                    } => Some(self.normalize_identifier(alias).name),
+                    SelectTarget::AliasedExpr { expr, alias: None } => match expr.as_ref() {
+                        Expr::ColumnRef { column, .. } => match &column.column {
+                            ColumnID::Name(name) => Some(self.normalize_identifier(name).name),
+                            ColumnID::Position(_) => None,
+                        },
+                        _ => None,
+                    },
+                    SelectTarget::StarColumns { .. } => None,
+                })
+                .collect::<Vec<_>>()
+        } else {
+            columns_alias.iter().cloned().map(Some).collect()
+        };
+        let mut positions = Vec::with_capacity(cycle.columns.len());
+        for column in cycle.columns.iter() {
+            let name = self.normalize_identifier(column).name;
+            let position = column_names
+                .iter()
+                .position(|column_name| column_name.as_deref() == Some(name.as_str()))
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "CYCLE column {name} is not found in the recursive CTE {cte_name}"
+                    ))
+                    .set_span(column.span)
+                })?;
+            positions.push(position);
+        }
+
+        let cte_ref = find_cte_reference(&recursive.from, cte_name, self).ok_or_else(|| {
+            ErrorCode::SemanticError(format!(
+                "The recursive term of CTE {cte_name} does not reference itself"
+            ))
+            .set_span(cycle.span)
+        })?;
+        let cte_column = |column: &Identifier| Expr::ColumnRef {
+            span: cycle.span,
+            column: ColumnRef {
+                database: None,
+                table: Some(cte_ref.clone()),
+                column: ColumnID::Name(column.clone()),
+            },
+        };
+
+        let anchor_row = cycle_row(&anchor, &positions, cycle)?;
+        let recursive_row = cycle_row(&recursive, &positions, cycle)?;
+
+        anchor.select_list.push(SelectTarget::AliasedExpr {
+            expr: Box::new(Expr::Literal {
+                span: cycle.span,
+                value: Literal::Boolean(false),
+            }),
+            alias: Some(cycle.mark_column.clone()),
+        });
+        anchor.select_list.push(SelectTarget::AliasedExpr {
+            expr: Box::new(Expr::Array {
+                span: cycle.span,
+                exprs: vec![anchor_row],
+            }),
+            alias: Some(cycle.path_column.clone()),
+        });
+
+        recursive.select_list.push(SelectTarget::AliasedExpr {
+            expr: Box::new(function_call(cycle, "contains", vec![
+                cte_column(&cycle.path_column),
+                recursive_row.clone(),
+            ])),
+            alias: Some(cycle.mark_column.clone()),
+        });
+        recursive.select_list.push(SelectTarget::AliasedExpr {
+            expr: Box::new(function_call(cycle, "array_append", vec![
+                cte_column(&cycle.path_column),
+                recursive_row,
+            ])),
+            alias: Some(cycle.path_column.clone()),
+        });
+        // Rows which closed a cycle are not expanded any more.
+        let not_cycle = Expr::UnaryOp {
+            span: cycle.span,
+            op: UnaryOperator::Not,
+            expr: Box::new(cte_column(&cycle.mark_column)),
+        };
+        recursive.selection = Some(match recursive.selection.take() {
+            Some(selection) => Expr::BinaryOp {
+                span: cycle.span,
+                op: BinaryOperator::And,
+                left: Box::new(selection),
+                right: Box::new(not_cycle),
+            },
+            None => not_cycle,
+        });
+
+        let mut set_operation = set_operation.clone();
+        set_operation.left = Box::new(SetExpr::Select(anchor));
+        set_operation.right = Box::new(SetExpr::Select(recursive));
+        let mut query = query.clone();
+        query.body = SetExpr::SetOperation(set_operation);
+        Ok(query)
+    }
+}
+
+/// The value tracked in the path for one row: the cycle column itself, or a tuple
+/// of the cycle columns if there are several of them.
+fn cycle_row(select: &SelectStmt, positions: &[usize], cycle: &CteCycle) -> Result<Expr> {
+    let mut exprs = Vec::with_capacity(positions.len());
+    for position in positions {
+        match select.select_list.get(*position) {
+            Some(SelectTarget::AliasedExpr { expr, .. }) => exprs.push(expr.as_ref().clone()),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "CYCLE clause doesn't support `*` in the select list of the recursive CTE"
+                        .to_string(),
+                )
+                .set_span(cycle.span));
+            }
+        }
+    }
+    if exprs.len() == 1 {
+        Ok(exprs.remove(0))
+    } else {
+        Ok(Expr::Tuple {
+            span: cycle.span,
+            exprs,
+        })
+    }
+}
+
+fn function_call(cycle: &CteCycle, name: &str, args: Vec<Expr>) -> Expr {
+    Expr::FunctionCall {
+        span: cycle.span,
+        func: FunctionCall {
+            distinct: false,
+            name: Identifier::from_name(cycle.span, name),
+            args,
+            params: vec![],
+            order_by: vec![],
+            window: None,
+            lambda: None,
+        },
+    }
+}
+
+/// Find the name which the recursive term uses to reference the CTE itself.
+fn find_cte_reference(
+    from: &[TableReference],
+    cte_name: &str,
+    binder: &Binder,
+) -> Option<Identifier> {
+    from.iter().find_map(|table_ref| match table_ref {
+        TableReference::Table {
+            database: None,
+            table,
+            alias,
+            ..
+        } if binder.normalize_identifier(table).name == cte_name => Some(
+            alias
+                .as_ref()
+                .map(|alias| alias.name.clone())
+                .unwrap_or_else(|| table.clone()),
+        ),
+        TableReference::Join { join, .. } => find_cte_reference(
+            &[join.left.as_ref().clone(), join.right.as_ref().clone()],
+            cte_name,
+            binder,
+        ),
+        _ => None,
+    })
+}
@@ -113,6 +113,11 @@ impl Binder {
         .with_subquery_executor(self.subquery_executor.clone());
         let new_stmt = rewriter.rewrite(stmt)?;
         let stmt = new_stmt.as_ref().unwrap_or(stmt);
+        let distinct_on = self.rewrite_distinct_on(stmt, order_by)?;
+        let (stmt, distinct_on) = match &distinct_on {
+            Some((stmt, first_row)) => (stmt, Some(first_row)),
+            None => (stmt, None),
+        };
 
         // Try put window definitions into bind context.
         // This operation should be before `normalize_select_list` because window functions can be used in select list.
@@ -181,6 +186,17 @@ impl Binder {
             None
         };
 
+        // The window of DISTINCT ON is computed on the rows kept by the QUALIFY,
+        // so it is analyzed apart from the other windows and bound after the QUALIFY.
+        let distinct_on = if let Some(first_row) = distinct_on {
+            let windows = std::mem::take(&mut from_context.windows);
+            let first_row = self.analyze_window_qualify(&mut from_context, &aliases, first_row)?;
+            let distinct_on_windows = std::mem::replace(&mut from_context.windows, windows);
+            Some((first_row, distinct_on_windows))
+        } else {
+            None
+        };
+
         let order_items = self.analyze_order_items(
             &mut from_context,
             &mut scalar_items,
@@ -229,6 +245,15 @@ impl Binder {
             s_expr = self.bind_qualify(&mut from_context, qualify, s_expr)?;
         }
 
+        if let Some((first_row, windows)) = distinct_on {
+            for window_info in &windows.window_functions {
+                s_expr = self.bind_window_function(window_info, s_expr)?;
+            }
+            let windows = std::mem::replace(&mut from_context.windows, windows);
+            s_expr = self.bind_qualify(&mut from_context, first_row, s_expr)?;
+            from_context.windows = windows;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -416,14 +441,24 @@ impl<'a> SelectRewriter<'a> {
         Ok(self.new_stmt.take())
     }
 
+    /// Rewrites `PIVOT` into a single aggregation with an `<aggregate>_if` function per pivot
+    /// value and aggregate function, so the output columns are known when binding.
+    ///
+    /// There is no dedicated `RelOperator` for `PIVOT`, the optimizer and the physical plan
+    /// only see the rewritten aggregation.
     fn rewrite_pivot(&mut self, stmt: &SelectStmt) -> Result<()> {
         if stmt.from.len() != 1 || stmt.from[0].pivot().is_none() {
             return Ok(());
         }
         let pivot = stmt.from[0].pivot().unwrap();
-        let (aggregate_name, aggregate_args) = Self::parse_aggregate_function(&pivot.aggregate)?;
-        let aggregate_args_names = aggregate_args
+        let aggregates = pivot
+            .aggregates
             .iter()
+            .map(Self::parse_aggregate_function)
+            .collect::<Result<Vec<_>>>()?;
+        let aggregate_args_names = aggregates
+            .iter()
+            .flat_map(|(_, args)| args.iter())
             .map(|expr| match expr {
                 Expr::ColumnRef {
                     column:
@@ -464,10 +499,45 @@ impl<'a> SelectRewriter<'a> {
             exclude_columns.push(pivot.value_column.clone());
             star.exclude(exclude_columns);
         };
-        let new_aggregate_name = Identifier {
-            name: format!("{}_if", aggregate_name.name),
-            ..aggregate_name.clone()
-        };
+        let new_aggregates = aggregates
+            .iter()
+            .map(|(name, args)| {
+                let new_name = Identifier {
+                    name: format!("{}_if", name.name),
+                    ..(*name).clone()
+                };
+                // A column per pivot value is named after the value, or after the value and
+                // the aggregate function if there are multiple aggregate functions, and also
+                // after the arguments if the same function is used more than once.
+                let suffix = (aggregates.len() > 1).then(|| {
+                    let func_name = name.name.to_lowercase();
+                    let is_repeated = aggregates
+                        .iter()
+                        .filter(|(other, _)| other.name.eq_ignore_ascii_case(&name.name))
+                        .count()
+                        > 1;
+                    if is_repeated {
+                        let args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+                        let args = args.join("_");
+                        format!("{func_name}_{args}")
+                    } else {
+                        func_name
+                    }
+                });
+                (new_name, *args, suffix)
+            })
+            .collect::<Vec<_>>();
+        if let Some((i, _)) = new_aggregates
+            .iter()
+            .enumerate()
+            .find(|(i, (_, _, suffix))| new_aggregates[..*i].iter().any(|(_, _, s)| s == suffix))
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Duplicate aggregate function in pivot: {}",
+                pivot.aggregates[i]
+            ))
+            .set_span(pivot.aggregates[i].span()));
+        }
 
         // The values of pivot are divided into two categories: Column(Vec<Expr>) and Subquery.
         // For Column, it must be literal. For Subquery, it should first be executed,
@@ -478,8 +548,7 @@ impl<'a> SelectRewriter<'a> {
                 self.process_pivot_column_values(
                     pivot,
                     values,
-                    &new_aggregate_name,
-                    aggregate_args,
+                    &new_aggregates,
                     &mut new_select_list,
                     stmt,
                 )?;
@@ -497,8 +566,7 @@ impl<'a> SelectRewriter<'a> {
                     self.process_pivot_column_values(
                         pivot,
                         &values,
-                        &new_aggregate_name,
-                        aggregate_args,
+                        &new_aggregates,
                         &mut new_select_list,
                         stmt,
                     )?;
@@ -527,24 +595,29 @@ impl<'a> SelectRewriter<'a> {
         &self,
         pivot: &Pivot,
         values: &[Expr],
-        new_aggregate_name: &Identifier,
-        aggregate_args: &[Expr],
+        new_aggregates: &[(Identifier, &[Expr], Option<String>)],
         new_select_list: &mut Vec<SelectTarget>,
         stmt: &SelectStmt,
     ) -> Result<()> {
         for value in values {
-            let mut args = aggregate_args.to_vec();
-            args.push(Self::expr_eq_from_col_and_value(
-                pivot.value_column.clone(),
-                value.clone(),
-            ));
-            let alias = Self::raw_string_from_literal_expr(value)
+            let value_name = Self::raw_string_from_literal_expr(value)
                 .ok_or_else(|| ErrorCode::SyntaxException("Pivot value should be literal"))?;
-            new_select_list.push(Self::target_func_from_name_args(
-                new_aggregate_name.clone(),
-                args,
-                Some(Identifier::from_name(stmt.span, &alias)),
-            ));
+            for (new_aggregate_name, aggregate_args, suffix) in new_aggregates {
+                let mut args = aggregate_args.to_vec();
+                args.push(Self::expr_eq_from_col_and_value(
+                    pivot.value_column.clone(),
+                    value.clone(),
+                ));
+                let alias = match suffix {
+                    Some(suffix) => format!("{value_name}_{suffix}"),
+                    None => value_name.clone(),
+                };
+                new_select_list.push(Self::target_func_from_name_args(
+                    new_aggregate_name.clone(),
+                    args,
+                    Some(Identifier::from_name(stmt.span, &alias)),
+                ));
+            }
         }
         Ok(())
     }
@@ -20,11 +20,14 @@ use databend_common_ast::ast::split_equivalent_predicate_expr;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::Span;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
+use super::bind_table_function::is_correlated_srf_table_function;
 use crate::binder::wrap_nullable;
 use crate::binder::Finder;
 use crate::binder::JoinPredicate;
@@ -77,11 +80,15 @@ impl Binder {
             .expression_scan_context
             .add_hash_join_build_cache(cache_column_bindings, cache_column_indexes);
 
-        if join.right.is_lateral_table_function() {
+        // The set-returning functions referencing the columns, like `FROM t, unnest(t.arr)`,
+        // are evaluated for each row of the left side even without `LATERAL`.
+        if join.right.is_lateral_table_function() || is_correlated_srf_table_function(&join.right) {
+            let outer = lateral_table_function_is_outer(join)?;
             let (result_expr, bind_context) = self.bind_lateral_table_function(
                 &mut left_context,
                 left_child.clone(),
                 &join.right,
+                outer,
             )?;
             return Ok((result_expr, bind_context));
         }
@@ -1019,3 +1026,29 @@ fn join_bind_context(
         _ => bind_context,
     }
 }
+
+// Returns whether the rows of the left side without rows of the lateral table function are
+// kept, the rows of the function are generated for each row, so the join condition can only
+// be always true.
+fn lateral_table_function_is_outer(join: &databend_common_ast::ast::Join) -> Result<bool> {
+    let span = match join.right.as_ref() {
+        TableReference::TableFunction { span, .. } => *span,
+        _ => None,
+    };
+    let always_true = match &join.condition {
+        JoinCondition::None => true,
+        JoinCondition::On(expr) => matches!(expr.as_ref(), Expr::Literal {
+            value: Literal::Boolean(true),
+            ..
+        }),
+        _ => false,
+    };
+    match &join.op {
+        JoinOperator::CrossJoin | JoinOperator::Inner if always_true => Ok(false),
+        JoinOperator::LeftOuter if always_true => Ok(true),
+        _ => Err(ErrorCode::SemanticError(
+            "Lateral table functions only support CROSS JOIN, and INNER JOIN or LEFT JOIN with ON TRUE",
+        )
+        .set_span(span)),
+    }
+}
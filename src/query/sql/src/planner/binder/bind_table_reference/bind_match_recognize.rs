@@ -0,0 +1,568 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::MatchRecognize as ASTMatchRecognize;
+use databend_common_ast::ast::RowPattern;
+use databend_common_ast::ast::RowPatternQuantifier;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+use crate::binder::contain_subquery;
+use crate::binder::ColumnBinding;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::ScalarBinder;
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::MatchMeasure;
+use crate::plans::MatchMeasureKind;
+use crate::plans::MatchPattern;
+use crate::plans::MatchPatternTerm;
+use crate::plans::MatchPrevColumn;
+use crate::plans::MatchRecognize;
+use crate::plans::MatchVariable;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ScalarExpr;
+
+impl Binder {
+    /// Bind `<table_ref> MATCH_RECOGNIZE(...)`.
+    ///
+    /// Only a subset of row pattern recognition is supported: the pattern is a
+    /// concatenation of pattern variables quantified by `*`, `+`, `?` or `{n,m}`,
+    /// optionally anchored by `^` and `$`. Every match produces one row with the
+    /// partition keys and the measures, and the next match starts after the last
+    /// row of the previous one.
+    pub(crate) fn bind_match_recognize(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        input: &TableReference,
+        match_recognize: &ASTMatchRecognize,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let (child, mut input_context) = self.bind_table_reference(bind_context, input)?;
+
+        let mut variables = vec![];
+        let pattern = self.bind_row_pattern(*span, &match_recognize.pattern, &mut variables)?;
+
+        // The partition keys, order keys and measured expressions which are not
+        // columns are evaluated before sorting the input.
+        let mut scalar_items = vec![];
+        let mut output_columns = vec![];
+
+        let mut partition_by = Vec::with_capacity(match_recognize.partition_by.len());
+        for expr in match_recognize.partition_by.iter() {
+            let (item, column) =
+                self.bind_match_recognize_expr(&mut input_context, expr, &mut scalar_items)?;
+            partition_by.push(item);
+            output_columns.push(column);
+        }
+
+        let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+        let mut order_by = Vec::with_capacity(match_recognize.order_by.len());
+        for order in match_recognize.order_by.iter() {
+            let (item, _) =
+                self.bind_match_recognize_expr(&mut input_context, &order.expr, &mut scalar_items)?;
+            let asc = order.asc.unwrap_or(true);
+            order_by.push(SortItem {
+                index: item.index,
+                asc,
+                nulls_first: order
+                    .nulls_first
+                    .unwrap_or_else(|| default_nulls_first(asc)),
+            });
+        }
+
+        let mut defines = HashMap::with_capacity(match_recognize.defines.len());
+        let mut prev_columns = vec![];
+        let mut prev_bindings: HashMap<String, ColumnBinding> = HashMap::new();
+        for define in match_recognize.defines.iter() {
+            let name = self.normalize_identifier(&define.variable).name;
+            if !variables.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Pattern variable {name} in DEFINE is not used in PATTERN"
+                ))
+                .set_span(define.variable.span));
+            }
+            if defines.contains_key(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Pattern variable {name} is defined more than once"
+                ))
+                .set_span(define.variable.span));
+            }
+
+            // Replace `PREV(<expr>)` with a column which holds the value of `<expr>`
+            // in the previous row.
+            let mut condition = define.condition.clone();
+            let mut rewriter = DefineRewriter {
+                binder: self,
+                variable: name.clone(),
+                variables: &variables,
+                prev_args: vec![],
+                error: None,
+            };
+            condition.drive_mut(&mut rewriter);
+            if let Some(error) = rewriter.error {
+                return Err(error);
+            }
+            let prev_args = rewriter.prev_args;
+
+            let mut define_context = input_context.clone();
+            for (prev_name, arg) in prev_args {
+                if !prev_bindings.contains_key(&prev_name) {
+                    let (item, column) = self.bind_match_recognize_expr(
+                        &mut input_context,
+                        &arg,
+                        &mut scalar_items,
+                    )?;
+                    let data_type = column.data_type.wrap_nullable();
+                    let index = self.metadata.write().add_derived_column(
+                        prev_name.clone(),
+                        data_type.clone(),
+                        None,
+                    );
+                    prev_columns.push(MatchPrevColumn {
+                        index,
+                        source: item.index,
+                    });
+                    let binding = ColumnBindingBuilder::new(
+                        prev_name.clone(),
+                        index,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .build();
+                    prev_bindings.insert(prev_name.clone(), binding);
+                }
+                define_context.add_column_binding(prev_bindings[&prev_name].clone());
+            }
+
+            let (scalar, _) = ScalarBinder::new(
+                &mut define_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            )
+            .bind(&condition)?;
+            if contain_subquery(&scalar) {
+                return Err(ErrorCode::SemanticError(
+                    "Subquery is not supported in the DEFINE clause of MATCH_RECOGNIZE",
+                )
+                .set_span(define.condition.span()));
+            }
+            defines.insert(name, scalar);
+        }
+
+        let mut measures = Vec::with_capacity(match_recognize.measures.len());
+        for measure in match_recognize.measures.iter() {
+            let (kind, data_type) = self.bind_match_measure(
+                &mut input_context,
+                &measure.expr,
+                &variables,
+                &mut scalar_items,
+            )?;
+            let name = self.normalize_identifier(&measure.alias).name;
+            let column = self.create_derived_column_binding(name, data_type, None);
+            measures.push(MatchMeasure {
+                index: column.index,
+                kind,
+            });
+            output_columns.push(column);
+        }
+
+        let match_recognize = MatchRecognize {
+            span: *span,
+            partition_by,
+            order_by,
+            prev_columns,
+            variables: variables
+                .into_iter()
+                .map(|name| MatchVariable {
+                    define: defines.remove(&name),
+                    name,
+                })
+                .collect(),
+            pattern,
+            measures,
+        };
+
+        let mut s_expr = child;
+        if !scalar_items.is_empty() {
+            s_expr = SExpr::create_unary(
+                Arc::new(
+                    EvalScalar {
+                        items: scalar_items,
+                    }
+                    .into(),
+                ),
+                Arc::new(s_expr),
+            );
+        }
+        let sort_items = match_recognize
+            .partition_by
+            .iter()
+            .map(|part| SortItem {
+                index: part.index,
+                asc: true,
+                nulls_first: default_nulls_first(true),
+            })
+            .chain(match_recognize.order_by.iter().cloned())
+            .collect::<Vec<_>>();
+        if !sort_items.is_empty() {
+            let sort = Sort {
+                items: sort_items,
+                limit: None,
+                after_exchange: None,
+                pre_projection: None,
+                window_partition: None,
+            };
+            s_expr = SExpr::create_unary(Arc::new(sort.into()), Arc::new(s_expr));
+        }
+        s_expr = SExpr::create_unary(Arc::new(match_recognize.into()), Arc::new(s_expr));
+
+        let mut output_context = input_context;
+        output_context.columns = output_columns;
+        if let Some(alias) = alias {
+            output_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((s_expr, output_context))
+    }
+
+    /// Bind an expression on the input of `MATCH_RECOGNIZE`. If the expression is not
+    /// a column, it's evaluated as a derived column before the operator.
+    fn bind_match_recognize_expr(
+        &mut self,
+        bind_context: &mut BindContext,
+        expr: &Expr,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<(ScalarItem, ColumnBinding)> {
+        let (scalar, data_type) = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        )
+        .bind(expr)?;
+        if let ScalarExpr::BoundColumnRef(column_ref) = &scalar {
+            let column = column_ref.column.clone();
+            return Ok((
+                ScalarItem {
+                    index: column.index,
+                    scalar,
+                },
+                column,
+            ));
+        }
+
+        let column = self.create_derived_column_binding(
+            format!("{:#}", expr),
+            data_type,
+            Some(scalar.clone()),
+        );
+        let item = ScalarItem {
+            index: column.index,
+            scalar,
+        };
+        scalar_items.push(item.clone());
+        Ok((item, column))
+    }
+
+    fn bind_match_measure(
+        &mut self,
+        bind_context: &mut BindContext,
+        expr: &Expr,
+        variables: &[String],
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<(MatchMeasureKind, DataType)> {
+        let unsupported = || {
+            ErrorCode::SemanticError(format!(
+                "Unsupported measure {expr} in MATCH_RECOGNIZE, only FIRST, LAST, COUNT and MATCH_NUMBER are supported"
+            ))
+            .set_span(expr.span())
+        };
+        let count_type = DataType::Number(NumberDataType::UInt64);
+        let func = match expr {
+            Expr::CountAll { window: None, .. } => {
+                return Ok((
+                    MatchMeasureKind::Count {
+                        variable: None,
+                        column: None,
+                    },
+                    count_type,
+                ));
+            }
+            Expr::FunctionCall { func, .. }
+                if !func.distinct && func.window.is_none() && func.params.is_empty() =>
+            {
+                func
+            }
+            _ => return Err(unsupported()),
+        };
+
+        let func_name = func.name.name.to_lowercase();
+        if func_name == "match_number" && func.args.is_empty() {
+            return Ok((MatchMeasureKind::MatchNumber, count_type));
+        }
+        if !matches!(func_name.as_str(), "first" | "last" | "count") || func.args.len() != 1 {
+            return Err(unsupported());
+        }
+
+        // `<variable>.<column>` only uses the rows mapped to the variable.
+        let mut arg = func.args[0].clone();
+        let mut variable = None;
+        if let Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: Some(table),
+                    ..
+                },
+            ..
+        } = &arg
+        {
+            let name = self.normalize_identifier(table).name;
+            if let Some(position) = variables.iter().position(|v| *v == name) {
+                variable = Some(position);
+                if let Expr::ColumnRef { column, .. } = &mut arg {
+                    column.table = None;
+                }
+            }
+        }
+
+        let (item, column) = self.bind_match_recognize_expr(bind_context, &arg, scalar_items)?;
+        Ok(match func_name.as_str() {
+            "first" => (
+                MatchMeasureKind::First {
+                    variable,
+                    column: item.index,
+                },
+                column.data_type.wrap_nullable(),
+            ),
+            "last" => (
+                MatchMeasureKind::Last {
+                    variable,
+                    column: item.index,
+                },
+                column.data_type.wrap_nullable(),
+            ),
+            _ => (
+                MatchMeasureKind::Count {
+                    variable,
+                    column: Some(item.index),
+                },
+                count_type,
+            ),
+        })
+    }
+
+    /// Flatten the row pattern into a concatenation of quantified variables, the
+    /// variables are collected into `variables` in the order of their first appearance.
+    fn bind_row_pattern(
+        &self,
+        span: Span,
+        pattern: &RowPattern,
+        variables: &mut Vec<String>,
+    ) -> Result<MatchPattern> {
+        let mut elements = vec![];
+        flatten_row_pattern(pattern, &mut elements);
+
+        let mut match_pattern = MatchPattern::default();
+        let num_elements = elements.len();
+        for (i, element) in elements.into_iter().enumerate() {
+            let (variable, min, max) = match element {
+                RowPattern::Start if i == 0 => {
+                    match_pattern.start_anchor = true;
+                    continue;
+                }
+                RowPattern::End if i + 1 == num_elements => {
+                    match_pattern.end_anchor = true;
+                    continue;
+                }
+                RowPattern::Start | RowPattern::End => {
+                    return Err(ErrorCode::SemanticError(
+                        "Anchors `^` and `$` are only supported at the start and the end of the pattern",
+                    )
+                    .set_span(span));
+                }
+                RowPattern::Variable(variable) => (variable, 1, Some(1)),
+                RowPattern::Quantified {
+                    pattern,
+                    quantifier,
+                    reluctant,
+                } => {
+                    let RowPattern::Variable(variable) = pattern.as_ref() else {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Quantified group {pattern} in PATTERN is not supported, only pattern variables can be quantified"
+                        ))
+                        .set_span(span));
+                    };
+                    if *reluctant {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Reluctant quantifier in {element} is not supported"
+                        ))
+                        .set_span(span));
+                    }
+                    let (min, max) = match quantifier {
+                        RowPatternQuantifier::ZeroOrMore => (0, None),
+                        RowPatternQuantifier::OneOrMore => (1, None),
+                        RowPatternQuantifier::ZeroOrOne => (0, Some(1)),
+                        RowPatternQuantifier::Range { min, max } => {
+                            let min = min.unwrap_or(0) as usize;
+                            let max = max.map(|max| max as usize);
+                            if max.is_some_and(|max| max < min || max == 0) {
+                                return Err(ErrorCode::SemanticError(format!(
+                                    "Invalid quantifier in {element}"
+                                ))
+                                .set_span(span));
+                            }
+                            (min, max)
+                        }
+                    };
+                    (variable, min, max)
+                }
+                RowPattern::Alternation(_) => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Alternation {element} in PATTERN is not supported"
+                    ))
+                    .set_span(span));
+                }
+                RowPattern::Concat(_) | RowPattern::Group(_) => unreachable!(),
+            };
+
+            let name = self.normalize_identifier(variable).name;
+            let variable = match variables.iter().position(|v| *v == name) {
+                Some(position) => position,
+                None => {
+                    variables.push(name);
+                    variables.len() - 1
+                }
+            };
+            match_pattern
+                .terms
+                .push(MatchPatternTerm { variable, min, max });
+        }
+
+        if match_pattern.terms.is_empty() {
+            return Err(
+                ErrorCode::SemanticError("PATTERN must contain at least one variable")
+                    .set_span(span),
+            );
+        }
+        Ok(match_pattern)
+    }
+}
+
+/// Unnest the concatenations and the groups without quantifiers.
+fn flatten_row_pattern<'a>(pattern: &'a RowPattern, elements: &mut Vec<&'a RowPattern>) {
+    match pattern {
+        RowPattern::Concat(patterns) => {
+            for pattern in patterns {
+                flatten_row_pattern(pattern, elements);
+            }
+        }
+        RowPattern::Group(pattern) => flatten_row_pattern(pattern, elements),
+        _ => elements.push(pattern),
+    }
+}
+
+/// Rewrite the condition of a pattern variable in the `DEFINE` clause:
+/// - `PREV(<expr>)` is replaced with a column reference named after it.
+/// - `<variable>.<column>` of the variable being defined is the column of the current row.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct DefineRewriter<'a> {
+    binder: &'a Binder,
+    variable: String,
+    variables: &'a [String],
+    prev_args: Vec<(String, Expr)>,
+    error: Option<ErrorCode>,
+}
+
+impl DefineRewriter<'_> {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::FunctionCall { span, func } if func.name.name.eq_ignore_ascii_case("prev") => {
+                if func.args.len() != 1 || func.window.is_some() {
+                    self.error = Some(
+                        ErrorCode::SemanticError("PREV expects exactly one argument")
+                            .set_span(*span),
+                    );
+                    return;
+                }
+                let mut arg = func.args[0].clone();
+                self.enter_expr(&mut arg);
+                let name = format!("prev({arg})").to_lowercase();
+                self.prev_args.push((name.clone(), arg));
+                *expr = Expr::ColumnRef {
+                    span: *span,
+                    column: ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(Identifier::from_name_with_quoted(
+                            *span,
+                            name,
+                            Some('"'),
+                        )),
+                    },
+                };
+            }
+            Expr::ColumnRef {
+                span,
+                column:
+                    ColumnRef {
+                        database: None,
+                        table: table @ Some(_),
+                        ..
+                    },
+            } => {
+                let name = self
+                    .binder
+                    .normalize_identifier(table.as_ref().unwrap())
+                    .name;
+                if name == self.variable {
+                    *table = None;
+                } else if self.variables.contains(&name) {
+                    self.error = Some(
+                        ErrorCode::SemanticError(format!(
+                            "Referencing pattern variable {name} in the definition of {} is not supported",
+                            self.variable
+                        ))
+                        .set_span(*span),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
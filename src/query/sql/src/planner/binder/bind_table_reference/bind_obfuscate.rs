@@ -385,6 +385,7 @@ fn zero_select_stmt() -> SelectStmt {
         span: None,
         hints: None,
         distinct: false,
+        distinct_on: None,
         top_n: None,
         select_list: vec![],
         from: vec![],
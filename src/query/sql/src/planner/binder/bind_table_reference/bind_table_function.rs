@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall as ASTFunctionCall;
 use databend_common_ast::ast::Identifier;
@@ -37,6 +38,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_storages_result_cache::ResultCacheMetaManager;
 use databend_common_storages_result_cache::ResultScan;
 use databend_common_users::UserApiProvider;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
 
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::table_args::bind_table_args;
@@ -47,6 +50,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::BindContext;
@@ -78,6 +82,7 @@ impl Binder {
                 span: *span,
                 hints: None,
                 distinct: false,
+                distinct_on: None,
                 top_n: None,
                 select_list: vec![SelectTarget::AliasedExpr {
                     expr: Box::new(databend_common_ast::ast::Expr::FunctionCall {
@@ -313,12 +318,14 @@ impl Binder {
         Ok((srf_expr, bind_context.clone()))
     }
 
-    /// Bind a lateral table function.
+    /// Bind a lateral table function, the set-returning function is evaluated for each row
+    /// of `child`, and the rows without results are kept with NULL results if `outer`.
     pub(crate) fn bind_lateral_table_function(
         &mut self,
         parent_context: &mut BindContext,
         child: SExpr,
         table_ref: &TableReference,
+        outer: bool,
     ) -> Result<(SExpr, BindContext)> {
         match table_ref {
             TableReference::TableFunction {
@@ -361,7 +368,12 @@ impl Binder {
                     // analyze Set-returning functions.
                     self.analyze_project_set_select(&mut bind_context, &mut select_list)?;
                     // bind Set-returning functions.
-                    let srf_expr = self.bind_project_set(&mut bind_context, child, false)?;
+                    let mut srf_expr = self.bind_project_set(&mut bind_context, child, false)?;
+                    if outer {
+                        let mut project_set = ProjectSet::try_from(srf_expr.plan().clone())?;
+                        project_set.outer = true;
+                        srf_expr = srf_expr.replace_plan(Arc::new(project_set.into()));
+                    }
                     // clear Set-returning functions, avoid duplicate bind.
                     bind_context.srf_info = Default::default();
 
@@ -431,6 +443,48 @@ impl Binder {
     }
 }
 
+#[derive(Visitor)]
+#[visitor(ColumnRef(enter))]
+struct ColumnRefFinder {
+    found: bool,
+}
+
+impl ColumnRefFinder {
+    fn enter_column_ref(&mut self, _column: &ColumnRef) {
+        self.found = true;
+    }
+}
+
+/// Checks whether `table_ref` is a set-returning table function whose arguments reference
+/// columns, like `unnest(t.arr)`, which can only be bound as a lateral table function.
+pub(super) fn is_correlated_srf_table_function(table_ref: &TableReference) -> bool {
+    let TableReference::TableFunction {
+        name,
+        params,
+        named_params,
+        ..
+    } = table_ref
+    else {
+        return false;
+    };
+    let is_srf = BUILTIN_FUNCTIONS
+        .get_property(&name.name.to_lowercase())
+        .map(|p| p.kind == FunctionKind::SRF)
+        .unwrap_or(false);
+    if !is_srf {
+        return false;
+    }
+
+    let mut finder = ColumnRefFinder { found: false };
+    for param in params
+        .iter()
+        .chain(named_params.iter().map(|(_, param)| param))
+    {
+        param.drive(&mut finder);
+    }
+    finder.found
+}
+
 // parse flatten named params to arguments
 fn parse_table_function_args(
     span: &Span,
@@ -339,6 +339,7 @@ impl<'a> Binder {
                 self.bind_vacuum_temporary_files(bind_context, stmt).await?
             }
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
+            Statement::ChecksumTable(stmt) => self.bind_checksum_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
             // Dictionaries
             Statement::CreateDictionary(stmt) => self.bind_create_dictionary(stmt).await?,
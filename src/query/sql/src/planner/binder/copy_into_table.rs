@@ -28,6 +28,7 @@ use databend_common_ast::ast::HintItem;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::LiteralStringOrVariable;
+use databend_common_ast::ast::OnErrorMode;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
@@ -170,6 +171,16 @@ impl Binder {
         stage_info
             .file_format_params
             .check_copy_options(&mut options)?;
+        match validation_mode {
+            // Keep parsing after an error, so that the errors of all the files are collected.
+            ValidationMode::ReturnErrors => options.on_error = OnErrorMode::Continue,
+            // Also validate the files which are already loaded.
+            ValidationMode::ReturnAllErrors => {
+                options.on_error = OnErrorMode::Continue;
+                options.force = true;
+            }
+            ValidationMode::None | ValidationMode::ReturnNRows(_) => {}
+        }
 
         if !(stmt.options.purge && stmt.options.force)
             && stmt.options.max_files > COPY_MAX_FILES_PER_COMMIT
@@ -224,7 +235,7 @@ impl Binder {
                 is_select: false,
                 default_values,
                 copy_into_location_options: Default::default(),
-                copy_into_table_options: stmt.options.clone(),
+                copy_into_table_options: options,
                 stage_root: "".to_string(),
             },
             values_consts: vec![],
@@ -609,6 +620,7 @@ fn check_transform_query(
         if let SetExpr::Select(select) = &query.body {
             if select.group_by.is_none()
                 && !select.distinct
+                && select.distinct_on.is_none()
                 && select.having.is_none()
                 && select.from.len() == 1
             {
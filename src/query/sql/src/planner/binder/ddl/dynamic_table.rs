@@ -123,7 +123,7 @@ impl Binder {
         }
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, indexes)) = &source {
+        if let Some(CreateTableSource::Columns(cols, indexes, constraints)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -140,6 +140,11 @@ impl Binder {
                     "dynamic table don't support inverted indexes".to_string(),
                 ));
             }
+            if !constraints.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "dynamic table don't support constraints".to_string(),
+                ));
+            }
         }
 
         let mut init_bind_context = BindContext::new();
@@ -21,11 +21,14 @@ use databend_common_ast::ast::AlterTableAction;
 use databend_common_ast::ast::AlterTableStmt;
 use databend_common_ast::ast::AnalyzeTableStmt;
 use databend_common_ast::ast::AttachTableStmt;
+use databend_common_ast::ast::ChecksumTableStmt;
 use databend_common_ast::ast::ClusterOption;
 use databend_common_ast::ast::ClusterType as AstClusterType;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
+use databend_common_ast::ast::ConstraintDefinition;
+use databend_common_ast::ast::ConstraintKind;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::DescribeTableStmt;
@@ -83,6 +86,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableConstraintType;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::check_operator;
@@ -100,6 +105,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use derive_visitor::DriveMut;
+use itertools::Itertools;
 use log::debug;
 use opendal::Operator;
 
@@ -112,6 +118,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::executor::cast_expr_to_non_null_boolean;
 use crate::optimizer::SExpr;
+use crate::parse_check_constraint_expr_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -119,8 +126,10 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::ChecksumTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -521,7 +530,7 @@ impl Binder {
         };
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, _)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry | TypeName::Geography))
@@ -715,6 +724,18 @@ impl Binder {
             }
         }
 
+        let constraints = match source {
+            Some(CreateTableSource::Columns(_, _, constraint_defs)) => self
+                .analyze_table_constraints(
+                    &database,
+                    &table,
+                    &schema,
+                    constraint_defs,
+                    BTreeMap::new(),
+                )?,
+            _ => BTreeMap::new(),
+        };
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -730,6 +751,7 @@ impl Binder {
             cluster_key,
             as_select: as_query_plan,
             inverted_indexes,
+            constraints,
             attached_columns: None,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
@@ -796,6 +818,7 @@ impl Binder {
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            constraints: BTreeMap::new(),
             attached_columns: stmt.columns_opt.clone(),
         })))
     }
@@ -1007,6 +1030,32 @@ impl Binder {
                     column,
                 })))
             }
+            AlterTableAction::AddConstraint { constraint } => {
+                let table_info = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .get_table_info()
+                    .clone();
+                let existing = table_info.meta.constraints.clone();
+                let mut constraints = self.analyze_table_constraints(
+                    &database,
+                    &table,
+                    &table_info.schema(),
+                    std::slice::from_ref(constraint),
+                    existing.clone(),
+                )?;
+                constraints.retain(|name, _| !existing.contains_key(name));
+                let Some((_, constraint)) = constraints.pop_first() else {
+                    return Err(ErrorCode::Internal("the added constraint is missing"));
+                };
+                Ok(Plan::AddTableConstraint(Box::new(AddTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    constraint,
+                })))
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let schema = self
                     .ctx
@@ -1444,15 +1493,43 @@ impl Binder {
             catalog,
             database,
             table,
+            columns,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
+        let columns = columns
+            .iter()
+            .map(|column| self.normalize_identifier(column).name)
+            .unique()
+            .collect();
 
         Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
             catalog,
             database,
             table,
+            columns,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_checksum_table(
+        &mut self,
+        stmt: &ChecksumTableStmt,
+    ) -> Result<Plan> {
+        let ChecksumTableStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::ChecksumTable(Box::new(ChecksumTablePlan {
+            catalog,
+            database,
+            table,
         })))
     }
 
@@ -1587,26 +1664,37 @@ impl Binder {
 
         let fields = if has_computed {
             let mut source_fields = Vec::with_capacity(fields.len());
+            let mut stored_fields = Vec::new();
             for (column, field) in columns.iter().zip(fields.iter()) {
                 match &column.expr {
-                    Some(ColumnExpr::Virtual(_)) | Some(ColumnExpr::Stored(_)) => {
+                    Some(ColumnExpr::Virtual(_)) => {
+                        continue;
+                    }
+                    Some(ColumnExpr::Stored(_)) => {
+                        stored_fields.push(field.clone());
                         continue;
                     }
                     _ => {}
                 }
                 source_fields.push(field.clone());
             }
-            let source_schema = TableSchemaRefExt::create(source_fields);
+            let source_schema = TableSchemaRefExt::create(source_fields.clone());
+            // The stored columns are computed from the source columns when the data is
+            // written, while the virtual columns are computed when they are read, so they
+            // can also refer to the stored columns and the virtual columns defined before.
+            let mut virtual_source_fields = source_fields;
+            virtual_source_fields.extend(stored_fields);
             let mut new_fields = Vec::with_capacity(fields.len());
             for (column, field) in columns.iter().zip(fields.into_iter()) {
                 match &column.expr {
                     Some(ColumnExpr::Virtual(virtual_expr)) => {
                         let expr = parse_computed_expr_to_string(
                             self.ctx.clone(),
-                            source_schema.clone(),
+                            TableSchemaRefExt::create(virtual_source_fields.clone()),
                             &field,
                             virtual_expr,
                         )?;
+                        virtual_source_fields.push(field.clone());
                         new_fields
                             .push(field.with_computed_expr(Some(ComputedExpr::Virtual(expr))));
                     }
@@ -1668,6 +1756,168 @@ impl Binder {
         Ok(inverted_indexes)
     }
 
+    // The primary key, unique and foreign key constraints are informational only, they are
+    // kept in the table meta and never enforced. The check constraints are evaluated when
+    // the rows are written.
+    fn analyze_table_constraints(
+        &self,
+        database: &str,
+        table: &str,
+        table_schema: &TableSchemaRef,
+        constraint_defs: &[ConstraintDefinition],
+        mut constraints: BTreeMap<String, TableConstraint>,
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        for constraint_def in constraint_defs {
+            let mut check_expr = None;
+            let (constraint_type, columns, reference_table, reference_columns) =
+                match &constraint_def.kind {
+                    ConstraintKind::PrimaryKey { columns } => (
+                        TableConstraintType::PrimaryKey,
+                        columns.as_slice(),
+                        "".to_string(),
+                        vec![],
+                    ),
+                    ConstraintKind::Unique { columns } => (
+                        TableConstraintType::Unique,
+                        columns.as_slice(),
+                        "".to_string(),
+                        vec![],
+                    ),
+                    ConstraintKind::ForeignKey {
+                        columns,
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                    } => {
+                        let ref_database = ref_database
+                            .as_ref()
+                            .map(|ident| self.normalize_object_identifier(ident))
+                            .unwrap_or_else(|| database.to_string());
+                        let ref_table = self.normalize_object_identifier(ref_table);
+                        let ref_columns = ref_columns
+                            .iter()
+                            .map(|ident| self.normalize_object_identifier(ident))
+                            .collect();
+                        (
+                            TableConstraintType::ForeignKey,
+                            columns.as_slice(),
+                            format!("{ref_database}.{ref_table}"),
+                            ref_columns,
+                        )
+                    }
+                    ConstraintKind::Check { expr } => {
+                        check_expr = Some(parse_check_constraint_expr_to_string(
+                            self.ctx.clone(),
+                            table_schema,
+                            expr,
+                        )?);
+                        (
+                            TableConstraintType::Check,
+                            [].as_slice(),
+                            "".to_string(),
+                            vec![],
+                        )
+                    }
+                };
+
+            let mut column_names = Vec::with_capacity(columns.len());
+            let mut column_ids = Vec::with_capacity(columns.len());
+            for column in columns {
+                let column_name = self.normalize_object_identifier(column);
+                let Ok(field) = table_schema.field_with_name(&column_name) else {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "column `{}` in the {} constraint does not exist",
+                        column_name, constraint_type
+                    ))
+                    .set_span(column.span));
+                };
+                if column_ids.contains(&field.column_id) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "column `{}` appears twice in the {} constraint",
+                        column_name, constraint_type
+                    )));
+                }
+                column_ids.push(field.column_id);
+                column_names.push(column_name);
+            }
+            let check_expr = match check_expr {
+                Some((check_expr, check_column_ids)) => {
+                    for column_id in check_column_ids {
+                        let field = table_schema.field_of_column_id(column_id)?;
+                        column_ids.push(column_id);
+                        column_names.push(field.name().clone());
+                    }
+                    Some(check_expr)
+                }
+                None => None,
+            };
+            if constraint_type == TableConstraintType::ForeignKey
+                && !reference_columns.is_empty()
+                && reference_columns.len() != column_ids.len()
+            {
+                return Err(ErrorCode::BadArguments(
+                    "number of referencing and referenced columns for foreign key disagree",
+                ));
+            }
+            if constraint_type == TableConstraintType::PrimaryKey
+                && constraints
+                    .values()
+                    .any(|c| c.constraint_type == TableConstraintType::PrimaryKey)
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "multiple primary keys for table `{table}` are not allowed"
+                )));
+            }
+
+            // The unnamed constraints are named like `<table>_pkey`, `<table>_<columns>_key`,
+            // `<table>_<columns>_fkey` and `<table>_<columns>_check`. As a table can have many
+            // check constraints on the same columns, a number is appended to the duplicated
+            // check constraint names.
+            let name = match &constraint_def.name {
+                Some(name) => self.normalize_object_identifier(name),
+                None => match constraint_type {
+                    TableConstraintType::PrimaryKey => format!("{table}_pkey"),
+                    TableConstraintType::Unique => {
+                        format!("{table}_{}_key", column_names.join("_"))
+                    }
+                    TableConstraintType::ForeignKey => {
+                        format!("{table}_{}_fkey", column_names.join("_"))
+                    }
+                    TableConstraintType::Check => {
+                        let prefix = if column_names.is_empty() {
+                            table.to_string()
+                        } else {
+                            format!("{table}_{}", column_names.join("_"))
+                        };
+                        let mut name = format!("{prefix}_check");
+                        let mut suffix = 0;
+                        while constraints.contains_key(&name) {
+                            suffix += 1;
+                            name = format!("{prefix}_check{suffix}");
+                        }
+                        name
+                    }
+                },
+            };
+            if constraints.contains_key(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated constraint name: {}",
+                    name
+                )));
+            }
+
+            constraints.insert(name.clone(), TableConstraint {
+                name,
+                constraint_type,
+                column_ids,
+                reference_table,
+                reference_columns,
+                check_expr,
+            });
+        }
+        Ok(constraints)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn analyze_create_table_schema(
         &self,
@@ -1678,7 +1928,7 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, inverted_index_defs, _) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
                 let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
@@ -15,7 +15,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OrderByExpr;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
+use databend_common_ast::ast::WindowSpec;
 use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use crate::binder::Binder;
@@ -92,4 +104,105 @@ impl Binder {
             Arc::new(new_expr),
         ))
     }
+
+    /// Rewrites `SELECT DISTINCT ON (keys) ... ORDER BY order_by` into `SELECT ...` and
+    /// the filter `row_number() OVER (PARTITION BY keys ORDER BY order_by) = 1`, which keeps
+    /// the first row of each group of the keys by the `ORDER BY`, and the kept rows are still
+    /// sorted by the `ORDER BY` of the query. The filter is applied after the `QUALIFY`.
+    pub(crate) fn rewrite_distinct_on(
+        &self,
+        stmt: &SelectStmt,
+        order_by: &[OrderByExpr],
+    ) -> Result<Option<(SelectStmt, Expr)>> {
+        let Some(keys) = &stmt.distinct_on else {
+            return Ok(None);
+        };
+
+        // The positions of the select list, like `ORDER BY 1`, are not allowed in the window.
+        let resolve_position = |expr: &Expr| -> Result<Expr> {
+            let Expr::Literal {
+                span,
+                value: Literal::UInt64(position),
+            } = expr
+            else {
+                return Ok(expr.clone());
+            };
+            let position = *position as usize;
+            if position == 0
+                || position > stmt.select_list.len()
+                || !stmt.select_list[..position]
+                    .iter()
+                    .all(|target| matches!(target, SelectTarget::AliasedExpr { .. }))
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "DISTINCT ON position {} is not an expression of the select list",
+                    position
+                ))
+                .set_span(*span));
+            }
+            match &stmt.select_list[position - 1] {
+                SelectTarget::AliasedExpr { expr, .. } => Ok(*expr.clone()),
+                SelectTarget::StarColumns { .. } => unreachable!(),
+            }
+        };
+        let keys = keys
+            .iter()
+            .map(resolve_position)
+            .collect::<Result<Vec<_>>>()?;
+        let order_by = order_by
+            .iter()
+            .map(|order| {
+                Ok(OrderByExpr {
+                    expr: resolve_position(&order.expr)?,
+                    ..order.clone()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Any row of a group may be kept if the rows of the group are not ordered.
+        let key_names = keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+        if order_by
+            .iter()
+            .all(|order| key_names.contains(&order.expr.to_string()))
+        {
+            self.ctx.push_warning(format!(
+                "SELECT DISTINCT ON ({}) has no ORDER BY on other expressions, the row kept for each group is nondeterministic",
+                key_names.join(", ")
+            ));
+        }
+
+        let row_number = Expr::FunctionCall {
+            span: stmt.span,
+            func: FunctionCall {
+                distinct: false,
+                name: Identifier::from_name(stmt.span, "row_number"),
+                args: vec![],
+                params: vec![],
+                order_by: vec![],
+                window: Some(WindowDesc {
+                    ignore_nulls: None,
+                    window: Window::WindowSpec(WindowSpec {
+                        existing_window_name: None,
+                        partition_by: keys,
+                        order_by,
+                        window_frame: None,
+                    }),
+                }),
+                lambda: None,
+            },
+        };
+        let first_row = Expr::BinaryOp {
+            span: stmt.span,
+            op: BinaryOperator::Eq,
+            left: Box::new(row_number),
+            right: Box::new(Expr::Literal {
+                span: stmt.span,
+                value: Literal::UInt64(1),
+            }),
+        };
+
+        let mut new_stmt = stmt.clone();
+        new_stmt.distinct_on = None;
+        Ok(Some((new_stmt, first_row)))
+    }
 }
@@ -30,6 +30,7 @@ pub struct ExplainConfig {
     pub logical: bool,
     pub optimized: bool,
     pub decorrelated: bool,
+    pub decisions: bool,
 }
 
 impl ExplainConfig {
@@ -63,6 +64,13 @@ impl ExplainConfig {
             .set_span(span));
         }
 
+        if self.decisions && (self.logical || !matches!(kind, ExplainKind::Plan)) {
+            return Err(ErrorCode::SyntaxException(
+                "This EXPLAIN statement does not support DECISIONS option".to_string(),
+            )
+            .set_span(span));
+        }
+
         Ok(())
     }
 }
@@ -73,6 +81,7 @@ struct ExplainConfigBuilder {
     logical: bool,
     optimized: bool,
     decorrelated: bool,
+    decisions: bool,
 }
 
 impl ExplainConfigBuilder {
@@ -88,6 +97,7 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.decorrelated = true;
             }
+            ExplainOption::Decisions => self.decisions = true,
         }
 
         self
@@ -99,6 +109,7 @@ impl ExplainConfigBuilder {
             logical: self.logical,
             optimized: self.optimized,
             decorrelated: self.decorrelated,
+            decisions: self.decisions,
         }
     }
 }
@@ -147,6 +147,7 @@ impl Binder {
                         data_type,
                         outer_columns,
                         contain_agg: None,
+                        correlated_query: None,
                     })
                 } else {
                     item.scalar.clone()
@@ -269,7 +269,7 @@ impl Binder {
             }
         }
 
-        let project_set = ProjectSet { srfs };
+        let project_set = ProjectSet { srfs, outer: false };
         let new_expr = SExpr::create_unary(Arc::new(project_set.into()), Arc::new(child));
 
         Ok(new_expr)
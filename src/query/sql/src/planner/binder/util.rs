@@ -86,6 +86,7 @@ impl Binder {
             | RelOperator::Limit(_)
             | RelOperator::Aggregate(_)
             | RelOperator::Window(_)
+            | RelOperator::MatchRecognize(_)
             | RelOperator::Mutation(_)
             | RelOperator::Recluster(_)
             | RelOperator::MutationSource(_)
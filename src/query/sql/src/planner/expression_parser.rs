@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr as AExpr;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
@@ -28,6 +32,7 @@ use databend_common_expression::type_check::check_cast;
 use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnId;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
@@ -37,10 +42,12 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
 use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
 use parking_lot::RwLock;
 
 use crate::binder::wrap_cast;
@@ -354,6 +361,129 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Checks the expression of a CHECK constraint against the stored columns of the table,
+/// returns the normalized expression and the ids of the columns it refers to.
+pub fn parse_check_constraint_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: &TableSchema,
+    ast: &AExpr,
+) -> Result<(String, Vec<ColumnId>)> {
+    // The virtual computed columns are not written, so they can't be checked.
+    let table_schema = table_schema.remove_virtual_computed_fields();
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            Some(field.column_id),
+            None,
+            None,
+        );
+    }
+
+    let settings = ctx.get_settings();
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+
+    let (scalar, data_type) = *type_checker.resolve(ast)?;
+    if !scalar.evaluable() {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{:#}` is invalid",
+            ast
+        )));
+    }
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{:#}` must be of type Boolean, but has type {}",
+            ast, data_type,
+        )));
+    }
+    let check_expr = scalar.as_expr()?;
+    if !check_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` is not deterministic",
+            check_expr.sql_display(),
+        )));
+    }
+    let column_ids = scalar
+        .used_columns()
+        .into_iter()
+        .map(|index| table_schema.field(index).column_id)
+        .collect::<BTreeSet<_>>();
+
+    let mut ast = ast.clone();
+    let mut normalizer = IdentifierNormalizer {
+        ctx: &name_resolution_ctx,
+    };
+    ast.drive_mut(&mut normalizer);
+    Ok((format!("{:#}", ast), column_ids.into_iter().collect()))
+}
+
+#[derive(VisitorMut)]
+#[visitor(ColumnRef(enter))]
+struct CheckColumnRenamer<'a> {
+    old_column: &'a str,
+    new_column: &'a str,
+}
+
+impl CheckColumnRenamer<'_> {
+    fn enter_column_ref(&mut self, column: &mut ColumnRef) {
+        if let ColumnID::Name(ident) = &mut column.column {
+            if ident.name == self.old_column {
+                *ident = Identifier::from_name_with_quoted(
+                    ident.span,
+                    self.new_column.to_string(),
+                    Some('`'),
+                );
+            }
+        }
+    }
+}
+
+/// Rewrites the expression of a CHECK constraint to refer to the renamed column.
+pub fn rename_check_constraint_column(
+    ctx: Arc<dyn TableContext>,
+    check_expr: &str,
+    old_column: &str,
+    new_column: &str,
+) -> Result<String> {
+    let settings = ctx.get_settings();
+    let tokens = tokenize_sql(check_expr)?;
+    let sql_dialect = settings.get_sql_dialect()?;
+    let mut asts = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+    if asts.len() != 1 {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Expected single expr, but got {}",
+            asts.len()
+        )));
+    }
+    let mut ast = asts.remove(0);
+    ast.drive_mut(&mut CheckColumnRenamer {
+        old_column,
+        new_column,
+    });
+    Ok(format!("{:#}", ast))
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     lambda_context: &mut BindContext,
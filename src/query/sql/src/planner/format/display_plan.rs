@@ -70,6 +70,7 @@ impl Plan {
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
+            Plan::AddTableConstraint(_) => Ok("AddTableConstraint".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable { .. } => Ok("ReclusterTable".to_string()),
@@ -81,6 +82,7 @@ impl Plan {
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
             Plan::VacuumTemporaryFiles(_) => Ok("VacuumTemporaryFiles".to_string()),
             Plan::AnalyzeTable(_) => Ok("AnalyzeTable".to_string()),
+            Plan::ChecksumTable(_) => Ok("ChecksumTable".to_string()),
             Plan::ExistsTable(_) => Ok("ExistsTable".to_string()),
 
             // Views
@@ -75,7 +75,7 @@ impl SubqueryRewriter {
             let table_index =
                 match metadata.table_index_by_column_indexes(correlated_columns) {
                     Some(index) => index,
-                    None => return Err(ErrorCode::SemanticError(
+                    None => return Err(ErrorCode::Unimplemented(
                         "Join left plan's from clause can't contain subquery to dcorrelated join right plan",
                     )),
                 };
@@ -237,7 +237,7 @@ impl SubqueryRewriter {
                 self.flatten_expression_scan(plan, scan, correlated_columns)
             }
 
-            _ => Err(ErrorCode::SemanticError(
+            _ => Err(ErrorCode::Unimplemented(
                 "Invalid plan type for flattening subquery",
             )),
         }
@@ -351,7 +351,13 @@ impl SubqueryRewriter {
             });
         }
         Ok(SExpr::create_unary(
-            Arc::new(ProjectSet { srfs }.into()),
+            Arc::new(
+                ProjectSet {
+                    srfs,
+                    outer: project_set.outer,
+                }
+                .into(),
+            ),
             Arc::new(SExpr::create_unary(
                 Arc::new(
                     EvalScalar {
@@ -700,7 +706,7 @@ impl SubqueryRewriter {
             .iter()
             .any(|index| correlated_columns.contains(index))
         {
-            return Err(ErrorCode::SemanticError(
+            return Err(ErrorCode::Unimplemented(
                 "correlated columns in window functions not supported",
             ));
         }
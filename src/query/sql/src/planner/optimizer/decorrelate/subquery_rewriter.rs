@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::vec;
 
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::Range;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -24,14 +28,20 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::Scalar;
 use databend_common_functions::aggregates::AggregateCountFunction;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
 
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
+use crate::plans::AsyncFunction;
+use crate::plans::AsyncFunctionArgument;
+use crate::plans::AsyncFunctionCall;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
@@ -51,8 +61,10 @@ use crate::plans::SubqueryType;
 use crate::plans::UDAFCall;
 use crate::plans::UDFCall;
 use crate::plans::UDFLambdaCall;
+use crate::plans::Visitor as ScalarVisitor;
 use crate::plans::WindowFuncType;
 use crate::Binder;
+use crate::ColumnBinding;
 use crate::IndexType;
 use crate::MetadataRef;
 
@@ -185,12 +197,13 @@ impl SubqueryRewriter {
                 Arc::new(self.rewrite(s_expr.child(1)?)?),
             )),
 
-            RelOperator::Limit(_) | RelOperator::Udf(_) | RelOperator::AsyncFunction(_) => {
-                Ok(SExpr::create_unary(
-                    Arc::new(s_expr.plan().clone()),
-                    Arc::new(self.rewrite(s_expr.child(0)?)?),
-                ))
-            }
+            RelOperator::Limit(_)
+            | RelOperator::MatchRecognize(_)
+            | RelOperator::Udf(_)
+            | RelOperator::AsyncFunction(_) => Ok(SExpr::create_unary(
+                Arc::new(s_expr.plan().clone()),
+                Arc::new(self.rewrite(s_expr.child(0)?)?),
+            )),
 
             RelOperator::DummyTableScan(_)
             | RelOperator::Scan(_)
@@ -254,6 +267,9 @@ impl SubqueryRewriter {
                 ))
             }
             ScalarExpr::SubqueryExpr(subquery) => {
+                // The outer column references of the subquery are located in the plan
+                // before rewriting if it's evaluated for each outer row.
+                let bound_subquery = subquery.subquery.clone();
                 // Rewrite subquery recursively
                 let mut subquery = subquery.clone();
                 subquery.subquery = Box::new(self.rewrite(&subquery.subquery)?);
@@ -277,12 +293,27 @@ impl SubqueryRewriter {
                         is_conjunctive_predicate,
                     )?
                 } else {
-                    self.try_decorrelate_subquery(
+                    match self.try_decorrelate_subquery(
                         s_expr,
                         &subquery,
                         &mut flatten_info,
                         is_conjunctive_predicate,
-                    )?
+                    ) {
+                        Ok(res) => res,
+                        // Fall back to evaluate the subquery for each outer row if the
+                        // decorrelation of its plan isn't implemented.
+                        Err(err) if err.code() == ErrorCode::UNIMPLEMENTED => {
+                            return match self.try_apply_correlated_scalar_subquery(
+                                s_expr,
+                                &bound_subquery,
+                                &subquery,
+                            )? {
+                                Some(res) => Ok(res),
+                                None => Err(err),
+                            };
+                        }
+                        Err(err) => return Err(err),
+                    }
                 };
 
                 // If we unnest the subquery into a simple join, then we can replace the
@@ -448,6 +479,93 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Evaluate a correlated scalar subquery that can't be decorrelated for each outer
+    /// row, the subquery is executed with its outer column references replaced by the
+    /// values of the row. Returns `None` if the subquery can't be evaluated in this way.
+    fn try_apply_correlated_scalar_subquery(
+        &mut self,
+        s_expr: &SExpr,
+        bound_subquery: &SExpr,
+        subquery: &SubqueryExpr,
+    ) -> Result<Option<(ScalarExpr, SExpr)>> {
+        let Some(query) = &subquery.correlated_query else {
+            return Ok(None);
+        };
+        if subquery.typ != SubqueryType::Scalar {
+            return Ok(None);
+        }
+        let Some((sql, outer_columns)) =
+            correlated_subquery_sql(query, bound_subquery, &subquery.outer_columns)?
+        else {
+            return Ok(None);
+        };
+
+        // The outer values are passed to the subquery as literals, so only the outer
+        // columns provided by the input and of a literal type are supported.
+        let prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+        let supported = outer_columns.iter().all(|column| {
+            prop.output_columns.contains(&column.index)
+                && matches!(
+                    column.data_type.remove_nullable(),
+                    DataType::Boolean
+                        | DataType::Number(_)
+                        | DataType::Decimal(_)
+                        | DataType::String
+                        | DataType::Date
+                )
+        });
+        if !supported {
+            return Ok(None);
+        }
+
+        let data_type = subquery.data_type.wrap_nullable();
+        let index = self.metadata.write().add_derived_column(
+            "scalar_subquery".to_string(),
+            data_type.clone(),
+            None,
+        );
+        let async_func = AsyncFunctionCall {
+            span: subquery.span,
+            func_name: "scalar_subquery".to_string(),
+            display_name: format!("({sql})"),
+            return_type: Box::new(data_type.clone()),
+            arguments: outer_columns
+                .iter()
+                .map(|column| {
+                    ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: subquery.span,
+                        column: column.clone(),
+                    })
+                })
+                .collect(),
+            func_arg: AsyncFunctionArgument::ScalarSubquery(sql),
+        };
+        let s_expr = SExpr::create_unary(
+            Arc::new(
+                AsyncFunction {
+                    items: vec![ScalarItem {
+                        scalar: async_func.into(),
+                        index,
+                    }],
+                }
+                .into(),
+            ),
+            Arc::new(s_expr.clone()),
+        );
+
+        let column_ref = ScalarExpr::BoundColumnRef(BoundColumnRef {
+            span: subquery.span,
+            column: ColumnBindingBuilder::new(
+                format!("scalar_subquery_{index}"),
+                index,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build(),
+        });
+        Ok(Some((column_ref, s_expr)))
+    }
+
     fn try_rewrite_uncorrelated_subquery(
         &mut self,
         left: &SExpr,
@@ -695,3 +813,142 @@ pub fn check_child_expr_in_subquery(
         ))),
     }
 }
+
+// Replace the outer column references of a correlated subquery with holes `:outer_<n>`,
+// returns the SQL of the subquery and the outer columns to fill the holes, or `None`
+// if some outer column references can't be located in the subquery.
+fn correlated_subquery_sql(
+    subquery: &Query,
+    s_expr: &SExpr,
+    outer_columns: &ColumnSet,
+) -> Result<Option<(String, Vec<ColumnBinding>)>> {
+    struct OuterColumnRefVisitor<'a> {
+        outer_columns: &'a ColumnSet,
+        column_refs: BTreeMap<Range, ColumnBinding>,
+    }
+
+    impl OuterColumnRefVisitor<'_> {
+        fn visit_s_expr(&mut self, s_expr: &SExpr) -> Result<()> {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(plan) => {
+                    for item in plan.items.iter() {
+                        self.visit(&item.scalar)?;
+                    }
+                }
+                RelOperator::Filter(plan) => {
+                    for predicate in plan.predicates.iter() {
+                        self.visit(predicate)?;
+                    }
+                }
+                RelOperator::ProjectSet(plan) => {
+                    for item in plan.srfs.iter() {
+                        self.visit(&item.scalar)?;
+                    }
+                }
+                RelOperator::Aggregate(plan) => {
+                    for item in plan
+                        .group_items
+                        .iter()
+                        .chain(plan.aggregate_functions.iter())
+                    {
+                        self.visit(&item.scalar)?;
+                    }
+                }
+                RelOperator::Window(plan) => {
+                    for item in plan.arguments.iter().chain(plan.partition_by.iter()) {
+                        self.visit(&item.scalar)?;
+                    }
+                    for item in plan.order_by.iter() {
+                        self.visit(&item.order_by_item.scalar)?;
+                    }
+                }
+                RelOperator::Join(plan) => {
+                    for condition in plan.equi_conditions.iter() {
+                        self.visit(&condition.left)?;
+                        self.visit(&condition.right)?;
+                    }
+                    for condition in plan.non_equi_conditions.iter() {
+                        self.visit(condition)?;
+                    }
+                }
+                _ => {}
+            }
+            for child in s_expr.children() {
+                self.visit_s_expr(child)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<'a> ScalarVisitor<'a> for OuterColumnRefVisitor<'_> {
+        fn visit_bound_column_ref(&mut self, col: &'a BoundColumnRef) -> Result<()> {
+            if let Some(span) = col.span {
+                if self.outer_columns.contains(&col.column.index) {
+                    self.column_refs.insert(span, col.column.clone());
+                }
+            }
+            Ok(())
+        }
+
+        fn visit_subquery(&mut self, subquery: &'a SubqueryExpr) -> Result<()> {
+            if let Some(child_expr) = subquery.child_expr.as_ref() {
+                self.visit(child_expr)?;
+            }
+            self.visit_s_expr(&subquery.subquery)
+        }
+    }
+
+    #[derive(VisitorMut)]
+    #[visitor(Expr(enter))]
+    struct HoleReplacer<'a> {
+        column_refs: &'a BTreeMap<Range, ColumnBinding>,
+        outer_columns: Vec<ColumnBinding>,
+    }
+
+    impl HoleReplacer<'_> {
+        fn enter_expr(&mut self, expr: &mut Expr) {
+            let Expr::ColumnRef {
+                span: Some(span), ..
+            } = expr
+            else {
+                return;
+            };
+            let Some(column) = self.column_refs.get(span) else {
+                return;
+            };
+            let position = match self
+                .outer_columns
+                .iter()
+                .position(|outer_column| outer_column.index == column.index)
+            {
+                Some(position) => position,
+                None => {
+                    self.outer_columns.push(column.clone());
+                    self.outer_columns.len() - 1
+                }
+            };
+            *expr = Expr::Hole {
+                span: Some(*span),
+                name: format!("outer_{position}"),
+            };
+        }
+    }
+
+    let mut visitor = OuterColumnRefVisitor {
+        outer_columns,
+        column_refs: BTreeMap::new(),
+    };
+    visitor.visit_s_expr(s_expr)?;
+
+    let mut query = subquery.clone();
+    let mut replacer = HoleReplacer {
+        column_refs: &visitor.column_refs,
+        outer_columns: vec![],
+    };
+    query.drive_mut(&mut replacer);
+
+    if replacer.outer_columns.len() != outer_columns.len() {
+        return Ok(None);
+    }
+    Ok(Some((query.to_string(), replacer.outer_columns)))
+}
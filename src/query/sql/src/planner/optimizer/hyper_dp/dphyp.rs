@@ -182,6 +182,7 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
                         | RelOperator::Udf(_)
                 ) {
                     left_is_subquery = true;
@@ -194,6 +195,7 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
                         | RelOperator::Udf(_)
                 ) {
                     right_is_subquery = true;
@@ -251,6 +253,7 @@ impl DPhpy {
             | RelOperator::Limit(_)
             | RelOperator::EvalScalar(_)
             | RelOperator::Window(_)
+            | RelOperator::MatchRecognize(_)
             | RelOperator::Udf(_)
             | RelOperator::Filter(_) => {
                 if join_child {
@@ -38,6 +38,7 @@ mod dynamic_sample;
 pub use cascades::CascadesOptimizer;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
+pub use distributed::SortAndLimitPushDownOptimizer;
 pub use extract::PatternExtractor;
 pub use filter::DeduplicateJoinConditionOptimizer;
 pub use filter::InferFilterOptimizer;
@@ -50,6 +51,7 @@ pub use optimizer::OptimizerContext;
 pub use optimizer::RecursiveOptimizer;
 pub use property::*;
 pub use rule::agg_index;
+pub(crate) use rule::extract_top_n;
 pub use rule::try_push_down_filter_join;
 pub use rule::RuleFactory;
 pub use rule::RuleID;
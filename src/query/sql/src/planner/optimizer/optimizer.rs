@@ -42,6 +42,7 @@ use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::util::contains_warehouse_table_scan;
+use crate::optimizer::util::max_estimated_rows;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -58,6 +59,7 @@ use crate::plans::Plan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::SetScalarsOrQuery;
+use crate::plans::ValidationMode;
 use crate::InsertInputSource;
 use crate::MetadataRef;
 
@@ -297,7 +299,9 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             options,
         })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
+            // The validation only reads the files, it is not worth being distributed.
             plan.enable_distributed = opt_ctx.enable_distributed_optimization
+                && plan.validation_mode == ValidationMode::None
                 && opt_ctx
                     .table_ctx
                     .get_settings()
@@ -381,6 +385,8 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
 }
 
 pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -> Result<SExpr> {
+    // The warehouse tables must be read on all the nodes.
+    let mut require_distributed = false;
     if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
         opt_ctx.enable_distributed_optimization = false;
         info!("Disable distributed optimization due to local table scan.");
@@ -389,6 +395,7 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
 
         if !warehouse.is_empty() {
             opt_ctx.enable_distributed_optimization = true;
+            require_distributed = true;
             info!("Enable distributed optimization due to warehouse table scan.");
         }
     }
@@ -423,6 +430,10 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     // Run post rewrite rules
     s_expr = RecursiveOptimizer::new(&[RuleID::SplitAggregate], opt_ctx).run(&s_expr)?;
 
+    if !require_distributed {
+        disable_distributed_for_small_query(opt_ctx, &s_expr)?;
+    }
+
     // Cost based optimization
     let mut dphyp_optimized = false;
     if opt_ctx.enable_dphyp && opt_ctx.enable_join_reorder {
@@ -477,8 +488,38 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     Ok(s_expr)
 }
 
+// The exchanges cost more than they save for the small queries, so the query is executed
+// on the local node if the estimated rows are below the `single_node_rows_threshold`.
+fn disable_distributed_for_small_query(
+    opt_ctx: &mut OptimizerContext,
+    s_expr: &SExpr,
+) -> Result<()> {
+    if !opt_ctx.enable_distributed_optimization {
+        return Ok(());
+    }
+    let threshold = opt_ctx
+        .table_ctx
+        .get_settings()
+        .get_single_node_rows_threshold()?;
+    if threshold == 0 {
+        return Ok(());
+    }
+    if let Some(rows) = max_estimated_rows(s_expr)? {
+        if rows < threshold as f64 {
+            opt_ctx.enable_distributed_optimization = false;
+            info!(
+                "Disable distributed optimization due to small estimated rows: {}",
+                rows
+            );
+        }
+    }
+    Ok(())
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
+    // The warehouse tables must be read on all the nodes.
+    let mut require_distributed = false;
     if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
         opt_ctx.enable_distributed_optimization = false;
         info!("Disable distributed optimization due to local table scan.");
@@ -487,6 +528,7 @@ async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
 
         if !warehouse.is_empty() {
             opt_ctx.enable_distributed_optimization = true;
+            require_distributed = true;
             info!("Enable distributed optimization due to warehouse table scan.");
         }
     }
@@ -516,6 +558,10 @@ async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     // Run post rewrite rules
     s_expr = RecursiveOptimizer::new(&[RuleID::SplitAggregate], opt_ctx).run(&s_expr)?;
 
+    if !require_distributed {
+        disable_distributed_for_small_query(opt_ctx, &s_expr)?;
+    }
+
     // Cost based optimization
     let mut dphyp_optimized = false;
     if opt_ctx.enable_dphyp && opt_ctx.enable_join_reorder {
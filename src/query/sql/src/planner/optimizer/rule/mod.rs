@@ -23,6 +23,7 @@ mod utils;
 
 pub use factory::RuleFactory;
 pub use rewrite::agg_index;
+pub(crate) use rewrite::extract_top_n;
 pub use rewrite::try_push_down_filter_join;
 pub use rule::Rule;
 pub use rule::RuleID;
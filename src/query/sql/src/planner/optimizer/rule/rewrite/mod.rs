@@ -69,6 +69,7 @@ pub use rule_push_down_filter_scan::RulePushDownFilterScan;
 pub use rule_push_down_filter_sort::RulePushDownFilterSort;
 pub use rule_push_down_filter_union::RulePushDownFilterUnion;
 pub use rule_push_down_filter_window::RulePushDownFilterWindow;
+pub(crate) use rule_push_down_filter_window_top_n::extract_top_n;
 pub use rule_push_down_filter_window_top_n::RulePushDownFilterWindowTopN;
 pub use rule_push_down_limit::RulePushDownLimit;
 pub use rule_push_down_limit_aggregate::RulePushDownRankLimitAggregate;
@@ -156,7 +156,9 @@ pub fn can_filter_null(
     join_type: &JoinType,
     metadata: MetadataRef,
 ) -> Result<bool> {
-    if !matches!(join_type, JoinType::Left | JoinType::Right | JoinType::Full) {
+    // The single joins pad the unmatched rows with NULLs like the outer joins, a
+    // predicate which keeps the NULLs must stay above them as well.
+    if !join_type.is_outer_join() {
         return Ok(true);
     }
 
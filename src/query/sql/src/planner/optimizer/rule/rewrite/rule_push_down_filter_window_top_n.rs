@@ -143,7 +143,8 @@ impl Rule for RulePushDownFilterWindowTopN {
     }
 }
 
-fn extract_top_n(column: usize, predicate: ScalarExpr) -> Option<usize> {
+/// The n of the top n rows kept by the `predicate` on the ranking window `column`.
+pub(crate) fn extract_top_n(column: usize, predicate: ScalarExpr) -> Option<usize> {
     let ScalarExpr::FunctionCall(call) = predicate else {
         return None;
     };
@@ -335,6 +335,7 @@ impl SExpr {
                 }
             }
             RelOperator::Limit(_)
+            | RelOperator::MatchRecognize(_)
             | RelOperator::UnionAll(_)
             | RelOperator::Sort(_)
             | RelOperator::DummyTableScan(_)
@@ -432,6 +433,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
     match rel_op {
         RelOperator::Scan(_)
         | RelOperator::Limit(_)
+        | RelOperator::MatchRecognize(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use databend_common_catalog::table::DistributionLevel;
+use databend_common_exception::Result;
 
+use super::RelExpr;
 use super::SExpr;
 use crate::plans::RelOperator;
 use crate::MetadataRef;
@@ -59,3 +61,29 @@ pub fn contains_warehouse_table_scan(s_expr: &SExpr, metadata: &MetadataRef) ->
 
     false
 }
+
+/// The largest estimated rows of the operators in `s_expr`, `None` if the rows of
+/// any scanned table are unknown.
+pub fn max_estimated_rows(s_expr: &SExpr) -> Result<Option<f64>> {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        let num_rows = scan
+            .statistics
+            .table_stats
+            .as_ref()
+            .and_then(|stats| stats.num_rows);
+        if num_rows.is_none() {
+            return Ok(None);
+        }
+    }
+
+    let mut rows = RelExpr::with_s_expr(s_expr)
+        .derive_cardinality()?
+        .cardinality;
+    for child in s_expr.children() {
+        match max_estimated_rows(child)? {
+            Some(child_rows) => rows = rows.max(child_rows),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(rows))
+}
@@ -322,6 +322,9 @@ impl CopyIntoTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
         if self.from_attachment {
             Arc::new(DataSchema::empty())
+        } else if let ValidationMode::ReturnNRows(_) = self.validation_mode {
+            // The parsed rows are returned instead of being loaded.
+            self.required_values_schema.clone()
         } else {
             Self::copy_into_table_schema()
         }
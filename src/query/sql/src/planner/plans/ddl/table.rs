@@ -28,6 +28,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
@@ -56,6 +57,7 @@ pub struct CreateTablePlan {
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub constraints: BTreeMap<String, TableConstraint>,
 
     pub attached_columns: Option<Vec<Identifier>>,
 }
@@ -203,6 +205,8 @@ pub struct AnalyzeTablePlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// Only collect the statistics of these columns if not empty.
+    pub columns: Vec<String>,
 }
 
 impl AnalyzeTablePlan {
@@ -211,6 +215,22 @@ impl AnalyzeTablePlan {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct ChecksumTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl ChecksumTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![DataField::new(
+            "checksum",
+            DataType::Number(NumberDataType::UInt64),
+        )])
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug)]
 pub struct RenameTablePlan {
@@ -332,6 +352,21 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table add constraint
+#[derive(Clone, Debug)]
+pub struct AddTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub constraint: TableConstraint,
+}
+
+impl AddTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone)]
 pub enum ModifyColumnAction {
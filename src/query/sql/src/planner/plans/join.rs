@@ -405,6 +405,24 @@ impl Join {
         Ok(join_card)
     }
 
+    /// Decides whether to broadcast the build side by its estimated rows and the
+    /// setting `broadcast_join_build_rows_threshold`, returns `None` if the setting
+    /// is 0 or there is no equi condition to shuffle the inputs by.
+    fn broadcast_by_build_rows(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        rel_expr: &RelExpr,
+    ) -> Result<Option<bool>> {
+        let threshold = ctx
+            .get_settings()
+            .get_broadcast_join_build_rows_threshold()?;
+        if threshold == 0 || self.equi_conditions.is_empty() {
+            return Ok(None);
+        }
+        let build_stat_info = rel_expr.derive_cardinality_child(1)?;
+        Ok(Some(build_stat_info.cardinality < threshold as f64))
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -608,10 +626,11 @@ impl Operator for Join {
                 // Use a very large value to prevent broadcast join.
                 1000.0
             };
+            let broadcast = self.broadcast_by_build_rows(&ctx, rel_expr)?.unwrap_or(
+                right_stat_info.cardinality * broadcast_join_threshold < left_stat_info.cardinality,
+            );
             if !settings.get_enforce_shuffle_join()?
-                && (right_stat_info.cardinality * broadcast_join_threshold
-                    < left_stat_info.cardinality
-                    || settings.get_enforce_broadcast_join()?)
+                && (broadcast || settings.get_enforce_broadcast_join()?)
             {
                 if child_index == 1 {
                     required.distribution = Distribution::Broadcast;
@@ -645,13 +664,31 @@ impl Operator for Join {
     fn compute_required_prop_children(
         &self,
         ctx: Arc<dyn TableContext>,
-        _rel_expr: &RelExpr,
+        rel_expr: &RelExpr,
         _required: &RequiredProperty,
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
         let settings = ctx.get_settings();
-        if self.join_type != JoinType::Cross && !settings.get_enforce_broadcast_join()? {
+        let can_broadcast = !matches!(
+            self.join_type,
+            JoinType::Right
+                | JoinType::Full
+                | JoinType::RightAnti
+                | JoinType::RightSemi
+                | JoinType::LeftMark
+                | JoinType::RightSingle
+        ) && !settings.get_enforce_shuffle_join()?;
+        let broadcast_by_build_rows = if can_broadcast {
+            self.broadcast_by_build_rows(&ctx, rel_expr)?
+        } else {
+            None
+        };
+
+        if self.join_type != JoinType::Cross
+            && !settings.get_enforce_broadcast_join()?
+            && broadcast_by_build_rows != Some(true)
+        {
             // (Hash, Hash)
             children_required.extend(self.equi_conditions.iter().map(|condition| {
                 vec![
@@ -665,16 +702,7 @@ impl Operator for Join {
             }));
         }
 
-        if !matches!(
-            self.join_type,
-            JoinType::Right
-                | JoinType::Full
-                | JoinType::RightAnti
-                | JoinType::RightSemi
-                | JoinType::LeftMark
-                | JoinType::RightSingle
-        ) && !settings.get_enforce_shuffle_join()?
-        {
+        if can_broadcast && broadcast_by_build_rows != Some(false) {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
             let right_distribution = Distribution::Broadcast;
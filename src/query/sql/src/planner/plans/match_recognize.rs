@@ -0,0 +1,246 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use databend_common_ast::Span;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use educe::Educe;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::SortItem;
+use crate::IndexType;
+
+/// `MATCH_RECOGNIZE`, row pattern recognition over the rows of each partition.
+///
+/// The input is sorted by the partition keys and the order keys. Every match of the
+/// pattern produces one row with the partition keys and the measures
+/// (`ONE ROW PER MATCH`), and the next match starts after the last row of the
+/// previous one (`AFTER MATCH SKIP PAST LAST ROW`).
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    pub span: Span,
+
+    pub partition_by: Vec<ScalarItem>,
+    pub order_by: Vec<SortItem>,
+    // The columns referenced by `PREV(..)` in the `DEFINE` clause.
+    pub prev_columns: Vec<MatchPrevColumn>,
+    pub variables: Vec<MatchVariable>,
+    pub pattern: MatchPattern,
+    pub measures: Vec<MatchMeasure>,
+}
+
+impl MatchRecognize {
+    /// The columns which must be provided by the input.
+    pub fn input_columns(&self) -> ColumnSet {
+        let mut col_set = ColumnSet::new();
+        for part in self.partition_by.iter() {
+            col_set.insert(part.index);
+        }
+        for order in self.order_by.iter() {
+            col_set.insert(order.index);
+        }
+        for prev in self.prev_columns.iter() {
+            col_set.insert(prev.source);
+        }
+        for variable in self.variables.iter() {
+            if let Some(define) = &variable.define {
+                col_set.extend(define.used_columns());
+            }
+        }
+        for measure in self.measures.iter() {
+            col_set.extend(measure.kind.column());
+        }
+        for prev in self.prev_columns.iter() {
+            col_set.remove(&prev.index);
+        }
+        col_set
+    }
+
+    pub fn used_columns(&self) -> ColumnSet {
+        let mut used_columns = self.input_columns();
+        used_columns.extend(self.prev_columns.iter().map(|prev| prev.index));
+        used_columns.extend(self.measures.iter().map(|measure| measure.index));
+        used_columns
+    }
+}
+
+impl Operator for MatchRecognize {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MatchRecognize
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(vec![vec![required]])
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns, only the partition keys and the measures are visible.
+        let mut output_columns = ColumnSet::new();
+        output_columns.extend(self.partition_by.iter().map(|part| part.index));
+        output_columns.extend(self.measures.iter().map(|measure| measure.index));
+
+        // Derive used columns
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns.clone(),
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+}
+
+/// `index` holds the value of `source` in the previous row of the partition,
+/// or NULL for the first row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchPrevColumn {
+    pub index: IndexType,
+    pub source: IndexType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchVariable {
+    pub name: String,
+    // A variable without a `DEFINE` condition matches any row.
+    pub define: Option<ScalarExpr>,
+}
+
+/// A concatenation of quantified pattern variables, optionally anchored to the
+/// start or the end of the partition, e.g. `^ A B+ C* $`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchPattern {
+    pub start_anchor: bool,
+    pub end_anchor: bool,
+    pub terms: Vec<MatchPatternTerm>,
+}
+
+impl MatchPattern {
+    pub fn display(&self, variables: &[String]) -> String {
+        let mut terms = Vec::with_capacity(self.terms.len() + 2);
+        if self.start_anchor {
+            terms.push("^".to_string());
+        }
+        for term in self.terms.iter() {
+            let mut text = variables[term.variable].clone();
+            match (term.min, term.max) {
+                (1, Some(1)) => {}
+                (0, None) => text.push('*'),
+                (1, None) => text.push('+'),
+                (0, Some(1)) => text.push('?'),
+                (min, Some(max)) if min == max => write!(text, "{{{min}}}").unwrap(),
+                (min, Some(max)) => write!(text, "{{{min},{max}}}").unwrap(),
+                (min, None) => write!(text, "{{{min},}}").unwrap(),
+            }
+            terms.push(text);
+        }
+        if self.end_anchor {
+            terms.push("$".to_string());
+        }
+        terms.join(" ")
+    }
+}
+
+/// A pattern variable which matches between `min` and `max` consecutive rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchPatternTerm {
+    pub variable: usize,
+    pub min: usize,
+    pub max: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchMeasure {
+    pub index: IndexType,
+    pub kind: MatchMeasureKind,
+}
+
+/// The measures supported in the `MEASURES` clause. `variable` restricts a measure
+/// to the rows mapped to the pattern variable, or to all the rows of the match if
+/// it's `None`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MatchMeasureKind {
+    /// `FIRST([<variable>.]<column>)`
+    First {
+        variable: Option<usize>,
+        column: IndexType,
+    },
+    /// `LAST([<variable>.]<column>)`
+    Last {
+        variable: Option<usize>,
+        column: IndexType,
+    },
+    /// `COUNT(*)` or `COUNT([<variable>.]<column>)`
+    Count {
+        variable: Option<usize>,
+        column: Option<IndexType>,
+    },
+    /// `MATCH_NUMBER()`, the sequential number of the match in the partition
+    MatchNumber,
+}
+
+impl MatchMeasureKind {
+    pub fn column(&self) -> Option<IndexType> {
+        match self {
+            MatchMeasureKind::First { column, .. } | MatchMeasureKind::Last { column, .. } => {
+                Some(*column)
+            }
+            MatchMeasureKind::Count { column, .. } => *column,
+            MatchMeasureKind::MatchNumber => None,
+        }
+    }
+}
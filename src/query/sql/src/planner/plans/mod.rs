@@ -31,6 +31,7 @@ mod insert_multi_table;
 mod join;
 mod kill;
 mod limit;
+mod match_recognize;
 mod mutation;
 mod mutation_source;
 mod operator;
@@ -72,6 +73,7 @@ pub use insert_multi_table::*;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use match_recognize::*;
 pub use mutation::MatchedEvaluator;
 pub use mutation::Mutation;
 pub use mutation::UnmatchedEvaluator;
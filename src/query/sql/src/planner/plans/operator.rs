@@ -37,6 +37,7 @@ use crate::plans::ExpressionScan;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::Limit;
+use crate::plans::MatchRecognize;
 use crate::plans::Mutation;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::ProjectSet;
@@ -107,6 +108,7 @@ pub enum RelOp {
     UnionAll,
     DummyTableScan,
     Window,
+    MatchRecognize,
     ProjectSet,
     ConstantTableScan,
     ExpressionScan,
@@ -145,6 +147,7 @@ pub enum RelOperator {
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
     Window(Window),
+    MatchRecognize(MatchRecognize),
     ProjectSet(ProjectSet),
     ConstantTableScan(ConstantTableScan),
     ExpressionScan(ExpressionScan),
@@ -173,6 +176,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ExpressionScan(rel_op) => rel_op.rel_op(),
             RelOperator::CacheScan(rel_op) => rel_op.rel_op(),
@@ -199,6 +203,7 @@ impl Operator for RelOperator {
             RelOperator::UnionAll(rel_op) => rel_op.arity(),
             RelOperator::DummyTableScan(rel_op) => rel_op.arity(),
             RelOperator::Window(rel_op) => rel_op.arity(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.arity(),
             RelOperator::ProjectSet(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
             RelOperator::ExpressionScan(rel_op) => rel_op.arity(),
@@ -227,6 +232,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -254,6 +260,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -281,6 +288,7 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_stats(rel_expr),
@@ -335,6 +343,9 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -411,6 +422,9 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -569,6 +583,26 @@ impl TryFrom<RelOperator> for Window {
     }
 }
 
+impl From<MatchRecognize> for RelOperator {
+    fn from(v: MatchRecognize) -> Self {
+        Self::MatchRecognize(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MatchRecognize {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MatchRecognize(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to MatchRecognize",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
 impl From<Sort> for RelOperator {
     fn from(v: Sort) -> Self {
         Self::Sort(v)
@@ -30,6 +30,9 @@ use crate::plans::ScalarItem;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProjectSet {
     pub srfs: Vec<ScalarItem>,
+    /// Whether an input row for which the set-returning functions return no rows is still
+    /// returned once, with NULLs as their results, like `LEFT JOIN LATERAL unnest(..) ON TRUE`.
+    pub outer: bool,
 }
 
 impl ProjectSet {
@@ -21,6 +21,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Query;
 use databend_common_ast::Range;
 use databend_common_ast::Span;
 use databend_common_catalog::catalog::Catalog;
@@ -812,6 +813,10 @@ pub struct SubqueryExpr {
     pub outer_columns: ColumnSet,
     // If contain aggregation function in scalar subquery output
     pub contain_agg: Option<bool>,
+    // The AST of a correlated scalar subquery with the outer CTEs it references. It's
+    // used to evaluate the subquery for each outer row if it can't be decorrelated.
+    #[educe(Hash(ignore), PartialEq(ignore))]
+    pub correlated_query: Option<Box<Query>>,
 }
 
 impl SubqueryExpr {
@@ -942,6 +947,17 @@ pub enum AsyncFunctionArgument {
     // The dictionary argument is connection URL of remote source, like Redis, MySQL ...
     // Used by `dict_get` function to connect source and read data.
     DictGetFunction(DictGetFunctionArgument),
+    // The argument of correlated scalar subquery is its SQL with holes `:outer_<n>`.
+    // Used to evaluate a correlated scalar subquery that can't be decorrelated,
+    // the holes are filled with the values of the arguments for each row.
+    ScalarSubquery(String),
+    // The argument of table checksum function is the `CHECKSUM TABLE` statement of the table.
+    // Used by `table_checksum` function to compute the checksum of all the rows of the table.
+    TableChecksum(String),
+    // The query text to estimate is the argument column of the function.
+    // Used by `system$estimate_query_cost` function to plan the query in dry run mode
+    // and estimate its cost.
+    EstimateQueryCost,
 }
 
 #[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
@@ -1023,6 +1039,15 @@ impl AsyncFunctionCall {
             AsyncFunctionArgument::DictGetFunction(_dict_get_function_argument) => {
                 Err(ErrorCode::Internal("Cannot generate dict_get function"))
             }
+            AsyncFunctionArgument::ScalarSubquery(_) => Err(ErrorCode::Internal(
+                "Cannot generate scalar subquery function",
+            )),
+            AsyncFunctionArgument::TableChecksum(_) => Err(ErrorCode::Internal(
+                "Cannot generate table_checksum function",
+            )),
+            AsyncFunctionArgument::EstimateQueryCost => Err(ErrorCode::Internal(
+                "Cannot generate system$estimate_query_cost function",
+            )),
         }
     }
 }
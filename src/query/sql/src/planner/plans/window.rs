@@ -182,6 +182,7 @@ pub struct WindowFuncFrame {
     pub units: WindowFuncFrameUnits,
     pub start_bound: WindowFuncFrameBound,
     pub end_bound: WindowFuncFrameBound,
+    pub exclusion: WindowFuncFrameExclusion,
 }
 
 impl Display for WindowFuncFrame {
@@ -190,7 +191,11 @@ impl Display for WindowFuncFrame {
             f,
             "{:?}: {:?} ~ {:?}",
             self.units, self.start_bound, self.end_bound
-        )
+        )?;
+        if self.exclusion != WindowFuncFrameExclusion::NoOthers {
+            write!(f, " exclude {:?}", self.exclusion)?;
+        }
+        Ok(())
     }
 }
 
@@ -212,6 +217,20 @@ pub enum WindowFuncFrameBound {
     Following(Option<Scalar>),
 }
 
+/// The rows excluded from the frame of the current row.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum WindowFuncFrameExclusion {
+    /// `EXCLUDE NO OTHERS`
+    #[default]
+    NoOthers,
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`, the current row and its peers.
+    Group,
+    /// `EXCLUDE TIES`, the peers of the current row but not the current row itself.
+    Ties,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum WindowFuncType {
     Aggregate(AggregateFunction),
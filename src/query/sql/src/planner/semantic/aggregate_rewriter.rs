@@ -20,30 +20,26 @@ use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Pivot;
 use derive_visitor::VisitorMut;
 
-#[derive(Debug, Clone, VisitorMut)]
+#[derive(Debug, Clone, Default, VisitorMut)]
 #[visitor(Expr(exit), Pivot(enter))]
 pub struct AggregateRewriter {
-    // aggr_expr_ptr is used for skipping the rewrite of Pivot.aggregate.
-    // It only skips the aggregate itself, while the arguments of the aggregate will still be rewritten.
-    // Here, it is assumed that the arguments of the aggregate do not contain nested Pivot.
-    aggr_expr_ptr: *const Expr,
-}
-
-impl Default for AggregateRewriter {
-    fn default() -> Self {
-        Self {
-            aggr_expr_ptr: std::ptr::null(),
-        }
-    }
+    // aggr_expr_ptrs is used for skipping the rewrite of Pivot.aggregates.
+    // It only skips the aggregates themselves, while the arguments of the aggregates will still be rewritten.
+    // Here, it is assumed that the arguments of the aggregates do not contain nested Pivot.
+    aggr_expr_ptrs: Vec<*const Expr>,
 }
 
 impl AggregateRewriter {
     fn enter_pivot(&mut self, pivot: &mut Pivot) {
-        self.aggr_expr_ptr = &pivot.aggregate
+        self.aggr_expr_ptrs = pivot
+            .aggregates
+            .iter()
+            .map(|aggregate| aggregate as *const Expr)
+            .collect();
     }
 
     fn exit_expr(&mut self, expr: &mut Expr) {
-        if self.aggr_expr_ptr == expr {
+        if self.aggr_expr_ptrs.contains(&(expr as *const Expr)) {
             return;
         }
 
@@ -251,6 +251,7 @@ impl AggregatingIndexChecker {
         if stmt.having.is_some()
             || stmt.window_list.is_some()
             || stmt.qualify.is_some()
+            || stmt.distinct_on.is_some()
             || stmt.top_n.is_some()
         {
             self.not_support = true;
@@ -75,6 +75,7 @@ impl DistinctToGroupBy {
                             span: None,
                             hints: None,
                             distinct: false,
+                            distinct_on: None,
                             top_n: None,
                             select_list: args
                                 .iter()
@@ -101,6 +102,7 @@ impl DistinctToGroupBy {
                         hints: None,
                         top_n: None,
                         distinct: false,
+                        distinct_on: None,
                         select_list: vec![databend_common_ast::ast::SelectTarget::AliasedExpr {
                             expr: Box::new(Expr::FunctionCall {
                                 span: None,
@@ -21,11 +21,13 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::vec;
 
+use databend_common_ast::ast::quote::QuotedIdent;
 use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::FrameExclusion;
 use databend_common_ast::ast::FunctionCall as ASTFunctionCall;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::IntervalKind as ASTIntervalKind;
@@ -37,6 +39,8 @@ use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::SubqueryModifier;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TrimWhere;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UnaryOperator;
@@ -46,6 +50,8 @@ use databend_common_ast::ast::Window;
 use databend_common_ast::ast::WindowFrame;
 use databend_common_ast::ast::WindowFrameBound;
 use databend_common_ast::ast::WindowFrameUnits;
+use databend_common_ast::ast::With;
+use databend_common_ast::ast::CTE;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
@@ -163,6 +169,7 @@ use crate::plans::Visitor as ScalarVisitor;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameExclusion;
 use crate::plans::WindowFuncFrameUnits;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -479,9 +486,36 @@ impl<'a> TypeChecker<'a> {
                 low,
                 high,
                 not,
-                ..
+                symmetric,
             } => {
-                if !*not {
+                if *symmetric {
+                    // Rewrite `expr BETWEEN SYMMETRIC low AND high`
+                    // into `expr BETWEEN low AND high OR expr BETWEEN high AND low`
+                    let between = |low: &Expr, high: &Expr| {
+                        Box::new(Expr::Between {
+                            span: *span,
+                            expr: expr.clone(),
+                            low: Box::new(low.clone()),
+                            high: Box::new(high.clone()),
+                            not: false,
+                            symmetric: false,
+                        })
+                    };
+                    let mut result = Expr::BinaryOp {
+                        span: *span,
+                        op: BinaryOperator::Or,
+                        left: between(low, high),
+                        right: between(high, low),
+                    };
+                    if *not {
+                        result = Expr::UnaryOp {
+                            span: *span,
+                            op: UnaryOperator::Not,
+                            expr: Box::new(result),
+                        };
+                    }
+                    self.resolve(&result)?
+                } else if !*not {
                     // Rewrite `expr BETWEEN low AND high`
                     // into `expr >= low AND expr <= high`
                     let (ge_func, _left_type) = *self.resolve_binary_op(
@@ -1330,6 +1364,7 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(frame.exclusion),
         })
     }
 
@@ -1376,9 +1411,21 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(frame.exclusion),
         })
     }
 
+    fn resolve_window_frame_exclusion(
+        exclusion: Option<FrameExclusion>,
+    ) -> WindowFuncFrameExclusion {
+        match exclusion {
+            Some(FrameExclusion::CurrentRow) => WindowFuncFrameExclusion::CurrentRow,
+            Some(FrameExclusion::Group) => WindowFuncFrameExclusion::Group,
+            Some(FrameExclusion::Ties) => WindowFuncFrameExclusion::Ties,
+            Some(FrameExclusion::NoOthers) | None => WindowFuncFrameExclusion::NoOthers,
+        }
+    }
+
     fn resolve_window_frame(
         &mut self,
         span: Span,
@@ -1386,12 +1433,30 @@ impl<'a> TypeChecker<'a> {
         order_by: &mut [WindowOrderBy],
         window_frame: Option<WindowFrame>,
     ) -> Result<WindowFuncFrame> {
+        let exclusion = window_frame
+            .as_ref()
+            .and_then(|frame| frame.exclusion.as_ref())
+            .filter(|exclusion| **exclusion != FrameExclusion::NoOthers);
+        if let Some(exclusion) = exclusion {
+            if !matches!(
+                func,
+                WindowFuncType::Aggregate(_) | WindowFuncType::NthValue(_)
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{exclusion} is only supported by aggregate window functions and value window functions, but got {}",
+                    func.func_name()
+                ))
+                .set_span(span));
+            }
+        }
+
         match func {
             WindowFuncType::PercentRank => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
                     start_bound: WindowFuncFrameBound::Preceding(None),
                     end_bound: WindowFuncFrameBound::Following(None),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
@@ -1403,6 +1468,7 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Preceding(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) => {
@@ -1414,6 +1480,7 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::Ntile(_) => {
@@ -1421,6 +1488,7 @@ impl<'a> TypeChecker<'a> {
                     units: WindowFuncFrameUnits::Rows,
                     start_bound: WindowFuncFrameBound::Preceding(None),
                     end_bound: WindowFuncFrameBound::Following(None),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::CumeDist => {
@@ -1428,6 +1496,7 @@ impl<'a> TypeChecker<'a> {
                     units: WindowFuncFrameUnits::Range,
                     start_bound: WindowFuncFrameBound::Preceding(None),
                     end_bound: WindowFuncFrameBound::Following(None),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             _ => {}
@@ -1449,12 +1518,14 @@ impl<'a> TypeChecker<'a> {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::Following(None),
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         } else {
             Ok(WindowFuncFrame {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::CurrentRow,
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         }
     }
@@ -1775,9 +1846,24 @@ impl<'a> TypeChecker<'a> {
         let params = if (func_name.eq_ignore_ascii_case("string_agg")
             || func_name.eq_ignore_ascii_case("listagg")
             || func_name.eq_ignore_ascii_case("group_concat"))
-            && arguments.len() == 2
+            && (arguments.len() == 2 || arguments.len() == 3)
             && params.is_empty()
         {
+            // The optional third argument is the max length of the result.
+            let max_length = if arguments.len() == 3 {
+                let max_length: u64 = check_number(
+                    None,
+                    &FunctionContext::default(),
+                    &arguments[2].as_expr()?,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                let _ = arguments.pop();
+                let _ = arg_types.pop();
+                Some(max_length)
+            } else {
+                None
+            };
+
             let delimiter_value = ConstantExpr::try_from(arguments[1].clone());
             if arg_types[1] != DataType::String || delimiter_value.is_err() {
                 return Err(ErrorCode::SemanticError(format!(
@@ -1787,7 +1873,11 @@ impl<'a> TypeChecker<'a> {
             let _ = arguments.pop();
             let _ = arg_types.pop();
             let delimiter = delimiter_value.unwrap();
-            vec![delimiter.value]
+            let mut params = vec![delimiter.value];
+            if let Some(max_length) = max_length {
+                params.push(Scalar::Number(NumberScalar::UInt64(max_length)));
+            }
+            params
         } else {
             params
         };
@@ -2865,6 +2955,23 @@ impl<'a> TypeChecker<'a> {
             params.push(Scalar::Number(NumberScalar::Int64(scale)));
         }
 
+        if func_name == "array_flatten" && args.len() == 2 && params.is_empty() {
+            let expr = type_check::check(&arguments[1], &BUILTIN_FUNCTIONS)?;
+            let depth = check_number::<_, i64>(
+                expr.span(),
+                &FunctionContext::default(),
+                &expr,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            if depth <= 0 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The depth of array_flatten must be a positive integer, but got {depth}"
+                ))
+                .set_span(span));
+            }
+            params.push(Scalar::Number(NumberScalar::Int64(depth)));
+        }
+
         let raw_expr = RawExpr::FunctionCall {
             span,
             name: func_name.to_string(),
@@ -3258,6 +3365,12 @@ impl<'a> TypeChecker<'a> {
         if typ.eq(&SubqueryType::Scalar) {
             data_type = data_type.wrap_nullable();
         }
+        let correlated_query = if typ == SubqueryType::Scalar && !rel_prop.outer_columns.is_empty()
+        {
+            self.correlated_subquery_query(subquery)?
+        } else {
+            None
+        };
         let subquery_expr = SubqueryExpr {
             span: subquery.span,
             subquery: Box::new(s_expr),
@@ -3269,12 +3382,114 @@ impl<'a> TypeChecker<'a> {
             typ,
             outer_columns: rel_prop.outer_columns.clone(),
             contain_agg,
+            correlated_query,
         };
 
         let data_type = subquery_expr.data_type();
         Ok(Box::new((subquery_expr.into(), data_type)))
     }
 
+    // The AST of a correlated scalar subquery to evaluate it alone for each outer row if
+    // it can't be decorrelated, the outer CTEs it references are added to its WITH clause.
+    // Returns `None` if it references a materialized CTE, which would be computed per row.
+    fn correlated_subquery_query(&self, subquery: &Query) -> Result<Option<Box<Query>>> {
+        #[derive(Visitor)]
+        #[visitor(TableReference(enter))]
+        struct TableNameVisitor<'a> {
+            name_resolution_ctx: &'a NameResolutionContext,
+            names: HashSet<String>,
+        }
+
+        impl TableNameVisitor<'_> {
+            fn enter_table_reference(&mut self, table_ref: &TableReference) {
+                if let TableReference::Table {
+                    catalog: None,
+                    database: None,
+                    table,
+                    ..
+                } = table_ref
+                {
+                    self.names
+                        .insert(normalize_identifier(table, self.name_resolution_ctx).name);
+                }
+            }
+        }
+
+        let mut query = subquery.clone();
+        let cte_map = &self.bind_context.cte_context.cte_map;
+        if cte_map.is_empty() {
+            return Ok(Some(Box::new(query)));
+        }
+
+        let mut visitor = TableNameVisitor {
+            name_resolution_ctx: self.name_resolution_ctx,
+            names: HashSet::new(),
+        };
+        subquery.drive(&mut visitor);
+        if let Some(with) = &subquery.with {
+            for cte in with.ctes.iter() {
+                let name = normalize_identifier(&cte.alias.name, self.name_resolution_ctx).name;
+                visitor.names.remove(&name);
+            }
+        }
+        // A CTE can only reference the CTEs defined before it.
+        for (name, cte_info) in cte_map.iter().rev() {
+            if visitor.names.contains(name) {
+                cte_info.query.drive(&mut visitor);
+            }
+        }
+
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        let mut recursive = false;
+        let mut ctes = vec![];
+        for (name, cte_info) in cte_map.iter() {
+            if !visitor.names.contains(name) {
+                continue;
+            }
+            if cte_info.materialized {
+                return Ok(None);
+            }
+            recursive |= cte_info.recursive;
+            ctes.push(CTE {
+                span: None,
+                alias: TableAlias {
+                    name: Identifier::from_name_with_quoted(None, name, Some(quote)),
+                    columns: cte_info
+                        .columns_alias
+                        .iter()
+                        .map(|column| Identifier::from_name_with_quoted(None, column, Some(quote)))
+                        .collect(),
+                },
+                materialized: false,
+                query: Box::new(cte_info.query.clone()),
+                cycle: None,
+            });
+        }
+        if ctes.is_empty() {
+            return Ok(Some(Box::new(query)));
+        }
+
+        match &mut query.with {
+            Some(with) => {
+                with.recursive |= recursive;
+                ctes.append(&mut with.ctes);
+                with.ctes = ctes;
+            }
+            None => {
+                query.with = Some(With {
+                    span: None,
+                    recursive,
+                    ctes,
+                });
+            }
+        }
+        Ok(Some(Box::new(query)))
+    }
+
     pub fn all_sugar_functions() -> &'static [Ascii<&'static str>] {
         static FUNCTIONS: &[Ascii<&'static str>] = &[
             Ascii::new("current_catalog"),
@@ -4261,6 +4476,12 @@ impl<'a> TypeChecker<'a> {
         let result = match func_name {
             "nextval" => self.resolve_nextval_async_function(span, func_name, arguments)?,
             "dict_get" => self.resolve_dict_get_async_function(span, func_name, arguments)?,
+            "table_checksum" => {
+                self.resolve_table_checksum_async_function(span, func_name, arguments)?
+            }
+            "system$estimate_query_cost" => {
+                self.resolve_estimate_query_cost_async_function(span, func_name, arguments)?
+            }
             _ => {
                 return Err(ErrorCode::SemanticError(format!(
                     "cannot find async function {}",
@@ -4336,6 +4557,105 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((async_func.into(), return_type)))
     }
 
+    fn resolve_table_checksum_async_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if arguments.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "table_checksum function need two arguments but got {}",
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+        let mut names = Vec::with_capacity(2);
+        for argument in arguments {
+            match argument {
+                Expr::Literal {
+                    value: Literal::String(name),
+                    ..
+                } => names.push(name.clone()),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "table_checksum function argument should be a string literal, but got {}",
+                        argument
+                    ))
+                    .set_span(argument.span()));
+                }
+            }
+        }
+        let (database, table) = (&names[0], &names[1]);
+
+        let catalog = self.ctx.get_current_catalog();
+        databend_common_base::runtime::block_on(self.ctx.get_table(&catalog, database, table))?;
+
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        let sql = format!(
+            "CHECKSUM TABLE {}.{}.{}",
+            QuotedIdent(&catalog, quote),
+            QuotedIdent(database, quote),
+            QuotedIdent(table, quote)
+        );
+
+        let display_name = format!("{}('{}', '{}')", func_name, database, table);
+        let return_type = DataType::Number(NumberDataType::UInt64);
+        let func_arg = AsyncFunctionArgument::TableChecksum(sql);
+
+        let async_func = AsyncFunctionCall {
+            span,
+            func_name: func_name.to_string(),
+            display_name,
+            return_type: Box::new(return_type.clone()),
+            arguments: vec![],
+            func_arg,
+        };
+
+        Ok(Box::new((async_func.into(), return_type)))
+    }
+
+    fn resolve_estimate_query_cost_async_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if arguments.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{} function need one argument but got {}",
+                func_name,
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+        let box (scalar, data_type) = self.resolve(arguments[0])?;
+        let (scalar, return_type) = if data_type.is_nullable_or_null() {
+            (
+                wrap_cast(&scalar, &DataType::String.wrap_nullable()),
+                DataType::Variant.wrap_nullable(),
+            )
+        } else {
+            (wrap_cast(&scalar, &DataType::String), DataType::Variant)
+        };
+
+        let display_name = format!("{}({})", func_name, arguments[0]);
+        let async_func = AsyncFunctionCall {
+            span,
+            func_name: func_name.to_string(),
+            display_name,
+            return_type: Box::new(return_type.clone()),
+            arguments: vec![scalar],
+            func_arg: AsyncFunctionArgument::EstimateQueryCost,
+        };
+
+        Ok(Box::new((async_func.into(), return_type)))
+    }
+
     fn resolve_dict_get_async_function(
         &mut self,
         span: Span,
@@ -4856,6 +5176,7 @@ impl<'a> TypeChecker<'a> {
             typ: SubqueryType::Any,
             outer_columns: rel_prop.outer_columns.clone(),
             contain_agg: None,
+            correlated_query: None,
         };
         let data_type = subquery_expr.data_type();
         Ok(Box::new((subquery_expr.into(), data_type)))
@@ -5082,6 +5403,7 @@ impl<'a> TypeChecker<'a> {
                     low,
                     high,
                     not,
+                    symmetric,
                 } => Ok(Expr::Between {
                     span: *span,
                     expr: Box::new(
@@ -5092,6 +5414,7 @@ impl<'a> TypeChecker<'a> {
                         self.clone_expr_with_replacement(high.as_ref(), replacement_fn)?,
                     ),
                     not: *not,
+                    symmetric: *symmetric,
                 }),
                 Expr::BinaryOp {
                     span,
@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_sql::executor::diff;
+use databend_common_sql::executor::physical_plans::ConstantTableScan;
+use databend_common_sql::executor::physical_plans::Filter;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PlanDiff;
+
+fn constant_scan(plan_id: u32) -> PhysicalPlan {
+    PhysicalPlan::ConstantTableScan(ConstantTableScan {
+        plan_id,
+        values: vec![],
+        num_rows: 0,
+        output_schema: DataSchemaRefExt::create(vec![]),
+    })
+}
+
+fn filter(plan_id: u32, predicate: bool, input: PhysicalPlan) -> PhysicalPlan {
+    PhysicalPlan::Filter(Filter {
+        plan_id,
+        projections: Default::default(),
+        input: Box::new(input),
+        predicates: vec![RemoteExpr::Constant {
+            span: None,
+            scalar: Scalar::Boolean(predicate),
+            data_type: DataType::Boolean,
+        }],
+        stat_info: None,
+    })
+}
+
+#[test]
+fn test_diff_identical_plans() {
+    let a = filter(0, true, constant_scan(1));
+    // Plan ids are ignored.
+    let b = filter(7, true, constant_scan(8));
+    assert!(diff(&a, &b).is_empty());
+}
+
+#[test]
+fn test_diff_extra_filter() {
+    let a = filter(0, true, constant_scan(1));
+    let b = filter(0, true, filter(1, false, constant_scan(2)));
+
+    let diffs = diff(&a, &b);
+    assert_eq!(diffs.len(), 1);
+    assert!(
+        matches!(&diffs[0], PlanDiff::Added { position: 1, name } if name.starts_with("Filter"))
+    );
+
+    let diffs = diff(&b, &a);
+    assert_eq!(diffs.len(), 1);
+    assert!(
+        matches!(&diffs[0], PlanDiff::Removed { position: 1, name } if name.starts_with("Filter"))
+    );
+}
+
+#[test]
+fn test_diff_changed_node() {
+    let a = filter(0, true, constant_scan(1));
+    let b = filter(0, false, constant_scan(1));
+
+    let diffs = diff(&a, &b);
+    assert_eq!(diffs.len(), 1);
+    assert!(matches!(&diffs[0], PlanDiff::Changed {
+        left_position: 0,
+        right_position: 0,
+        ..
+    }));
+}
@@ -0,0 +1,135 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_sql::executor::physical_plans::AggregateFinal;
+use databend_common_sql::executor::physical_plans::AggregatePartial;
+use databend_common_sql::executor::physical_plans::ConstantTableScan;
+use databend_common_sql::executor::physical_plans::ExchangeSink;
+use databend_common_sql::executor::physical_plans::Filter;
+use databend_common_sql::executor::physical_plans::FragmentKind;
+use databend_common_sql::executor::PhysicalPlan;
+
+fn constant_scan(plan_id: u32) -> PhysicalPlan {
+    PhysicalPlan::ConstantTableScan(ConstantTableScan {
+        plan_id,
+        values: vec![],
+        num_rows: 0,
+        output_schema: DataSchemaRefExt::create(vec![DataField::new(
+            "0",
+            DataType::Number(NumberDataType::Int32),
+        )]),
+    })
+}
+
+fn filter(plan_id: u32, input: PhysicalPlan) -> PhysicalPlan {
+    PhysicalPlan::Filter(Filter {
+        plan_id,
+        projections: Default::default(),
+        input: Box::new(input),
+        predicates: vec![RemoteExpr::Constant {
+            span: None,
+            scalar: Scalar::Boolean(true),
+            data_type: DataType::Boolean,
+        }],
+        stat_info: None,
+    })
+}
+
+fn exchange_sink(plan_id: u32, input: PhysicalPlan) -> PhysicalPlan {
+    PhysicalPlan::ExchangeSink(ExchangeSink {
+        plan_id,
+        schema: input.output_schema().unwrap(),
+        input: Box::new(input),
+        kind: FragmentKind::Merge,
+        keys: vec![],
+        destination_fragment_id: 0,
+        query_id: "query".to_string(),
+        ignore_exchange: false,
+        allow_adjust_parallelism: true,
+    })
+}
+
+fn aggregate(final_group_by: Vec<usize>, partial_group_by: Vec<usize>) -> PhysicalPlan {
+    let partial = PhysicalPlan::AggregatePartial(AggregatePartial {
+        plan_id: 1,
+        input: Box::new(constant_scan(2)),
+        group_by_display: partial_group_by.iter().map(|i| i.to_string()).collect(),
+        group_by: partial_group_by,
+        agg_funcs: vec![],
+        enable_experimental_aggregate_hashtable: true,
+        rank_limit: None,
+        stat_info: None,
+    });
+    PhysicalPlan::AggregateFinal(AggregateFinal {
+        plan_id: 0,
+        input: Box::new(partial),
+        group_by_display: final_group_by.iter().map(|i| i.to_string()).collect(),
+        group_by: final_group_by,
+        agg_funcs: vec![],
+        before_group_by_schema: DataSchemaRefExt::create(vec![]),
+        stat_info: None,
+    })
+}
+
+fn validate_error(plan: &PhysicalPlan) -> String {
+    plan.validate().unwrap_err().message()
+}
+
+#[test]
+fn test_validate_valid_plans() {
+    assert!(filter(0, filter(1, constant_scan(2))).validate().is_ok());
+    assert!(exchange_sink(0, filter(1, constant_scan(2)))
+        .validate()
+        .is_ok());
+    assert!(aggregate(vec![0], vec![0]).validate().is_ok());
+}
+
+#[test]
+fn test_validate_plan_id() {
+    assert_eq!(
+        validate_error(&filter(0, filter(1, constant_scan(1)))),
+        "Invalid physical plan: duplicate plan id 1 of Filter and PhysicalConstantTableScan"
+    );
+    // The plan ids are not assigned by `adjust_plan_id`.
+    assert_eq!(
+        validate_error(&filter(0, constant_scan(0))),
+        "Invalid physical plan: the plan id of PhysicalConstantTableScan is not assigned"
+    );
+}
+
+#[test]
+fn test_validate_exchange() {
+    assert_eq!(
+        validate_error(&filter(0, exchange_sink(1, constant_scan(2)))),
+        "Invalid physical plan: ExchangeSink #1 is not the root of a fragment"
+    );
+}
+
+#[test]
+fn test_validate_aggregate() {
+    assert_eq!(
+        validate_error(&aggregate(vec![], vec![0])),
+        "Invalid physical plan: AggregateFinal #0 has 0 group by keys and 0 aggregate functions, but its AggregatePartial #1 has 1 group by keys and 0 aggregate functions"
+    );
+    assert_eq!(
+        validate_error(&aggregate(vec![5], vec![5])),
+        "Invalid physical plan: the group by key of AggregatePartial #1 references column 5 which is not in its input"
+    );
+}
@@ -14,6 +14,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod executor;
 mod optimizer;
 
 use std::collections::BTreeMap;
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::optimizer::SortAndLimitPushDownOptimizer;
+use databend_common_sql::plans::Exchange;
+use databend_common_sql::plans::Limit;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::plans::Scan;
+use databend_common_sql::plans::Sort;
+use databend_common_sql::plans::SortItem;
+
+fn create_sort(limit: Option<usize>) -> Sort {
+    Sort {
+        items: vec![SortItem {
+            index: 0,
+            asc: true,
+            nulls_first: false,
+        }],
+        limit,
+        after_exchange: None,
+        pre_projection: None,
+        window_partition: None,
+    }
+}
+
+/// Limit -> Sort -> Exchange(Merge) -> Scan, as built for `ORDER BY .. LIMIT .. OFFSET ..`
+/// once the limit has been pushed down into the sort.
+fn create_top_n(limit: usize, offset: usize) -> SExpr {
+    let scan = SExpr::create_leaf(Arc::new(RelOperator::Scan(Scan {
+        table_index: 0,
+        ..Default::default()
+    })));
+    let exchange = SExpr::create_unary(
+        Arc::new(RelOperator::Exchange(Exchange::Merge)),
+        Arc::new(scan),
+    );
+    let sort = SExpr::create_unary(
+        Arc::new(RelOperator::Sort(create_sort(Some(limit + offset)))),
+        Arc::new(exchange),
+    );
+    SExpr::create_unary(
+        Arc::new(RelOperator::Limit(Limit {
+            before_exchange: false,
+            limit: Some(limit),
+            offset,
+        })),
+        Arc::new(sort),
+    )
+}
+
+fn as_sort(s_expr: &SExpr) -> &Sort {
+    match s_expr.plan() {
+        RelOperator::Sort(sort) => sort,
+        plan => panic!("expect Sort, got {:?}", plan),
+    }
+}
+
+#[test]
+fn test_top_n_is_computed_before_exchange() -> Result<()> {
+    let optimizer = SortAndLimitPushDownOptimizer::create();
+    let s_expr = optimizer.optimize(&create_top_n(3, 2))?;
+
+    // The final limit keeps the offset and is applied after the merge.
+    let RelOperator::Limit(limit) = s_expr.plan() else {
+        panic!("expect Limit, got {:?}", s_expr.plan());
+    };
+    assert_eq!((limit.limit, limit.offset), (Some(3), 2));
+
+    // The final sort merges the sorted streams of all nodes.
+    let final_sort = s_expr.child(0)?;
+    assert_eq!(as_sort(final_sort).after_exchange, Some(true));
+    assert_eq!(as_sort(final_sort).limit, Some(5));
+
+    let exchange = final_sort.child(0)?;
+    assert!(matches!(
+        exchange.plan(),
+        RelOperator::Exchange(Exchange::MergeSort)
+    ));
+
+    // Each node only ships its local top `limit + offset` rows.
+    let local_sort = exchange.child(0)?;
+    assert_eq!(as_sort(local_sort).after_exchange, Some(false));
+    assert_eq!(as_sort(local_sort).limit, Some(5));
+    assert!(matches!(local_sort.child(0)?.plan(), RelOperator::Scan(_)));
+    Ok(())
+}
+
+#[test]
+fn test_full_sort_is_not_bounded() -> Result<()> {
+    let optimizer = SortAndLimitPushDownOptimizer::create();
+    let s_expr = SExpr::create_unary(
+        Arc::new(RelOperator::Sort(create_sort(None))),
+        Arc::new(SExpr::create_unary(
+            Arc::new(RelOperator::Exchange(Exchange::Merge)),
+            Arc::new(SExpr::create_leaf(Arc::new(RelOperator::Scan(
+                Scan::default(),
+            )))),
+        )),
+    );
+    let s_expr = optimizer.optimize(&s_expr)?;
+
+    let local_sort = s_expr.child(0)?.child(0)?;
+    assert_eq!(as_sort(local_sort).after_exchange, Some(false));
+    assert_eq!(as_sort(local_sort).limit, None);
+    Ok(())
+}
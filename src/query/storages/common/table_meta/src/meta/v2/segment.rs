@@ -82,6 +82,11 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// XOR of the hashes of the rows over the columns of the table, `None` if the
+    /// block is written before the checksum is recorded.
+    #[serde(default)]
+    pub checksum: Option<u64>,
 }
 
 impl BlockMeta {
@@ -99,6 +104,7 @@ impl BlockMeta {
         inverted_index_size: Option<u64>,
         compression: Compression,
         create_on: Option<DateTime<Utc>>,
+        checksum: Option<u64>,
     ) -> Self {
         Self {
             row_count,
@@ -113,6 +119,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            checksum,
         }
     }
 
@@ -254,6 +261,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            checksum: None,
         }
     }
 
@@ -278,6 +286,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            checksum: None,
         }
     }
 }
@@ -118,11 +118,12 @@ impl BlockReader {
 
                 // Then calc project scalars by path_indices
                 let mut default_vals = Vec::with_capacity(schema.fields().len());
-                path_indices.values().for_each(|path| {
-                    default_vals.push(
-                        inner_project_field_default_values(&field_default_vals, path).unwrap(),
-                    );
-                });
+                for path in path_indices.values() {
+                    default_vals.push(inner_project_field_default_values(
+                        &field_default_vals,
+                        path,
+                    )?);
+                }
 
                 (projected_schema, default_vals)
             }
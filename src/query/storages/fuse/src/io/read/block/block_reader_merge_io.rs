@@ -50,6 +50,15 @@ impl BlockReadResult {
         }
     }
 
+    /// The bytes of the column data read from the storage, the cached columns are excluded.
+    pub fn read_bytes(&self) -> usize {
+        self.merge_io_result
+            .columns_chunk_offsets
+            .values()
+            .map(|(_, range)| range.len())
+            .sum()
+    }
+
     pub fn columns_chunks(&self) -> Result<HashMap<ColumnId, DataItem>> {
         let mut res = HashMap::with_capacity(self.merge_io_result.columns_chunk_offsets.len());
 
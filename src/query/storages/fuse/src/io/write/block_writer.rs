@@ -21,6 +21,8 @@ use chrono::Utc;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::is_stream_column;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
@@ -118,6 +120,27 @@ pub fn serialize_block(
     }
 }
 
+/// XOR of the hashes of the rows of `block` over its first `num_columns` columns,
+/// so the checksum of the rows doesn't depend on their order or on how they are
+/// split into blocks.
+pub fn block_checksum(block: &DataBlock, num_columns: usize) -> u64 {
+    let num_rows = block.num_rows();
+    if num_rows == 0 || num_columns == 0 {
+        return 0;
+    }
+    let columns = block.columns()[..num_columns]
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        })
+        .collect::<Vec<Column>>();
+    let mut hashes = vec![0; num_rows];
+    group_hash_columns((&columns).into(), &mut hashes);
+    hashes.into_iter().fold(0, |acc, hash| acc ^ hash)
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -382,6 +405,17 @@ impl BlockBuilder {
         let block_size = data_block.memory_size() as u64;
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        // Hashing the rows is not free, it's only done if the table records the checksums.
+        let checksum = self.write_settings.enable_block_checksum.then(|| {
+            // The stream columns are appended after the columns of the table.
+            let num_table_columns = self
+                .source_schema
+                .fields()
+                .iter()
+                .filter(|field| !is_stream_column(field.name()))
+                .count();
+            block_checksum(&data_block, num_table_columns)
+        });
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let col_metas = serialize_block(
@@ -413,6 +447,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            checksum,
         };
 
         let serialized = BlockSerialization {
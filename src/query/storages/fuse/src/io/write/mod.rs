@@ -17,6 +17,7 @@ mod inverted_index_writer;
 mod meta_writer;
 mod write_settings;
 
+pub use block_writer::block_checksum;
 pub(crate) use block_writer::create_inverted_index_builders;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
@@ -26,6 +26,9 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // record the checksum of the rows of each block, used by `CHECKSUM TABLE`
+    pub enable_block_checksum: bool,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +38,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            enable_block_checksum: false,
         }
     }
 }
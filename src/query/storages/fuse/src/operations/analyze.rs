@@ -68,6 +68,7 @@ impl FuseTable {
         database: &str,
         table: &str,
         snapshot_id: SnapshotId,
+        is_partial: bool,
         pipeline: &mut Pipeline,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
     ) -> Result<()> {
@@ -79,6 +80,7 @@ impl FuseTable {
                 database,
                 table,
                 snapshot_id,
+                is_partial,
                 input,
                 histogram_info_receivers.clone(),
             )
@@ -96,6 +98,8 @@ struct SinkAnalyzeState {
     database: String,
     table: String,
     snapshot_id: SnapshotId,
+    // Only some of the columns are analyzed, keep the statistics of the others.
+    is_partial: bool,
     histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
     input_data: Option<DataBlock>,
     committed: bool,
@@ -113,6 +117,7 @@ impl SinkAnalyzeState {
         database: &str,
         table: &str,
         snapshot_id: SnapshotId,
+        is_partial: bool,
         input: Arc<InputPort>,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
     ) -> Result<ProcessorPtr> {
@@ -124,6 +129,7 @@ impl SinkAnalyzeState {
             database: database.to_string(),
             table: table.to_string(),
             snapshot_id,
+            is_partial,
             histogram_info_receivers,
             input_data: None,
             committed: false,
@@ -172,7 +178,9 @@ impl SinkAnalyzeState {
 
         let is_full = is_full.as_boolean().unwrap();
 
-        let mut ndv_states = table_statistics.map(|s| s.hll.clone()).unwrap_or_default();
+        let (mut ndv_states, mut histograms) = table_statistics
+            .map(|s| (s.hll.clone(), s.histograms.clone()))
+            .unwrap_or_default();
 
         let index_num = self.output_schema.num_fields() - 1;
 
@@ -198,9 +206,14 @@ impl SinkAnalyzeState {
             } else {
                 ndv_states.insert(index, hll);
             }
+            // The histograms of the analyzed columns are rebuilt.
+            histograms.remove(&index);
         }
 
         self.ndv_states = ndv_states;
+        if self.is_partial {
+            self.histograms = histograms;
+        }
         Ok(())
     }
 
@@ -49,6 +49,7 @@ use log::info;
 use crate::io::SegmentsIO;
 use crate::io::SnapshotsIO;
 use crate::pruning::FusePruner;
+use crate::pruning::PruneLevel;
 use crate::FuseTable;
 
 #[derive(Clone)]
@@ -308,6 +309,7 @@ impl FuseTable {
             bloom_index_cols,
             None,
             self.get_storage_format(),
+            PruneLevel::Full,
         )?;
 
         let block_metas = pruner.stream_pruning(blocks).await?;
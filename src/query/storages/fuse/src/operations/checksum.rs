@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::is_stream_column_id;
+use databend_common_expression::ColumnId;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Reduces the checksums recorded in the block metas of the current snapshot
+    /// to the checksum of the rows of the table, without reading the blocks.
+    ///
+    /// Returns `None` if any block has no checksum, or is written with other columns
+    /// than the current ones (e.g. before a column is added or dropped), the rows
+    /// have to be read in that case.
+    #[async_backtrace::framed]
+    pub async fn checksum_from_block_metas(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<u64>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(Some(0));
+        };
+
+        let column_ids: HashSet<ColumnId> = self
+            .schema()
+            .remove_virtual_computed_fields()
+            .to_leaf_column_ids()
+            .into_iter()
+            .collect();
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let mut checksum = 0;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?;
+            for segment in segments {
+                for block in segment?.block_metas()? {
+                    match recorded_checksum(&block, &column_ids) {
+                        Some(block_checksum) => checksum ^= block_checksum,
+                        None => return Ok(None),
+                    }
+                }
+            }
+        }
+        Ok(Some(checksum))
+    }
+}
+
+/// The checksum of `block`, if it is recorded over exactly the columns of `column_ids`.
+fn recorded_checksum(block: &BlockMeta, column_ids: &HashSet<ColumnId>) -> Option<u64> {
+    // The stream columns are not part of the checksum.
+    let mut num_columns = 0;
+    for column_id in block.col_metas.keys() {
+        if is_stream_column_id(*column_id) {
+            continue;
+        }
+        if !column_ids.contains(column_id) {
+            return None;
+        }
+        num_columns += 1;
+    }
+    if num_columns != column_ids.len() {
+        return None;
+    }
+    block.checksum
+}
@@ -16,6 +16,7 @@ mod agg_index_sink;
 mod analyze;
 mod append;
 mod changes;
+mod checksum;
 mod commit;
 mod common;
 mod compact;
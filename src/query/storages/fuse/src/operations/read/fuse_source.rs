@@ -37,6 +37,7 @@ use crate::io::VirtualColumnReader;
 use crate::operations::read::block_partition_receiver_source::BlockPartitionReceiverSource;
 use crate::operations::read::block_partition_source::BlockPartitionSource;
 use crate::operations::read::native_data_transform_reader::ReadNativeDataTransform;
+use crate::operations::read::ordered_merge_processor::OrderedMergeProcessor;
 use crate::operations::read::parquet_data_transform_reader::ReadParquetDataTransform;
 use crate::operations::read::DeserializeDataTransform;
 use crate::operations::read::NativeDeserializeDataTransform;
@@ -63,12 +64,17 @@ pub fn build_fuse_native_source_pipeline(
         max_io_requests = max_io_requests.min(16);
     }
 
+    if plan.ordered_output {
+        max_threads = ordered_output_streams(max_threads, plan, receiver.is_some());
+        max_io_requests = max_threads;
+    }
+
     match block_reader.support_blocking_api() {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
             let mut partitions = StealablePartitions::new(partitions, ctx.clone());
 
-            if topk.is_some() {
+            if topk.is_some() || plan.ordered_output {
                 partitions.disable_steal();
             }
             match receiver {
@@ -98,7 +104,7 @@ pub fn build_fuse_native_source_pipeline(
             let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
             let mut partitions = StealablePartitions::new(partitions, ctx.clone());
 
-            if topk.is_some() {
+            if topk.is_some() || plan.ordered_output {
                 partitions.disable_steal();
             }
             match receiver {
@@ -150,6 +156,10 @@ pub fn build_fuse_native_source_pipeline(
 
     pipeline.try_resize(max_threads)?;
 
+    if plan.ordered_output && pipeline.output_len() > 1 {
+        pipeline.add_pipe(OrderedMergeProcessor::create_pipe(pipeline.output_len()));
+    }
+
     Ok(())
 }
 
@@ -169,10 +179,19 @@ pub fn build_fuse_parquet_source_pipeline(
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
+    if plan.ordered_output {
+        max_threads = ordered_output_streams(max_threads, plan, receiver.is_some());
+        max_io_requests = max_threads;
+    }
+
     match block_reader.support_blocking_api() {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
-            let partitions = StealablePartitions::new(partitions, ctx.clone());
+            let mut partitions = StealablePartitions::new(partitions, ctx.clone());
+
+            if plan.ordered_output {
+                partitions.disable_steal();
+            }
 
             match receiver {
                 Some(rx) => {
@@ -201,7 +220,11 @@ pub fn build_fuse_parquet_source_pipeline(
             info!("read block data adjust max io requests:{}", max_io_requests);
 
             let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
-            let partitions = StealablePartitions::new(partitions, ctx.clone());
+            let mut partitions = StealablePartitions::new(partitions, ctx.clone());
+
+            if plan.ordered_output {
+                partitions.disable_steal();
+            }
 
             match receiver {
                 Some(rx) => {
@@ -255,6 +278,10 @@ pub fn build_fuse_parquet_source_pipeline(
         )
     })?;
 
+    if plan.ordered_output && pipeline.output_len() > 1 {
+        pipeline.add_pipe(OrderedMergeProcessor::create_pipe(pipeline.output_len()));
+    }
+
     Ok(())
 }
 
@@ -285,12 +312,32 @@ pub fn dispatch_partitions(
         return results;
     }
 
-    for (i, part) in partitions.iter().enumerate() {
-        results[i % max_streams].push_back(part.clone());
+    if plan.ordered_output {
+        // Each stream reads a contiguous range of the parts, so that the parts are
+        // still in order after merging the streams one by one.
+        let chunk_size = partitions.len().div_ceil(max_streams);
+        for (i, part) in partitions.iter().enumerate() {
+            results[i / chunk_size].push_back(part.clone());
+        }
+    } else {
+        for (i, part) in partitions.iter().enumerate() {
+            results[i % max_streams].push_back(part.clone());
+        }
     }
     results
 }
 
+/// The number of streams to read the parts when the output must keep the order
+/// of the parts. The streams are neither resized nor steal parts from each other.
+fn ordered_output_streams(max_threads: usize, plan: &DataSourcePlan, has_receiver: bool) -> usize {
+    // The lazy parts and the parts sent by the prune pipeline are only known while
+    // reading, a single stream reads them in the order they arrive.
+    if has_receiver || plan.parts.partitions_type() == PartInfoType::LazyLevel {
+        return 1;
+    }
+    max_threads
+}
+
 pub fn adjust_threads_and_request(
     is_native: bool,
     mut max_threads: usize,
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
+
+// The max number of blocks buffered for each input which is not the current one.
+const MAX_BUFFERED_BLOCKS: usize = 4;
+
+/// Merges the streams into one, outputs all the blocks of the first stream,
+/// then all the blocks of the second stream, and so on.
+///
+/// The streams behind the current one are still read in parallel, their blocks
+/// are kept in a bounded reorder buffer until it's their turn.
+pub struct OrderedMergeProcessor {
+    inputs: Vec<Arc<InputPort>>,
+    output: Arc<OutputPort>,
+
+    current: usize,
+    buffers: Vec<VecDeque<DataBlock>>,
+}
+
+impl OrderedMergeProcessor {
+    pub fn create_pipe(num_inputs: usize) -> Pipe {
+        let inputs = (0..num_inputs)
+            .map(|_| InputPort::create())
+            .collect::<Vec<_>>();
+        let output = OutputPort::create();
+
+        let processor = ProcessorPtr::create(Box::new(OrderedMergeProcessor {
+            inputs: inputs.clone(),
+            output: output.clone(),
+            current: 0,
+            buffers: vec![VecDeque::new(); num_inputs],
+        }));
+
+        Pipe::create(num_inputs, 1, vec![PipeItem::create(
+            processor,
+            inputs,
+            vec![output],
+        )])
+    }
+}
+
+impl Processor for OrderedMergeProcessor {
+    fn name(&self) -> String {
+        String::from("OrderedMerge")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            for input in &self.inputs {
+                input.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        for index in self.current..self.inputs.len() {
+            let input = &self.inputs[index];
+            let buffer = &mut self.buffers[index];
+            if input.has_data() && buffer.len() < MAX_BUFFERED_BLOCKS {
+                buffer.push_back(input.pull_data().unwrap()?);
+            }
+            if !input.is_finished() && buffer.len() < MAX_BUFFERED_BLOCKS {
+                input.set_need_data();
+            }
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        while self.current < self.inputs.len() {
+            if let Some(block) = self.buffers[self.current].pop_front() {
+                self.output.push_data(Ok(block));
+                return Ok(Event::NeedConsume);
+            }
+
+            let input = &self.inputs[self.current];
+            if input.has_data() {
+                self.output.push_data(input.pull_data().unwrap());
+                return Ok(Event::NeedConsume);
+            }
+            if !input.is_finished() {
+                input.set_need_data();
+                return Ok(Event::NeedData);
+            }
+            self.current += 1;
+        }
+
+        self.output.finish();
+        Ok(Event::Finished)
+    }
+}
@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::block_idx_in_segment;
 use databend_common_catalog::plan::split_prefix;
 use databend_common_catalog::plan::split_row_id;
@@ -263,6 +265,11 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                 chunks.push(chunk);
             }
         }
+        // The column chunks of the whole blocks are read, not only the fetched rows.
+        Profile::record_usize_profile(
+            ProfileStatisticsName::ScanBytes,
+            chunks.iter().map(BlockReadResult::read_bytes).sum(),
+        );
         let fetched_blocks = chunks
             .into_iter()
             .zip(parts.iter())
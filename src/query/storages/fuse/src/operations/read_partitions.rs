@@ -61,6 +61,7 @@ use crate::pruning::create_segment_location_vector;
 use crate::pruning::table_sample;
 use crate::pruning::BlockPruner;
 use crate::pruning::FusePruner;
+use crate::pruning::PruneLevel;
 use crate::pruning::SegmentLocation;
 use crate::pruning::SegmentPruner;
 use crate::pruning_pipeline::AsyncBlockPruneTransform;
@@ -490,16 +491,24 @@ impl FuseTable {
             None
         };
 
+        let prune_level = if ctx.get_settings().get_enable_fast_pruning()? {
+            PruneLevel::Fast
+        } else {
+            PruneLevel::Full
+        };
         let pruner =
             if !self.is_native() || self.cluster_type().is_none_or(|v| v != ClusterType::Linear) {
-                FusePruner::create(
+                FusePruner::create_with_pages(
                     &ctx,
                     dal,
                     table_schema.clone(),
                     &push_downs,
+                    None,
+                    vec![],
                     self.bloom_index_cols(),
                     bloom_index_builder,
                     self.get_storage_format(),
+                    prune_level,
                 )?
             } else {
                 let cluster_keys = self.linear_cluster_keys(ctx.clone());
@@ -514,6 +523,7 @@ impl FuseTable {
                     self.bloom_index_cols(),
                     bloom_index_builder,
                     self.get_storage_format(),
+                    prune_level,
                 )?
             };
         Ok(pruner)
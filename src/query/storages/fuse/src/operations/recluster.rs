@@ -40,6 +40,7 @@ use crate::operations::acquire_task_permit;
 use crate::operations::mutation::ReclusterMode;
 use crate::operations::ReclusterMutator;
 use crate::pruning::create_segment_location_vector;
+use crate::pruning::PruneLevel;
 use crate::pruning::PruningContext;
 use crate::pruning::SegmentPruner;
 use crate::FuseStorageFormat;
@@ -255,6 +256,7 @@ impl FuseTable {
             max_concurrency,
             bloom_index_builder,
             storage_format,
+            PruneLevel::Full,
         )?;
 
         let segment_pruner = SegmentPruner::create(pruning_ctx.clone(), schema)?;
@@ -213,7 +213,16 @@ impl BlockPruner {
                                     }
                                     let matched_rows = inverted_index_pruner
                                         .should_keep(&block_location.0, row_count)
-                                        .await?;
+                                        .await?
+                                        .filter(|matched_rows| {
+                                            // Intersect with the pages kept by range predicates,
+                                            // the matched rows outside them can't pass the filter.
+                                            any_row_in_page_range(
+                                                matched_rows,
+                                                prune_result.range.as_ref(),
+                                                block_meta.page_size() as usize,
+                                            )
+                                        });
                                     prune_result.keep = matched_rows.is_some();
                                     prune_result.matched_rows = matched_rows;
 
@@ -371,6 +380,21 @@ impl BlockPruner {
     }
 }
 
+// Check whether any of the matched rows is located in the kept page range,
+// `None` range means all the pages of the block are kept.
+fn any_row_in_page_range(
+    matched_rows: &[(usize, Option<F32>)],
+    range: Option<&Range<usize>>,
+    page_size: usize,
+) -> bool {
+    match range {
+        Some(range) if page_size > 0 => matched_rows
+            .iter()
+            .any(|(row, _)| range.contains(&(row / page_size))),
+        _ => !matched_rows.is_empty(),
+    }
+}
+
 // result of block pruning
 struct BlockPruneResult {
     // the block index in segment
@@ -400,3 +424,25 @@ impl BlockPruneResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::F32;
+
+    use super::any_row_in_page_range;
+
+    #[test]
+    fn test_matched_rows_in_page_range() {
+        let matched_rows = vec![(3, None), (17, Some(F32::from(1.0)))];
+
+        // all the pages are kept.
+        assert!(any_row_in_page_range(&matched_rows, None, 8));
+        assert!(!any_row_in_page_range(&[], None, 8));
+
+        // row 3 is in page 0, row 17 is in page 2.
+        assert!(any_row_in_page_range(&matched_rows, Some(&(0..1)), 8));
+        assert!(any_row_in_page_range(&matched_rows, Some(&(2..3)), 8));
+        assert!(!any_row_in_page_range(&matched_rows, Some(&(1..2)), 8));
+        assert!(!any_row_in_page_range(&matched_rows, Some(&(3..5)), 8));
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_storages_common_table_meta::meta::BlockMeta;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use rand::Rng;
+
+/// Samples the blocks of `TABLESAMPLE SYSTEM(n)` with probabilities proportional
+/// to their row counts.
+///
+/// Picking blocks uniformly makes the number of sampled rows depend on which
+/// blocks happen to be picked, which is far from the requested percentage when
+/// there are only a few large blocks. Instead, each block is kept with the
+/// probability `min(1, p * rows / avg_rows)`, where `avg_rows` is the average
+/// block size weighted by rows, so the expected share of the sampled rows is `p`
+/// however skewed the block sizes are. At least one block is always kept, which
+/// oversamples when `p` of the rows is less than one average block.
+pub fn sample_block_metas(
+    block_metas: &Arc<Vec<Arc<BlockMeta>>>,
+    probability: f64,
+) -> Arc<Vec<Arc<BlockMeta>>> {
+    let row_counts = block_metas
+        .iter()
+        .map(|block| block.row_count)
+        .collect::<Vec<_>>();
+    let indices = sample_by_row_counts(&row_counts, probability, &mut thread_rng());
+    Arc::new(
+        indices
+            .into_iter()
+            .map(|index| block_metas[index].clone())
+            .collect(),
+    )
+}
+
+/// Returns the sorted indices of the sampled blocks.
+fn sample_by_row_counts<R: Rng>(row_counts: &[u64], probability: f64, rng: &mut R) -> Vec<usize> {
+    if row_counts.is_empty() {
+        return vec![];
+    }
+
+    // Systematic sampling: the blocks are laid in a random order on a line on
+    // which each one covers the length of its probability, and the blocks which
+    // cover one of the points `u, u + 1, u + 2, ..` are kept. So each block is
+    // kept with exactly its probability, and the sample isn't empty as long as
+    // the probabilities add up to 1.
+    let probabilities = inclusion_probabilities(row_counts, probability);
+    let mut order = (0..row_counts.len()).collect::<Vec<_>>();
+    order.shuffle(rng);
+    let start = rng.gen::<f64>();
+    let mut covered = 0.0;
+    let mut indices = Vec::new();
+    for index in order {
+        let next = covered + probabilities[index];
+        if (next - start).floor() > (covered - start).floor() {
+            indices.push(index);
+        }
+        covered = next;
+    }
+
+    if indices.is_empty() {
+        // Pick a block with a probability proportional to its row count.
+        let total_rows = row_counts.iter().sum::<u64>();
+        let mut point = if total_rows == 0 {
+            0
+        } else {
+            rng.gen_range(0..total_rows)
+        };
+        let index = row_counts
+            .iter()
+            .position(|rows| {
+                if point < *rows {
+                    return true;
+                }
+                point -= rows;
+                false
+            })
+            .unwrap_or(0);
+        indices.push(index);
+    }
+    indices.sort_unstable();
+    indices
+}
+
+/// The probability of each block to be sampled.
+///
+/// A block whose probability exceeds 1 is always kept, and the rows it brings
+/// beyond its share are taken from the target of the other blocks.
+fn inclusion_probabilities(row_counts: &[u64], probability: f64) -> Vec<f64> {
+    let total_rows = row_counts.iter().sum::<u64>() as f64;
+    let mut target_rows = total_rows * probability.clamp(0.0, 1.0);
+    let mut probabilities = vec![0.0; row_counts.len()];
+    let mut capped = vec![false; row_counts.len()];
+    loop {
+        let (rows, squared_rows) = row_counts
+            .iter()
+            .zip(capped.iter())
+            .filter(|(_, capped)| !**capped)
+            .fold((0.0, 0.0), |(rows, squared_rows), (block_rows, _)| {
+                let block_rows = *block_rows as f64;
+                (rows + block_rows, squared_rows + block_rows * block_rows)
+            });
+        if squared_rows == 0.0 || target_rows <= 0.0 {
+            return probabilities;
+        }
+
+        let probability = target_rows / rows;
+        let avg_rows = squared_rows / rows;
+        let mut newly_capped = false;
+        for (index, block_rows) in row_counts.iter().enumerate() {
+            if capped[index] {
+                continue;
+            }
+            let block_probability = probability * *block_rows as f64 / avg_rows;
+            if block_probability >= 1.0 {
+                capped[index] = true;
+                probabilities[index] = 1.0;
+                target_rows -= *block_rows as f64;
+                newly_capped = true;
+            } else {
+                probabilities[index] = block_probability;
+            }
+        }
+        if !newly_capped {
+            return probabilities;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::max;
+
+    use rand::distributions::Bernoulli;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use super::inclusion_probabilities;
+    use super::sample_by_row_counts;
+
+    // The uniform block sampling used before, kept to compare with.
+    fn sample_uniformly(row_counts: &[u64], probability: f64, rng: &mut StdRng) -> Vec<usize> {
+        let indices = (0..row_counts.len()).collect::<Vec<_>>();
+        if row_counts.len() <= 100 {
+            let sample_size = max(1, (row_counts.len() as f64 * probability).round() as usize);
+            indices.choose_multiple(rng, sample_size).cloned().collect()
+        } else {
+            let bernoulli = Bernoulli::new(probability).unwrap();
+            let mut sampled = indices
+                .iter()
+                .filter(|_| bernoulli.sample(rng))
+                .cloned()
+                .collect::<Vec<_>>();
+            if sampled.is_empty() {
+                sampled.push(*indices.choose(rng).unwrap());
+            }
+            sampled
+        }
+    }
+
+    fn sampled_fraction(row_counts: &[u64], indices: &[usize]) -> f64 {
+        let total = row_counts.iter().sum::<u64>() as f64;
+        let sampled = indices.iter().map(|i| row_counts[*i]).sum::<u64>() as f64;
+        sampled / total
+    }
+
+    // Average share of the sampled rows over many rounds.
+    fn mean_fraction(
+        row_counts: &[u64],
+        probability: f64,
+        sample: impl Fn(&[u64], f64, &mut StdRng) -> Vec<usize>,
+    ) -> f64 {
+        let mut rng = StdRng::seed_from_u64(42);
+        let rounds = 2000;
+        (0..rounds)
+            .map(|_| sampled_fraction(row_counts, &sample(row_counts, probability, &mut rng)))
+            .sum::<f64>()
+            / rounds as f64
+    }
+
+    #[test]
+    fn test_sample_by_row_counts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(sample_by_row_counts(&[], 0.5, &mut rng).is_empty());
+        // at least one block is sampled.
+        assert_eq!(sample_by_row_counts(&[10, 10], 0.0, &mut rng).len(), 1);
+        assert_eq!(sample_by_row_counts(&[0, 0], 0.5, &mut rng).len(), 1);
+        assert_eq!(sample_by_row_counts(&[0, 10], 0.0, &mut rng), vec![1]);
+        // all the blocks are sampled.
+        assert_eq!(sample_by_row_counts(&[1, 100, 7], 1.0, &mut rng), vec![
+            0, 1, 2
+        ]);
+        // the indices are sorted.
+        let indices = sample_by_row_counts(&[5; 1000], 0.3, &mut rng);
+        assert!((250..350).contains(&indices.len()), "{}", indices.len());
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_inclusion_probabilities() {
+        // Proportional to the row counts.
+        assert_eq!(inclusion_probabilities(&[100, 300], 0.25), vec![0.1, 0.3]);
+        // The block above 1 is capped, the others share the rest of the target.
+        assert_eq!(inclusion_probabilities(&[800, 100, 100], 0.85), vec![
+            1.0, 0.25, 0.25
+        ]);
+        assert_eq!(inclusion_probabilities(&[1, 100, 7], 1.0), vec![1.0; 3]);
+        assert_eq!(inclusion_probabilities(&[10, 10], 0.0), vec![0.0; 2]);
+        assert_eq!(inclusion_probabilities(&[0, 10], 0.5), vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_sample_skewed_block_sizes() {
+        // A few large blocks and many small ones, the large blocks are sampled
+        // as well as the small ones.
+        let mut row_counts = vec![1_000_000; 5];
+        row_counts.extend(vec![1_000; 5000]);
+        for probability in [0.05, 0.1, 0.15, 0.3, 0.5] {
+            let weighted = mean_fraction(&row_counts, probability, sample_by_row_counts);
+            assert!(
+                (weighted - probability).abs() < 0.01,
+                "{probability}: {weighted}"
+            );
+        }
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!((0..100).any(|_| {
+            sample_by_row_counts(&row_counts, 0.05, &mut rng)
+                .iter()
+                .any(|index| *index < 5)
+        }));
+
+        // Very few large blocks, uniform sampling over/under-shoots the target.
+        let row_counts = [4_000_000, 3_000_000, 2_000_000, 1_000_000];
+        for probability in [0.3, 0.45, 0.6] {
+            let weighted = mean_fraction(&row_counts, probability, sample_by_row_counts);
+            let uniform = mean_fraction(&row_counts, probability, sample_uniformly);
+            assert!(
+                (weighted - probability).abs() < 0.02,
+                "{probability}: {weighted}"
+            );
+            assert!(
+                (weighted - probability).abs() < (uniform - probability).abs(),
+                "{probability}: {weighted} {uniform}"
+            );
+        }
+    }
+}
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::max;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Semaphore;
@@ -49,13 +48,10 @@ use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use log::info;
 use log::warn;
 use opendal::Operator;
-use rand::distributions::Bernoulli;
-use rand::distributions::Distribution;
-use rand::prelude::SliceRandom;
-use rand::thread_rng;
 
 use crate::io::BloomIndexBuilder;
 use crate::operations::DeletedSegmentInfo;
+use crate::pruning::sample_block_metas;
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
@@ -66,7 +62,15 @@ use crate::pruning::SegmentLocation;
 use crate::pruning::VirtualColumnPruner;
 use crate::FuseStorageFormat;
 
-const SMALL_DATASET_SAMPLE_THRESHOLD: usize = 100;
+/// How precisely the blocks are pruned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruneLevel {
+    /// Only the min/max statistics are used, the bloom index is not read. The blocks
+    /// kept are a superset of the ones kept by `Full`, at a lower latency.
+    Fast,
+    /// The min/max statistics and the bloom index are used.
+    Full,
+}
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -98,6 +102,7 @@ impl PruningContext {
         max_concurrency: usize,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
+        prune_level: PruneLevel,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
 
@@ -143,14 +148,18 @@ impl PruningContext {
 
         // Bloom pruner.
         // None will be returned, if filter is not applicable (e.g. unsuitable filter expression, index not available, etc.)
-        let bloom_pruner = BloomPrunerCreator::create(
-            func_ctx.clone(),
-            &table_schema,
-            dal.clone(),
-            filter_expr.as_ref(),
-            bloom_index_cols,
-            bloom_index_builder,
-        )?;
+        // or the pruning level is `Fast`.
+        let bloom_pruner = match prune_level {
+            PruneLevel::Fast => None,
+            PruneLevel::Full => BloomPrunerCreator::create(
+                func_ctx.clone(),
+                &table_schema,
+                dal.clone(),
+                filter_expr.as_ref(),
+                bloom_index_cols,
+                bloom_index_builder,
+            )?,
+        };
 
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
@@ -233,6 +242,7 @@ impl FusePruner {
             bloom_index_cols,
             bloom_index_builder,
             storage_format,
+            PruneLevel::Full,
         )
     }
 
@@ -247,6 +257,7 @@ impl FusePruner {
         bloom_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
+        prune_level: PruneLevel,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -274,6 +285,7 @@ impl FusePruner {
             max_concurrency,
             bloom_index_builder,
             storage_format,
+            prune_level,
         )?;
 
         Ok(FusePruner {
@@ -383,39 +395,7 @@ impl FusePruner {
                             let mut block_metas =
                                 Self::extract_block_metas(&location.location.0, &info, true)?;
                             if let Some(probability) = sample_probability {
-                                if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
-                                    // Deterministic sampling for small datasets
-                                    // Ensure at least one block is sampled for small datasets
-                                    let sample_size = max(
-                                        1,
-                                        (block_metas.len() as f64 * probability).round() as usize,
-                                    );
-                                    let mut rng = thread_rng();
-                                    block_metas = Arc::new(
-                                        block_metas
-                                            .choose_multiple(&mut rng, sample_size)
-                                            .cloned()
-                                            .collect(),
-                                    );
-                                } else {
-                                    // Random sampling for larger datasets
-                                    let mut sample_block_metas =
-                                        Vec::with_capacity(block_metas.len());
-                                    let mut rng = thread_rng();
-                                    let bernoulli = Bernoulli::new(probability).unwrap();
-                                    for block in block_metas.iter() {
-                                        if bernoulli.sample(&mut rng) {
-                                            sample_block_metas.push(block.clone());
-                                        }
-                                    }
-                                    // Ensure at least one block is sampled for large datasets too
-                                    if sample_block_metas.is_empty() && !block_metas.is_empty() {
-                                        // Safe to unwrap, because we've checked that block_metas is not empty
-                                        sample_block_metas
-                                            .push(block_metas.choose(&mut rng).unwrap().clone());
-                                    }
-                                    block_metas = Arc::new(sample_block_metas);
-                                }
+                                block_metas = sample_block_metas(&block_metas, probability);
                             }
                             res.extend(block_pruner.pruning(location.clone(), block_metas).await?);
                         }
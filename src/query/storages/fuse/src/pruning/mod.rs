@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block_pruner;
+mod block_sampler;
 mod bloom_pruner;
 mod fuse_pruner;
 mod inverted_index_pruner;
@@ -22,10 +23,12 @@ mod segment_pruner;
 mod virtual_column_pruner;
 
 pub use block_pruner::BlockPruner;
+pub use block_sampler::sample_block_metas;
 pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::table_sample;
 pub use fuse_pruner::FusePruner;
+pub use fuse_pruner::PruneLevel;
 pub use fuse_pruner::PruningContext;
 pub use inverted_index_pruner::create_inverted_index_query;
 pub use inverted_index_pruner::InvertedIndexPruner;
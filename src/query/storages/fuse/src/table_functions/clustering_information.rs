@@ -137,15 +137,16 @@ pub struct ClusteringInformation<'a> {
     pub cluster_key: Option<String>,
 }
 
-struct ClusteringStatistics {
-    cluster_key: String,
-    cluster_type: String,
-    timestamp: i64,
-    total_block_count: u64,
-    constant_block_count: u64,
-    average_overlaps: f64,
-    average_depth: f64,
-    block_depth_histogram: JsonValue,
+/// The clustering quality metrics of a table.
+pub struct ClusteringMetrics {
+    pub cluster_key: String,
+    pub cluster_type: String,
+    pub timestamp: i64,
+    pub total_block_count: u64,
+    pub constant_block_count: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub block_depth_histogram: JsonValue,
 }
 
 impl<'a> ClusteringInformation<'a> {
@@ -163,6 +164,12 @@ impl<'a> ClusteringInformation<'a> {
 
     #[async_backtrace::framed]
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
+        let info = self.get_clustering_metrics().await?;
+        self.build_block(info)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_clustering_metrics(&self) -> Result<ClusteringMetrics> {
         let mut default_cluster_key_id = None;
         let (cluster_key, exprs) = match (self.table.cluster_key_str(), &self.cluster_key) {
             (a, Some(b)) => {
@@ -217,7 +224,7 @@ impl<'a> ClusteringInformation<'a> {
             .map_or(now, |s| s.timestamp.unwrap_or(now))
             .timestamp_micros();
         if snapshot.is_none() {
-            return self.build_block(ClusteringStatistics {
+            return Ok(ClusteringMetrics {
                 cluster_key,
                 cluster_type,
                 timestamp,
@@ -350,7 +357,7 @@ impl<'a> ClusteringInformation<'a> {
             },
         );
         let block_depth_histogram = JsonValue::Object(objects);
-        let info = ClusteringStatistics {
+        Ok(ClusteringMetrics {
             cluster_key,
             cluster_type,
             timestamp,
@@ -359,12 +366,10 @@ impl<'a> ClusteringInformation<'a> {
             average_overlaps,
             average_depth,
             block_depth_histogram,
-        };
-
-        self.build_block(info)
+        })
     }
 
-    fn build_block(&self, info: ClusteringStatistics) -> Result<DataBlock> {
+    fn build_block(&self, info: ClusteringMetrics) -> Result<DataBlock> {
         Ok(DataBlock::new(
             vec![
                 BlockEntry::new(
@@ -31,7 +31,9 @@ mod table_args;
 
 mod set_cache_capacity;
 
+pub use clustering_information::ClusteringInformation;
 pub use clustering_information::ClusteringInformationFunc;
+pub use clustering_information::ClusteringMetrics;
 pub use clustering_statistics::ClusteringStatisticsFunc;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
@@ -24,6 +24,10 @@ use log::debug;
 pub struct PredicateBuilder;
 
 impl PredicateBuilder {
+    /// Build the iceberg [`Predicate`] used to prune the manifests and data files.
+    ///
+    /// The returned flag is `true` if the expression can't be converted exactly, in that case
+    /// the predicate is relaxed, it may match more rows than the expression but never less.
     pub fn build(expr: &RemoteExpr<String>) -> (bool, Predicate) {
         match expr {
             RemoteExpr::Constant {
@@ -47,17 +51,7 @@ impl PredicateBuilder {
                 generics: _,
                 args,
                 return_type: _,
-            } if args.len() == 1 && id.name().as_ref() == "is_true" => {
-                let (uncertain, predicate) = Self::build(&args[0]);
-                if uncertain {
-                    return (uncertain, Predicate::AlwaysTrue);
-                }
-                match predicate {
-                    Predicate::AlwaysTrue => (false, Predicate::AlwaysTrue),
-                    Predicate::AlwaysFalse => (false, Predicate::AlwaysFalse),
-                    _ => (false, predicate),
-                }
-            }
+            } if args.len() == 1 && id.name().as_ref() == "is_true" => Self::build(&args[0]),
 
             // unary
             RemoteExpr::FunctionCall {
@@ -97,26 +91,45 @@ impl PredicateBuilder {
                 (false, predicate)
             }
 
-            // binary {a op datum}
+            // and: the uncertain side is dropped, the certain side still prunes the files.
+            RemoteExpr::FunctionCall {
+                span: _,
+                id,
+                generics: _,
+                args,
+                return_type: _,
+            } if args.len() >= 2 && ["and", "and_filters"].contains(&id.name().as_ref()) => {
+                let mut uncertain = false;
+                let mut predicate: Option<Predicate> = None;
+                for arg in args {
+                    let (arg_uncertain, arg_predicate) = Self::build(arg);
+                    if arg_uncertain {
+                        uncertain = true;
+                        continue;
+                    }
+                    predicate = Some(match predicate {
+                        Some(predicate) => predicate.and(arg_predicate),
+                        None => arg_predicate,
+                    });
+                }
+                (uncertain, predicate.unwrap_or(Predicate::AlwaysTrue))
+            }
+
+            // or
             RemoteExpr::FunctionCall {
                 span: _,
                 id,
                 generics: _,
                 args,
                 return_type: _,
-            } if args.len() == 2 && ["and", "and_filters", "or"].contains(&id.name().as_ref()) => {
+            } if args.len() == 2 && id.name().as_ref() == "or" => {
                 let (left_uncertain, left) = Self::build(&args[0]);
                 let (right_uncertain, right) = Self::build(&args[1]);
                 if left_uncertain || right_uncertain {
                     return (true, Predicate::AlwaysTrue);
                 }
-                let predicate = match id.name().as_ref() {
-                    "and" | "and_filters" => left.and(right),
-                    "or" => left.or(right),
-                    _ => unreachable!(),
-                };
 
-                (false, predicate)
+                (false, left.or(right))
             }
 
             // binary {a op datum}
@@ -27,7 +27,6 @@ use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
-use databend_common_expression::TableField;
 use databend_common_storage::Datum as DatabendDatum;
 use iceberg::spec::DataContentType;
 use iceberg::spec::Datum;
@@ -164,10 +163,16 @@ impl IcebergStatistics {
             });
         }
 
+        // The columns are matched by their iceberg field ids, which are kept unchanged
+        // when the columns are renamed, reordered or dropped.
+        let iceberg_schema = table.metadata().current_schema();
         let mut computed_statistics = HashMap::new();
         for field in IcebergTable::get_schema(table)?.fields() {
+            let Some(iceberg_field_id) = iceberg_schema.field_id_by_name(field.name()) else {
+                continue;
+            };
             let column_stats = get_column_stats(
-                field,
+                iceberg_field_id,
                 &column_sizes,
                 &lower_bounds,
                 &upper_bounds,
@@ -193,14 +198,12 @@ impl ColumnStatisticsProvider for IcebergStatistics {
 
 /// Try get [`ColumnStatistics`] for one column.
 fn get_column_stats(
-    field: &TableField,
+    iceberg_col_id: i32,
     _column_size: &HashMap<i32, u64>,
     lower: &HashMap<i32, Datum>,
     upper: &HashMap<i32, Datum>,
     null_counts: &HashMap<i32, u64>,
 ) -> BasicColumnStatistics {
-    // The column id in iceberg is 1-based while the column id in Databend is 0-based.
-    let iceberg_col_id = field.column_id as i32 + 1;
     BasicColumnStatistics {
         min: lower
             .get(&iceberg_col_id)
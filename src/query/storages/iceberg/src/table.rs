@@ -268,6 +268,7 @@ impl IcebergTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let mut scan = self.table.scan();
+        let mut has_filter = false;
 
         if let Some(push_downs) = &push_downs {
             if let Some(projection) = &push_downs.projection {
@@ -281,7 +282,8 @@ impl IcebergTable {
             }
             if let Some(filter) = &push_downs.filters {
                 let (_, predicate) = PredicateBuilder::build(&filter.filter);
-                scan = scan.with_filter(predicate)
+                scan = scan.with_filter(predicate);
+                has_filter = true;
             }
         }
 
@@ -297,7 +299,6 @@ impl IcebergTable {
 
         let mut read_rows = 0;
         let mut read_bytes = 0;
-        let total_files = tasks.len();
         let parts: Vec<_> = tasks
             .into_iter()
             .map(|v: iceberg::scan::FileScanTask| {
@@ -307,8 +308,18 @@ impl IcebergTable {
             })
             .collect();
 
+        // The data files are pruned by the scan planning, with the partition values and
+        // the min/max stats of the manifests, the same snapshot as the table statistics.
+        let total_files = self.statistics.number_of_data_files as usize;
+        let mut statistics =
+            PartStatistics::new_exact(read_rows, read_bytes, parts.len(), total_files);
+        if has_filter {
+            statistics.pruning_stats.blocks_range_pruning_before = total_files;
+            statistics.pruning_stats.blocks_range_pruning_after = parts.len();
+        }
+
         Ok((
-            PartStatistics::new_exact(read_rows, read_bytes, parts.len(), total_files),
+            statistics,
             Partitions::create(PartitionsShuffleKind::Mod, parts),
         ))
     }
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use jsonb::Value as JsonbValue;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An object accessed by a query, with the columns read or written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessObject {
+    pub object_type: String,
+    pub database: String,
+    pub name: String,
+    pub columns: Vec<String>,
+}
+
+impl AccessObject {
+    pub fn new_table(database: &str, name: &str, columns: Vec<String>) -> Self {
+        AccessObject {
+            object_type: "table".to_string(),
+            database: database.to_string(),
+            name: name.to_string(),
+            columns,
+        }
+    }
+
+    /// Merges the objects with the same type, database and name, the columns of the
+    /// merged objects are deduplicated and sorted.
+    pub fn merge(objects: impl IntoIterator<Item = AccessObject>) -> Vec<AccessObject> {
+        let mut merged: BTreeMap<(String, String, String), Vec<String>> = BTreeMap::new();
+        for object in objects {
+            merged
+                .entry((object.object_type, object.database, object.name))
+                .or_default()
+                .extend(object.columns);
+        }
+        merged
+            .into_iter()
+            .map(|((object_type, database, name), mut columns)| {
+                columns.sort();
+                columns.dedup();
+                AccessObject {
+                    object_type,
+                    database,
+                    name,
+                    columns,
+                }
+            })
+            .collect()
+    }
+
+    fn to_jsonb_value(&self) -> JsonbValue<'static> {
+        JsonbValue::Object(BTreeMap::from([
+            (
+                "object_type".to_string(),
+                JsonbValue::String(self.object_type.clone().into()),
+            ),
+            (
+                "database".to_string(),
+                JsonbValue::String(self.database.clone().into()),
+            ),
+            (
+                "name".to_string(),
+                JsonbValue::String(self.name.clone().into()),
+            ),
+            (
+                "columns".to_string(),
+                JsonbValue::Array(
+                    self.columns
+                        .iter()
+                        .map(|column| JsonbValue::String(column.clone().into()))
+                        .collect(),
+                ),
+            ),
+        ]))
+    }
+}
+
+fn objects_to_variant(objects: &[AccessObject]) -> Scalar {
+    let value = JsonbValue::Array(objects.iter().map(AccessObject::to_jsonb_value).collect());
+    Scalar::Variant(value.to_vec())
+}
+
+/// The objects accessed by a `SELECT`, `INSERT`, `UPDATE`, `DELETE` or `MERGE` query.
+///
+/// The tables read through the views are only in `base_objects_accessed`, while the other
+/// tables read by the query are in both `base_objects_accessed` and `direct_objects_accessed`.
+#[derive(Clone)]
+pub struct AccessHistoryLogElement {
+    pub query_id: String,
+    pub query_start_time: i64,
+    pub user_name: String,
+    pub base_objects_accessed: Vec<AccessObject>,
+    pub direct_objects_accessed: Vec<AccessObject>,
+    pub objects_modified: Vec<AccessObject>,
+}
+
+impl SystemLogElement for AccessHistoryLogElement {
+    const TABLE_NAME: &'static str = "access_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_start_time", TableDataType::Timestamp),
+            TableField::new("user_name", TableDataType::String),
+            TableField::new("accessed_objects", TableDataType::Variant),
+            TableField::new("objects_modified", TableDataType::Variant),
+            TableField::new("base_objects_accessed", TableDataType::Variant),
+            TableField::new("direct_objects_accessed", TableDataType::Variant),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let accessed_objects = AccessObject::merge(
+            self.base_objects_accessed
+                .iter()
+                .chain(self.objects_modified.iter())
+                .cloned(),
+        );
+
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.query_start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.user_name.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(objects_to_variant(&accessed_objects).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(objects_to_variant(&self.objects_modified).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(objects_to_variant(&self.base_objects_accessed).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(objects_to_variant(&self.direct_objects_accessed).as_ref());
+        Ok(())
+    }
+}
+
+pub type AccessHistoryQueue = SystemLogQueue<AccessHistoryLogElement>;
+pub type AccessHistoryTable = SystemLogTable<AccessHistoryLogElement>;
@@ -0,0 +1,245 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::types::F64;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::table_functions::ClusteringInformation;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::ClusterType;
+use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
+use jsonb::Value as JsonbValue;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// Reports the clustering quality of the linear clustered fuse tables, one row per table,
+/// the same metrics as the `clustering_information` table function.
+///
+/// A high `average_depth` or `average_overlaps` means the table is worth reclustering.
+pub struct ClusteringInformationTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ClusteringInformationTable {
+    const NAME: &'static str = "system.clustering_information";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let visibility_checker = ctx.get_visibility_checker(false).await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let ctl_name = catalog.name();
+
+        // Computing the metrics reads all the segments, so only the filtered tables are visited.
+        let mut filtered_db_names = vec![];
+        let mut filtered_table_names = vec![];
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.filters.as_ref())
+        {
+            let expr = filter.filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(name) = scalar {
+                    if col_name == "database" && !filtered_db_names.contains(name) {
+                        filtered_db_names.push(name.clone());
+                    } else if col_name == "table" && !filtered_table_names.contains(name) {
+                        filtered_table_names.push(name.clone());
+                    }
+                }
+                Ok(())
+            });
+        }
+
+        let dbs = match catalog.list_databases(&tenant).await {
+            Ok(dbs) => dbs
+                .into_iter()
+                .filter(|db| {
+                    (filtered_db_names.is_empty()
+                        || filtered_db_names.iter().any(|name| name == db.name()))
+                        && visibility_checker.check_database_visibility(
+                            &ctl_name,
+                            db.name(),
+                            db.get_db_info().database_id.db_id,
+                        )
+                })
+                .collect::<Vec<_>>(),
+            Err(err) => {
+                let msg = format!("List databases failed on catalog {}: {}", ctl_name, err);
+                warn!("{}", msg);
+                ctx.push_warning(msg);
+
+                vec![]
+            }
+        };
+
+        let mut databases = vec![];
+        let mut tables = vec![];
+        let mut cluster_keys = vec![];
+        let mut total_block_counts = vec![];
+        let mut total_constant_blocks = vec![];
+        let mut average_overlaps = vec![];
+        let mut average_depths = vec![];
+        let mut block_depth_histograms = vec![];
+        for db in dbs {
+            let db_id = db.get_db_info().database_id.db_id;
+            let db_name = db.name();
+
+            let db_tables = match catalog.list_tables(&tenant, db_name).await {
+                Ok(tables) => tables,
+                Err(err) => {
+                    let msg = format!("Failed to list tables in database: {}, {}", db_name, err);
+                    warn!("{}", msg);
+                    ctx.push_warning(msg);
+                    continue;
+                }
+            };
+            for table in db_tables {
+                if (!filtered_table_names.is_empty()
+                    && !filtered_table_names.iter().any(|name| name == table.name()))
+                    || !visibility_checker.check_table_visibility(
+                        &ctl_name,
+                        db_name,
+                        table.name(),
+                        db_id,
+                        table.get_id(),
+                    )
+                {
+                    continue;
+                }
+
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                if fuse_table.cluster_key_str().is_none()
+                    || fuse_table.get_option(OPT_KEY_CLUSTER_TYPE, ClusterType::Linear)
+                        == ClusterType::Hilbert
+                {
+                    continue;
+                }
+
+                let metrics = match ClusteringInformation::new(ctx.clone(), fuse_table, None)
+                    .get_clustering_metrics()
+                    .await
+                {
+                    Ok(metrics) => metrics,
+                    Err(err) => {
+                        let msg = format!(
+                            "Failed to get clustering information of table: {}.{}, {}",
+                            db_name,
+                            table.name(),
+                            err
+                        );
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+                        continue;
+                    }
+                };
+
+                databases.push(db_name.to_string());
+                tables.push(table.name().to_string());
+                cluster_keys.push(metrics.cluster_key);
+                total_block_counts.push(metrics.total_block_count);
+                total_constant_blocks.push(metrics.constant_block_count);
+                average_overlaps.push(F64::from(metrics.average_overlaps));
+                average_depths.push(F64::from(metrics.average_depth));
+                block_depth_histograms
+                    .push(JsonbValue::from(&metrics.block_depth_histogram).to_vec());
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(cluster_keys),
+            UInt64Type::from_data(total_block_counts),
+            UInt64Type::from_data(total_constant_blocks),
+            Float64Type::from_data(average_overlaps),
+            Float64Type::from_data(average_depths),
+            VariantType::from_data(block_depth_histograms),
+        ]))
+    }
+}
+
+impl ClusteringInformationTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("cluster_key", TableDataType::String),
+            TableField::new(
+                "total_block_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "total_constant_blocks",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "average_overlaps",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new(
+                "average_depth",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("block_depth_histogram", TableDataType::Variant),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'clustering_information'".to_string(),
+            name: "clustering_information".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemClusteringInformation".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -42,6 +42,7 @@ use log::warn;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
+use crate::util::NameFilter;
 
 pub struct ColumnsTable {
     table_info: TableInfo,
@@ -147,6 +148,8 @@ impl ColumnsTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<Vec<(String, String, String, TableField)>> {
+        // `SHOW COLUMNS FROM t LIKE 'pattern'`
+        let filter = NameFilter::from_push_downs(push_downs.as_ref(), "name");
         let database_and_tables = dump_tables(&ctx, push_downs).await?;
 
         let mut rows: Vec<(String, String, String, TableField)> = vec![];
@@ -174,6 +177,9 @@ impl ColumnsTable {
                             vec![]
                         };
                         for field in fields {
+                            if !filter.matches(field.name()) {
+                                continue;
+                            }
                             rows.push((
                                 database.clone(),
                                 table.name().into(),
@@ -187,6 +193,9 @@ impl ColumnsTable {
                         match stream.source_table(ctx.clone()).await {
                             Ok(source_table) => {
                                 for field in source_table.schema().fields() {
+                                    if !filter.matches(field.name()) {
+                                        continue;
+                                    }
                                     rows.push((
                                         database.clone(),
                                         table.name().into(),
@@ -209,6 +218,9 @@ impl ColumnsTable {
                         let field_comments = table.field_comments();
                         let n_fields = schema.fields().len();
                         for (idx, field) in schema.fields().iter().enumerate() {
+                            if !filter.matches(field.name()) {
+                                continue;
+                            }
                             // compatibility: creating table in the old planner will not have `fields_comments`
                             let comment = if field_comments.len() == n_fields
                                 && !field_comments[idx].is_empty()
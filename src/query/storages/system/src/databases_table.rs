@@ -20,6 +20,7 @@ use databend_common_catalog::database::Database;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
@@ -30,6 +31,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::TableIdent;
@@ -41,6 +43,7 @@ use log::warn;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::util::NameFilter;
 
 pub type DatabasesTableWithHistory = DatabasesTable<true>;
 pub type DatabasesTableWithoutHistory = DatabasesTable<false>;
@@ -84,6 +87,38 @@ macro_rules! impl_history_aware {
 impl_history_aware!(true, "databases_with_history");
 impl_history_aware!(false, "databases");
 
+/// Filters on `catalog` and `name` pushed down from the query, e.g. by
+/// `SHOW DATABASES LIKE 'pattern'`. Databases which can't match are skipped before
+/// their metadata and ownership are loaded.
+#[derive(Default)]
+struct DatabasesFilter {
+    catalogs: NameFilter,
+    names: NameFilter,
+}
+
+impl DatabasesFilter {
+    fn from_push_downs(push_downs: Option<&PushDownInfo>) -> Self {
+        match push_downs.and_then(|push_downs| push_downs.filters.as_ref()) {
+            Some(filters) => {
+                let expr = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+                DatabasesFilter {
+                    catalogs: NameFilter::from_expr(&expr, "catalog"),
+                    names: NameFilter::from_expr(&expr, "name"),
+                }
+            }
+            None => DatabasesFilter::default(),
+        }
+    }
+
+    fn match_catalog(&self, catalog: &str) -> bool {
+        self.catalogs.matches(catalog)
+    }
+
+    fn match_name(&self, name: &str) -> bool {
+        self.names.matches(name)
+    }
+}
+
 #[async_trait::async_trait]
 impl<const WITH_HISTORY: bool> AsyncSystemTable for DatabasesTable<WITH_HISTORY>
 where DatabasesTable<WITH_HISTORY>: HistoryAware
@@ -98,15 +133,17 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
     async fn get_full_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
+        let filter = DatabasesFilter::from_push_downs(push_downs.as_ref());
 
         let catalogs = CatalogManager::instance();
         let catalogs: Vec<(String, Arc<dyn Catalog>)> = catalogs
             .list_catalogs(&tenant, ctx.session_state())
             .await?
             .iter()
+            .filter(|e| filter.match_catalog(&e.name()))
             .map(|e| (e.name(), e.clone()))
             .collect();
 
@@ -123,6 +160,9 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
         if let Some(catalog_dbs) = catalog_dbs {
             if WITH_HISTORY {
                 for (ctl_name, dbs) in catalog_dbs {
+                    if !filter.match_catalog(ctl_name) {
+                        continue;
+                    }
                     let catalog = ctx.get_catalog(ctl_name).await?;
                     let dbs_history = catalog.list_databases_history(&tenant).await?;
                     for db_history in dbs_history {
@@ -132,7 +172,7 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
                             .database_name()
                             .to_string();
                         let id = db_history.get_db_info().database_id.db_id;
-                        if db_ids.contains(&id) {
+                        if db_ids.contains(&id) || !filter.match_name(&db_name) {
                             continue;
                         }
                         if dbs.contains(&(None, Some(&id)))
@@ -164,6 +204,9 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
                 }
             } else {
                 for (catalog, dbs) in catalog_dbs {
+                    if !filter.match_catalog(catalog) {
+                        continue;
+                    }
                     let mut catalog_db_ids = vec![];
                     let mut catalog_db_names = vec![];
                     let ctl = ctx.get_catalog(catalog).await?;
@@ -185,6 +228,7 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
                     }
                     let db_idents = catalog_db_names
                         .iter()
+                        .filter(|name| filter.match_name(name))
                         .map(|name| DatabaseNameIdent::new(&tenant, name))
                         .collect::<Vec<DatabaseNameIdent>>();
                     let dbs = ctl.mget_databases(&tenant, &db_idents).await?;
@@ -214,9 +258,23 @@ where DatabasesTable<WITH_HISTORY>: HistoryAware
             }
         } else {
             for (ctl_name, catalog) in catalogs.into_iter() {
-                let databases = Self::list_databases(&catalog, &tenant, WITH_HISTORY).await?;
+                let databases = if !WITH_HISTORY && !filter.names.is_empty() {
+                    // Point lookups of the pushed down names avoid listing all the databases.
+                    let mut databases = Vec::with_capacity(filter.names.len());
+                    for name in filter.names.iter() {
+                        match catalog.get_database(&tenant, name).await {
+                            Ok(db) => databases.push(db),
+                            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    databases
+                } else {
+                    Self::list_databases(&catalog, &tenant, WITH_HISTORY).await?
+                };
                 let final_dbs = databases
                     .into_iter()
+                    .filter(|db| filter.match_name(db.name()))
                     .filter(|db| {
                         visibility_checker.check_database_visibility(
                             &ctl_name,
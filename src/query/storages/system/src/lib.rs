@@ -20,6 +20,7 @@
 
 extern crate core;
 
+mod access_history_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -27,6 +28,7 @@ mod build_options_table;
 mod caches_table;
 mod catalogs_table;
 mod clustering_history_table;
+mod clustering_information_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
@@ -44,6 +46,7 @@ mod malloc_stats_table;
 #[cfg(feature = "jemalloc")]
 mod malloc_stats_totals_table;
 mod metrics_table;
+mod network_policies_table;
 mod notification_history_table;
 mod notifications_table;
 mod one_table;
@@ -58,6 +61,7 @@ mod settings_table;
 mod stages_table;
 mod streams_table;
 mod table;
+mod table_constraints_table;
 mod table_functions_table;
 mod tables_table;
 mod task_history_table;
@@ -69,6 +73,10 @@ mod users_table;
 mod util;
 mod virtual_columns_table;
 
+pub use access_history_table::AccessHistoryLogElement;
+pub use access_history_table::AccessHistoryQueue;
+pub use access_history_table::AccessHistoryTable;
+pub use access_history_table::AccessObject;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -78,6 +86,7 @@ pub use catalogs_table::CatalogsTable;
 pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
+pub use clustering_information_table::ClusteringInformationTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
@@ -99,6 +108,7 @@ pub use malloc_stats_table::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
+pub use network_policies_table::NetworkPoliciesTable;
 pub use notification_history_table::NotificationHistoryTable;
 pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
@@ -121,6 +131,7 @@ pub use streams_table::FullStreamsTable;
 pub use streams_table::TerseStreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
+pub use table_constraints_table::TableConstraintsTable;
 pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct NetworkPoliciesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for NetworkPoliciesTable {
+    const NAME: &'static str = "system.network_policies";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let network_policies = UserApiProvider::instance()
+            .get_network_policies(&tenant)
+            .await?;
+
+        let mut names = Vec::with_capacity(network_policies.len());
+        let mut allowed_ip_lists_builder = ColumnBuilder::with_capacity(
+            &DataType::Array(Box::new(DataType::String)),
+            network_policies.len(),
+        );
+        let mut blocked_ip_lists_builder = ColumnBuilder::with_capacity(
+            &DataType::Array(Box::new(DataType::String)),
+            network_policies.len(),
+        );
+        let mut comments = Vec::with_capacity(network_policies.len());
+        let mut created_on_columns = Vec::with_capacity(network_policies.len());
+        let mut updated_on_columns = Vec::with_capacity(network_policies.len());
+        for network_policy in network_policies {
+            names.push(network_policy.name.clone());
+            allowed_ip_lists_builder.push(ScalarRef::Array(StringType::from_data(
+                network_policy.allowed_ip_list.clone(),
+            )));
+            blocked_ip_lists_builder.push(ScalarRef::Array(StringType::from_data(
+                network_policy.blocked_ip_list.clone(),
+            )));
+            comments.push(network_policy.comment.clone());
+            created_on_columns.push(network_policy.create_on.timestamp_micros());
+            updated_on_columns.push(network_policy.update_on.map(|u| u.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            allowed_ip_lists_builder.build(),
+            blocked_ip_lists_builder.build(),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_opt_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl NetworkPoliciesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "allowed_ip_list",
+                TableDataType::Array(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "blocked_ip_list",
+                TableDataType::Array(Box::new(TableDataType::String)),
+            ),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'network_policies'".to_string(),
+            name: "network_policies".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemNetworkPolicies".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(NetworkPoliciesTable { table_info })
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableConstraintType;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::TableContext;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Lists the constraints declared on the tables, the constraints are informational
+/// only and not enforced.
+pub struct TableConstraintsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableConstraintsTable {
+    const NAME: &'static str = "system.table_constraints";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let visibility_checker = ctx.get_visibility_checker(false).await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let ctl_name = catalog.name();
+
+        let dbs = match catalog.list_databases(&tenant).await {
+            Ok(dbs) => dbs
+                .into_iter()
+                .filter(|db| {
+                    visibility_checker.check_database_visibility(
+                        &ctl_name,
+                        db.name(),
+                        db.get_db_info().database_id.db_id,
+                    )
+                })
+                .collect::<Vec<_>>(),
+            Err(err) => {
+                let msg = format!("List databases failed on catalog {}: {}", ctl_name, err);
+                warn!("{}", msg);
+                ctx.push_warning(msg);
+
+                vec![]
+            }
+        };
+
+        let mut catalogs = vec![];
+        let mut schemas = vec![];
+        let mut names = vec![];
+        let mut table_names = vec![];
+        let mut types = vec![];
+        let mut enforced = vec![];
+        for db in dbs {
+            let db_id = db.get_db_info().database_id.db_id;
+            let db_name = db.name();
+
+            let tables = match catalog.list_tables(&tenant, db_name).await {
+                Ok(tables) => tables,
+                Err(err) => {
+                    let msg = format!("Failed to list tables in database: {}, {}", db_name, err);
+                    warn!("{}", msg);
+                    ctx.push_warning(msg);
+                    continue;
+                }
+            };
+            for table in tables {
+                let table_info = table.get_table_info();
+                if table_info.meta.constraints.is_empty()
+                    || !visibility_checker.check_table_visibility(
+                        &ctl_name,
+                        db_name,
+                        table.name(),
+                        db_id,
+                        table.get_id(),
+                    )
+                {
+                    continue;
+                }
+
+                for constraint in table_info.meta.constraints.values() {
+                    catalogs.push(ctl_name.clone());
+                    schemas.push(db_name.to_string());
+                    names.push(constraint.name.clone());
+                    table_names.push(table.name().to_string());
+                    types.push(constraint.constraint_type.to_string());
+                    // Only the check constraints are enforced when the rows are written.
+                    enforced.push(
+                        if constraint.constraint_type == TableConstraintType::Check {
+                            "YES"
+                        } else {
+                            "NO"
+                        },
+                    );
+                }
+            }
+        }
+
+        let rows = names.len();
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(schemas.clone()),
+            StringType::from_data(names),
+            StringType::from_data(schemas),
+            StringType::from_data(table_names),
+            StringType::from_data(types),
+            StringType::from_data(vec!["NO"; rows]),
+            StringType::from_data(vec!["NO"; rows]),
+            StringType::from_data(enforced),
+        ]))
+    }
+}
+
+impl TableConstraintsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("constraint_catalog", TableDataType::String),
+            TableField::new("constraint_schema", TableDataType::String),
+            TableField::new("constraint_name", TableDataType::String),
+            TableField::new("table_schema", TableDataType::String),
+            TableField::new("table_name", TableDataType::String),
+            TableField::new("constraint_type", TableDataType::String),
+            TableField::new("is_deferrable", TableDataType::String),
+            TableField::new("initially_deferred", TableDataType::String),
+            TableField::new("enforced", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_constraints'".to_string(),
+            name: "table_constraints".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableConstraints".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
+use databend_common_expression::filter::generate_like_pattern;
+use databend_common_expression::filter::LikePattern;
 use databend_common_expression::Expr;
 use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 
 pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar) -> Result<()>) {
     match expr {
@@ -49,6 +53,30 @@ pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scala
     }
 }
 
+pub fn find_like_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar)) {
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
+        Expr::Cast { expr, .. } => find_like_filter(expr, visitor),
+        Expr::FunctionCall { function, args, .. } => {
+            if function.signature.name == "like" {
+                if let [Expr::ColumnRef { id, .. }, Expr::Constant { scalar, .. }] = args.as_slice()
+                {
+                    visitor(id, scalar);
+                }
+            } else if function.signature.name == "and_filters" {
+                for arg in args {
+                    find_like_filter(arg, visitor)
+                }
+            }
+        }
+        Expr::LambdaFunctionCall { args, .. } => {
+            for arg in args {
+                find_like_filter(arg, visitor)
+            }
+        }
+    }
+}
+
 pub fn find_gt_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar)) {
     match expr {
         Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
@@ -222,3 +250,51 @@ pub fn find_eq_or_filter(
     inner(expr, visitor, &mut invalid_optimize);
     invalid_optimize
 }
+
+/// The `=` and `LIKE` filters on a string column pushed down from the query, e.g. by
+/// `SHOW DATABASES LIKE 'pattern'`. The rows which can't match are skipped before
+/// they are built.
+#[derive(Default)]
+pub struct NameFilter {
+    names: Vec<String>,
+    patterns: Vec<LikePattern<'static>>,
+}
+
+impl NameFilter {
+    pub fn from_push_downs(push_downs: Option<&PushDownInfo>, column: &str) -> Self {
+        match push_downs.and_then(|push_downs| push_downs.filters.as_ref()) {
+            Some(filters) => Self::from_expr(&filters.filter.as_expr(&BUILTIN_FUNCTIONS), column),
+            None => NameFilter::default(),
+        }
+    }
+
+    pub fn from_expr(expr: &Expr<String>, column: &str) -> Self {
+        let mut filter = NameFilter::default();
+        find_eq_filter(expr, &mut |col_name, scalar| {
+            if let Scalar::String(value) = scalar {
+                if col_name == column && !filter.names.contains(value) {
+                    filter.names.push(value.clone());
+                }
+            }
+            Ok(())
+        });
+        find_like_filter(expr, &mut |col_name, scalar| {
+            if let Scalar::String(pattern) = scalar {
+                if col_name == column {
+                    filter
+                        .patterns
+                        .push(generate_like_pattern(pattern.as_bytes().to_vec(), 1));
+                }
+            }
+        });
+        filter
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        (self.names.is_empty() || self.names.iter().any(|n| n == name))
+            && self
+                .patterns
+                .iter()
+                .all(|pattern| pattern.compare(name.as_bytes()))
+    }
+}
@@ -301,6 +301,7 @@ impl QueryVisitor {
                 low: Box::new(self.fuzz_expr(low)),
                 high: Box::new(self.fuzz_expr(high)),
                 not: self.rng.gen_bool(0.5),
+                symmetric: self.rng.gen_bool(0.5),
             },
             Expr::BinaryOp {
                 span,
@@ -794,6 +795,7 @@ impl QueryVisitor {
                     units,
                     start_bound,
                     end_bound,
+                    exclusion: None,
                 };
                 window_spec.window_frame = Some(window_frame);
             }
@@ -413,6 +413,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                         low: Box::new(self.gen_expr(&expr_ty)),
                         high: Box::new(self.gen_expr(&expr_ty)),
                         not: self.rng.gen_bool(0.5),
+                        symmetric: self.rng.gen_bool(0.5),
                     }
                 }
                 4..=6 => self.gen_binary_expr(),
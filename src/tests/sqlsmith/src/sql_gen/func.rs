@@ -728,6 +728,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                     units: WindowFrameUnits::Rows,
                     start_bound: WindowFrameBound::Preceding(None),
                     end_bound: WindowFrameBound::CurrentRow,
+                    exclusion: None,
                 })
             },
         }
@@ -146,6 +146,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             span: None,
             hints: None,
             distinct: false,
+            distinct_on: None,
             top_n: None,
             select_list,
             from,
@@ -207,6 +208,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             alias,
             materialized,
             query: Box::new(subquery),
+            cycle: None,
         }
     }
 
@@ -345,6 +347,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             // TODO
             hints: None,
             distinct: self.rng.gen_bool(0.7),
+            distinct_on: None,
             top_n: None,
             select_list,
             from,
@@ -381,6 +384,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             // TODO
             hints: None,
             distinct: false,
+            distinct_on: None,
             top_n: None,
             select_list,
             from,
@@ -167,4 +167,21 @@ for table_name, (schema, file_path) in tables.items():
     df.write.format("iceberg").mode("overwrite").save(full_table_name)
     print(f"table {full_table_name} has been created")
 
+# A partitioned table with an evolved schema, the data files written before the evolution
+# keep the old column names, they are read through the iceberg field ids.
+spark.sql(
+    """
+    CREATE OR REPLACE TABLE iceberg.tpch.events (
+        id int, category string, amount int
+    ) USING iceberg PARTITIONED BY (category);
+    """
+)
+spark.sql(
+    "INSERT INTO iceberg.tpch.events VALUES (1, 'a', 10), (2, 'a', 20), (3, 'b', 30), (4, 'c', 40)"
+)
+spark.sql("ALTER TABLE iceberg.tpch.events RENAME COLUMN amount TO price")
+spark.sql("ALTER TABLE iceberg.tpch.events ADD COLUMN note string")
+spark.sql("INSERT INTO iceberg.tpch.events VALUES (5, 'b', 50, 'new')")
+print("table iceberg.tpch.events has been created")
+
 spark.stop()
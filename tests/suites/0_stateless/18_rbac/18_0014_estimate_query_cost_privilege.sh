@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+export TEST_USER_PASSWORD="password"
+export USER_A_CONNECT="bendsql --user=a --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+
+echo "drop user if exists a" | $BENDSQL_CLIENT_CONNECT
+echo "create user a identified by '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "create or replace database estimate_db" | $BENDSQL_CLIENT_CONNECT
+echo "create table estimate_db.t(c1 int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into estimate_db.t values(1)" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== estimate without select privilege ==="
+echo "select system\$estimate_query_cost('select * from estimate_db.t') is not null" | $USER_A_CONNECT
+
+echo "=== estimate with select privilege ==="
+echo "grant select on estimate_db.t to a" | $BENDSQL_CLIENT_CONNECT
+echo "select system\$estimate_query_cost('select * from estimate_db.t') is not null" | $USER_A_CONNECT
+
+echo "drop database estimate_db" | $BENDSQL_CLIENT_CONNECT
+echo "drop user a" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Skip in cluster mode, only the standalone node is restarted
+port_check=$(sudo lsof -i :9093)
+if [ -n "$port_check" ]; then
+    echo "1"
+    exit 0
+fi
+
+ROOTDIR="$(cd "$(dirname "${BASH_SOURCE[0]}")"/../../../../ && pwd)"
+
+restart_query() {
+    killall -9 databend-query > /dev/null 2>&1
+    sleep 1
+    env "RUST_BACKTRACE=1" nohup "$ROOTDIR"/target/${BUILD_PROFILE:-debug}/databend-query -c "$ROOTDIR"/scripts/ci/deploy/config/databend-query-node-1.toml --internal-enable-sandbox-tenant "$@" > "$ROOTDIR"/.databend/query-1.out 2>&1 &
+    python3 "$ROOTDIR"/scripts/ci/wait_tcp.py --timeout 30 --port 8000 > /dev/null 2>&1
+}
+
+restart_query --log-history-on --log-history-interval=1
+
+echo "select 'query_history_restart_marker'" | $BENDSQL_CLIENT_CONNECT > /dev/null
+
+# wait for the background flush
+sleep 5
+
+# the logs of the queries before the restart are still in the history
+restart_query --log-history-on --log-history-interval=1
+
+echo "select count(*) from system_history.query_history where query_text like '%query_history_restart_marker%' and log_type_name = 'Finish'" | $BENDSQL_CLIENT_CONNECT
+
+# back to the default config
+restart_query
+
+echo "drop database if exists system_history" | $BENDSQL_CLIENT_CONNECT